@@ -27,18 +27,61 @@ class FileType(Enum):
     FILE_TYPE_UNSPECIFIED = 0
     FILE_TYPE_PARQUET = 1
     FILE_TYPE_CSV = 2
+    FILE_TYPE_ARROW = 3
+    FILE_TYPE_ARROW_STREAM = 4
+    FILE_TYPE_JSON = 5
+
+
+class TimeBucket(Enum):
+    TIME_BUCKET_UNSPECIFIED = 0
+    TIME_BUCKET_HOUR = 1
+    TIME_BUCKET_DAY = 2
+    TIME_BUCKET_MONTH = 3
+    TIME_BUCKET_YEAR = 4
+
+
+class Partitioning(object):
+    def __init__(
+        self,
+        time_bucket: TimeBucket = TimeBucket.TIME_BUCKET_UNSPECIFIED,
+        key_buckets: int = 0,
+    ):
+        """
+        Partitioning of object store output into Hive-style directories
+
+        Args:
+            time_bucket (TimeBucket): the size of the buckets of `_time` to partition by. defaults to no time partitioning.
+            key_buckets (int): the number of buckets to partition the entity keys into, at most 1024. defaults to no key partitioning.
+        """
+        self._time_bucket = time_bucket
+        self._key_buckets = key_buckets
+
+    def to_request(self) -> Dict[str, Any]:
+        return {
+            "time_bucket": self._time_bucket.name,
+            "key_buckets": self._key_buckets,
+        }
 
 
 class ObjectStoreDestination(Destination):
-    def __init__(self, file_type: FileType, output_prefix_uri: str):
+    def __init__(
+        self,
+        file_type: FileType,
+        output_prefix_uri: str,
+        partitioning: Optional[Partitioning] = None,
+    ):
         self._file_type = file_type
         self._output_prefix_uri = output_prefix_uri
+        self._partitioning = partitioning
 
     def to_request(self) -> Dict[str, Any]:
-        return {
+        request = {
             "file_type": self._file_type.name,
             "output_prefix_uri": format_output_prefix_uri(self._output_prefix_uri),
         }
+        if self._partitioning is not None:
+            request["partitioning"] = self._partitioning.to_request()
+        return request
 
 
 class PulsarDestination(Destination):
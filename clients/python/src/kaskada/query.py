@@ -85,6 +85,9 @@ class QueryResource(object):
 class ResponseType(Enum):
     FILE_TYPE_PARQUET = 1
     FILE_TYPE_CSV = 2
+    FILE_TYPE_ARROW = 3
+    FILE_TYPE_ARROW_STREAM = 4
+    FILE_TYPE_JSON = 5
 
 
 def create_query(
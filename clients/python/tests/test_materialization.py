@@ -10,7 +10,9 @@ from kaskada.materialization import (
     FileType,
     MaterializationView,
     ObjectStoreDestination,
+    Partitioning,
     PulsarDestination,
+    TimeBucket,
     create_materialization,
     delete_materialization,
     get_materialization,
@@ -52,6 +54,27 @@ def test_object_store_destination_to_request():
     }
 
 
+def test_object_store_destination_with_partitioning_to_request():
+    partitioning = Partitioning(TimeBucket.TIME_BUCKET_DAY, key_buckets=4)
+    object_store = ObjectStoreDestination(
+        FileType.FILE_TYPE_PARQUET, "file:///my_prefix", partitioning
+    )
+    assert object_store.to_request() == {
+        "file_type": "FILE_TYPE_PARQUET",
+        "output_prefix_uri": "file:///my_prefix",
+        "partitioning": {"time_bucket": "TIME_BUCKET_DAY", "key_buckets": 4},
+    }
+
+    # The request is accepted by the proto message.
+    material_pb.CreateMaterializationRequest(
+        materialization={
+            "materialization_name": "my_materialization",
+            "expression": "my_expression",
+            "destination": {"object_store": object_store.to_request()},
+        }
+    )
+
+
 def test_object_store_destination_fails_for_invalid_output_uri():
     csv_file = FileType.FILE_TYPE_CSV
     output_prefix = "my_prefix"
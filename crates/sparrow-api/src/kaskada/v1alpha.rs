@@ -166,6 +166,10 @@ impl SourceData {
                 source_data::Source::ParquetPath(format!("file://{}", path.display()))
             }
             Some("csv") => source_data::Source::CsvPath(format!("file://{}", path.display())),
+            Some("json" | "jsonl" | "ndjson") => {
+                source_data::Source::JsonPath(format!("file://{}", path.display()))
+            }
+            Some("avro") => source_data::Source::AvroPath(format!("file://{}", path.display())),
             unsupported => anyhow::bail!("Unsupported extension {:?}", unsupported),
         };
         Ok(path)
@@ -196,8 +200,10 @@ impl std::fmt::Display for TickBehavior {
             TickBehavior::Minutely => write!(f, "minutely"),
             TickBehavior::Hourly => write!(f, "hourly"),
             TickBehavior::Daily => write!(f, "daily"),
+            TickBehavior::Weekly => write!(f, "weekly"),
             TickBehavior::Monthly => write!(f, "monthly"),
             TickBehavior::Yearly => write!(f, "yearly"),
+            TickBehavior::Every => write!(f, "every"),
             TickBehavior::Finished => write!(f, "final"),
             TickBehavior::Unspecified => panic!("Unspecified tick behavior"),
         }
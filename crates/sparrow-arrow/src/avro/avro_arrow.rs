@@ -1,9 +1,15 @@
-use arrow::array::{ArrayRef, ArrowPrimitiveType, PrimitiveArray, StringBuilder};
+use arrow::array::{
+    new_null_array, ArrayRef, ArrowPrimitiveType, BinaryArray, BooleanArray, FixedSizeBinaryArray,
+    PrimitiveArray, StringArray, StringBuilder, StructArray,
+};
+use arrow::buffer::NullBuffer;
 use arrow::datatypes::{
-    Date32Type, Float32Type, Float64Type, Int32Type, Int64Type, TimestampMicrosecondType,
+    DataType, Date32Type, Float32Type, Float64Type, Int32Type, Int64Type, SchemaRef,
+    Time32MillisecondType, Time64MicrosecondType, TimeUnit, TimestampMicrosecondType,
     TimestampMillisecondType,
 };
 use arrow::error::ArrowError;
+use arrow::record_batch::{RecordBatch, RecordBatchOptions};
 use avro_rs::types::Value;
 use std::sync::Arc;
 
@@ -97,6 +103,187 @@ fn avro_to_arrow_field(
     }
 }
 
+/// Converts Avro records into a [RecordBatch] with the given `schema`.
+///
+/// Unlike [avro_to_arrow], the type of each column is determined by the
+/// `schema` (such as one from [from_avro_schema](super::from_avro_schema))
+/// rather than the first value. This allows columns to start with `null`
+/// values. Union values are unwrapped, and nested records are converted to
+/// structs.
+pub fn avro_records_to_batch(
+    schema: SchemaRef,
+    records: &[Value],
+) -> Result<RecordBatch, ArrowError> {
+    let records: Vec<_> = records.iter().collect();
+    let columns = schema
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let values = record_fields(&records, index, field.name())?;
+            avro_to_array(field.data_type(), &values)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let options = RecordBatchOptions::new().with_row_count(Some(records.len()));
+    RecordBatch::try_new_with_options(schema, columns, &options)
+}
+
+static NULL: Value = Value::Null;
+
+/// Return the value of a (possibly nullable) union without the union.
+fn unwrap_union(value: &Value) -> &Value {
+    match value {
+        Value::Union(value) => unwrap_union(value),
+        value => value,
+    }
+}
+
+/// Return the values of the field at `index` in each of the `records`.
+///
+/// Records are read with the fields in schema order, but the field `name` is
+/// used if they differ. The value is `null` for `null` records.
+fn record_fields<'a>(
+    records: &[&'a Value],
+    index: usize,
+    name: &str,
+) -> Result<Vec<&'a Value>, ArrowError> {
+    records
+        .iter()
+        .map(|record| match unwrap_union(record) {
+            Value::Null => Ok(&NULL),
+            Value::Record(fields) => match fields.get(index) {
+                Some((field_name, value)) if field_name == name => Ok(value),
+                _ => fields
+                    .iter()
+                    .find(|(field_name, _)| field_name == name)
+                    .map(|(_, value)| value)
+                    .ok_or_else(|| {
+                        ArrowError::ParseError(format!("Avro record missing field '{name}'"))
+                    }),
+            },
+            other => Err(ArrowError::ParseError(format!(
+                "Expected Avro record, but was {other:?}"
+            ))),
+        })
+        .collect()
+}
+
+fn unexpected_value(data_type: &DataType, value: &Value) -> ArrowError {
+    ArrowError::ParseError(format!(
+        "Unable to convert Avro value {value:?} to {data_type:?}"
+    ))
+}
+
+fn avro_to_array(data_type: &DataType, values: &[&Value]) -> Result<ArrayRef, ArrowError> {
+    let values: Vec<_> = values.iter().map(|value| unwrap_union(value)).collect();
+    let array: ArrayRef = match data_type {
+        DataType::Null => new_null_array(data_type, values.len()),
+        DataType::Boolean => {
+            let array: BooleanArray = values
+                .iter()
+                .map(|value| match value {
+                    Value::Null => Ok(None),
+                    Value::Boolean(b) => Ok(Some(*b)),
+                    other => Err(unexpected_value(data_type, other)),
+                })
+                .collect::<Result<_, _>>()?;
+            Arc::new(array)
+        }
+        DataType::Int32 => avro_to_primitive::<Int32Type>(data_type, &values)?,
+        DataType::Int64 => avro_to_primitive::<Int64Type>(data_type, &values)?,
+        DataType::Float32 => avro_to_primitive::<Float32Type>(data_type, &values)?,
+        DataType::Float64 => avro_to_primitive::<Float64Type>(data_type, &values)?,
+        DataType::Date32 => avro_to_primitive::<Date32Type>(data_type, &values)?,
+        DataType::Time32(TimeUnit::Millisecond) => {
+            avro_to_primitive::<Time32MillisecondType>(data_type, &values)?
+        }
+        DataType::Time64(TimeUnit::Microsecond) => {
+            avro_to_primitive::<Time64MicrosecondType>(data_type, &values)?
+        }
+        DataType::Timestamp(TimeUnit::Millisecond, _) => {
+            avro_to_primitive::<TimestampMillisecondType>(data_type, &values)?
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, _) => {
+            avro_to_primitive::<TimestampMicrosecondType>(data_type, &values)?
+        }
+        DataType::Utf8 => {
+            let array: StringArray = values
+                .iter()
+                .map(|value| match value {
+                    Value::Null => Ok(None),
+                    Value::String(s) | Value::Enum(_, s) => Ok(Some(s.as_str())),
+                    other => Err(unexpected_value(data_type, other)),
+                })
+                .collect::<Result<_, _>>()?;
+            Arc::new(array)
+        }
+        DataType::Binary => {
+            let array: BinaryArray = values
+                .iter()
+                .map(|value| match value {
+                    Value::Null => Ok(None),
+                    Value::Bytes(b) | Value::Fixed(_, b) => Ok(Some(b.as_slice())),
+                    other => Err(unexpected_value(data_type, other)),
+                })
+                .collect::<Result<_, _>>()?;
+            Arc::new(array)
+        }
+        DataType::FixedSizeBinary(size) => {
+            let values = values
+                .iter()
+                .map(|value| match value {
+                    Value::Null => Ok(None),
+                    Value::Fixed(_, b) => Ok(Some(b.as_slice())),
+                    other => Err(unexpected_value(data_type, other)),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Arc::new(FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+                values.into_iter(),
+                *size,
+            )?)
+        }
+        DataType::Struct(fields) => {
+            let nulls: NullBuffer = values
+                .iter()
+                .map(|value| !matches!(value, Value::Null))
+                .collect();
+            let columns = fields
+                .iter()
+                .enumerate()
+                .map(|(index, field)| {
+                    let values = record_fields(&values, index, field.name())?;
+                    avro_to_array(field.data_type(), &values)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Arc::new(StructArray::try_new(fields.clone(), columns, Some(nulls))?)
+        }
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Conversion from Avro to {other:?}"
+            )))
+        }
+    };
+    Ok(array)
+}
+
+fn avro_to_primitive<T: AvroParser>(
+    data_type: &DataType,
+    values: &[&Value],
+) -> Result<ArrayRef, ArrowError> {
+    let array: PrimitiveArray<T> = values
+        .iter()
+        .map(|value| match value {
+            Value::Null => Ok(None),
+            value => T::parse_avro_value(value)
+                .map(Some)
+                .ok_or_else(|| unexpected_value(data_type, value)),
+        })
+        .collect::<Result<_, _>>()?;
+    // Set the data type to preserve the time zone of timestamps.
+    Ok(Arc::new(array.with_data_type(data_type.clone())))
+}
+
 pub trait AvroParser: ArrowPrimitiveType {
     fn parse_avro_value(value: &Value) -> Option<Self::Native>;
 }
@@ -155,6 +342,24 @@ impl AvroParser for TimestampMicrosecondType {
     }
 }
 
+impl AvroParser for Time32MillisecondType {
+    fn parse_avro_value(value: &Value) -> Option<Self::Native> {
+        match value {
+            Value::TimeMillis(t) => Some(*t),
+            _ => None,
+        }
+    }
+}
+
+impl AvroParser for Time64MicrosecondType {
+    fn parse_avro_value(value: &Value) -> Option<Self::Native> {
+        match value {
+            Value::TimeMicros(t) => Some(*t),
+            _ => None,
+        }
+    }
+}
+
 impl AvroParser for Date32Type {
     fn parse_avro_value(value: &Value) -> Option<Self::Native> {
         match value {
@@ -167,7 +372,8 @@ impl AvroParser for Date32Type {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use arrow::array::{Array, Int32Array, StringArray};
+    use arrow::array::{Array, AsArray, Int32Array, StringArray, TimestampMillisecondArray};
+    use arrow::datatypes::{Field, Schema};
     use avro_rs::types::Value;
 
     #[test]
@@ -194,4 +400,73 @@ mod tests {
         assert_eq!(str_array.value(0), "hello");
         assert_eq!(str_array.value(1), "world");
     }
+
+    #[test]
+    fn test_avro_records_to_batch() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new(
+                "time",
+                DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+                false,
+            ),
+            Field::new("n", DataType::Int64, true),
+            Field::new(
+                "s",
+                DataType::Struct(vec![Field::new("b", DataType::Boolean, false)].into()),
+                true,
+            ),
+        ]));
+
+        let records = vec![
+            Value::Record(vec![
+                ("time".to_owned(), Value::TimestampMillis(1000)),
+                ("n".to_owned(), Value::Union(Box::new(Value::Null))),
+                (
+                    "s".to_owned(),
+                    Value::Union(Box::new(Value::Record(vec![(
+                        "b".to_owned(),
+                        Value::Boolean(true),
+                    )]))),
+                ),
+            ]),
+            Value::Record(vec![
+                ("time".to_owned(), Value::TimestampMillis(2000)),
+                ("n".to_owned(), Value::Union(Box::new(Value::Long(5)))),
+                ("s".to_owned(), Value::Union(Box::new(Value::Null))),
+            ]),
+        ];
+
+        let batch = avro_records_to_batch(schema.clone(), &records).unwrap();
+        assert_eq!(batch.schema(), schema);
+        assert_eq!(
+            batch.column(0).as_ref(),
+            &TimestampMillisecondArray::from(vec![1000, 2000]).with_timezone("UTC")
+        );
+        assert_eq!(
+            batch.column(1).as_primitive::<Int64Type>(),
+            &PrimitiveArray::<Int64Type>::from(vec![None, Some(5)])
+        );
+        let s = batch.column(2).as_struct();
+        assert!(s.is_valid(0));
+        assert!(s.is_null(1));
+        assert_eq!(
+            s.column(0).as_ref(),
+            &BooleanArray::from(vec![Some(true), None])
+        );
+    }
+
+    #[test]
+    fn test_avro_records_to_batch_unexpected_value() {
+        let schema = Arc::new(Schema::new(vec![Field::new("n", DataType::Int64, true)]));
+        let records = vec![Value::Record(vec![(
+            "n".to_owned(),
+            Value::String("five".to_owned()),
+        )])];
+
+        let error = avro_records_to_batch(schema, &records).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Parser error: Unable to convert Avro value String(\"five\") to Int64"
+        );
+    }
 }
@@ -142,6 +142,14 @@ fn schema_to_type(
         AvroSchema::Long(Some(LongLogical::Time)) => {
             (DataType::Time64(TimeUnit::Microsecond), false)
         }
+        AvroSchema::Long(Some(LongLogical::TimestampMillis)) => (
+            DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+            false,
+        ),
+        AvroSchema::Long(Some(LongLogical::TimestampMicros)) => (
+            DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+            false,
+        ),
         AvroSchema::Long(Some(LongLogical::LocalTimestampMillis)) => {
             (DataType::Timestamp(TimeUnit::Millisecond, None), false)
         }
@@ -180,7 +188,8 @@ fn record_to_type(
 mod tests {
     use super::to_avro_schema;
     use crate::avro::from_avro_schema;
-    use arrow::datatypes::{DataType, Field, Schema};
+    use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+    use avro_schema::schema::{Field as AvroField, LongLogical, Record, Schema as AvroSchema};
 
     #[test]
     fn test_simple_struct_to_avro() {
@@ -267,4 +276,47 @@ mod tests {
     fn test_nullable_round_trip() {
         test_round_trip(nullable_arrow_schema());
     }
+
+    #[test]
+    fn test_timestamps_from_avro() {
+        let avro_schema = AvroSchema::Record(Record::new(
+            "r",
+            vec![
+                AvroField::new(
+                    "millis",
+                    AvroSchema::Long(Some(LongLogical::TimestampMillis)),
+                ),
+                AvroField::new(
+                    "micros",
+                    AvroSchema::Long(Some(LongLogical::TimestampMicros)),
+                ),
+                AvroField::new(
+                    "local_millis",
+                    AvroSchema::Long(Some(LongLogical::LocalTimestampMillis)),
+                ),
+            ],
+        ));
+
+        let result = from_avro_schema(&avro_schema).unwrap();
+        assert_eq!(
+            result,
+            Schema::new(vec![
+                Field::new(
+                    "millis",
+                    DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+                    false
+                ),
+                Field::new(
+                    "micros",
+                    DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+                    false
+                ),
+                Field::new(
+                    "local_millis",
+                    DataType::Timestamp(TimeUnit::Millisecond, None),
+                    false
+                ),
+            ])
+        );
+    }
 }
@@ -93,6 +93,7 @@ pub(super) async fn execute_example(
         output_prefix_uri: format!("file:///{}", tempdir.path().display()),
         file_type: FileType::Parquet.into(),
         output_paths: None,
+        partitioning: None,
     };
     let output_to = Destination {
         destination: Some(destination::Destination::ObjectStore(destination)),
@@ -218,6 +219,7 @@ impl ExampleInputPreparer {
     ) -> error_stack::Result<ComputeTable, Error> {
         let sd = SourceData {
             source: Some(source_data::Source::CsvData(input_csv.to_owned())),
+            schema: None,
         };
         let prepared_batches: Vec<_> = sparrow_runtime::prepare::prepared_batches(
             &ObjectStoreRegistry::default(),
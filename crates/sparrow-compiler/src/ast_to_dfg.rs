@@ -1,19 +1,25 @@
 //! Conversion from the Fenl  AST to DFG nodes.
 
 mod ast_dfg;
+mod case_to_dfg;
+mod lambda_to_dfg;
 mod record_ops_to_dfg;
+mod user_function;
 mod window_args;
 
 #[cfg(test)]
 mod tests;
+use std::borrow::Cow;
 use std::sync::Arc;
 
 use anyhow::{anyhow, Context};
 use arrow::datatypes::{DataType, FieldRef};
 use arrow_schema::Field;
 pub use ast_dfg::*;
+use case_to_dfg::*;
 use egg::Id;
 use itertools::{izip, Itertools};
+use lambda_to_dfg::*;
 use record_ops_to_dfg::*;
 use smallvec::{smallvec, SmallVec};
 use sparrow_arrow::scalar_value::ScalarValue;
@@ -21,15 +27,18 @@ use sparrow_instructions::CastEvaluator;
 use sparrow_plan::{GroupId, InstKind, InstOp};
 use sparrow_syntax::{
     Collection, ExprOp, FenlType, FormatDataType, LiteralValue, Located, Location, Resolved,
-    ResolvedExpr,
+    ResolvedExpr, WindowBehavior,
 };
+pub(crate) use user_function::UserFunction;
 
-use self::window_args::flatten_window_args;
+pub(crate) use self::window_args::duration_nanos;
+use self::window_args::{flatten_window_args, window_behavior, FlattenedWindow};
 use crate::dfg::{Dfg, Expression, Operation};
 use crate::diagnostics::DiagnosticCode;
+use crate::functions::Function;
 use crate::time_domain::TimeDomain;
 use crate::types::inference::instantiate;
-use crate::{DataContext, DiagnosticBuilder, DiagnosticCollector};
+use crate::{DataContext, DiagnosticBuilder, DiagnosticCollector, NearestMatches};
 
 /// Convert the `expr` to corresponding DFG nodes.
 pub(super) fn ast_to_dfg(
@@ -77,6 +86,38 @@ pub(super) fn ast_to_dfg(
             dfg.exit_env();
             return Ok(result);
         }
+        ExprOp::Def(name, signature, _) => {
+            let function = UserFunction::new(
+                data_context,
+                dfg,
+                diagnostics,
+                name,
+                signature,
+                &arguments[0],
+            )?;
+            dfg.enter_env();
+            dfg.bind_function(name.inner(), Arc::new(function));
+            let result = ast_to_dfg(data_context, dfg, diagnostics, &arguments[1])?;
+            dfg.exit_env();
+            return Ok(result);
+        }
+        ExprOp::Call(function_name) if takes_lambda(function_name) => {
+            return lambda_call_to_dfg(data_context, dfg, diagnostics, function_name, expr);
+        }
+        ExprOp::Lambda(_, location) => {
+            DiagnosticCode::IllegalLambda
+                .builder()
+                .with_label(
+                    location
+                        .primary_label()
+                        .with_message("Lambdas may only be passed to list functions"),
+                )
+                .with_note(
+                    "Functions taking a lambda include 'list_map' and 'list_filter'".to_owned(),
+                )
+                .emit(diagnostics);
+            return Ok(dfg.error_node());
+        }
         // Note: Now that `AstDfgRef` contains a `Location`, this likely does not
         // need to be wrapped in `Located`.
         _ => arguments.try_transform(|e| -> anyhow::Result<Located<AstDfgRef>> {
@@ -168,20 +209,7 @@ pub fn add_to_dfg(
         ExprOp::Reference(reference) => match dfg.get_binding(reference) {
             Ok(value) => Ok(value),
             Err(nearest) => {
-                DiagnosticCode::UnboundReference
-                    .builder()
-                    .with_label(
-                        reference
-                            .location()
-                            .primary_label()
-                            .with_message(format!("No reference named '{reference}'")),
-                    )
-                    .with_note(if nearest.is_empty() {
-                        "No formulas, tables, or let-bound names available".to_owned()
-                    } else {
-                        format!("Nearest matches: {nearest}")
-                    })
-                    .emit(diagnostics);
+                report_unbound_reference(reference, nearest, diagnostics);
                 Ok(dfg.error_node())
             }
         },
@@ -189,6 +217,13 @@ pub fn add_to_dfg(
             let base = &arguments[0];
             let base_type = &argument_types[0];
 
+            if !matches!(base_type.inner(), FenlType::Json) && dfg.is_json_path(base.value()) {
+                // Fields of a nested JSON value are accessed from the string
+                // containing that value.
+                let field_name = dfg.add_string_literal(field.inner())?;
+                return add_json_field(dfg, base, field_name);
+            }
+
             let field_type = match field_type(field, base_type.inner(), arguments[0].location()) {
                 Ok(Some(field_type)) => field_type,
                 Ok(None) => {
@@ -218,6 +253,17 @@ pub fn add_to_dfg(
                 None,
             )))
         }
+        ExprOp::Call(function_name) if dfg.get_function(function_name).is_some() => {
+            let function = dfg.get_function(function_name).expect("checked by guard");
+            function.call(
+                data_context,
+                dfg,
+                diagnostics,
+                function_name,
+                &arguments,
+                &argument_types,
+            )
+        }
         ExprOp::Call(function_name) => {
             // Assumption: All instructions are exposed as Fenl functions. It seems
             // reasonable / desirable to keep this consistency, until such
@@ -233,6 +279,51 @@ pub fn add_to_dfg(
                 )
             })?;
 
+            if dfg.in_lambda() && !function.is_allowed_in_lambda() {
+                DiagnosticCode::IllegalLambda
+                    .builder()
+                    .with_label(
+                        function_name
+                            .location()
+                            .primary_label()
+                            .with_message(format!(
+                                "Function '{function_name}' may not be used within a lambda"
+                            )),
+                    )
+                    .with_note(
+                        "Lambdas are applied to each element of a list, so they may not use \
+                         aggregations or functions depending on the time of each row"
+                            .to_owned(),
+                    )
+                    .emit(diagnostics);
+                return Ok(dfg.error_node());
+            }
+
+            if let Some(overload) = function.string_overload() {
+                let is_string = |arg: &Located<FenlType>| {
+                    matches!(arg.inner(), FenlType::Concrete(DataType::Utf8))
+                };
+                if argument_types.iter().all(is_string) {
+                    // For instance, `a + b` on strings is `concat(a, b)`.
+                    let overload_name = function_name.with_value(overload.to_owned());
+                    let overload = crate::functions::get_function(overload)
+                        .map_err(|_| anyhow!("Missing overload {overload} of {function_name}"))?;
+                    let arguments = Resolved::new(
+                        Cow::Borrowed(overload.signature().parameters().names()),
+                        arguments.take_values(),
+                        overload.signature().parameters().has_vararg,
+                    );
+                    return add_to_dfg(
+                        data_context,
+                        dfg,
+                        diagnostics,
+                        &ExprOp::Call(overload_name),
+                        arguments,
+                        original_ast,
+                    );
+                }
+            }
+
             if function.is_tick() {
                 // This is a strange pattern - when creating the initial tick argument, we don't
                 // yet know the input. However, we ensure that ticks are recreated with the
@@ -240,12 +331,17 @@ pub fn add_to_dfg(
                 //
                 // TODO: Can we move this before we create the args, so we don't have to
                 // recreate them?
-                let behavior = function.tick_behavior().context("tick behavior")?;
                 if let Ok(agg_input) = dfg.get_binding("$condition_input") {
+                    // Invalid schedules were reported when the tick was first
+                    // created, so there is no need to report them again.
+                    let Some(schedule) = function.tick_schedule(dfg, arguments.values()) else {
+                        return Ok(dfg.error_node());
+                    };
+
                     // The argument is a tick, so we can directly create the necessary node.
                     let agg_input_op = dfg.operation(agg_input.value());
                     let tick_input = smallvec![agg_input_op];
-                    let tick_node = dfg.add_operation(Operation::Tick(behavior), tick_input)?;
+                    let tick_node = dfg.add_operation(Operation::Tick(schedule), tick_input)?;
                     let tick_node = Arc::new(AstDfg::new(
                         tick_node,
                         tick_node,
@@ -259,6 +355,16 @@ pub fn add_to_dfg(
                 }
             }
 
+            if function.name() == "index" && dfg.is_json_path(arguments[1].value()) {
+                // Elements of a JSON array are accessed like fields, using the
+                // index as the field name.
+                let Some(index) = json_index(dfg, diagnostics, &arguments[0]) else {
+                    return Ok(dfg.error_node());
+                };
+                let index = dfg.add_string_literal(&index.to_string())?;
+                return add_json_field(dfg, &arguments[1], index);
+            }
+
             let signature = if original_ast.is_some() {
                 // If we have an original AST, then we're running from a Fenl file.
                 // In that case, we use the AST signature.
@@ -274,6 +380,11 @@ pub fn add_to_dfg(
                 let argument = &arguments.values()[constant_index];
                 if dfg.literal(argument.value()).is_none() {
                     invalid = true;
+                    if dfg.is_error(argument.value()) {
+                        // The error has already been reported, or the value
+                        // is a parameter which will be checked at each call.
+                        continue;
+                    }
 
                     let argument_name = &signature.arg_names()[constant_index];
                     DiagnosticCode::InvalidNonConstArgument
@@ -503,17 +614,20 @@ pub fn add_to_dfg(
                 .try_collect()?;
 
             let args: Vec<_> = if function.is_aggregation() {
-                let window_arg = original_ast.map(|e| &e.args()[1]);
-                let (condition, duration) = match window_arg {
+                // The window is the last argument of an aggregation. Any preceding
+                // arguments (the input, and constants such as the percentile) are
+                // passed through, with the exception of half-lives.
+                let window_arg = original_ast.map(|e| &e.args()[e.args().len() - 1]);
+                let (leading_args, (condition, duration, kind)) = match window_arg {
                     Some(window) => {
                         // If the function is an aggregation, we may need to flatten the window.
                         dfg.enter_env();
                         dfg.bind("$condition_input", args[0].inner().clone());
 
-                        let result =
+                        let window =
                             flatten_window_args_if_needed(window, dfg, data_context, diagnostics)?;
                         dfg.exit_env();
-                        result
+                        (&args[..args.len() - 1], window)
                     }
                     None => {
                         // If `expr` is None, we're running the Python builder code,
@@ -521,12 +635,41 @@ pub fn add_to_dfg(
                         //
                         // Note that this won't define the `condition_input` for the
                         // purposes of ticks.
-                        (args[1].clone(), args[2].clone())
+                        let n = args.len();
+                        let window = (
+                            args[n - 3].clone(),
+                            args[n - 2].clone(),
+                            args[n - 1].clone(),
+                        );
+                        (&args[..n - 3], window)
                     }
                 };
+                if !check_window(
+                    dfg,
+                    diagnostics,
+                    function,
+                    &args[0],
+                    &condition,
+                    &duration,
+                    &kind,
+                ) {
+                    return Ok(dfg.error_node());
+                }
+
+                if !function.check_arguments(dfg, diagnostics, leading_args) {
+                    return Ok(dfg.error_node());
+                }
+
+                let mut leading_args = leading_args.to_vec();
+                if !function.rewrite_arguments(dfg, diagnostics, &mut leading_args)? {
+                    return Ok(dfg.error_node());
+                }
 
-                // [agg_input, condition, duration]
-                vec![args[0].clone(), condition, duration]
+                // [agg_input, ...constants, condition, duration, kind]
+                leading_args
+                    .into_iter()
+                    .chain([condition, duration, kind])
+                    .collect()
             } else if function.name() == "collect" {
                 // The collect function contains a window, but does not follow the same signature
                 // pattern as aggregations, so it requires a different flattening strategy.
@@ -534,7 +677,7 @@ pub fn add_to_dfg(
                 // TODO: Flattening the window arguments is hacky and confusing. We should instead
                 // incorporate the tick directly into the function containing the window.
                 let window_arg = original_ast.map(|e| &e.args()[3]);
-                let (condition, duration) = match window_arg {
+                let (condition, duration, kind) = match window_arg {
                     Some(window) => {
                         dfg.enter_env();
                         dfg.bind("$condition_input", args[0].inner().clone());
@@ -550,9 +693,20 @@ pub fn add_to_dfg(
                         //
                         // Note that this won't define the `condition_input` for the
                         // purposes of ticks.
-                        (args[3].clone(), args[4].clone())
+                        (args[3].clone(), args[4].clone(), args[5].clone())
                     }
                 };
+                if !check_window(
+                    dfg,
+                    diagnostics,
+                    function,
+                    &args[0],
+                    &condition,
+                    &duration,
+                    &kind,
+                ) {
+                    return Ok(dfg.error_node());
+                }
 
                 let min = dfg.literal(args[2].value());
                 let max = dfg.literal(args[1].value());
@@ -573,13 +727,14 @@ pub fn add_to_dfg(
                     (_, _) => panic!("previously verified min and max are scalar types"),
                 }
 
-                // [input, max, min, condition, duration]
+                // [input, max, min, condition, duration, kind]
                 vec![
                     args[0].clone(),
                     args[1].clone(),
                     args[2].clone(),
                     condition,
                     duration,
+                    kind,
                 ]
             } else if function.name() == "when" || function.name() == "if" {
                 match original_ast {
@@ -604,6 +759,9 @@ pub fn add_to_dfg(
                     }
                 }
             } else {
+                if !function.check_arguments(dfg, diagnostics, &args) {
+                    return Ok(dfg.error_node());
+                }
                 args
             };
 
@@ -619,10 +777,13 @@ pub fn add_to_dfg(
         }
         ExprOp::Pipe(_) => Err(anyhow!("Unreachable: Pipe expression handled above")),
         ExprOp::Let(_, _) => Err(anyhow!("Unreachable: Let expression handled above")),
+        ExprOp::Def(_, _, _) => Err(anyhow!("Unreachable: Def expression handled above")),
+        ExprOp::Lambda(_, _) => Err(anyhow!("Unreachable: Lambda expression handled above")),
         ExprOp::Error => Err(anyhow!("Unreachable: Error expression handled above")),
         ExprOp::Record(fields, location) => {
             record_to_dfg(data_context, location, dfg, diagnostics, fields, arguments)
         }
+        ExprOp::Case(location) => case_to_dfg(data_context, location, dfg, diagnostics, arguments),
         ExprOp::ExtendRecord(location) => extend_record_to_dfg(
             data_context,
             location,
@@ -650,14 +811,35 @@ pub fn add_to_dfg(
     }
 }
 
-#[allow(clippy::type_complexity)]
+/// Report a reference to a name that isn't bound.
+fn report_unbound_reference(
+    reference: &Located<String>,
+    nearest: NearestMatches<&str>,
+    diagnostics: &mut DiagnosticCollector<'_>,
+) {
+    DiagnosticCode::UnboundReference
+        .builder()
+        .with_label(
+            reference
+                .location()
+                .primary_label()
+                .with_message(format!("No reference named '{reference}'")),
+        )
+        .with_note(if nearest.is_empty() {
+            "No formulas, tables, or let-bound names available".to_owned()
+        } else {
+            format!("Nearest matches: {nearest}")
+        })
+        .emit(diagnostics);
+}
+
 fn flatten_window_args_if_needed(
     window: &Located<Box<ResolvedExpr>>,
     dfg: &mut Dfg,
     data_context: &mut DataContext,
     diagnostics: &mut DiagnosticCollector<'_>,
-) -> anyhow::Result<(Located<Arc<AstDfg>>, Located<Arc<AstDfg>>)> {
-    let (condition, duration) = match window.op() {
+) -> anyhow::Result<FlattenedWindow> {
+    let window = match window.op() {
         ExprOp::Call(window_name) => {
             flatten_window_args(window_name, window, dfg, data_context, diagnostics)?
         }
@@ -674,11 +856,138 @@ fn flatten_window_args_if_needed(
                 window.location().clone(),
             );
 
-            (null_arg.clone(), null_arg)
+            (null_arg.clone(), null_arg.clone(), null_arg)
         }
         unexpected => anyhow::bail!("expected window, found {:?}", unexpected),
     };
-    Ok((condition, duration))
+    Ok(window)
+}
+
+/// Report a diagnostic if the function does not support the window.
+///
+/// The window is determined from the flattened window `kind`, so this
+/// applies to windows from Fenl and the Python builder alike.
+///
+/// Returns `false` if any diagnostics were reported.
+fn check_window(
+    dfg: &Dfg,
+    diagnostics: &mut DiagnosticCollector<'_>,
+    function: &Function,
+    input: &Located<AstDfgRef>,
+    condition: &Located<AstDfgRef>,
+    duration: &Located<AstDfgRef>,
+    kind: &Located<AstDfgRef>,
+) -> bool {
+    if [condition, duration, kind]
+        .iter()
+        .any(|arg| arg.value_type().is_error())
+    {
+        // Invalid windows have already been reported.
+        return true;
+    }
+    let Some(window) = window_behavior(dfg, kind.inner()) else {
+        if matches!(dfg.literal(kind.value()), Some(ScalarValue::Null)) {
+            return true;
+        }
+        DiagnosticCode::InvalidArgumentType
+            .builder()
+            .with_label(
+                kind.location()
+                    .primary_label()
+                    .with_message("Invalid window kind"),
+            )
+            .with_note("Supported windows: 'since', 'sliding', 'trailing', 'session'".to_string())
+            .emit(diagnostics);
+        return false;
+    };
+    let window_kind = match window {
+        WindowBehavior::Since => "Since",
+        WindowBehavior::Sliding => "Sliding",
+        WindowBehavior::Trailing => "Trailing",
+        WindowBehavior::Session => "Session",
+    };
+
+    let function_name = function.name();
+    let message = if !function.supports_window(window) {
+        format!("{window_kind} windows are not supported for '{function_name}'")
+    } else if !function.supports_collection_window(window)
+        && input
+            .value_type()
+            .arrow_type()
+            .is_some_and(contains_collection)
+    {
+        let input_type = input.value_type();
+        format!(
+            "{window_kind} windows are not supported for '{function_name}' of type {input_type}"
+        )
+    } else {
+        return true;
+    };
+
+    DiagnosticCode::InvalidArgumentType
+        .builder()
+        .with_label(condition.location().primary_label().with_message(message))
+        .emit(diagnostics);
+    false
+}
+
+/// Returns true if the type is a list or map, or a record containing one.
+fn contains_collection(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::List(_) | DataType::Map(..) => true,
+        DataType::Struct(fields) => fields
+            .iter()
+            .any(|field| contains_collection(field.data_type())),
+        _ => false,
+    }
+}
+
+/// Add a `json_field` accessing `field` within the JSON `base`.
+fn add_json_field(
+    dfg: &mut Dfg,
+    base: &Located<AstDfgRef>,
+    field: Id,
+) -> anyhow::Result<AstDfgRef> {
+    let value = dfg.add_instruction(InstOp::JsonField, smallvec![base.value(), field])?;
+    Ok(Arc::new(AstDfg::new(
+        value,
+        base.is_new(),
+        FenlType::Concrete(DataType::Utf8),
+        base.grouping(),
+        base.time_domain().clone(),
+        base.location().clone(),
+        None,
+    )))
+}
+
+/// Returns the index used to access an element of a JSON array.
+///
+/// Reports a diagnostic and returns `None` if the index is not a non-negative
+/// integer literal.
+fn json_index(
+    dfg: &Dfg,
+    diagnostics: &mut DiagnosticCollector<'_>,
+    index: &Located<AstDfgRef>,
+) -> Option<u64> {
+    let literal = dfg.literal(index.value());
+    let index_value = match literal {
+        Some(ScalarValue::Int64(Some(i))) => u64::try_from(*i).ok(),
+        Some(ScalarValue::UInt64(Some(i))) => Some(*i),
+        _ => None,
+    };
+
+    if index_value.is_none() {
+        DiagnosticCode::InvalidNonConstArgument
+            .builder()
+            .with_label(
+                index
+                    .location()
+                    .primary_label()
+                    .with_message("Index into 'json' must be a non-negative integer literal"),
+            )
+            .emit(diagnostics);
+    }
+    index_value
 }
 
 // Verify that the arguments are compatibly partitioned.
@@ -830,7 +1139,7 @@ pub(crate) fn is_any_new(dfg: &mut Dfg, arguments: &[Located<AstDfgRef>]) -> any
     Ok(result)
 }
 
-fn add_literal(
+pub(crate) fn add_literal(
     dfg: &mut Dfg,
     value: Id,
     value_type: FenlType,
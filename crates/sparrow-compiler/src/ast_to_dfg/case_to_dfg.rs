@@ -0,0 +1,129 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use anyhow::Context;
+use arrow::datatypes::DataType;
+use smallvec::SmallVec;
+use sparrow_plan::InstKind;
+use sparrow_syntax::{FeatureSetPart, FenlType, Located, Location, Resolved, Signature};
+use static_init::dynamic;
+
+use crate::ast_to_dfg::{cast_if_needed, is_any_new, verify_same_partitioning};
+use crate::dfg::{Dfg, Expression};
+use crate::time_domain::{combine_time_domains, TimeDomain};
+use crate::types::inference::instantiate;
+use crate::{AstDfg, AstDfgRef, DataContext, DiagnosticCode, DiagnosticCollector};
+
+/// Signature used to determine the result type from the values of a `case`.
+const CASE_VALUES_SIGNATURE: &str = "case<T: any>(values+: T) -> T";
+
+#[dynamic]
+static CASE_VALUES: Signature = Signature::try_from_str(
+    FeatureSetPart::Internal(CASE_VALUES_SIGNATURE),
+    CASE_VALUES_SIGNATURE,
+)
+.expect("valid case signature");
+
+/// Convert a `case` expression to a single `case` instruction.
+///
+/// The arguments are pairs of conditions and values, followed by the default.
+pub(super) fn case_to_dfg(
+    data_context: &DataContext,
+    location: &Location,
+    dfg: &mut Dfg,
+    diagnostics: &mut DiagnosticCollector<'_>,
+    arguments: Resolved<Located<AstDfgRef>>,
+) -> anyhow::Result<AstDfgRef> {
+    if arguments.iter().any(|arg| arg.value_type().is_error()) {
+        return Ok(dfg.error_node());
+    }
+
+    let (default, arms) = arguments
+        .values()
+        .split_last()
+        .context("case missing default")?;
+
+    // Check that each condition is a boolean.
+    let mut invalid = false;
+    for condition in arms.iter().step_by(2) {
+        match condition.value_type() {
+            FenlType::Concrete(DataType::Boolean | DataType::Null) => {}
+            invalid_type => {
+                invalid = true;
+                DiagnosticCode::InvalidArgumentType
+                    .builder()
+                    .with_label(condition.location().primary_label().with_message(format!(
+                        "Invalid condition type '{invalid_type}' for 'case'"
+                    )))
+                    .with_note("Conditions must be of type 'bool'".to_owned())
+                    .emit(diagnostics);
+            }
+        }
+    }
+    if invalid {
+        return Ok(dfg.error_node());
+    }
+
+    // The result type is the least upper bound of the values (and default).
+    let value_types: SmallVec<_> = arms
+        .iter()
+        .skip(1)
+        .step_by(2)
+        .chain(std::iter::once(default))
+        .map(|value| value.with_value(value.value_type().clone()))
+        .collect();
+    let value_types = Resolved::new(
+        Cow::Borrowed(CASE_VALUES.parameters().names()),
+        value_types,
+        true,
+    );
+    let call = Located::new("case".to_owned(), location.clone());
+    let result_type = match instantiate(&call, &value_types, &CASE_VALUES) {
+        Ok((_, result_type)) => result_type,
+        Err(diagnostic) => {
+            diagnostic.emit(diagnostics);
+            return Ok(dfg.error_node());
+        }
+    };
+
+    let operation = Located::new("case", location.clone());
+    let grouping = verify_same_partitioning(data_context, diagnostics, &operation, &arguments)?;
+
+    let is_new = is_any_new(dfg, arguments.values())?;
+
+    let time_domain = combine_time_domains(location, arguments.values(), data_context)?
+        .unwrap_or_else(|diagnostic| {
+            diagnostic.emit(diagnostics);
+            TimeDomain::error()
+        });
+
+    // Cast the conditions to booleans and the values to the result type.
+    let condition_type = FenlType::Concrete(DataType::Boolean);
+    let mut instruction_args = SmallVec::with_capacity(arguments.len());
+    for (index, arg) in arguments.iter().enumerate() {
+        let is_condition = index % 2 == 0 && index < arms.len();
+        let expected_type = if is_condition {
+            &condition_type
+        } else {
+            &result_type
+        };
+        instruction_args.push(cast_if_needed(
+            dfg,
+            arg.value(),
+            arg.value_type(),
+            expected_type,
+        )?);
+    }
+
+    let value = dfg.add_expression(Expression::Inst(InstKind::Case), instruction_args)?;
+
+    Ok(Arc::new(AstDfg::new(
+        value,
+        is_new,
+        result_type,
+        grouping,
+        time_domain,
+        location.clone(),
+        None,
+    )))
+}
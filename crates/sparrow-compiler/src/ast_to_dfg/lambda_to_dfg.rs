@@ -0,0 +1,205 @@
+use std::sync::Arc;
+
+use anyhow::Context;
+use itertools::Itertools;
+use smallvec::smallvec;
+use sparrow_plan::InstOp;
+use sparrow_syntax::{ArgVec, ExprOp, Located, ResolvedExpr};
+
+use crate::ast_to_dfg::{add_to_dfg, ast_to_dfg, AstDfg};
+use crate::dfg::Dfg;
+use crate::diagnostics::DiagnosticCode;
+use crate::frontend::free_variable::free_names;
+use crate::types::inference::lambda_parameter_type;
+use crate::{AstDfgRef, DataContext, DiagnosticCollector};
+
+/// Returns true if the function with the given name takes a lambda.
+///
+/// The lambda is the last argument, and is applied to each element of the
+/// list passed as the first argument.
+pub(super) fn takes_lambda(name: &str) -> bool {
+    matches!(name, "list_map" | "list_filter")
+}
+
+/// Convert a call to a function taking a lambda to DFG nodes.
+///
+/// The body of the lambda is converted with the parameter bound to the
+/// elements of the list (`list_elements`), so each instruction in the body
+/// is applied to every element. Values referenced from outside the lambda
+/// are repeated for each element of the list (`list_broadcast`). The
+/// function then collects the result for each element back into lists.
+pub(super) fn lambda_call_to_dfg(
+    data_context: &mut DataContext,
+    dfg: &mut Dfg,
+    diagnostics: &mut DiagnosticCollector<'_>,
+    function_name: &Located<String>,
+    expr: &ResolvedExpr,
+) -> anyhow::Result<AstDfgRef> {
+    let arguments = expr.args();
+    let (lambda, leading) = arguments
+        .values()
+        .split_last()
+        .context("missing lambda argument")?;
+    let mut values: ArgVec<Located<AstDfgRef>> = leading
+        .iter()
+        .map(|e| -> anyhow::Result<_> {
+            let ast_dfg = ast_to_dfg(data_context, dfg, diagnostics, e.inner())?;
+            Ok(e.with_value(ast_dfg))
+        })
+        .try_collect()?;
+
+    let ExprOp::Lambda(parameter, _) = lambda.op() else {
+        let argument_name = arguments.parameter_name(arguments.len() - 1);
+        DiagnosticCode::IllegalLambda
+            .builder()
+            .with_label(lambda.location().primary_label().with_message(format!(
+                "Argument '{argument_name}' to '{function_name}' must be a lambda"
+            )))
+            .with_note("Lambdas are written as 'x -> x + 1'".to_owned())
+            .emit(diagnostics);
+        return Ok(dfg.error_node());
+    };
+
+    let list = &values[0];
+    let parameter_type =
+        match lambda_parameter_type(function_name, &list.with_value(list.value_type().clone())) {
+            Ok(parameter_type) => parameter_type,
+            Err(diagnostic) => {
+                diagnostic.emit(diagnostics);
+                return Ok(dfg.error_node());
+            }
+        };
+    if parameter_type.is_error() {
+        return Ok(dfg.error_node());
+    }
+
+    // Determine the values the body references from outside the lambda.
+    // Literals are the same for every element, and errors have already
+    // been reported, so neither needs to be repeated for each element.
+    // Unbound references are reported when converting the body.
+    let body = &lambda.args()[0];
+    let captured: Vec<_> = free_names(body.inner())
+        .into_iter()
+        .filter(|name| *name != parameter.inner())
+        .filter_map(|name| dfg.get_binding(name).ok().map(|value| (name, value)))
+        .filter(|(_, value)| dfg.literal(value.value()).is_none() && !value.value_type().is_error())
+        .collect();
+
+    // The elements and the captured values need to be in the same operation
+    // as the list, so that each element lines up with the row it came from.
+    let operation = dfg.infer_operation(
+        std::iter::once(list.value()).chain(captured.iter().map(|(_, value)| value.value())),
+    )?;
+    let list = Arc::new(AstDfg::new(
+        dfg.move_to_operation(list.value(), operation)?,
+        list.is_new(),
+        list.value_type().clone(),
+        list.grouping(),
+        list.time_domain().clone(),
+        list.location().clone(),
+        None,
+    ));
+    values[0].update_value(list.clone());
+
+    let elements = Arc::new(AstDfg::new(
+        dfg.add_instruction(InstOp::ListElements, smallvec![list.value()])?,
+        list.is_new(),
+        parameter_type,
+        list.grouping(),
+        list.time_domain().clone(),
+        parameter.location().clone(),
+        None,
+    ));
+
+    dfg.enter_env();
+    dfg.bind(parameter.inner(), elements);
+    for (name, value) in captured {
+        let value = Arc::new(AstDfg::new(
+            dfg.move_to_operation(value.value(), operation)?,
+            value.is_new(),
+            value.value_type().clone(),
+            value.grouping(),
+            value.time_domain().clone(),
+            value.location().clone(),
+            None,
+        ));
+        let value = broadcast(dfg, &list, &value)?;
+        dfg.bind(name, value);
+    }
+    dfg.enter_lambda(list);
+    let result = ast_to_dfg(data_context, dfg, diagnostics, body.inner());
+    dfg.exit_lambda();
+    dfg.exit_env();
+    let result = result?;
+
+    // Functions which change the rows (such as `shift_to` or `lookup`)
+    // produce values in a different operation, which don't line up with the
+    // elements.
+    if !result.value_type().is_error()
+        && dfg.literal(result.value()).is_none()
+        && dfg.operation(result.value()) != operation
+    {
+        DiagnosticCode::IllegalLambda
+            .builder()
+            .with_label(
+                body.location()
+                    .primary_label()
+                    .with_message("Lambda body must be computed from the elements of the list"),
+            )
+            .with_note(
+                "Functions which change the rows, such as 'shift_to' or 'lookup', may not be \
+                 used within a lambda"
+                    .to_owned(),
+            )
+            .emit(diagnostics);
+        return Ok(dfg.error_node());
+    }
+
+    values.push(lambda.with_value(result));
+    add_to_dfg(
+        data_context,
+        dfg,
+        diagnostics,
+        expr.op(),
+        arguments.with_values(values),
+        Some(expr),
+    )
+}
+
+/// Repeat a value for each element of a list.
+///
+/// The value and list should be in the same operation.
+fn broadcast(dfg: &mut Dfg, list: &AstDfgRef, value: &AstDfgRef) -> anyhow::Result<AstDfgRef> {
+    Ok(Arc::new(AstDfg::new(
+        dfg.add_instruction(
+            InstOp::ListBroadcast,
+            smallvec![list.value(), value.value()],
+        )?,
+        value.is_new(),
+        value.value_type().clone(),
+        value.grouping(),
+        value.time_domain().clone(),
+        value.location().clone(),
+        None,
+    )))
+}
+
+/// Repeat a value bound within the first `depth` enclosing lambdas for each
+/// element of the remaining (inner) lambdas.
+///
+/// This is used for values captured by user-defined functions, which may be
+/// called within a lambda nested more deeply than their definition.
+pub(super) fn broadcast_from_depth(
+    dfg: &mut Dfg,
+    value: AstDfgRef,
+    depth: usize,
+) -> anyhow::Result<AstDfgRef> {
+    if dfg.literal(value.value()).is_some() || value.value_type().is_error() {
+        return Ok(value);
+    }
+
+    let lists = dfg.lambda_lists().get(depth..).unwrap_or_default().to_vec();
+    lists
+        .iter()
+        .try_fold(value, |value, list| broadcast(dfg, list, &value))
+}
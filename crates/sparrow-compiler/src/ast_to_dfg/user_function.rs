@@ -0,0 +1,169 @@
+use std::sync::Arc;
+
+use itertools::izip;
+use sparrow_syntax::{FenlType, Located, Resolved, ResolvedExpr, Signature};
+
+use crate::ast_to_dfg::lambda_to_dfg::broadcast_from_depth;
+use crate::ast_to_dfg::report_unbound_reference;
+use crate::dfg::Dfg;
+use crate::frontend::first_reference::first_reference;
+use crate::frontend::free_variable::free_names;
+use crate::time_domain::TimeDomain;
+use crate::types::inference::instantiate;
+use crate::{AstDfg, AstDfgRef, DataContext, DiagnosticCollector};
+
+/// A function defined using `def`, either within a Fenl expression or as a
+/// formula.
+///
+/// Calls are expanded by converting the body to DFG nodes with each
+/// parameter bound to the corresponding argument.
+#[derive(Debug)]
+pub(crate) struct UserFunction {
+    /// The name of the function, located at the definition.
+    name: Located<String>,
+    signature: Signature,
+    body: ResolvedExpr,
+    /// The values of names referenced by the body, as they were bound where
+    /// the function was defined.
+    captured: Vec<(String, AstDfgRef)>,
+    /// The number of lambdas enclosing the definition.
+    ///
+    /// Calls within more deeply nested lambdas repeat the captured values for
+    /// each element of the inner lambdas.
+    lambda_depth: usize,
+    /// False if the body references a name which wasn't defined, or has
+    /// errors which don't depend on the arguments.
+    ///
+    /// The errors are reported at the definition, and calls to the function
+    /// produce errors without further diagnostics.
+    is_valid: bool,
+}
+
+impl UserFunction {
+    /// Define a function, capturing the values of names referenced by the
+    /// body.
+    ///
+    /// Reports any unbound references in the body, and checks the body with
+    /// each parameter bound to a placeholder of the declared type.
+    pub(crate) fn new(
+        data_context: &mut DataContext,
+        dfg: &mut Dfg,
+        diagnostics: &mut DiagnosticCollector<'_>,
+        name: &Located<String>,
+        signature: &Signature,
+        body: &ResolvedExpr,
+    ) -> anyhow::Result<Self> {
+        let mut captured = Vec::new();
+        let mut is_valid = true;
+        for free_name in free_names(body) {
+            if signature
+                .arg_names()
+                .iter()
+                .any(|parameter| parameter.inner() == free_name)
+            {
+                continue;
+            }
+
+            match dfg.get_binding(free_name) {
+                Ok(value) => captured.push((free_name.to_owned(), value)),
+                Err(nearest) => {
+                    let reference = first_reference(body, free_name)
+                        .expect("free names are referenced in the body");
+                    report_unbound_reference(reference, nearest, diagnostics);
+                    is_valid = false;
+                }
+            }
+        }
+
+        if is_valid {
+            let num_errors = diagnostics.num_errors();
+            dfg.enter_env();
+            for (parameter, parameter_type) in
+                izip!(signature.arg_names(), signature.parameters().types())
+            {
+                let placeholder = parameter_placeholder(dfg, parameter, parameter_type.inner());
+                dfg.bind(parameter.inner(), placeholder);
+            }
+            let checked = crate::ast_to_dfg(data_context, dfg, diagnostics, body);
+            dfg.exit_env();
+            checked?;
+            is_valid = diagnostics.num_errors() == num_errors;
+        }
+
+        Ok(Self {
+            name: name.clone(),
+            signature: signature.clone(),
+            body: body.clone(),
+            captured,
+            lambda_depth: dfg.lambda_lists().len(),
+            is_valid,
+        })
+    }
+
+    /// Add the DFG nodes for a call to this function.
+    ///
+    /// Reports invalid argument types at the call, with a label at the
+    /// definition.
+    pub(super) fn call(
+        &self,
+        data_context: &mut DataContext,
+        dfg: &mut Dfg,
+        diagnostics: &mut DiagnosticCollector<'_>,
+        call: &Located<String>,
+        arguments: &Resolved<Located<AstDfgRef>>,
+        argument_types: &Resolved<Located<FenlType>>,
+    ) -> anyhow::Result<AstDfgRef> {
+        if !self.is_valid {
+            return Ok(dfg.error_node());
+        }
+
+        if let Err(diagnostic) = instantiate(call, argument_types, &self.signature) {
+            diagnostic
+                .with_label(
+                    self.name
+                        .location()
+                        .secondary_label()
+                        .with_message(format!("'{}' defined here", self.name)),
+                )
+                .emit(diagnostics);
+            return Ok(dfg.error_node());
+        }
+
+        dfg.enter_env();
+        for (name, value) in &self.captured {
+            let value = broadcast_from_depth(dfg, value.clone(), self.lambda_depth)?;
+            dfg.bind(name, value);
+        }
+        for (parameter, argument) in izip!(self.signature.arg_names(), arguments.iter()) {
+            dfg.bind(parameter.inner(), argument.inner().clone());
+        }
+        let result = crate::ast_to_dfg(data_context, dfg, diagnostics, &self.body);
+        dfg.exit_env();
+        result
+    }
+}
+
+/// Returns the value bound to a parameter while checking the body of a
+/// function at its definition.
+///
+/// Parameters with a concrete type are placeholders of that type. Others are
+/// errors, so parts of the body depending on them are checked at each call.
+fn parameter_placeholder(
+    dfg: &mut Dfg,
+    parameter: &Located<String>,
+    parameter_type: &FenlType,
+) -> AstDfgRef {
+    let error = dfg.error_node();
+    match parameter_type {
+        FenlType::TypeRef(_) => error,
+        concrete => Arc::new(AstDfg::new(
+            error.value(),
+            error.is_new(),
+            concrete.clone(),
+            None,
+            TimeDomain::error(),
+            parameter.location().clone(),
+            None,
+        )),
+    }
+}
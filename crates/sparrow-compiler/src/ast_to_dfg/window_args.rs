@@ -1,23 +1,34 @@
-use arrow::datatypes::DataType;
-use sparrow_syntax::{FenlType, LiteralValue, Located, ResolvedExpr};
+use std::sync::Arc;
 
-use crate::ast_to_dfg::add_literal;
-use crate::dfg::Dfg;
+use anyhow::anyhow;
+use arrow::datatypes::{DataType, TimeUnit};
+use smallvec::smallvec;
+use sparrow_api::kaskada::v1alpha::operation_plan::tick_operation::TickBehavior;
+use sparrow_arrow::scalar_value::ScalarValue;
+use sparrow_plan::InstOp;
+use sparrow_syntax::{FenlType, LiteralValue, Located, ResolvedExpr, WindowBehavior};
+
+use crate::ast_to_dfg::{add_literal, AstDfg};
+use crate::dfg::{Dfg, Operation, TickSchedule};
 use crate::{AstDfgRef, DataContext, DiagnosticCode, DiagnosticCollector};
 
-/// Flattens window arguments into condition and duration nodes.
+/// Flattened window arguments: the condition, duration and window kind.
+pub(crate) type FlattenedWindow = (Located<AstDfgRef>, Located<AstDfgRef>, Located<AstDfgRef>);
+
+/// Flattens window arguments into condition, duration and window kind nodes.
 ///
 /// Windows are flattened to components that are executable concepts.
 /// Ticks indicate at what times rows will be inserted into tables,
 /// and the duration configures when aggregations will reset their
-/// internal states.
+/// internal states. The window kind is the label of the window, which
+/// determines how the condition and duration are interpreted.
 pub(crate) fn flatten_window_args(
     name: &Located<String>,
     window: &Located<Box<ResolvedExpr>>,
     dfg: &mut Dfg,
     data_context: &mut DataContext,
     diagnostics: &mut DiagnosticCollector<'_>,
-) -> anyhow::Result<(Located<AstDfgRef>, Located<AstDfgRef>)> {
+) -> anyhow::Result<FlattenedWindow> {
     if name.inner() == "since" {
         debug_assert!(
             window.args().len() == 1,
@@ -38,7 +49,8 @@ pub(crate) fn flatten_window_args(
         );
 
         let condition = crate::ast_to_dfg(data_context, dfg, diagnostics, &window.args()[0])?;
-        Ok((window.with_value(condition), null_arg))
+        let kind = window_kind(dfg, window, WindowBehavior::Since)?;
+        Ok((window.with_value(condition), null_arg, kind))
     } else if name.inner() == "sliding" {
         debug_assert!(
             window.args().len() == 2,
@@ -47,10 +59,97 @@ pub(crate) fn flatten_window_args(
         );
         let duration = &window.args()[0];
         let duration_node = crate::ast_to_dfg(data_context, dfg, diagnostics, duration)?;
+        if let Some(&ScalarValue::Int64(Some(count))) = dfg.literal(duration_node.value()) {
+            if count <= 0 {
+                DiagnosticCode::InvalidArgumentType
+                    .builder()
+                    .with_label(
+                        duration.location().primary_label().with_message(format!(
+                            "Invalid duration for sliding window: '{count}'"
+                        )),
+                    )
+                    .with_note("Sliding windows require a positive number of intervals".to_string())
+                    .emit(diagnostics);
+                return Ok(error_window(dfg, window));
+            }
+        }
         let duration = duration.with_value(duration_node);
 
         let condition = crate::ast_to_dfg(data_context, dfg, diagnostics, &window.args()[1])?;
-        Ok((window.with_value(condition), duration))
+        let kind = window_kind(dfg, window, WindowBehavior::Sliding)?;
+        Ok((window.with_value(condition), duration, kind))
+    } else if name.inner() == "trailing" || name.inner() == "session" {
+        debug_assert!(
+            window.args().len() == 1,
+            "expected one arg for {} window, saw {}",
+            name.inner(),
+            window.args().len()
+        );
+
+        // Trailing aggregations use a null condition, since they are based on
+        // the time of each input rather than on ticks. Session aggregations use
+        // a condition marking the events of the input, which extend the session
+        // even if the input is null.
+        let behavior = if name.inner() == "session" {
+            WindowBehavior::Session
+        } else {
+            WindowBehavior::Trailing
+        };
+        let condition = if behavior == WindowBehavior::Session {
+            session_events(dfg, window)?
+        } else {
+            let null_arg = dfg.add_literal(LiteralValue::Null.to_scalar()?)?;
+            Located::new(
+                add_literal(
+                    dfg,
+                    null_arg,
+                    FenlType::Concrete(DataType::Null),
+                    window.location().clone(),
+                )?,
+                window.location().clone(),
+            )
+        };
+
+        let duration = &window.args()[0];
+        let duration_node = crate::ast_to_dfg(data_context, dfg, diagnostics, duration)?;
+        let Some(literal) = dfg.literal(duration_node.value()) else {
+            // Non-constant durations are reported when checking the arguments to
+            // the window function.
+            return Ok(error_window(dfg, window));
+        };
+
+        match duration_nanos(literal) {
+            Some(nanos) if nanos > 0 => {
+                // For session windows, the duration is the inactivity gap.
+                let nanos = dfg.add_literal(ScalarValue::Int64(Some(nanos)))?;
+                let nanos = add_literal(
+                    dfg,
+                    nanos,
+                    FenlType::Concrete(DataType::Int64),
+                    duration.location().clone(),
+                )?;
+                let kind = window_kind(dfg, window, behavior)?;
+                Ok((condition, duration.with_value(nanos), kind))
+            }
+            _ => {
+                let note = if behavior == WindowBehavior::Session {
+                    "Session windows require a positive fixed gap, such as \
+                     'seconds(1800)' or 'days(1)'"
+                } else {
+                    "Trailing windows require a positive fixed duration, such as \
+                     'days(7)' or 'seconds(30)'"
+                };
+                DiagnosticCode::InvalidArgumentType
+                    .builder()
+                    .with_label(duration.location().primary_label().with_message(format!(
+                        "Invalid duration for {} window: '{literal}'",
+                        name.inner()
+                    )))
+                    .with_note(note.to_string())
+                    .emit(diagnostics);
+                Ok(error_window(dfg, window))
+            }
+        }
     } else {
         DiagnosticCode::InvalidArgumentType
             .builder()
@@ -59,11 +158,109 @@ pub(crate) fn flatten_window_args(
                     .primary_label()
                     .with_message(format!("Invalid window function: '{}'", name.inner())),
             )
-            .with_note("Supported windows: 'since', 'sliding'".to_string())
+            .with_note("Supported windows: 'since', 'sliding', 'trailing', 'session'".to_string())
             .emit(diagnostics);
-        Ok((
-            window.with_value(dfg.error_node()),
-            window.with_value(dfg.error_node()),
-        ))
+        Ok(error_window(dfg, window))
+    }
+}
+
+/// Returns a string literal containing the label of the window behavior.
+fn window_kind(
+    dfg: &mut Dfg,
+    window: &Located<Box<ResolvedExpr>>,
+    behavior: WindowBehavior,
+) -> anyhow::Result<Located<AstDfgRef>> {
+    let label = dfg.add_literal(ScalarValue::Utf8(Some(behavior.label().to_owned())))?;
+    let label = add_literal(
+        dfg,
+        label,
+        FenlType::Concrete(DataType::Utf8),
+        window.location().clone(),
+    )?;
+    Ok(window.with_value(label))
+}
+
+/// Returns the flattened arguments for a window which has been reported as invalid.
+fn error_window(dfg: &Dfg, window: &Located<Box<ResolvedExpr>>) -> FlattenedWindow {
+    (
+        window.with_value(dfg.error_node()),
+        window.with_value(dfg.error_node()),
+        window.with_value(dfg.error_node()),
+    )
+}
+
+/// Returns a boolean which is `true` at each event of the aggregation input.
+///
+/// Every row of the input is an event, including those where the input is
+/// null, so all aggregations of the same input see the same sessions.
+///
+/// For final results, the condition also includes the final tick, which is
+/// not an event. This closes sessions that ended more than the gap before
+/// the final tick, rather than reporting them as the current session.
+fn session_events(
+    dfg: &mut Dfg,
+    window: &Located<Box<ResolvedExpr>>,
+) -> anyhow::Result<Located<AstDfgRef>> {
+    let input = dfg
+        .get_binding("$condition_input")
+        .map_err(|_| anyhow!("Expected '$condition_input' to be bound for session window"))?;
+    let time = dfg.add_instruction(InstOp::TimeOf, smallvec![input.value()])?;
+    let mut events = dfg.add_instruction(InstOp::IsValid, smallvec![time])?;
+    let mut is_new = input.is_new();
+    if dfg.is_final_results() {
+        let input_op = dfg.operation(input.value());
+        let tick = dfg.add_operation(
+            Operation::Tick(TickSchedule::new(TickBehavior::Finished)),
+            smallvec![input_op],
+        )?;
+        let is_tick = dfg.add_instruction(InstOp::IsValid, smallvec![tick])?;
+        let not_tick = dfg.add_instruction(InstOp::Not, smallvec![is_tick])?;
+        events = dfg.add_instruction(InstOp::LogicalAnd, smallvec![events, not_tick])?;
+        is_new = dfg.add_instruction(InstOp::LogicalOr, smallvec![is_new, tick])?;
+    }
+    let events = Arc::new(AstDfg::new(
+        events,
+        is_new,
+        FenlType::Concrete(DataType::Boolean),
+        input.grouping(),
+        input.time_domain().clone(),
+        window.location().clone(),
+        None,
+    ));
+    Ok(window.with_value(events))
+}
+
+/// Returns the behavior of the window with the given flattened window kind.
+///
+/// This applies to windows flattened from Fenl and those built by the Python
+/// builder alike. Returns `None` if the aggregation is unwindowed, or if the
+/// window kind is not the label of a known window.
+pub(crate) fn window_behavior(dfg: &Dfg, kind: &AstDfgRef) -> Option<WindowBehavior> {
+    dfg.string_literal(kind.value())
+        .and_then(WindowBehavior::from_label)
+}
+
+/// Returns the number of nanoseconds in a fixed duration literal.
+///
+/// Returns `None` if the literal is null, overflows, or is not a fixed
+/// duration. Month intervals are not fixed, since the length of a month
+/// varies.
+pub(crate) fn duration_nanos(literal: &ScalarValue) -> Option<i64> {
+    match literal {
+        ScalarValue::Duration(Some(n), unit) => {
+            let nanos_per_unit = match unit {
+                TimeUnit::Second => 1_000_000_000,
+                TimeUnit::Millisecond => 1_000_000,
+                TimeUnit::Microsecond => 1_000,
+                TimeUnit::Nanosecond => 1,
+            };
+            n.checked_mul(nanos_per_unit)
+        }
+        ScalarValue::IntervalDayTime(Some((days, millis))) => {
+            let days = (*days as i64).checked_mul(86_400_000_000_000)?;
+            let millis = (*millis as i64).checked_mul(1_000_000)?;
+            days.checked_add(millis)
+        }
+        _ => None,
     }
 }
@@ -47,7 +47,7 @@ use smallvec::smallvec;
 use tracing::{info, info_span};
 pub use useless_transforms::*;
 
-use crate::ast_to_dfg::AstDfg;
+use crate::ast_to_dfg::{AstDfg, UserFunction};
 use crate::dfg::language::DfgLang;
 use crate::env::Env;
 use crate::nearest_matches::NearestMatches;
@@ -61,6 +61,10 @@ pub struct Dfg {
     graph: DfgGraph,
     /// A mapping from identifiers to corresponding DFG nodes.
     env: Env<String, AstDfgRef>,
+    /// A mapping from names to the user-defined functions in scope.
+    ///
+    /// Scopes are entered and exited along with `env`.
+    functions: Env<String, Arc<UserFunction>>,
     /// String literal IDs (used for interning, and to avoid copying).
     string_literals: HashMap<String, Id>,
     /// Reference to a shared error node.
@@ -70,12 +74,17 @@ pub struct Dfg {
     error_node: AstDfgRef,
     /// Id of the empty operation.
     empty_operation: Id,
+    /// The lists each enclosing lambda is applied to, innermost last.
+    lambdas: Vec<AstDfgRef>,
+    /// Whether the query produces final results.
+    final_results: bool,
 }
 
 impl Default for Dfg {
     fn default() -> Self {
         let mut graph = DfgGraph::default();
         let env = Env::default();
+        let functions = Env::default();
         let string_literals = HashMap::default();
 
         // Preemptively create a single error node, allowing for shallow
@@ -99,9 +108,12 @@ impl Default for Dfg {
         Self {
             graph,
             env,
+            functions,
             string_literals,
             error_node,
             empty_operation,
+            lambdas: Vec::new(),
+            final_results: false,
         }
     }
 }
@@ -138,23 +150,7 @@ impl Dfg {
         // Second, make sure all of the arguments are in that operation.
         // This may require applying `transform`.
         for arg in children.iter_mut() {
-            let arg_operation = self.operation(*arg);
-            if arg_operation != operation {
-                if let Some(literal) = self.literal(*arg).cloned() {
-                    // TODO: Re-evaluate the various `add` methods.
-                    // Should the operation be explicit? etc.
-                    // Can't use `add_literal` because we *want* the literal in an operation (not
-                    // `none`). Can't use `add_expression` because that infers
-                    // the operation.
-                    *arg = self.add_node(
-                        StepKind::Expression(Expression::Literal(literal)),
-                        smallvec![operation],
-                    )?;
-                } else {
-                    *arg = self.add_node(StepKind::Transform, smallvec![*arg, operation])?;
-                }
-            }
-            debug_assert_eq!(self.operation(*arg), operation);
+            *arg = self.move_to_operation(*arg, operation)?;
         }
         children.push(operation);
 
@@ -162,11 +158,38 @@ impl Dfg {
         self.add_node(StepKind::Expression(expression), children)
     }
 
+    /// Return the node for the value of `id` within the given `operation`.
+    ///
+    /// This may require applying `transform`.
+    pub(super) fn move_to_operation(&mut self, id: Id, operation: Id) -> anyhow::Result<Id> {
+        let id_operation = self.operation(id);
+        let id = if id_operation == operation {
+            id
+        } else if let Some(literal) = self.literal(id).cloned() {
+            // TODO: Re-evaluate the various `add` methods.
+            // Should the operation be explicit? etc.
+            // Can't use `add_literal` because we *want* the literal in an operation (not
+            // `none`). Can't use `add_expression` because that infers
+            // the operation.
+            self.add_node(
+                StepKind::Expression(Expression::Literal(literal)),
+                smallvec![operation],
+            )?
+        } else {
+            self.add_node(StepKind::Transform, smallvec![id, operation])?
+        };
+        debug_assert_eq!(self.operation(id), operation);
+        Ok(id)
+    }
+
     /// Infer the operation for the a function of the given arguments.
     ///
     /// This may require creating a new operation merging together
     /// the different operations associated with the expression.
-    fn infer_operation(&mut self, args: impl IntoIterator<Item = Id>) -> anyhow::Result<Id> {
+    pub(super) fn infer_operation(
+        &mut self,
+        args: impl IntoIterator<Item = Id>,
+    ) -> anyhow::Result<Id> {
         // First, determine the operation the expression should be in.
         // This is created by merging the operations from each of the arguments.
         #[allow(clippy::needless_collect)]
@@ -289,6 +312,14 @@ impl Dfg {
                             children.len()
                         )
                     }
+                    Expression::Inst(InstKind::Case) => {
+                        anyhow::ensure!(
+                            children.len() % 2 == 0 && children.len() > 2,
+                            "Case should have an even number of arguments (operation + \
+                             (condition, value)+ + default) but was {}",
+                            children.len()
+                        )
+                    }
                 }
             }
             StepKind::Transform => {
@@ -327,12 +358,22 @@ impl Dfg {
         self.error_node.clone()
     }
 
+    /// Returns true if the ID is the error node.
+    ///
+    /// This includes the placeholders bound to parameters while checking the
+    /// body of a user-defined function.
+    pub(super) fn is_error(&self, id: Id) -> bool {
+        self.graph.find(id) == self.graph.find(self.error_node.value())
+    }
+
     pub(super) fn enter_env(&mut self) {
         self.env.enter();
+        self.functions.enter();
     }
 
     pub(super) fn exit_env(&mut self) {
-        self.env.exit()
+        self.env.exit();
+        self.functions.exit();
     }
 
     /// Add a binding for the given name to the environment.
@@ -343,6 +384,48 @@ impl Dfg {
         self.env.insert(name.to_owned(), value)
     }
 
+    /// Add a user-defined function with the given name to the environment.
+    pub(super) fn bind_function(&mut self, name: &str, function: Arc<UserFunction>) {
+        self.functions.insert(name.to_owned(), function);
+    }
+
+    /// Return the user-defined function with the given name, if one is in
+    /// scope.
+    pub(super) fn get_function(&self, name: &str) -> Option<Arc<UserFunction>> {
+        self.functions.get(name).cloned()
+    }
+
+    /// Enter the body of a lambda applied to the elements of `list`.
+    pub(super) fn enter_lambda(&mut self, list: AstDfgRef) {
+        self.lambdas.push(list);
+    }
+
+    pub(super) fn exit_lambda(&mut self) {
+        self.lambdas
+            .pop()
+            .expect("exit_lambda should follow enter_lambda");
+    }
+
+    /// Returns true if currently within the body of a lambda.
+    pub(super) fn in_lambda(&self) -> bool {
+        !self.lambdas.is_empty()
+    }
+
+    /// Returns the lists each enclosing lambda is applied to, innermost last.
+    pub(super) fn lambda_lists(&self) -> &[AstDfgRef] {
+        &self.lambdas
+    }
+
+    /// Mark the query as producing final results.
+    pub(super) fn set_final_results(&mut self) {
+        self.final_results = true;
+    }
+
+    /// Returns true if the query produces final results.
+    pub(super) fn is_final_results(&self) -> bool {
+        self.final_results
+    }
+
     pub(super) fn is_bound(&self, name: &str) -> bool {
         self.env.contains(name)
     }
@@ -513,6 +596,22 @@ impl Dfg {
         })
     }
 
+    /// Returns true if the ID is a `json` value or a path within one.
+    ///
+    /// Paths within a `json` value are strings containing the JSON at that
+    /// path, which may themselves be accessed by field or index.
+    pub fn is_json_path(&self, id: Id) -> bool {
+        self.graph[id].nodes.iter().any(|node| match node.kind() {
+            StepKind::Expression(Expression::Inst(InstKind::Simple(
+                InstOp::Json | InstOp::JsonField,
+            ))) => true,
+            StepKind::Expression(Expression::Inst(InstKind::FieldRef)) => {
+                self.is_json_path(node.children()[0])
+            }
+            _ => false,
+        })
+    }
+
     /// Returns the ID of the operation node defining the domain of `id`.
     pub fn operation(&self, id: Id) -> Id {
         self.graph[id].data.operation(id)
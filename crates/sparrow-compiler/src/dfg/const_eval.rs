@@ -32,13 +32,24 @@ pub(super) fn evaluate_constant(
             //
             // It may turn out to need more thinking, but we're sticking with it for
             // now to fix various panics caused by not having *some* behavior defined.
+            InstOp::ApproxCountDistinct => return Ok(ScalarValue::UInt32(Some(0))),
+            InstOp::ApproxPercentile => return Ok(ScalarValue::Float64(None)),
+            InstOp::CollectDistinct => return Ok(ScalarValue::Null),
+            InstOp::CollectMap => return Ok(ScalarValue::Null),
             InstOp::CountIf => return Ok(ScalarValue::UInt32(Some(0))),
+            InstOp::DecayedCount => return Ok(ScalarValue::from_f64(0.0)),
+            InstOp::DecayedSum => return Ok(ScalarValue::Float64(None)),
+            InstOp::Ewma => return Ok(ScalarValue::Float64(None)),
             InstOp::First => return Ok(inputs[0].null()),
             InstOp::Last => return Ok(inputs[0].null()),
             InstOp::Max => return Ok(inputs[0].null()),
+            InstOp::MaxBy => return Ok(inputs[0].null()),
             InstOp::Mean => return Ok(ScalarValue::Float64(None)),
             InstOp::Min => return Ok(inputs[0].null()),
+            InstOp::MinBy => return Ok(inputs[0].null()),
+            InstOp::MostFrequent => return Ok(ScalarValue::Null),
             InstOp::Sum => return Ok(inputs[0].null()),
+            InstOp::TopK => return Ok(ScalarValue::Null),
             InstOp::Variance => return Ok(ScalarValue::Float64(None)),
 
             // Handle instructions for which the default `null` behavior of
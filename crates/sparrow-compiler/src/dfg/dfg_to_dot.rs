@@ -87,6 +87,7 @@ fn node_label(kind: &StepKind) -> Cow<'static, str> {
             Cow::Owned(label)
         }
         StepKind::Expression(Expression::Inst(InstKind::Record)) => Cow::Borrowed("record"),
+        StepKind::Expression(Expression::Inst(InstKind::Case)) => Cow::Borrowed("case"),
         StepKind::Transform => Cow::Borrowed("transform"),
         StepKind::Error => Cow::Borrowed("error"),
         StepKind::Window(window) => Cow::Borrowed(window.label()),
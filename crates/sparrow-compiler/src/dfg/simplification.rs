@@ -109,6 +109,7 @@ static RULES: Vec<Rewrite<DfgLang, DfgAnalysis>> = vec![
     // HACK: This simplifies away the `json(str) -> json` instruction into the internal
     // `json_field(str, field) -> str` instruction.
     rewrite!("json-to-json_field"; "(field_ref (json ?value ?op) ?field ?op)" => "(json_field ?value ?field ?op)"),
+    rewrite!("json_field-of-json"; "(json_field (json ?value ?op) ?field ?op)" => "(json_field ?value ?field ?op)"),
     //
     //--------------------------------------
     // Rewrite rules for operations (merge join, transform, etc.)
@@ -73,8 +73,38 @@ pub(crate) enum Operation {
     ShiftUntil,
     /// Takes one operation argument indicating the operation to tick over.
     ///
-    /// Used for creating signals at periodic points in time.
-    Tick(TickBehavior),
+    /// Used for creating signals at periodic points in time, as described by
+    /// the schedule.
+    Tick(TickSchedule),
+}
+
+/// Describes the times a tick operation produces ticks at.
+#[derive(Debug, Clone, PartialEq, Hash, Eq, PartialOrd, Ord)]
+pub(crate) struct TickSchedule {
+    pub behavior: TickBehavior,
+    /// The IANA time zone calendar ticks (such as `daily`) are produced in.
+    pub time_zone: String,
+    /// The day weekly ticks are produced on, as the number of days from
+    /// Monday.
+    pub week_start: u32,
+    /// The number of nanoseconds between `every` ticks.
+    pub interval: i64,
+    /// The offset of `every` ticks from the epoch, in nanoseconds.
+    ///
+    /// This is normalized to be less than the interval.
+    pub offset: i64,
+}
+
+impl TickSchedule {
+    pub fn new(behavior: TickBehavior) -> Self {
+        Self {
+            behavior,
+            time_zone: "UTC".to_owned(),
+            week_start: 0,
+            interval: 0,
+            offset: 0,
+        }
+    }
 }
 
 /// The expression nodes in the DFG.
@@ -125,6 +155,21 @@ pub(crate) enum StepKind {
     Window(WindowBehavior),
 }
 
+impl std::fmt::Display for TickSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.behavior)?;
+        match self.behavior {
+            TickBehavior::Weekly => write!(f, ":{}", self.week_start)?,
+            TickBehavior::Every => write!(f, ":{}+{}", self.interval, self.offset)?,
+            _ => (),
+        }
+        if self.time_zone != "UTC" {
+            write!(f, ":{}", self.time_zone)?;
+        }
+        Ok(())
+    }
+}
+
 impl std::fmt::Display for Operation {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -140,7 +185,7 @@ impl std::fmt::Display for Operation {
             Self::Select => write!(f, "select"),
             Self::ShiftTo => write!(f, "shift_to"),
             Self::ShiftUntil => write!(f, "shift_until"),
-            Self::Tick(behavior) => write!(f, "tick:{behavior:?}"),
+            Self::Tick(schedule) => write!(f, "tick:{schedule}"),
         }
     }
 }
@@ -61,6 +61,8 @@ CyclicReference(E0012, Error, "Circular dependency", ""),
 InvalidOutputType(E0013, Error, "Invalid output type", "https://kaskada.io/docs-site/kaskada/main/fenl/fenl-diagnostic-codes.html#e0013"),
 InvalidNonConstArgument(E0014, Error, "Invalid non-constant argument", ""),
 IncompatibleArgumentTypes(E0015, Error, "Incompatible argument types", ""),
+FunctionAlreadyDefined(E0016, Error, "Function already defined", ""),
+IllegalLambda(E0017, Error, "Illegal lambda", ""),
 
 // Bugs: 1000 - 1999
 InternalError(B1000, Bug, "Internal error", ""),
@@ -1,7 +1,7 @@
 //! Functionality for analyzing the AST before conversion to the DFG.
 
-mod first_reference;
-mod free_variable;
+pub(crate) mod first_reference;
+pub(crate) mod free_variable;
 mod incremental_enabled;
 mod output_types;
 mod parse_expr;
@@ -22,6 +22,7 @@ use sparrow_syntax::{FeatureSetPart, FenlType, Location};
 use tracing::error;
 
 use self::resolve_arguments::resolve_recursive;
+use crate::ast_to_dfg::UserFunction;
 use crate::dfg::{Dfg, DfgExpr, Expression};
 use crate::frontend::parse_feature_set::ParsedFeatureSet;
 use crate::time_domain::TimeDomain;
@@ -97,8 +98,11 @@ pub(super) struct FrontendOutput {
 }
 
 const CHANGED_SINCE_DECORATION: &str = "result | when(time_of($input) >= __changed_since_time__)";
+// Rows at the final tick, such as those closing sessions, occur just after
+// the final time, so they are kept by `finished()`.
 const FINAL_QUERY_AT_TIME_DECORATION: &str = "result | when(time_of($input) <= __final_at_time__ \
-                                              ) | last() | when(last(time_of($input)) >= \
+                                              or finished()) | last() | \
+                                              when(last(time_of($input)) >= \
                                               __changed_since_time__ and finished())";
 const FINAL_QUERY_DECORATION: &str =
     "result | last() | when(last(time_of(result)) >= __changed_since_time__ and finished())";
@@ -117,6 +121,14 @@ impl FrontendOutput {
         let mut dfg = data_context.create_dfg()?;
         let mut diagnostics = DiagnosticCollector::new(feature_set);
 
+        if matches!(
+            options.per_entity_behavior,
+            PerEntityBehavior::Final | PerEntityBehavior::FinalAtTime
+        ) {
+            // Session windows are closed at the final tick.
+            dfg.set_final_results();
+        }
+
         let parsed = ParsedFeatureSet::try_new(feature_set, &mut diagnostics)?;
         for formula in parsed.formulas.into_iter() {
             debug_assert!(
@@ -124,8 +136,20 @@ impl FrontendOutput {
                 "Unexpected: Formula '{}' is already bound",
                 formula.name
             );
-            let expr_dfg = ast_to_dfg(data_context, &mut dfg, &mut diagnostics, &formula.expr)?;
-            dfg.bind(formula.name, expr_dfg);
+            if let Some(function) = formula.function {
+                let function = UserFunction::new(
+                    data_context,
+                    &mut dfg,
+                    &mut diagnostics,
+                    &function.name,
+                    &function.signature,
+                    &formula.expr,
+                )?;
+                dfg.bind_function(formula.name, Arc::new(function));
+            } else {
+                let expr_dfg = ast_to_dfg(data_context, &mut dfg, &mut diagnostics, &formula.expr)?;
+                dfg.bind(formula.name, expr_dfg);
+            }
         }
 
         // Add the query.
@@ -10,7 +10,7 @@ use sparrow_syntax::{ExprOp, Located, Resolved, ResolvedExpr};
 ///
 /// This doesn't need to use an environment. Instead, it just skips processing
 /// any sub-expressions in which `needle` would be shadowed.
-pub(super) fn first_reference<'a>(
+pub(crate) fn first_reference<'a>(
     expr: &'a ResolvedExpr,
     needle: &str,
 ) -> Option<&'a Located<String>> {
@@ -54,15 +54,48 @@ pub(super) fn first_reference<'a>(
 
             first_reference(expr.args()[bindings].inner(), needle)
         }
+        ExprOp::Def(_, signature, _) => {
+            // The parameters shadow the needle within the body.
+            if !signature.arg_names().iter().any(|p| p.inner() == needle) {
+                let found = first_reference(expr.args()[0].inner(), needle);
+                if found.is_some() {
+                    return found;
+                }
+            }
+
+            first_reference(expr.args()[1].inner(), needle)
+        }
+        ExprOp::Lambda(parameter, _) => {
+            // The parameter shadows the needle within the body.
+            if parameter.inner() == needle {
+                None
+            } else {
+                first_reference(expr.args()[0].inner(), needle)
+            }
+        }
         ExprOp::Record(_, _) => recurse(expr.args(), needle),
         ExprOp::ExtendRecord(_) | ExprOp::RemoveFields(_) | ExprOp::SelectFields(_) => {
             recurse(expr.args(), needle)
         }
         ExprOp::Cast(_, _) => recurse(expr.args(), needle),
+        ExprOp::Case(_) => recurse(expr.args(), needle),
         ExprOp::Error => None,
     }
 }
 
+/// Return the first call to the function named `needle`.
+///
+/// User-defined functions may not be redefined, so calls can't be shadowed.
+pub(crate) fn first_call<'a>(expr: &'a ResolvedExpr, needle: &str) -> Option<&'a Located<String>> {
+    match expr.op() {
+        ExprOp::Call(name) if name.inner() == needle => Some(name),
+        _ => expr
+            .args()
+            .iter()
+            .find_map(|arg| first_call(arg.inner(), needle)),
+    }
+}
+
 fn recurse<'a>(
     args: &'a Resolved<Located<Box<ResolvedExpr>>>,
     needle: &str,
@@ -3,7 +3,7 @@ use std::collections::BTreeSet;
 use hashbrown::HashSet;
 use sparrow_syntax::{ExprOp, Located, Resolved, ResolvedExpr};
 
-use crate::{DiagnosticCode, DiagnosticCollector};
+use crate::{DiagnosticBuilder, DiagnosticCode, DiagnosticCollector};
 
 /// Return the names (identifiers) of variables which are free in `expr`.
 ///
@@ -14,16 +14,78 @@ pub(super) fn free_variables(
     expr: &ResolvedExpr,
     diagnostics: &mut DiagnosticCollector<'_>,
 ) -> HashSet<String> {
-    analysis(expr, diagnostics)
+    let mut warnings = Vec::new();
+    let free = analysis(expr, &mut warnings)
         .into_iter()
         .map(|s| s.to_owned())
-        .collect()
+        .collect();
+    diagnostics.collect_all(warnings);
+    free
+}
+
+/// Return the names of variables which are free in `expr`.
+///
+/// Unlike [free_variables], this doesn't report unused bindings. It is used
+/// when the expression has already been analyzed.
+pub(crate) fn free_names(expr: &ResolvedExpr) -> BTreeSet<&str> {
+    analysis(expr, &mut Vec::new())
+}
+
+/// Return the names of variables which are free in the body of a function.
+///
+/// The parameters are bound within the body. Reports unused bindings and
+/// parameters as diagnostics.
+pub(super) fn function_free_variables(
+    body: &ResolvedExpr,
+    parameters: &[Located<String>],
+    diagnostics: &mut DiagnosticCollector<'_>,
+) -> HashSet<String> {
+    let mut warnings = Vec::new();
+    let mut free = analysis(body, &mut warnings);
+    remove_parameters(&mut free, parameters, &mut warnings);
+    diagnostics.collect_all(warnings);
+    free.into_iter().map(|s| s.to_owned()).collect()
+}
+
+/// Return the names of the functions called in `expr`.
+pub(super) fn called_functions(expr: &ResolvedExpr) -> BTreeSet<&str> {
+    let mut called: BTreeSet<_> = expr
+        .args()
+        .iter()
+        .flat_map(|arg| called_functions(arg.inner()))
+        .collect();
+    if let ExprOp::Call(name) = expr.op() {
+        called.insert(name.inner().as_str());
+    }
+    called
+}
+
+/// Remove the parameters from the free set of a function body.
+///
+/// Reports a warning for each parameter which isn't used.
+fn remove_parameters(
+    free: &mut BTreeSet<&str>,
+    parameters: &[Located<String>],
+    diagnostics: &mut Vec<DiagnosticBuilder>,
+) {
+    for parameter in parameters {
+        if !free.remove(parameter.inner().as_str()) {
+            diagnostics.push(
+                DiagnosticCode::UnusedBinding.builder().with_label(
+                    parameter
+                        .location()
+                        .primary_label()
+                        .with_message(format!("Unused parameter '{}'", parameter.inner())),
+                ),
+            );
+        }
+    }
 }
 
 /// Analyzes the individual arguments and unions their free sets.
 fn analyze_args<'a>(
     args: &'a Resolved<Located<Box<ResolvedExpr>>>,
-    diagnostics: &mut DiagnosticCollector<'_>,
+    diagnostics: &mut Vec<DiagnosticBuilder>,
 ) -> BTreeSet<&'a str> {
     args.iter()
         .map(|arg| analysis(arg.inner(), diagnostics))
@@ -38,7 +100,7 @@ fn analyze_args<'a>(
 /// Recursively apply the analysis to `expr`.
 fn analysis<'a>(
     expr: &'a ResolvedExpr,
-    diagnostics: &mut DiagnosticCollector<'_>,
+    diagnostics: &mut Vec<DiagnosticBuilder>,
 ) -> BTreeSet<&'a str> {
     match expr.op() {
         ExprOp::Literal(_) => BTreeSet::new(),
@@ -53,14 +115,13 @@ fn analysis<'a>(
 
             // 2. If the rhs does not contain "$input", report a warning
             if !free.remove("$input") {
-                DiagnosticCode::UnusedBinding
-                    .builder()
-                    .with_label(
+                diagnostics.push(
+                    DiagnosticCode::UnusedBinding.builder().with_label(
                         location
                             .primary_label()
                             .with_message("Left-hand side of pipe not used"),
-                    )
-                    .emit(diagnostics);
+                    ),
+                );
             };
 
             // 3. Add free variables from the lhs
@@ -83,14 +144,13 @@ fn analysis<'a>(
                     // 1. Remove this binding from the free set. Report an unused
                     //    error if it wasn't there.
                     if !free.remove(name.inner().as_str()) {
-                        DiagnosticCode::UnusedBinding
-                            .builder()
-                            .with_label(
+                        diagnostics.push(
+                            DiagnosticCode::UnusedBinding.builder().with_label(
                                 name.location()
                                     .primary_label()
                                     .with_message(format!("Unused binding '{}'", name.inner())),
-                            )
-                            .emit(diagnostics);
+                            ),
+                        );
                     };
 
                     // 2. Add things that are free in the value definition.
@@ -98,11 +158,37 @@ fn analysis<'a>(
                     free
                 })
         }
+        ExprOp::Def(_, signature, _) => {
+            // Parameters are bound within the body. The function itself is
+            // not a variable, so it isn't removed from the free set.
+            let mut body_free = analysis(expr.args()[0].inner(), diagnostics);
+            remove_parameters(&mut body_free, signature.arg_names(), diagnostics);
+
+            let mut free = analysis(expr.args()[1].inner(), diagnostics);
+            free.append(&mut body_free);
+            free
+        }
+        ExprOp::Lambda(parameter, _) => {
+            // The parameter is bound within the body.
+            let mut free = analysis(expr.args()[0].inner(), diagnostics);
+            if !free.remove(parameter.inner().as_str()) {
+                diagnostics.push(
+                    DiagnosticCode::UnusedBinding.builder().with_label(
+                        parameter
+                            .location()
+                            .primary_label()
+                            .with_message(format!("Unused parameter '{}'", parameter.inner())),
+                    ),
+                );
+            }
+            free
+        }
 
         ExprOp::SelectFields(_)
         | ExprOp::RemoveFields(_)
         | ExprOp::FieldRef(_, _)
         | ExprOp::Cast(_, _)
+        | ExprOp::Case(_)
         | ExprOp::Call(_)
         | ExprOp::Record(_, _)
         | ExprOp::ExtendRecord(_) => analyze_args(expr.args(), diagnostics),
@@ -226,6 +312,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_free_lambda_parameter_bound() {
+        assert_eq!(
+            test_free_variables("list_map(Foo.x, x -> x + Bar.y)"),
+            vec!["Bar".to_string(), "Foo".to_string()]
+        );
+    }
+
     #[test]
     fn test_free_missing_input_pipe() {
         let input = "Foo.x | sum(Foo.y)";
@@ -1,7 +1,7 @@
 use codespan_reporting::diagnostic::Label;
 use itertools::Itertools;
 use lalrpop_util::ParseError;
-use sparrow_syntax::{Expr, ExprRef, FeatureSetPart, Token};
+use sparrow_syntax::{Expr, ExprRef, FeatureSetPart, FormulaBody, Token};
 
 use crate::{DiagnosticBuilder, DiagnosticCode};
 
@@ -19,6 +19,22 @@ pub(super) fn parse_expr(
     }
 }
 
+/// Parse the given string to the body of a formula.
+///
+/// The formula may be an expression or a function definition.
+pub(super) fn parse_formula(
+    part_id: FeatureSetPart,
+    formula: &str,
+) -> Result<FormulaBody, Vec<DiagnosticBuilder>> {
+    match FormulaBody::try_from_str(part_id, formula) {
+        Ok(formula) => Ok(formula),
+        Err(errors) => Err(errors
+            .into_iter()
+            .map(|error| parse_error_to_diagnostic(part_id, error))
+            .collect()),
+    }
+}
+
 fn parse_error_to_diagnostic(
     part_id: FeatureSetPart,
     error: ParseError<usize, Token<'_>, (usize, String, usize)>,
@@ -6,13 +6,16 @@ use bit_set::BitSet;
 use hashbrown::{HashMap, HashSet};
 use itertools::Itertools;
 use sparrow_api::kaskada::v1alpha::FeatureSet;
-use sparrow_syntax::{Expr, ExprOp, FeatureSetPart, Resolved, ResolvedExpr};
+use sparrow_syntax::{
+    Expr, ExprOp, FeatureSetPart, FormulaBody, FunctionDef, Located, Resolved, ResolvedExpr,
+    Signature,
+};
 use tracing::error;
 
-use super::parse_expr::parse_expr;
-use super::resolve_arguments::resolve_recursive;
-use crate::frontend::first_reference::first_reference;
-use crate::frontend::free_variable::free_variables;
+use super::parse_expr::{parse_expr, parse_formula};
+use super::resolve_arguments::{resolve_in_scope, FunctionScope};
+use crate::frontend::first_reference::{first_call, first_reference};
+use crate::frontend::free_variable::{called_functions, free_variables, function_free_variables};
 use crate::{DiagnosticBuilder, DiagnosticCode, DiagnosticCollector};
 
 /// The parsed and resolved [FeatureSet].
@@ -31,7 +34,16 @@ pub(super) struct ParsedFeatureSet<'a> {
 /// The parsed and resolved [Formula].
 pub(super) struct ParsedFormula<'a> {
     pub name: &'a str,
+    /// The value of the formula, or the body of the function it defines.
     pub expr: ResolvedExpr,
+    /// The function defined by the formula, if any.
+    pub function: Option<FormulaFunction>,
+}
+
+/// The name and signature of a function defined by a formula.
+pub(super) struct FormulaFunction {
+    pub name: Located<String>,
+    pub signature: Signature,
 }
 
 impl<'a> ParsedFeatureSet<'a> {
@@ -80,7 +92,11 @@ struct FormulaInfo<'a> {
     /// The indices of formulas this formula depends on.
     dependencies: BitSet,
     /// The resolved expression for this formula.
+    ///
+    /// If the formula defines a function, this is the body of the function.
     expr: ResolvedExpr,
+    /// The function defined by this formula, if any.
+    function: Option<FormulaFunction>,
     /// The assigned index in the topologic order.
     ///
     /// Initialized as 0, and set appropriately when doing
@@ -104,43 +120,90 @@ fn parse_and_resolve<'a>(
 ) -> anyhow::Result<QueryInfo<'a>> {
     let name_to_index = formula_name_to_index(feature_set, diagnostics)?;
 
-    // Parse and resolve all formulas
-    let mut formula_infos: Vec<_> = feature_set
+    // Parse all formulas. The functions defined by formulas are collected
+    // before resolving, since they may be called from any formula.
+    let mut functions = FunctionScope::new();
+    let parsed_formulas: Vec<_> = feature_set
         .formulas
         .iter()
         .enumerate()
-        .map(|(index, formula)| -> anyhow::Result<_> {
+        .map(|(index, formula)| {
             let mut formula_diagnostics = Vec::new();
 
-            // Note: Could split up `parse` and `resolve`
             let part_id = FeatureSetPart::Formula(index as u32);
-            let expr = match parse_expr(part_id, &formula.formula) {
-                Ok(expr) => expr,
+            let (expr, function) = match parse_formula(part_id, &formula.formula) {
+                Ok(FormulaBody::Expr(expr)) => (expr, None),
+                Ok(FormulaBody::Function(function)) => {
+                    match check_formula_function(&formula.name, &function) {
+                        Ok(()) => {
+                            functions.insert(
+                                formula.name.clone(),
+                                (
+                                    function.name().location().clone(),
+                                    function.signature().arg_names().to_vec(),
+                                ),
+                            );
+                            let body = function.body().clone();
+                            let function = FormulaFunction {
+                                name: function.name().clone(),
+                                signature: function.signature().clone(),
+                            };
+                            (body, Some(function))
+                        }
+                        Err(diagnostic) => {
+                            // Like invalid formula names, this is reported even if
+                            // the formula isn't used, since calls to the function
+                            // wouldn't resolve to the formula.
+                            diagnostic.emit(diagnostics);
+                            (Arc::new(Expr::error()), None)
+                        }
+                    }
+                }
                 Err(mut parse_diagnostics) => {
                     formula_diagnostics.append(&mut parse_diagnostics);
-                    Arc::new(Expr::error())
+                    (Arc::new(Expr::error()), None)
                 }
             };
-
-            // Recursively resolve the expression
-            //
-            // TODO: Can use trait that implements `extend_one` for diagnostic
-            // once it is stable. https://github.com/rust-lang/rust/issues/72631
-            let resolved_expr = resolve_recursive(&expr, &mut formula_diagnostics)?;
-            let (free_names, dependencies) =
-                direct_dependencies(&resolved_expr, &name_to_index, diagnostics);
-            Ok(FormulaInfo {
-                name: &formula.name,
-                formula: &formula.formula,
-                index,
-                dependencies,
-                expr: resolved_expr,
-                // Initialize all with a default topo_order of 0. Will be re-set during ordering.
-                topo_order: 0,
-                free_names,
-                diagnostics: formula_diagnostics,
-            })
+            (expr, function, formula_diagnostics)
         })
+        .collect();
+
+    // Resolve all formulas
+    let mut formula_infos: Vec<_> = feature_set
+        .formulas
+        .iter()
+        .zip(parsed_formulas)
+        .enumerate()
+        .map(
+            |(index, (formula, (expr, function, mut formula_diagnostics)))| -> anyhow::Result<_> {
+                // Recursively resolve the expression
+                //
+                // TODO: Can use trait that implements `extend_one` for diagnostic
+                // once it is stable. https://github.com/rust-lang/rust/issues/72631
+                let resolved_expr =
+                    resolve_in_scope(&expr, &mut functions, &mut formula_diagnostics)?;
+                let (free_names, dependencies) = direct_dependencies(
+                    &resolved_expr,
+                    function.as_ref(),
+                    &name_to_index,
+                    &functions,
+                    diagnostics,
+                );
+                Ok(FormulaInfo {
+                    name: &formula.name,
+                    formula: &formula.formula,
+                    index,
+                    dependencies,
+                    expr: resolved_expr,
+                    function,
+                    // Initialize all with a default topo_order of 0. Will be re-set during
+                    // ordering.
+                    topo_order: 0,
+                    free_names,
+                    diagnostics: formula_diagnostics,
+                })
+            },
+        )
         .try_collect()?;
 
     // Parse the top-level query
@@ -157,15 +220,21 @@ fn parse_and_resolve<'a>(
     // TODO: Can use trait that implements `extend_one` for diagnostic
     // once it is stable. https://github.com/rust-lang/rust/issues/72631
     let mut query_diagnostics = Vec::new();
-    let resolved_query_expr = resolve_recursive(&query_expr, &mut query_diagnostics)?;
+    let resolved_query_expr =
+        resolve_in_scope(&query_expr, &mut functions, &mut query_diagnostics)?;
 
     // Collect any diagnostics from resolving the query
     if !query_diagnostics.is_empty() {
         diagnostics.collect_all(query_diagnostics);
     }
 
-    let (query_free_names, query_dependencies) =
-        direct_dependencies(&resolved_query_expr, &name_to_index, diagnostics);
+    let (query_free_names, query_dependencies) = direct_dependencies(
+        &resolved_query_expr,
+        None,
+        &name_to_index,
+        &functions,
+        diagnostics,
+    );
     if query_dependencies
         .iter()
         .any(|dep| !formula_infos[dep].diagnostics.is_empty())
@@ -236,6 +305,7 @@ fn order_feature_set<'a>(
         .map(|info| ParsedFormula {
             name: info.name,
             expr: info.expr,
+            function: info.function,
         })
         .collect();
 
@@ -286,14 +356,50 @@ fn formula_name_to_index<'a>(
     }
 }
 
+/// Check that a function defined by a formula may be bound to its name.
+///
+/// The function must have the same name as the formula, and may not redefine
+/// a built-in function.
+fn check_formula_function(
+    formula_name: &str,
+    function: &FunctionDef,
+) -> Result<(), DiagnosticBuilder> {
+    let name = function.name();
+    if name.inner() != formula_name {
+        Err(DiagnosticCode::IllegalIdentifier
+            .builder()
+            .with_label(name.location().primary_label().with_message(format!(
+                "Function '{name}' is defined in formula '{formula_name}'"
+            )))
+            .with_note("A function must be defined in a formula with the same name".to_owned()))
+    } else if crate::functions::get_function(name).is_ok() {
+        Err(DiagnosticCode::FunctionAlreadyDefined
+            .builder()
+            .with_label(
+                name.location()
+                    .primary_label()
+                    .with_message(format!("Function '{name}' is already defined")),
+            )
+            .with_note(format!("'{name}' is a built-in function")))
+    } else {
+        Ok(())
+    }
+}
+
 /// Returns all referenced names and referenced formulas.
 ///
 /// All referenced names are in a `HashSet<String>`, while
 /// referenced formulas are placed in a `BitSet` keyed by the
-/// `name_to_index` map.
+/// `name_to_index` map. Calls to the `functions` defined by
+/// formulas are also dependencies on those formulas.
+///
+/// If `expr` is the body of a `function`, the parameters are
+/// bound within it.
 pub(super) fn direct_dependencies(
     expr: &ResolvedExpr,
+    function: Option<&FormulaFunction>,
     name_to_index: &HashMap<&String, usize>,
+    functions: &FunctionScope,
     diagnostics: &mut DiagnosticCollector<'_>,
 ) -> (HashSet<String>, BitSet) {
     if matches!(expr.op(), ExprOp::Error) {
@@ -301,7 +407,12 @@ pub(super) fn direct_dependencies(
     } else {
         // Add the dependencies from free variables in the expression.
         let mut dependencies = BitSet::with_capacity(name_to_index.len());
-        let free_variables = free_variables(expr, diagnostics);
+        let free_variables = match function {
+            Some(function) => {
+                function_free_variables(expr, function.signature.arg_names(), diagnostics)
+            }
+            None => free_variables(expr, diagnostics),
+        };
 
         free_variables
             .iter()
@@ -309,6 +420,15 @@ pub(super) fn direct_dependencies(
             .for_each(|index| {
                 dependencies.insert(*index);
             });
+
+        // Add the dependencies from calls to functions defined by formulas.
+        called_functions(expr)
+            .into_iter()
+            .filter(|name| functions.contains(*name))
+            .filter_map(|name| name_to_index.get(&name.to_owned()))
+            .for_each(|index| {
+                dependencies.insert(*index);
+            });
         (free_variables, dependencies)
     }
 }
@@ -324,6 +444,10 @@ fn cycle_to_diagnostic(formula_infos: &[FormulaInfo<'_>], cycle: Vec<usize>) ->
                 diagnostic.with_label(dst_reference.location().primary_label().with_message(
                     format!("Formula '{}' referenced here in '{}'", dst.name, src.name),
                 ));
+        } else if let Some(dst_call) = first_call(&src.expr, dst.name) {
+            diagnostic = diagnostic.with_label(dst_call.location().primary_label().with_message(
+                format!("Function '{}' called here in '{}'", dst.name, src.name),
+            ));
         } else {
             error!(
                 "Missing reference to '{}' in dependency cycle from expr '{}'",
@@ -568,6 +692,7 @@ mod tests {
                         op: ExprOp::Error,
                         args: Resolved::empty(),
                     },
+                    function: None,
                     topo_order: 0,
                     free_names: HashSet::new(),
                     diagnostics: Vec::new(),
@@ -671,7 +796,7 @@ mod tests {
         1 | Foo + $$
           |       ^ Invalid token '$'
           |
-          = Expected "!", "$input", "(", "-", "{", ident, literal
+          = Expected "!", "$input", "(", "-", "case", "{", "~", ident, literal
 
         "###);
     }
@@ -693,7 +818,7 @@ mod tests {
         1 | Foo + $$
           |       ^ Invalid token '$'
           |
-          = Expected "!", "$input", "(", "-", "{", ident, literal
+          = Expected "!", "$input", "(", "-", "case", "{", "~", ident, literal
 
         "###);
     }
@@ -789,4 +914,63 @@ mod tests {
             query: "foo".to_owned(),
         }), @"Ordered: foo");
     }
+
+    #[test]
+    fn test_formula_function() {
+        // Calls to a function defined by a formula depend on that formula.
+        insta::assert_snapshot!(run_parse_and_order(FeatureSet {
+            formulas: vec![
+                Formula {
+                    name: "half".to_owned(),
+                    formula: "ratio(Foo, 2)".to_owned(),
+                    source_location: "half view".to_owned(),
+                },
+                Formula {
+                    name: "ratio".to_owned(),
+                    formula: "def ratio(a, b) = a / b".to_owned(),
+                    source_location: "ratio view".to_owned(),
+                },
+            ],
+            query: "{ half }".to_owned(),
+        }), @"Ordered: ratio, half");
+    }
+
+    #[test]
+    fn test_formula_function_parameter_shadows_formula() {
+        insta::assert_snapshot!(run_parse_and_order(FeatureSet {
+            formulas: vec![
+                Formula {
+                    name: "x".to_owned(),
+                    formula: "Foo.x".to_owned(),
+                    source_location: "x view".to_owned(),
+                },
+                Formula {
+                    name: "double".to_owned(),
+                    formula: "def double(x) = x + x".to_owned(),
+                    source_location: "double view".to_owned(),
+                },
+            ],
+            query: "{ y: double(Foo.y) }".to_owned(),
+        }), @"Ordered: double");
+    }
+
+    #[test]
+    fn test_formula_function_unused_parameter() {
+        insta::assert_snapshot!(run_parse_and_order(FeatureSet {
+            formulas: vec![Formula {
+                name: "first".to_owned(),
+                formula: "def first(a, b) = a".to_owned(),
+                source_location: "first view".to_owned(),
+            }],
+            query: "{ a: first(Foo.a, Foo.b) }".to_owned(),
+        }), @r###"
+        Diagnostics
+        warning[W2001]: Unused binding
+          --> 'first view':1:14
+          |
+        1 | def first(a, b) = a
+          |              ^ Unused parameter 'b'
+
+        "###);
+    }
 }
@@ -1,10 +1,12 @@
 use std::borrow::Cow;
 
+use smallvec::smallvec;
 use sparrow_syntax::{
-    Arguments, Expr, ExprOp, ExprRef, Located, ResolveError, Resolved, ResolvedExpr,
+    Arguments, Expr, ExprOp, ExprRef, Located, Location, ResolveError, Resolved, ResolvedExpr,
 };
 use static_init::dynamic;
 
+use crate::env::Env;
 use crate::{DiagnosticBuilder, DiagnosticCode};
 
 #[dynamic]
@@ -31,6 +33,24 @@ static SELECT_REMOVE_FIELDS_ARGUMENTS: [Located<String>; 2] = [
 #[dynamic]
 static CAST_ARGUMENTS: [Located<String>; 1] = [Located::internal_string("input")];
 
+#[dynamic]
+static CASE_ARGUMENTS: [Located<String>; 1] = [Located::internal_string("arms")];
+
+#[dynamic]
+static DEF_ARGUMENTS: [Located<String>; 2] = [
+    Located::internal_string("def_body"),
+    Located::internal_string("def_value"),
+];
+
+#[dynamic]
+static LAMBDA_ARGUMENTS: [Located<String>; 1] = [Located::internal_string("lambda_body")];
+
+/// The user-defined functions in scope.
+///
+/// Maps the name of each function to the location it was defined and the
+/// names of its parameters.
+pub(crate) type FunctionScope = Env<String, (Location, Vec<Located<String>>)>;
+
 /// Recursively resolves the arguments to the given operator and
 /// all sub-expressions.
 ///
@@ -45,15 +65,53 @@ static CAST_ARGUMENTS: [Located<String>; 1] = [Located::internal_string("input")
 pub(crate) fn resolve_recursive(
     expr: &ExprRef,
     diagnostics: &mut Vec<DiagnosticBuilder>,
+) -> anyhow::Result<ResolvedExpr> {
+    resolve_in_scope(expr, &mut FunctionScope::new(), diagnostics)
+}
+
+/// Recursively resolves the arguments, with the given user-defined functions
+/// in scope.
+///
+/// This is used to resolve calls to functions defined by formulas.
+pub(crate) fn resolve_in_scope(
+    expr: &ExprRef,
+    functions: &mut FunctionScope,
+    diagnostics: &mut Vec<DiagnosticBuilder>,
 ) -> anyhow::Result<ResolvedExpr> {
     // Resolve first -- this takes the reference to arguments we have and gives
     // us back an owned `Resolved<ExprRef>`.
-    match resolve_arguments(expr.op(), expr.args()) {
+    match resolve_arguments(expr.op(), expr.args(), functions) {
         Ok(args) => {
-            let args = args.try_transform(|arg| -> anyhow::Result<_> {
-                let resolved_arg = resolve_recursive(arg.inner(), diagnostics)?;
-                Ok(arg.with_value(Box::new(resolved_arg)))
-            })?;
+            let args = if let ExprOp::Def(name, signature, _) = expr.op() {
+                // The function is only available within the value, so it may
+                // not be called (recursively) from its own body.
+                let body = &args[0];
+                let body = body.with_value(Box::new(resolve_in_scope(
+                    body.inner(),
+                    functions,
+                    diagnostics,
+                )?));
+
+                functions.enter();
+                functions.insert(
+                    name.inner().clone(),
+                    (name.location().clone(), signature.arg_names().to_vec()),
+                );
+                let value = &args[1];
+                let value = value.with_value(Box::new(resolve_in_scope(
+                    value.inner(),
+                    functions,
+                    diagnostics,
+                )?));
+                functions.exit();
+
+                args.with_values(smallvec![body, value])
+            } else {
+                args.try_transform(|arg| -> anyhow::Result<_> {
+                    let resolved_arg = resolve_in_scope(arg.inner(), functions, diagnostics)?;
+                    Ok(arg.with_value(Box::new(resolved_arg)))
+                })?
+            };
             Ok(ResolvedExpr {
                 op: expr.op().clone(),
                 args,
@@ -74,6 +132,7 @@ pub(crate) fn resolve_recursive(
 fn resolve_arguments(
     op: &ExprOp,
     arguments: &Arguments<ExprRef>,
+    functions: &FunctionScope,
 ) -> Result<Resolved<Located<ExprRef>>, Option<DiagnosticBuilder>> {
     let (operator_location, names, defaults, vararg): (
         _,
@@ -86,6 +145,12 @@ fn resolve_arguments(
         ExprOp::FieldRef(_, location) => {
             (location, Cow::Borrowed(&*FIELD_REF_ARGUMENTS), None, false)
         }
+        ExprOp::Call(function_name) if functions.contains(function_name.inner()) => {
+            let (definition, names) = functions
+                .get(function_name.inner())
+                .expect("checked by guard");
+            return resolve_user_defined(function_name, definition, names, arguments);
+        }
         ExprOp::Call(function_name) => match crate::functions::get_function(function_name) {
             Ok(function) => {
                 let parameters = function.signature().parameters();
@@ -131,6 +196,31 @@ fn resolve_arguments(
             true,
         ),
         ExprOp::Cast(_, location) => (location, Cow::Borrowed(&*CAST_ARGUMENTS), None, false),
+        ExprOp::Case(location) => (location, Cow::Borrowed(&*CASE_ARGUMENTS), None, true),
+        ExprOp::Def(name, _, location) => {
+            let already_defined = || {
+                DiagnosticCode::FunctionAlreadyDefined.builder().with_label(
+                    name.location()
+                        .primary_label()
+                        .with_message(format!("Function '{name}' is already defined")),
+                )
+            };
+            if let Some((previous, _)) = functions.get(name.inner()) {
+                return Err(Some(
+                    already_defined().with_label(
+                        previous
+                            .secondary_label()
+                            .with_message("Previous definition was here"),
+                    ),
+                ));
+            } else if crate::functions::get_function(name).is_ok() {
+                return Err(Some(
+                    already_defined().with_note(format!("'{name}' is a built-in function")),
+                ));
+            }
+            (location, Cow::Borrowed(&*DEF_ARGUMENTS), None, false)
+        }
+        ExprOp::Lambda(_, location) => (location, Cow::Borrowed(&*LAMBDA_ARGUMENTS), None, false),
         ExprOp::Error => return Err(None),
     };
 
@@ -147,7 +237,16 @@ fn resolve_arguments(
         arguments.resolve(names.clone(), &defaults, &input, vararg)
     };
 
-    result.map_err(|err| match err {
+    result.map_err(|err| resolve_error_to_diagnostic(operator_location, &names, err))
+}
+
+/// Convert an error resolving arguments to the corresponding diagnostic.
+fn resolve_error_to_diagnostic(
+    operator_location: &Location,
+    names: &[Located<String>],
+    err: ResolveError,
+) -> Option<DiagnosticBuilder> {
+    match err {
         ResolveError::Internal(message) => Some(
             DiagnosticCode::InternalError
                 .builder()
@@ -228,7 +327,33 @@ fn resolve_arguments(
                     .with_note(format!("Nearest matches: {nearest}")),
             )
         }
-    })
+    }
+}
+
+/// Resolve the arguments to a call to a user-defined function.
+///
+/// Errors are reported at the call, with a secondary label at the definition.
+fn resolve_user_defined(
+    function_name: &Located<String>,
+    definition: &Location,
+    names: &[Located<String>],
+    arguments: &Arguments<ExprRef>,
+) -> Result<Resolved<Located<ExprRef>>, Option<DiagnosticBuilder>> {
+    let names: Cow<'static, [Located<String>]> = Cow::Owned(names.to_vec());
+    let defaults = vec![None; names.len()];
+    let input = Expr::implicit_input();
+    let input = Located::internal_str("$input").with_value(input.clone());
+    arguments
+        .resolve(names.clone(), &defaults, &input, false)
+        .map_err(|err| {
+            resolve_error_to_diagnostic(function_name.location(), &names, err).map(|diagnostic| {
+                diagnostic.with_label(
+                    definition
+                        .secondary_label()
+                        .with_message(format!("'{function_name}' defined here")),
+                )
+            })
+        })
 }
 
 #[cfg(test)]
@@ -1,22 +1,31 @@
 use anyhow::Context;
+use arrow::datatypes::DataType;
+use sparrow_arrow::scalar_value::ScalarValue;
+use sparrow_syntax::{FenlType, Located, WindowBehavior};
 
+use crate::ast_to_dfg::{add_literal, duration_nanos};
+use crate::dfg::Dfg;
+use crate::diagnostics::DiagnosticCode;
 use crate::functions::time_domain_check::TimeDomainCheck;
-use crate::functions::{Implementation, Pushdown, Registry};
+use crate::functions::{Function, Implementation, Pushdown, Registry};
+use crate::{AstDfgRef, DiagnosticCollector};
 
 /// The `is_new` pattern used for basic aggregations.
-const AGGREGATION_IS_NEW: &str = "(logical_or ?window_is_new ?input_is_new)";
+pub(super) const AGGREGATION_IS_NEW: &str = "(logical_or ?window_is_new ?input_is_new)";
 
 pub(super) fn register(registry: &mut Registry) {
     registry
         .register("count_if<T: any>(input: T, window: window = null) -> u32")
         .with_dfg_signature(
-            "count_if<T: any>(input: T, window: bool = null, duration: i64 = null) -> u32",
+            "count_if<T: any>(input: T, window: bool = null, duration: i64 = null, \
+             window_kind: string = null) -> u32",
         )
         .with_implementation(Implementation::new_pattern(&format!(
-            "(count_if ({}) ({}) ({}))",
+            "(count_if ({}) ({}) ({}) ({}))",
             "transform (if ?input_is_new ?input_value) (merge_join ?input_op ?window_op)",
             "?window_value",
             "?duration_value",
+            "?window_kind_value",
         )))
         .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
         .with_time_domain_check(TimeDomainCheck::Aggregation);
@@ -24,28 +33,50 @@ pub(super) fn register(registry: &mut Registry) {
     registry
         .register("count<T: any>(input: T, window: window = null) -> u32")
         .with_dfg_signature(
-            "count_if<T: any>(input: T, window: bool = null, duration: i64 = null) -> u32",
+            "count_if<T: any>(input: T, window: bool = null, duration: i64 = null, \
+             window_kind: string = null) -> u32",
         )
         .with_implementation(Implementation::new_pattern(&format!(
-            "(count_if ({}) ({}) ({}))",
+            "(count_if ({}) ({}) ({}) ({}))",
             "transform (is_valid (if ?input_is_new ?input_value)) (merge_join ?input_op \
              ?window_op)",
             "?window_value",
             "?duration_value",
+            "?window_kind_value",
         )))
         .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
         .with_time_domain_check(TimeDomainCheck::Aggregation);
 
+    registry
+        .register("approx_count_distinct<K: key>(input: K, window: window = null) -> u32")
+        .with_dfg_signature(
+            "approx_count_distinct<K: key>(input: K, window: bool = null, duration: i64 = null, \
+             window_kind: string = null) \
+             -> u32",
+        )
+        .with_implementation(Implementation::new_pattern(&format!(
+            "(approx_count_distinct ({}) ({}) ({}) ({}))",
+            "transform (if ?input_is_new ?input_value) (merge_join ?input_op ?window_op)",
+            "?window_value",
+            "?duration_value",
+            "?window_kind_value",
+        )))
+        .with_supported_windows(&[WindowBehavior::Since])
+        .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
+        .with_time_domain_check(TimeDomainCheck::Aggregation);
+
     registry
         .register("sum<N: number>(input: N, window: window = null) -> N ")
         .with_dfg_signature(
-            "sum<N: number>(input: N, window: bool = null, duration: i64 = null) -> N",
+            "sum<N: number>(input: N, window: bool = null, duration: i64 = null, \
+             window_kind: string = null) -> N",
         )
         .with_implementation(Implementation::new_pattern(&format!(
-            "(sum ({}) ({}) ({}))",
+            "(sum ({}) ({}) ({}) ({}))",
             "transform (if ?input_is_new ?input_value) (merge_join ?input_op ?window_op)",
             "?window_value",
-            "?duration_value"
+            "?duration_value",
+            "?window_kind_value"
         )))
         .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
         .with_time_domain_check(TimeDomainCheck::Aggregation);
@@ -53,13 +84,15 @@ pub(super) fn register(registry: &mut Registry) {
     registry
         .register("min<O: ordered>(input: O, window: window = null) -> O")
         .with_dfg_signature(
-            "min<O: ordered>(input: O, window: bool = null, duration: i64 = null) -> O",
+            "min<O: ordered>(input: O, window: bool = null, duration: i64 = null, \
+             window_kind: string = null) -> O",
         )
         .with_implementation(Implementation::new_pattern(&format!(
-            "(min ({}) ({}) ({}))",
+            "(min ({}) ({}) ({}) ({}))",
             "transform (if ?input_is_new ?input_value) (merge_join ?input_op ?window_op)",
             "?window_value",
-            "?duration_value"
+            "?duration_value",
+            "?window_kind_value"
         )))
         .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
         .with_time_domain_check(TimeDomainCheck::Aggregation);
@@ -67,27 +100,71 @@ pub(super) fn register(registry: &mut Registry) {
     registry
         .register("max<O: ordered>(input: O, window: window = null) -> O")
         .with_dfg_signature(
-            "max<O: ordered>(input: O, window: bool = null, duration: i64 = null) -> O",
+            "max<O: ordered>(input: O, window: bool = null, duration: i64 = null, \
+             window_kind: string = null) -> O",
         )
         .with_implementation(Implementation::new_pattern(&format!(
-            "(max ({}) ({}) ({}))",
+            "(max ({}) ({}) ({}) ({}))",
             "transform (if ?input_is_new ?input_value) (merge_join ?input_op ?window_op)",
             "?window_value",
-            "?duration_value"
+            "?duration_value",
+            "?window_kind_value"
         )))
         .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
         .with_time_domain_check(TimeDomainCheck::Aggregation);
 
+    registry
+        .register("max_by<T: any, O: ordered>(value: T, ordering: O, window: window = null) -> T")
+        .with_dfg_signature(
+            "max_by<T: any, O: ordered>(value: T, ordering: O, window: bool = null, duration: \
+             i64 = null, window_kind: string = null) -> T",
+        )
+        .with_implementation(Implementation::new_pattern(&format!(
+            "(max_by ({}) ({}) ({}) ({}) ({}))",
+            "transform (if ?value_is_new ?value_value) (merge_join ?value_op ?window_op)",
+            "transform (if ?ordering_is_new ?ordering_value) (merge_join ?ordering_op ?window_op)",
+            "?window_value",
+            "?duration_value",
+            "?window_kind_value"
+        )))
+        .with_is_new(Implementation::new_pattern(
+            "(logical_or ?window_is_new (logical_or ?value_is_new ?ordering_is_new))",
+        ))
+        .with_supported_windows(&[WindowBehavior::Since, WindowBehavior::Sliding])
+        .with_time_domain_check(TimeDomainCheck::Aggregation);
+
+    registry
+        .register("min_by<T: any, O: ordered>(value: T, ordering: O, window: window = null) -> T")
+        .with_dfg_signature(
+            "min_by<T: any, O: ordered>(value: T, ordering: O, window: bool = null, duration: \
+             i64 = null, window_kind: string = null) -> T",
+        )
+        .with_implementation(Implementation::new_pattern(&format!(
+            "(min_by ({}) ({}) ({}) ({}) ({}))",
+            "transform (if ?value_is_new ?value_value) (merge_join ?value_op ?window_op)",
+            "transform (if ?ordering_is_new ?ordering_value) (merge_join ?ordering_op ?window_op)",
+            "?window_value",
+            "?duration_value",
+            "?window_kind_value"
+        )))
+        .with_is_new(Implementation::new_pattern(
+            "(logical_or ?window_is_new (logical_or ?value_is_new ?ordering_is_new))",
+        ))
+        .with_supported_windows(&[WindowBehavior::Since, WindowBehavior::Sliding])
+        .with_time_domain_check(TimeDomainCheck::Aggregation);
+
     registry
         .register("mean<N: number>(input: N, window: window = null) -> f64")
         .with_dfg_signature(
-            "mean<N: number>(input: N, window: bool = null, duration: i64 = null) -> f64",
+            "mean<N: number>(input: N, window: bool = null, duration: i64 = null, \
+             window_kind: string = null) -> f64",
         )
         .with_implementation(Implementation::new_pattern(&format!(
-            "(mean ({}) ({}) ({}))",
+            "(mean ({}) ({}) ({}) ({}))",
             "transform (if ?input_is_new ?input_value) (merge_join ?input_op ?window_op)",
             "?window_value",
-            "?duration_value"
+            "?duration_value",
+            "?window_kind_value"
         )))
         .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
         .with_time_domain_check(TimeDomainCheck::Aggregation);
@@ -95,13 +172,15 @@ pub(super) fn register(registry: &mut Registry) {
     registry
         .register("variance<N: number>(input: N, window: window = null) -> f64")
         .with_dfg_signature(
-            "variance<N: number>(input: N, window: bool = null, duration: i64 = null) -> f64",
+            "variance<N: number>(input: N, window: bool = null, duration: i64 = null, \
+             window_kind: string = null) -> f64",
         )
         .with_implementation(Implementation::new_pattern(&format!(
-            "(variance ({}) ({}) ({}))",
+            "(variance ({}) ({}) ({}) ({}))",
             "transform (if ?input_is_new ?input_value) (merge_join ?input_op ?window_op)",
             "?window_value",
-            "?duration_value"
+            "?duration_value",
+            "?window_kind_value"
         )))
         .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
         .with_time_domain_check(TimeDomainCheck::Aggregation);
@@ -109,29 +188,133 @@ pub(super) fn register(registry: &mut Registry) {
     registry
         .register("stddev<N: number>(input: N, window: window = null) -> f64")
         .with_dfg_signature(
-            "stddev<N: number>(input: N, window: bool = null, duration: i64 = null) -> f64",
+            "stddev<N: number>(input: N, window: bool = null, duration: i64 = null, \
+             window_kind: string = null) -> f64",
         )
         .with_implementation(Implementation::new_pattern(&format!(
-            "(powf (variance ({}) ({}) ({})) 0.5f64)",
+            "(powf (variance ({}) ({}) ({}) ({})) 0.5f64)",
             "transform (if ?input_is_new ?input_value) (merge_join ?input_op ?window_op)",
             "?window_value",
-            "?duration_value"
+            "?duration_value",
+            "?window_kind_value"
         )))
         .with_time_domain_check(TimeDomainCheck::Aggregation);
 
+    registry
+        .register(
+            "approx_percentile<N: number>(input: N, const p: f64, window: window = null) -> f64",
+        )
+        .with_dfg_signature(
+            "approx_percentile<N: number>(input: N, const p: f64, window: bool = null, duration: \
+             i64 = null, window_kind: string = null) -> f64",
+        )
+        .with_implementation(Implementation::new_pattern(&format!(
+            "(approx_percentile ({}) ?p_value ({}) ({}) ({}))",
+            "transform (if ?input_is_new ?input_value) (merge_join ?input_op ?window_op)",
+            "?window_value",
+            "?duration_value",
+            "?window_kind_value"
+        )))
+        .with_argument_check(check_percentile)
+        .with_supported_windows(&[WindowBehavior::Since])
+        .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
+        .with_time_domain_check(TimeDomainCheck::Aggregation);
+
+    registry
+        .register("median<N: number>(input: N, window: window = null) -> f64")
+        .with_dfg_signature(
+            "median<N: number>(input: N, window: bool = null, duration: i64 = null, \
+             window_kind: string = null) -> f64",
+        )
+        .with_implementation(Implementation::new_pattern(&format!(
+            "(approx_percentile ({}) 0.5f64 ({}) ({}) ({}))",
+            "transform (if ?input_is_new ?input_value) (merge_join ?input_op ?window_op)",
+            "?window_value",
+            "?duration_value",
+            "?window_kind_value"
+        )))
+        .with_supported_windows(&[WindowBehavior::Since])
+        .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
+        .with_time_domain_check(TimeDomainCheck::Aggregation);
+
+    registry
+        .register(
+            "ewma<N: number, D: timedelta>(input: N, const half_life: D, window: window = null) \
+             -> f64",
+        )
+        .with_dfg_signature(
+            "ewma<N: number>(input: N, const half_life: i64, window: bool = null, duration: i64 = \
+             null, window_kind: string = null) -> f64",
+        )
+        .with_implementation(Implementation::new_pattern(&format!(
+            "(ewma ({}) ?half_life_value ({}) ({}) ({}))",
+            "transform (if ?input_is_new ?input_value) (merge_join ?input_op ?window_op)",
+            "?window_value",
+            "?duration_value",
+            "?window_kind_value"
+        )))
+        .with_argument_rewrite(convert_half_life)
+        .with_supported_windows(&[WindowBehavior::Since])
+        .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
+        .with_time_domain_check(TimeDomainCheck::Aggregation);
+
+    registry
+        .register(
+            "decayed_sum<N: number, D: timedelta>(input: N, const half_life: D, window: window = null) \
+             -> f64",
+        )
+        .with_dfg_signature(
+            "decayed_sum<N: number>(input: N, const half_life: i64, window: bool = null, duration: i64 = \
+             null, window_kind: string = null) -> f64",
+        )
+        .with_implementation(Implementation::new_pattern(&format!(
+            "(decayed_sum ({}) ?half_life_value ({}) ({}) ({}))",
+            "transform (if ?input_is_new ?input_value) (merge_join ?input_op ?window_op)",
+            "?window_value",
+            "?duration_value",
+            "?window_kind_value"
+        )))
+        .with_argument_rewrite(convert_half_life)
+        .with_supported_windows(&[WindowBehavior::Since])
+        .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
+        .with_time_domain_check(TimeDomainCheck::Aggregation);
+
+    registry
+        .register(
+            "decayed_count<T: any, D: timedelta>(input: T, const half_life: D, window: window = null) \
+             -> f64",
+        )
+        .with_dfg_signature(
+            "decayed_count<T: any>(input: T, const half_life: i64, window: bool = null, duration: i64 = \
+             null, window_kind: string = null) -> f64",
+        )
+        .with_implementation(Implementation::new_pattern(&format!(
+            "(decayed_count ({}) ?half_life_value ({}) ({}) ({}))",
+            "transform (if ?input_is_new ?input_value) (merge_join ?input_op ?window_op)",
+            "?window_value",
+            "?duration_value",
+            "?window_kind_value"
+        )))
+        .with_argument_rewrite(convert_half_life)
+        .with_supported_windows(&[WindowBehavior::Since])
+        .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
+        .with_time_domain_check(TimeDomainCheck::Aggregation);
+
     registry
         .register("last<T: any>(input: T, window: window = null) -> T")
         .with_dfg_signature(
-            "last<T: any>(input: T, window: bool = null, duration: i64 = null) -> T",
+            "last<T: any>(input: T, window: bool = null, duration: i64 = null, \
+             window_kind: string = null) -> T",
         )
         .with_implementation(Implementation::Pushdown(Box::new(
             Pushdown::try_new(
                 0,
                 &format!(
-                    "(last ({}) ({}) ({}))",
+                    "(last ({}) ({}) ({}) ({}))",
                     "transform (if ?is_new ?input_value) (merge_join ?op ?window_op)",
                     "?window_value",
-                    "?duration_value"
+                    "?duration_value",
+                    "?window_kind_value"
                 ),
                 // The per-field pattern produces the last value of the field.
                 // The outer if and last is handling the case where the latest *record*
@@ -139,41 +322,47 @@ pub(super) fn register(registry: &mut Registry) {
                 // the field if the record is new and valid and the input field is valid in
                 // that record.
                 &format!(
-                    "(if (last ({}) ({}) ({})) ?recurse_on_input_field)",
+                    "(if (last ({}) ({}) ({}) ({})) ?recurse_on_input_field)",
                     "transform (if (logical_and ?is_new (is_valid ?input_record)) (is_valid \
                      ?input_field)) (merge_join ?op ?window_op)",
                     "?window_value",
-                    "?duration_value"
+                    "?duration_value",
+                    "?window_kind_value"
                 ),
                 // The result pattern treats the resulting record as `null` if there haven't
                 // been any new non-null records observed. Eg., requires the count to be > 0.
                 &format!(
-                    "(if (gt (count_if ({}) ({}) ({})) 0u32) ?result_record)",
+                    "(if (gt (count_if ({}) ({}) ({}) ({})) 0u32) ?result_record)",
                     "transform (logical_and ?is_new (is_valid ?input_record)) (merge_join ?op \
                      ?window_op)",
                     "?window_value",
-                    "?duration_value"
+                    "?duration_value",
+                    "?window_kind_value"
                 ),
             )
             .context("last")
             .unwrap(),
         )))
+        // Trailing and session windows of lists and maps are not yet supported.
+        .with_collection_windows(&[WindowBehavior::Since, WindowBehavior::Sliding])
         .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
         .with_time_domain_check(TimeDomainCheck::Aggregation);
 
     registry
         .register("first<T: any>(input: T, window: window = null) -> T")
         .with_dfg_signature(
-            "first<T: any>(input: T, window: bool = null, duration: i64 = null) -> T",
+            "first<T: any>(input: T, window: bool = null, duration: i64 = null, \
+             window_kind: string = null) -> T",
         )
         .with_implementation(Implementation::Pushdown(Box::new(
             Pushdown::try_new(
                 0,
                 &format!(
-                    "(first({}) ({}) ({}))",
+                    "(first({}) ({}) ({}) ({}))",
                     "transform (if ?is_new ?input_value) (merge_join ?op ?window_op)",
                     "?window_value",
-                    "?duration_value"
+                    "?duration_value",
+                    "?window_kind_value"
                 ),
                 // The per-field pattern produces the last value of the field.
                 // The outer if and last is handling the case where the latest *record*
@@ -181,25 +370,112 @@ pub(super) fn register(registry: &mut Registry) {
                 // the field if the record is new and valid and the input field is valid in
                 // that record.
                 &format!(
-                    "(if (first ({}) ({}) ({})) ?recurse_on_input_field)",
+                    "(if (first ({}) ({}) ({}) ({})) ?recurse_on_input_field)",
                     "transform (if (logical_and ?is_new (is_valid ?input_record)) (is_valid \
                      ?input_field)) (merge_join ?op ?window_op)",
                     "?window_value",
-                    "?duration_value"
+                    "?duration_value",
+                    "?window_kind_value"
                 ),
                 // The result pattern treats the resulting record as `null` if there haven't
                 // been any new non-null records observed. Eg., requires the count to be > 0.
                 &format!(
-                    "(if (gt (count_if ({}) ({}) ({})) 0u32) ?result_record)",
+                    "(if (gt (count_if ({}) ({}) ({}) ({})) 0u32) ?result_record)",
                     "transform (logical_and ?is_new (is_valid ?input_record)) (merge_join ?op \
                      ?window_op)",
                     "?window_value",
-                    "?duration_value"
+                    "?duration_value",
+                    "?window_kind_value"
                 ),
             )
             .context("first")
             .unwrap(),
         )))
+        // Trailing and session windows of lists and maps are not yet supported.
+        .with_collection_windows(&[WindowBehavior::Since, WindowBehavior::Sliding])
         .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
         .with_time_domain_check(TimeDomainCheck::Aggregation);
 }
+
+/// Report a diagnostic if the percentile is not a literal between 0 and 1.
+///
+/// Returns `false` if any diagnostics were reported.
+fn check_percentile(
+    _function: &Function,
+    dfg: &Dfg,
+    diagnostics: &mut DiagnosticCollector<'_>,
+    args: &[Located<AstDfgRef>],
+) -> bool {
+    let percentile = &args[1];
+    match dfg.literal(percentile.value()) {
+        Some(ScalarValue::Float64(Some(p))) if (0.0..=1.0).contains(&p.into_inner()) => true,
+        // Null percentiles produce null results, like other null arguments.
+        Some(ScalarValue::Float64(None)) => true,
+        literal => {
+            let literal = literal.map_or_else(|| "non-literal".to_owned(), |l| l.to_string());
+            DiagnosticCode::InvalidArgumentType
+                .builder()
+                .with_label(
+                    percentile
+                        .location()
+                        .primary_label()
+                        .with_message(format!("Invalid percentile: '{literal}'")),
+                )
+                .with_note("Percentiles must be between 0 and 1, such as '0.95'".to_string())
+                .emit(diagnostics);
+            false
+        }
+    }
+}
+
+/// Convert the half-life of a time-decayed aggregation to an `i64` literal
+/// containing the number of nanoseconds.
+///
+/// Reports a diagnostic and returns `false` if the half-life is not a positive
+/// fixed duration.
+fn convert_half_life(
+    dfg: &mut Dfg,
+    diagnostics: &mut DiagnosticCollector<'_>,
+    args: &mut [Located<AstDfgRef>],
+) -> anyhow::Result<bool> {
+    let half_life = &args[1];
+    let literal = dfg.literal(half_life.value());
+    let nanos = match literal {
+        // The builder passes the half-life as nanoseconds.
+        Some(ScalarValue::Int64(Some(nanos))) => Some(*nanos),
+        Some(literal) => duration_nanos(literal),
+        None => None,
+    };
+
+    match nanos {
+        Some(nanos) if nanos > 0 => {
+            let nanos = dfg.add_literal(ScalarValue::Int64(Some(nanos)))?;
+            let nanos = add_literal(
+                dfg,
+                nanos,
+                FenlType::Concrete(DataType::Int64),
+                half_life.location().clone(),
+            )?;
+            args[1] = half_life.with_value(nanos);
+            Ok(true)
+        }
+        _ => {
+            let literal = literal.map_or_else(|| "non-literal".to_owned(), |l| l.to_string());
+            DiagnosticCode::InvalidArgumentType
+                .builder()
+                .with_label(
+                    half_life
+                        .location()
+                        .primary_label()
+                        .with_message(format!("Invalid half-life: '{literal}'")),
+                )
+                .with_note(
+                    "Half-lives must be a positive fixed duration, such as 'hours(1)' or \
+                     'days(7)'"
+                        .to_string(),
+                )
+                .emit(diagnostics);
+            Ok(false)
+        }
+    }
+}
@@ -1,7 +1,13 @@
+use sparrow_arrow::scalar_value::ScalarValue;
 use sparrow_plan::InstOp;
+use sparrow_syntax::{Located, WindowBehavior};
 
-use crate::functions::{Implementation, Registry};
+use crate::dfg::Dfg;
+use crate::diagnostics::DiagnosticCode;
+use crate::functions::{Function, Implementation, Registry};
+use crate::{AstDfgRef, DiagnosticCollector};
 
+use super::aggregation::AGGREGATION_IS_NEW;
 use super::time_domain_check::TimeDomainCheck;
 
 pub(super) fn register(registry: &mut Registry) {
@@ -18,19 +24,166 @@ pub(super) fn register(registry: &mut Registry) {
     registry
         .register("collect<T: any>(input: T, const max: i64, const min: i64 = 0, window: window = null) -> list<T>")
         .with_dfg_signature(
-            "collect<T: any>(input: T, const max: i64, const min: i64 = 0, window: bool = null, duration: i64 = null) -> list<T>",
+            "collect<T: any>(input: T, const max: i64, const min: i64 = 0, window: bool = null, duration: i64 = null, window_kind: string = null) -> list<T>",
         )
         .with_implementation(Implementation::Instruction(InstOp::Collect))
+        .with_supported_windows(&[
+            WindowBehavior::Since,
+            WindowBehavior::Sliding,
+            WindowBehavior::Trailing,
+        ])
         // This makes `collect` a continuous function. Though, it's not perhaps defined
         // as an aggregation, so we may want to rename or create a new category for it.
         .with_time_domain_check(TimeDomainCheck::Aggregation)
         .set_internal();
 
+    registry
+        .register("collect_map<K: key, V: any>(key: K, value: V, window: window = null) -> map<K, V>")
+        .with_dfg_signature(
+            "collect_map<K: key, V: any>(key: K, value: V, window: bool = null, duration: i64 = null, window_kind: string = null) -> map<K, V>",
+        )
+        .with_implementation(Implementation::new_pattern(&format!(
+            "(collect_map ({}) ({}) ({}) ({}) ({}))",
+            "transform (if ?key_is_new ?key_value) (merge_join ?key_op ?window_op)",
+            "transform (if ?value_is_new ?value_value) (merge_join ?value_op ?window_op)",
+            "?window_value",
+            "?duration_value",
+            "?window_kind_value",
+        )))
+        .with_is_new(Implementation::new_pattern(
+            "(logical_or ?window_is_new (logical_or ?key_is_new ?value_is_new))",
+        ))
+        .with_supported_windows(&[WindowBehavior::Since])
+        .with_time_domain_check(TimeDomainCheck::Aggregation)
+        .set_internal();
+
+    registry
+        .register("collect_distinct<T: key>(input: T, const max: i64, window: window = null) -> list<T>")
+        .with_dfg_signature(
+            "collect_distinct<T: key>(input: T, const max: i64, window: bool = null, duration: i64 = null, window_kind: string = null) -> list<T>",
+        )
+        .with_implementation(Implementation::new_pattern(&format!(
+            "(collect_distinct ({}) ?max_value ({}) ({}) ({}))",
+            "transform (if ?input_is_new ?input_value) (merge_join ?input_op ?window_op)",
+            "?window_value",
+            "?duration_value",
+            "?window_kind_value",
+        )))
+        .with_argument_check(check_limit)
+        .with_supported_windows(&[WindowBehavior::Since])
+        .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
+        .with_time_domain_check(TimeDomainCheck::Aggregation)
+        .set_internal();
+
+    registry
+        .register("most_frequent<T: key>(input: T, const k: i64, window: window = null) -> list<T>")
+        .with_dfg_signature(
+            "most_frequent<T: key>(input: T, const k: i64, window: bool = null, duration: i64 = null, window_kind: string = null) -> list<T>",
+        )
+        .with_implementation(Implementation::new_pattern(&format!(
+            "(most_frequent ({}) ?k_value ({}) ({}) ({}))",
+            "transform (if ?input_is_new ?input_value) (merge_join ?input_op ?window_op)",
+            "?window_value",
+            "?duration_value",
+            "?window_kind_value",
+        )))
+        .with_argument_check(check_limit)
+        .with_supported_windows(&[WindowBehavior::Since])
+        .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
+        .with_time_domain_check(TimeDomainCheck::Aggregation)
+        .set_internal();
+
+    registry
+        .register("top_k<O: ordered>(input: O, const k: i64, window: window = null) -> list<O>")
+        .with_dfg_signature(
+            "top_k<O: ordered>(input: O, const k: i64, window: bool = null, duration: i64 = null, window_kind: string = null) -> list<O>",
+        )
+        .with_implementation(Implementation::new_pattern(&format!(
+            "(top_k ({}) ?k_value ({}) ({}) ({}))",
+            "transform (if ?input_is_new ?input_value) (merge_join ?input_op ?window_op)",
+            "?window_value",
+            "?duration_value",
+            "?window_kind_value",
+        )))
+        .with_argument_check(check_limit)
+        .with_supported_windows(&[WindowBehavior::Since])
+        .with_is_new(Implementation::new_pattern(AGGREGATION_IS_NEW))
+        .with_time_domain_check(TimeDomainCheck::Aggregation)
+        .set_internal();
+
+    registry
+        .register("map_keys<K: key, V: any>(map: map<K, V>) -> list<K>")
+        .with_implementation(Implementation::Instruction(InstOp::MapKeys))
+        .set_internal();
+
+    registry
+        .register("map_values<K: key, V: any>(map: map<K, V>) -> list<V>")
+        .with_implementation(Implementation::Instruction(InstOp::MapValues))
+        .set_internal();
+
+    registry
+        .register("map_len<K: key, V: any>(map: map<K, V>) -> i32")
+        .with_implementation(Implementation::Instruction(InstOp::MapLen))
+        .set_internal();
+
+    registry
+        .register("map_contains<K: key, V: any>(key: K, map: map<K, V>) -> bool")
+        .with_implementation(Implementation::Instruction(InstOp::MapContains))
+        .set_internal();
+
+    registry
+        .register("map_from_lists<K: key, V: any>(keys: list<K>, values: list<V>) -> map<K, V>")
+        .with_implementation(Implementation::Instruction(InstOp::MapFromLists))
+        .set_internal();
+
     registry
         .register("list_len<T: any>(input: list<T>) -> i32")
         .with_implementation(Implementation::Instruction(InstOp::ListLen))
         .set_internal();
 
+    // The last argument to `list_map` and `list_filter` is a lambda, which is
+    // converted with the parameter bound to each element of the list. The
+    // argument type is the type of the lambda body.
+    registry
+        .register("list_map<T: any, U: any>(list: list<T>, f: U) -> list<U>")
+        .with_implementation(Implementation::Instruction(InstOp::ListMap))
+        .set_internal();
+
+    registry
+        .register("list_filter<T: any>(list: list<T>, predicate: bool) -> list<T>")
+        .with_implementation(Implementation::Instruction(InstOp::ListFilter))
+        .set_internal();
+
+    registry
+        .register("list_sum<N: number>(list: list<N>) -> N")
+        .with_implementation(Implementation::Instruction(InstOp::ListSum))
+        .set_internal();
+
+    registry
+        .register("list_mean<N: number>(list: list<N>) -> f64")
+        .with_implementation(Implementation::Instruction(InstOp::ListMean))
+        .set_internal();
+
+    registry
+        .register("list_contains<T: key>(list: list<T>, value: T) -> bool")
+        .with_implementation(Implementation::Instruction(InstOp::ListContains))
+        .set_internal();
+
+    registry
+        .register("list_sort<T: ordered>(list: list<T>) -> list<T>")
+        .with_implementation(Implementation::Instruction(InstOp::ListSort))
+        .set_internal();
+
+    registry
+        .register("list_slice<T: any>(list: list<T>, start: i64, end: i64) -> list<T>")
+        .with_implementation(Implementation::Instruction(InstOp::ListSlice))
+        .set_internal();
+
+    registry
+        .register("list_distinct<T: key>(list: list<T>) -> list<T>")
+        .with_implementation(Implementation::Instruction(InstOp::ListDistinct))
+        .set_internal();
+
     registry
         .register("flatten<T: any>(input: list<list<T>>) -> list<T>")
         .with_implementation(Implementation::Instruction(InstOp::Flatten))
@@ -41,3 +194,42 @@ pub(super) fn register(registry: &mut Registry) {
         .with_implementation(Implementation::Instruction(InstOp::Union))
         .set_internal();
 }
+
+/// Report a diagnostic if the number of values to collect is not positive.
+///
+/// A `null` limit is rejected, since the values are held in memory.
+///
+/// Returns `false` if any diagnostics were reported.
+fn check_limit(
+    function: &Function,
+    dfg: &Dfg,
+    diagnostics: &mut DiagnosticCollector<'_>,
+    args: &[Located<AstDfgRef>],
+) -> bool {
+    let limit = &args[1];
+    let literal = match dfg.literal(limit.value()) {
+        Some(ScalarValue::Int64(Some(n))) if *n > 0 => return true,
+        Some(ScalarValue::Int64(Some(n))) => n.to_string(),
+        Some(literal) => literal.to_string(),
+        None => "non-literal".to_owned(),
+    };
+
+    let name = function
+        .arg_names()
+        .nth(1)
+        .map_or("limit", |name| name.inner().as_str());
+    DiagnosticCode::InvalidArgumentType
+        .builder()
+        .with_label(
+            limit
+                .location()
+                .primary_label()
+                .with_message(format!("Invalid {name}: '{literal}'")),
+        )
+        .with_note(format!(
+            "'{}' requires a positive number of values, such as '10'",
+            function.name()
+        ))
+        .emit(diagnostics);
+    false
+}
@@ -4,15 +4,31 @@ use std::sync::Arc;
 use egg::{Subst, Var};
 use itertools::{izip, Itertools};
 use sparrow_api::kaskada::v1alpha::operation_plan::tick_operation::TickBehavior;
+use sparrow_arrow::scalar_value::ScalarValue;
 use sparrow_plan::GroupId;
-use sparrow_syntax::{FeatureSetPart, FenlType, Located, Location, Signature};
+use sparrow_syntax::{FeatureSetPart, FenlType, Located, Location, Signature, WindowBehavior};
 
-use crate::ast_to_dfg::AstDfg;
-use crate::dfg::Dfg;
+use crate::ast_to_dfg::{duration_nanos, AstDfg};
+use crate::dfg::{Dfg, TickSchedule};
 use crate::functions::implementation::Implementation;
 use crate::functions::time_domain_check::TimeDomainCheck;
 use crate::{AstDfgRef, DataContext, DiagnosticCollector};
 
+/// Checks the arguments to a function, other than the window.
+///
+/// Reports diagnostics for invalid arguments, such as literals out of range,
+/// and returns `false` if any were reported.
+pub type ArgumentCheck =
+    fn(&Function, &Dfg, &mut DiagnosticCollector<'_>, &[Located<AstDfgRef>]) -> bool;
+
+/// Rewrites the arguments to a function, other than the window.
+///
+/// Reports diagnostics for arguments which can't be rewritten, such as a
+/// half-life which isn't a fixed duration, and returns `false` if any were
+/// reported.
+pub type ArgumentRewrite =
+    fn(&mut Dfg, &mut DiagnosticCollector<'_>, &mut [Located<AstDfgRef>]) -> anyhow::Result<bool>;
+
 /// Struct representing an instruction.
 pub struct Function {
     /// The signature of this function as a string.
@@ -29,6 +45,17 @@ pub struct Function {
     time_domain_check: TimeDomainCheck,
     /// Whether the function is internal only.
     internal: bool,
+    /// The windows supported by the function, if it accepts a window.
+    supported_windows: &'static [WindowBehavior],
+    /// The windows supported by the function when the input contains lists or
+    /// maps, including as fields of a record.
+    collection_windows: &'static [WindowBehavior],
+    /// Additional checks on the arguments to the function.
+    argument_check: Option<ArgumentCheck>,
+    /// Rewrites applied to the arguments to the function.
+    argument_rewrite: Option<ArgumentRewrite>,
+    /// The function to call instead when all arguments are strings.
+    string_overload: Option<&'static str>,
 }
 
 pub(super) struct FunctionBuilder<'building>(&'building mut Function);
@@ -58,6 +85,31 @@ impl<'building> FunctionBuilder<'building> {
         self
     }
 
+    pub fn with_supported_windows(self, supported_windows: &'static [WindowBehavior]) -> Self {
+        self.0.supported_windows = supported_windows;
+        self
+    }
+
+    pub fn with_collection_windows(self, collection_windows: &'static [WindowBehavior]) -> Self {
+        self.0.collection_windows = collection_windows;
+        self
+    }
+
+    pub fn with_argument_check(self, argument_check: ArgumentCheck) -> Self {
+        self.0.argument_check = Some(argument_check);
+        self
+    }
+
+    pub fn with_argument_rewrite(self, argument_rewrite: ArgumentRewrite) -> Self {
+        self.0.argument_rewrite = Some(argument_rewrite);
+        self
+    }
+
+    pub fn with_string_overload(self, string_overload: &'static str) -> Self {
+        self.0.string_overload = Some(string_overload);
+        self
+    }
+
     pub fn with_dfg_signature(self, signature_str: &'static str) -> Self {
         let signature =
             Signature::try_from_str(FeatureSetPart::Function(signature_str), signature_str)
@@ -78,6 +130,21 @@ impl Function {
             is_new: Implementation::AnyInputIsNew,
             time_domain_check: TimeDomainCheck::default(),
             internal: false,
+            supported_windows: &[
+                WindowBehavior::Since,
+                WindowBehavior::Sliding,
+                WindowBehavior::Trailing,
+                WindowBehavior::Session,
+            ],
+            collection_windows: &[
+                WindowBehavior::Since,
+                WindowBehavior::Sliding,
+                WindowBehavior::Trailing,
+                WindowBehavior::Session,
+            ],
+            argument_check: None,
+            argument_rewrite: None,
+            string_overload: None,
         }
     }
 
@@ -95,9 +162,78 @@ impl Function {
                 | "mean"
                 | "variance"
                 | "stddev"
+                | "approx_count_distinct"
+                | "approx_percentile"
+                | "median"
+                | "collect_map"
+                | "max_by"
+                | "min_by"
+                | "ewma"
+                | "decayed_sum"
+                | "decayed_count"
+                | "top_k"
+                | "most_frequent"
+                | "collect_distinct"
         )
     }
 
+    /// Returns true if this function may be used within the body of a lambda.
+    ///
+    /// Lambdas are applied to each element of a list, so they may not use
+    /// functions which accumulate values across rows or depend on the time
+    /// of each row.
+    pub(crate) fn is_allowed_in_lambda(&self) -> bool {
+        !(self.is_aggregation()
+            || self.is_tick()
+            || matches!(self.time_domain_check, TimeDomainCheck::Aggregation)
+            || matches!(self.name(), "time_of" | "lag"))
+    }
+
+    /// Returns true if this function supports the given window.
+    pub(crate) fn supports_window(&self, window: WindowBehavior) -> bool {
+        self.supported_windows.contains(&window)
+    }
+
+    /// Returns true if this function supports the given window when the
+    /// input contains lists or maps.
+    pub(crate) fn supports_collection_window(&self, window: WindowBehavior) -> bool {
+        self.collection_windows.contains(&window)
+    }
+
+    /// Checks the arguments to this function, other than the window.
+    ///
+    /// Returns `false` if any diagnostics were reported.
+    pub(crate) fn check_arguments(
+        &self,
+        dfg: &Dfg,
+        diagnostics: &mut DiagnosticCollector<'_>,
+        args: &[Located<AstDfgRef>],
+    ) -> bool {
+        self.argument_check
+            .map_or(true, |check| check(self, dfg, diagnostics, args))
+    }
+
+    /// Rewrites the arguments to this function, other than the window.
+    ///
+    /// Returns `false` if any diagnostics were reported.
+    pub(crate) fn rewrite_arguments(
+        &self,
+        dfg: &mut Dfg,
+        diagnostics: &mut DiagnosticCollector<'_>,
+        args: &mut [Located<AstDfgRef>],
+    ) -> anyhow::Result<bool> {
+        match self.argument_rewrite {
+            Some(rewrite) => rewrite(dfg, diagnostics, args),
+            None => Ok(true),
+        }
+    }
+
+    /// Returns the name of the function to call instead when all arguments
+    /// are strings, if any.
+    pub(crate) fn string_overload(&self) -> Option<&'static str> {
+        self.string_overload
+    }
+
     pub fn is_tick(&self) -> bool {
         matches!(self.implementation, Implementation::Tick(_))
     }
@@ -110,6 +246,56 @@ impl Function {
         }
     }
 
+    /// Returns the index of the `tz` parameter, if this function has one.
+    pub(crate) fn time_zone_index(&self) -> Option<usize> {
+        self.parameter_index("tz")
+    }
+
+    /// Returns the index of the parameter with the given name.
+    pub(crate) fn parameter_index(&self, name: &str) -> Option<usize> {
+        self.arg_names().position(|arg| arg.inner() == name)
+    }
+
+    /// Returns the schedule a tick is produced on.
+    ///
+    /// Ticks without a `tz` parameter, or with a `null` time zone, are produced
+    /// in UTC. Weekly ticks with a `null` start are produced on Mondays.
+    /// Returns `None` if any of the arguments configuring the schedule are
+    /// invalid.
+    pub(crate) fn tick_schedule(
+        &self,
+        dfg: &Dfg,
+        args: &[Located<AstDfgRef>],
+    ) -> Option<TickSchedule> {
+        let mut schedule = TickSchedule::new(self.tick_behavior()?);
+        let argument = |name: &str| self.parameter_index(name).map(|index| args[index].value());
+
+        if let Some(name) = argument("tz").and_then(|tz| dfg.string_literal(tz)) {
+            sparrow_kernels::time::parse_time_zone(name).ok()?;
+            schedule.time_zone = name.to_owned();
+        }
+
+        if let Some(start) = argument("start").and_then(|start| dfg.string_literal(start)) {
+            let start: chrono::Weekday = start.parse().ok()?;
+            schedule.week_start = start.num_days_from_monday();
+        }
+
+        if let Some(interval) = argument("interval") {
+            let interval = dfg.literal(interval).and_then(duration_nanos)?;
+            if interval <= 0 {
+                return None;
+            }
+            let offset = match argument("offset").and_then(|offset| dfg.literal(offset)) {
+                Some(ScalarValue::Null) | None => 0,
+                Some(offset) => duration_nanos(offset)?,
+            };
+            schedule.interval = interval;
+            schedule.offset = offset.rem_euclid(interval);
+        }
+
+        Some(schedule)
+    }
+
     pub fn name(&self) -> &str {
         self.signature.name()
     }
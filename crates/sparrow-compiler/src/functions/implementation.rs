@@ -87,8 +87,11 @@ impl Implementation {
             Implementation::Instruction(inst) => {
                 Ok(dfg.add_instruction(*inst, args.iter().map(|i| i.value()).collect())?)
             }
-            Implementation::Tick(tick) => {
-                Ok(dfg.add_operation(Operation::Tick(*tick), smallvec![])?)
+            Implementation::Tick(_) => {
+                let schedule = function
+                    .tick_schedule(dfg, args)
+                    .context("tick schedule should be validated")?;
+                Ok(dfg.add_operation(Operation::Tick(schedule), smallvec![])?)
             }
             Implementation::Window(window) => Ok(dfg.add_node(
                 StepKind::Window(*window),
@@ -21,7 +21,8 @@ pub(super) fn register(registry: &mut Registry) {
 
     registry
         .register("add<N: number>(a: N, b: N) -> N")
-        .with_implementation(Implementation::Instruction(InstOp::Add));
+        .with_implementation(Implementation::Instruction(InstOp::Add))
+        .with_string_overload("concat");
 
     registry
         .register("sub<N: number>(a: N, b: N) -> N")
@@ -58,4 +59,88 @@ pub(super) fn register(registry: &mut Registry) {
     registry
         .register("clamp<N: number>(value: N, min: N = null, max: N = null) -> N")
         .with_implementation(Implementation::Instruction(InstOp::Clamp));
+
+    registry
+        .register("abs<N: number>(n: N) -> N")
+        .with_implementation(Implementation::Instruction(InstOp::Abs));
+
+    registry
+        .register("sign<N: number>(n: N) -> N")
+        .with_implementation(Implementation::Instruction(InstOp::Sign));
+
+    registry
+        .register("ln(n: f64) -> f64")
+        .with_implementation(Implementation::Instruction(InstOp::Ln));
+
+    registry
+        .register("log10(n: f64) -> f64")
+        .with_implementation(Implementation::Instruction(InstOp::Log10));
+
+    registry
+        .register("log(n: f64, base: f64) -> f64")
+        .with_implementation(Implementation::new_fenl_rewrite("ln(n) / ln(base)"));
+
+    registry
+        .register("sin(n: f64) -> f64")
+        .with_implementation(Implementation::Instruction(InstOp::Sin));
+
+    registry
+        .register("cos(n: f64) -> f64")
+        .with_implementation(Implementation::Instruction(InstOp::Cos));
+
+    registry
+        .register("tan(n: f64) -> f64")
+        .with_implementation(Implementation::Instruction(InstOp::Tan));
+
+    registry
+        .register("asin(n: f64) -> f64")
+        .with_implementation(Implementation::Instruction(InstOp::Asin));
+
+    registry
+        .register("acos(n: f64) -> f64")
+        .with_implementation(Implementation::Instruction(InstOp::Acos));
+
+    registry
+        .register("atan(n: f64) -> f64")
+        .with_implementation(Implementation::Instruction(InstOp::Atan));
+
+    registry
+        .register("atan2(y: f64, x: f64) -> f64")
+        .with_implementation(Implementation::Instruction(InstOp::Atan2));
+
+    registry
+        .register("rem<N: number>(a: N, b: N) -> N")
+        .with_implementation(Implementation::Instruction(InstOp::Rem));
+
+    registry
+        .register("mod<I: int>(a: I, b: I) -> I")
+        .with_implementation(Implementation::Instruction(InstOp::Mod));
+
+    registry
+        .register("floor_div<I: int>(a: I, b: I) -> I")
+        .with_implementation(Implementation::Instruction(InstOp::FloorDiv));
+
+    registry
+        .register("bitwise_and<I: int>(a: I, b: I) -> I")
+        .with_implementation(Implementation::Instruction(InstOp::BitwiseAnd));
+
+    registry
+        .register("bitwise_or<I: int>(a: I, b: I) -> I")
+        .with_implementation(Implementation::Instruction(InstOp::BitwiseOr));
+
+    registry
+        .register("bitwise_xor<I: int>(a: I, b: I) -> I")
+        .with_implementation(Implementation::Instruction(InstOp::BitwiseXor));
+
+    registry
+        .register("bitwise_not<I: int>(n: I) -> I")
+        .with_implementation(Implementation::Instruction(InstOp::BitwiseNot));
+
+    registry
+        .register("shift_left<I: int>(n: I, bits: I) -> I")
+        .with_implementation(Implementation::Instruction(InstOp::ShiftLeft));
+
+    registry
+        .register("shift_right<I: int>(n: I, bits: I) -> I")
+        .with_implementation(Implementation::Instruction(InstOp::ShiftRight));
 }
@@ -1,6 +1,11 @@
+use sparrow_arrow::scalar_value::ScalarValue;
 use sparrow_plan::InstOp;
+use sparrow_syntax::Located;
 
-use crate::functions::{Implementation, Registry};
+use crate::dfg::Dfg;
+use crate::diagnostics::DiagnosticCode;
+use crate::functions::{Function, Implementation, Registry};
+use crate::{AstDfgRef, DiagnosticCollector};
 
 pub(super) fn register(registry: &mut Registry) {
     registry
@@ -18,4 +23,188 @@ pub(super) fn register(registry: &mut Registry) {
     registry
         .register("substring(s: string, start: i64 = null, end: i64 = null) -> string")
         .with_implementation(Implementation::Instruction(InstOp::Substring));
+
+    registry
+        .register("regex_match(s: string, const pattern: string) -> bool")
+        .with_implementation(Implementation::Instruction(InstOp::RegexMatch))
+        .with_argument_check(check_regex);
+
+    registry
+        .register("regex_extract(s: string, const pattern: string, const group: i64) -> string")
+        .with_implementation(Implementation::Instruction(InstOp::RegexExtract))
+        .with_argument_check(check_regex_group);
+
+    registry
+        .register("regex_replace(s: string, const pattern: string, replacement: string) -> string")
+        .with_implementation(Implementation::Instruction(InstOp::RegexReplace))
+        .with_argument_check(check_regex);
+
+    registry
+        .register("concat(strings+: string) -> string")
+        .with_implementation(Implementation::Instruction(InstOp::Concat));
+
+    registry
+        .register("split(s: string, separator: string) -> list<string>")
+        .with_implementation(Implementation::Instruction(InstOp::Split));
+
+    registry
+        .register("trim(s: string) -> string")
+        .with_implementation(Implementation::Instruction(InstOp::Trim));
+
+    registry
+        .register("starts_with(s: string, prefix: string) -> bool")
+        .with_implementation(Implementation::Instruction(InstOp::StartsWith));
+
+    registry
+        .register("ends_with(s: string, suffix: string) -> bool")
+        .with_implementation(Implementation::Instruction(InstOp::EndsWith));
+
+    registry
+        .register("contains(s: string, substring: string) -> bool")
+        .with_implementation(Implementation::Instruction(InstOp::Contains));
+
+    registry
+        .register("replace(s: string, pattern: string, replacement: string) -> string")
+        .with_implementation(Implementation::Instruction(InstOp::Replace));
+
+    registry
+        .register("lpad(s: string, length: i64, pad: string = \" \") -> string")
+        .with_implementation(Implementation::Instruction(InstOp::Lpad));
+
+    registry
+        .register("rpad(s: string, length: i64, pad: string = \" \") -> string")
+        .with_implementation(Implementation::Instruction(InstOp::Rpad));
+
+    registry
+        .register("format(const template: string, values+: string) -> string")
+        .with_implementation(Implementation::Instruction(InstOp::Format))
+        .with_argument_check(check_format);
+}
+
+/// Report diagnostics if the pattern of a regex function is invalid.
+///
+/// Returns `false` if any diagnostics were reported.
+fn check_regex(
+    _function: &Function,
+    dfg: &Dfg,
+    diagnostics: &mut DiagnosticCollector<'_>,
+    args: &[Located<AstDfgRef>],
+) -> bool {
+    compile_regex(dfg, diagnostics, &args[1]).is_some()
+}
+
+/// Report diagnostics if the pattern or capture group of `regex_extract` is
+/// invalid.
+///
+/// Returns `false` if any diagnostics were reported.
+fn check_regex_group(
+    _function: &Function,
+    dfg: &Dfg,
+    diagnostics: &mut DiagnosticCollector<'_>,
+    args: &[Located<AstDfgRef>],
+) -> bool {
+    let Some(regex) = compile_regex(dfg, diagnostics, &args[1]) else {
+        return false;
+    };
+    // Null patterns produce null results, like other null arguments.
+    let Some(regex) = regex else {
+        return true;
+    };
+
+    // The number of capture groups, not including the implicit group 0.
+    let groups = regex.captures_len() - 1;
+    if let Some(ScalarValue::Int64(Some(group))) = dfg.literal(args[2].value()) {
+        if *group < 0 || *group as usize > groups {
+            DiagnosticCode::InvalidArguments
+                .builder()
+                .with_label(
+                    args[2]
+                        .location()
+                        .primary_label()
+                        .with_message(format!("Invalid capture group: '{group}'")),
+                )
+                .with_note(format!(
+                    "The pattern has {groups} capture group(s), so the group must be between 0 \
+                     and {groups}"
+                ))
+                .emit(diagnostics);
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Compile the pattern of a regex function.
+///
+/// Returns `Some(None)` if the pattern is null, and reports a diagnostic and
+/// returns `None` if the pattern is invalid.
+fn compile_regex(
+    dfg: &Dfg,
+    diagnostics: &mut DiagnosticCollector<'_>,
+    pattern: &Located<AstDfgRef>,
+) -> Option<Option<regex::Regex>> {
+    // Null patterns produce null results, like other null arguments.
+    let Some(pattern_str) = dfg.string_literal(pattern.value()) else {
+        return Some(None);
+    };
+
+    match regex::Regex::new(pattern_str) {
+        Ok(regex) => Some(Some(regex)),
+        Err(e) => {
+            // Syntax errors span multiple lines, pointing at the location of the
+            // error within the pattern. Only the last line describes the error.
+            let reason = e.to_string();
+            let reason = reason.lines().last().unwrap_or_default();
+            let reason = reason.trim_start_matches("error: ").to_owned();
+            DiagnosticCode::InvalidArguments
+                .builder()
+                .with_label(
+                    pattern
+                        .location()
+                        .primary_label()
+                        .with_message(format!("Invalid regular expression: '{pattern_str}'")),
+                )
+                .with_note(reason)
+                .emit(diagnostics);
+            None
+        }
+    }
+}
+
+/// Report diagnostics if the template of a `format` call is invalid.
+///
+/// Returns `false` if any diagnostics were reported.
+fn check_format(
+    _function: &Function,
+    dfg: &Dfg,
+    diagnostics: &mut DiagnosticCollector<'_>,
+    args: &[Located<AstDfgRef>],
+) -> bool {
+    // Null templates produce null results, like other null arguments.
+    let Some(template) = dfg.string_literal(args[0].value()) else {
+        return true;
+    };
+
+    let num_values = args.len() - 1;
+    let note = match sparrow_kernels::string::FormatTemplate::try_new(template) {
+        Ok(parsed) if parsed.num_values() <= num_values => return true,
+        Ok(parsed) => format!(
+            "The template references {} value(s), but only {num_values} provided",
+            parsed.num_values()
+        ),
+        Err(e) => format!("{e}"),
+    };
+
+    DiagnosticCode::InvalidArguments
+        .builder()
+        .with_label(
+            args[0]
+                .location()
+                .primary_label()
+                .with_message(format!("Invalid format template: '{template}'")),
+        )
+        .with_note(note)
+        .emit(diagnostics);
+    false
 }
@@ -1,7 +1,11 @@
 use sparrow_plan::InstOp;
+use sparrow_syntax::Located;
 
+use crate::dfg::Dfg;
+use crate::diagnostics::DiagnosticCode;
 use crate::functions::time_domain_check::TimeDomainCheck;
-use crate::functions::{Implementation, Registry};
+use crate::functions::{Function, Implementation, Registry};
+use crate::{AstDfgRef, DiagnosticCollector, NearestMatches};
 
 pub(super) fn register(registry: &mut Registry) {
     registry
@@ -67,36 +71,53 @@ pub(super) fn register(registry: &mut Registry) {
         .with_implementation(Implementation::Instruction(InstOp::Days));
 
     registry
-        .register("day_of_month(time: timestamp_ns) -> u32")
-        .with_implementation(Implementation::Instruction(InstOp::DayOfMonth));
+        .register("day_of_month(time: timestamp_ns, const tz: string = \"UTC\") -> u32")
+        .with_implementation(Implementation::Instruction(InstOp::DayOfMonth))
+        .with_argument_check(check_time_zone);
 
     registry
-        .register("day_of_month0(time: timestamp_ns) -> u32")
-        .with_implementation(Implementation::Instruction(InstOp::DayOfMonth0));
+        .register("day_of_month0(time: timestamp_ns, const tz: string = \"UTC\") -> u32")
+        .with_implementation(Implementation::Instruction(InstOp::DayOfMonth0))
+        .with_argument_check(check_time_zone);
 
     registry
-        .register("day_of_year(time: timestamp_ns) -> u32")
-        .with_implementation(Implementation::Instruction(InstOp::DayOfYear));
+        .register("day_of_week(time: timestamp_ns, const tz: string = \"UTC\") -> u32")
+        .with_implementation(Implementation::Instruction(InstOp::DayOfWeek))
+        .with_argument_check(check_time_zone);
 
     registry
-        .register("day_of_year0(time: timestamp_ns) -> u32")
-        .with_implementation(Implementation::Instruction(InstOp::DayOfYear0));
+        .register("day_of_week0(time: timestamp_ns, const tz: string = \"UTC\") -> u32")
+        .with_implementation(Implementation::Instruction(InstOp::DayOfWeek0))
+        .with_argument_check(check_time_zone);
+
+    registry
+        .register("day_of_year(time: timestamp_ns, const tz: string = \"UTC\") -> u32")
+        .with_implementation(Implementation::Instruction(InstOp::DayOfYear))
+        .with_argument_check(check_time_zone);
+
+    registry
+        .register("day_of_year0(time: timestamp_ns, const tz: string = \"UTC\") -> u32")
+        .with_implementation(Implementation::Instruction(InstOp::DayOfYear0))
+        .with_argument_check(check_time_zone);
 
     registry
         .register("months(months: i64) -> interval_months")
         .with_implementation(Implementation::Instruction(InstOp::Months));
 
     registry
-        .register("month_of_year(time: timestamp_ns) -> u32")
-        .with_implementation(Implementation::Instruction(InstOp::MonthOfYear));
+        .register("month_of_year(time: timestamp_ns, const tz: string = \"UTC\") -> u32")
+        .with_implementation(Implementation::Instruction(InstOp::MonthOfYear))
+        .with_argument_check(check_time_zone);
 
     registry
-        .register("month_of_year0(time: timestamp_ns) -> u32")
-        .with_implementation(Implementation::Instruction(InstOp::MonthOfYear0));
+        .register("month_of_year0(time: timestamp_ns, const tz: string = \"UTC\") -> u32")
+        .with_implementation(Implementation::Instruction(InstOp::MonthOfYear0))
+        .with_argument_check(check_time_zone);
 
     registry
-        .register("year(time: timestamp_ns) -> i32")
-        .with_implementation(Implementation::Instruction(InstOp::Year));
+        .register("year(time: timestamp_ns, const tz: string = \"UTC\") -> i32")
+        .with_implementation(Implementation::Instruction(InstOp::Year))
+        .with_argument_check(check_time_zone);
 
     registry
         .register("seconds(seconds: i64) -> duration_s")
@@ -137,3 +158,45 @@ pub(super) fn register(registry: &mut Registry) {
             "input | collect(min=n+1, max=n+1) | index(0)",
         ));
 }
+
+/// Report a diagnostic if the `tz` argument is not a known IANA time zone.
+///
+/// Returns `false` if any diagnostics were reported.
+pub(super) fn check_time_zone(
+    function: &Function,
+    dfg: &Dfg,
+    diagnostics: &mut DiagnosticCollector<'_>,
+    args: &[Located<AstDfgRef>],
+) -> bool {
+    let Some(index) = function.time_zone_index() else {
+        return true;
+    };
+    let time_zone = &args[index];
+
+    // Null time zones produce null results, like other null arguments.
+    let Some(name) = dfg.string_literal(time_zone.value()) else {
+        return true;
+    };
+
+    if sparrow_kernels::time::parse_time_zone(name).is_ok() {
+        return true;
+    }
+
+    let nearest = NearestMatches::new_nearest_strs(
+        name,
+        sparrow_kernels::time::TZ_VARIANTS
+            .iter()
+            .map(|tz| tz.name()),
+    );
+    DiagnosticCode::InvalidArguments
+        .builder()
+        .with_label(
+            time_zone
+                .location()
+                .primary_label()
+                .with_message(format!("Invalid time zone: '{name}'")),
+        )
+        .with_note(format!("Nearest matches: {nearest}"))
+        .emit(diagnostics);
+    false
+}
@@ -1,8 +1,14 @@
 use sparrow_api::kaskada::v1alpha::operation_plan::tick_operation::TickBehavior;
-use sparrow_syntax::WindowBehavior;
+use sparrow_arrow::scalar_value::ScalarValue;
+use sparrow_syntax::{Located, WindowBehavior};
 
+use crate::ast_to_dfg::duration_nanos;
+use crate::dfg::Dfg;
+use crate::diagnostics::DiagnosticCode;
+use crate::functions::time::check_time_zone;
 use crate::functions::time_domain_check::TimeDomainCheck;
-use crate::functions::{Implementation, Registry};
+use crate::functions::{Function, Implementation, Registry};
+use crate::{AstDfgRef, DiagnosticCollector};
 
 pub(super) fn register(registry: &mut Registry) {
     registry
@@ -15,6 +21,14 @@ pub(super) fn register(registry: &mut Registry) {
         .with_implementation(Implementation::Window(WindowBehavior::Sliding))
         .with_is_new(Implementation::new_pattern("?condition_value"));
 
+    registry
+        .register("trailing<D: timedelta>(const duration: D) -> window")
+        .with_implementation(Implementation::Window(WindowBehavior::Trailing));
+
+    registry
+        .register("session<D: timedelta>(const gap: D) -> window")
+        .with_implementation(Implementation::Window(WindowBehavior::Session));
+
     registry
         .register("minutely() -> bool")
         .with_implementation(Implementation::Tick(TickBehavior::Minutely))
@@ -28,21 +42,40 @@ pub(super) fn register(registry: &mut Registry) {
         .with_time_domain_check(TimeDomainCheck::Compatible);
 
     registry
-        .register("daily() -> bool")
+        .register("daily(const tz: string = \"UTC\") -> bool")
         .with_implementation(Implementation::Tick(TickBehavior::Daily))
         .with_is_new(Implementation::Tick(TickBehavior::Daily))
+        .with_argument_check(check_time_zone)
+        .with_time_domain_check(TimeDomainCheck::Compatible);
+
+    registry
+        .register("weekly(const start: string = \"monday\", const tz: string = \"UTC\") -> bool")
+        .with_implementation(Implementation::Tick(TickBehavior::Weekly))
+        .with_is_new(Implementation::Tick(TickBehavior::Weekly))
+        .with_argument_check(check_tick_schedule)
         .with_time_domain_check(TimeDomainCheck::Compatible);
 
     registry
-        .register("monthly() -> bool")
+        .register("monthly(const tz: string = \"UTC\") -> bool")
         .with_implementation(Implementation::Tick(TickBehavior::Monthly))
         .with_is_new(Implementation::Tick(TickBehavior::Monthly))
+        .with_argument_check(check_time_zone)
         .with_time_domain_check(TimeDomainCheck::Compatible);
 
     registry
-        .register("yearly() -> bool")
+        .register("yearly(const tz: string = \"UTC\") -> bool")
         .with_implementation(Implementation::Tick(TickBehavior::Yearly))
         .with_is_new(Implementation::Tick(TickBehavior::Yearly))
+        .with_argument_check(check_time_zone)
+        .with_time_domain_check(TimeDomainCheck::Compatible);
+
+    registry
+        .register(
+            "every<I: timedelta, O: timedelta>(const interval: I, const offset: O = null) -> bool",
+        )
+        .with_implementation(Implementation::Tick(TickBehavior::Every))
+        .with_is_new(Implementation::Tick(TickBehavior::Every))
+        .with_argument_check(check_tick_schedule)
         .with_time_domain_check(TimeDomainCheck::Compatible);
 
     registry
@@ -52,3 +85,87 @@ pub(super) fn register(registry: &mut Registry) {
         .with_time_domain_check(TimeDomainCheck::Compatible)
         .set_internal();
 }
+
+/// Report diagnostics if the arguments configuring a tick are invalid.
+///
+/// Returns `false` if any diagnostics were reported.
+fn check_tick_schedule(
+    function: &Function,
+    dfg: &Dfg,
+    diagnostics: &mut DiagnosticCollector<'_>,
+    args: &[Located<AstDfgRef>],
+) -> bool {
+    let argument = |name: &str| function.parameter_index(name).map(|index| &args[index]);
+    let mut valid = check_time_zone(function, dfg, diagnostics, args);
+
+    if let Some(start) = argument("start") {
+        if let Some(name) = dfg.string_literal(start.value()) {
+            if name.parse::<chrono::Weekday>().is_err() {
+                DiagnosticCode::InvalidArguments
+                    .builder()
+                    .with_label(
+                        start
+                            .location()
+                            .primary_label()
+                            .with_message(format!("Invalid day of week: '{name}'")),
+                    )
+                    .with_note("Expected a day such as 'monday' or 'sun'".to_owned())
+                    .emit(diagnostics);
+                valid = false;
+            }
+        }
+    }
+
+    if let Some(interval) = argument("interval") {
+        valid &= check_tick_duration(dfg, diagnostics, "interval", interval, 1);
+    }
+
+    // A `null` offset means ticks are not offset.
+    if let Some(offset) = argument("offset") {
+        if !matches!(dfg.literal(offset.value()), Some(ScalarValue::Null)) {
+            valid &= check_tick_duration(dfg, diagnostics, "offset", offset, 0);
+        }
+    }
+
+    valid
+}
+
+/// Report a diagnostic if the duration configuring a tick is not a fixed
+/// duration of at least `min_nanos` nanoseconds.
+///
+/// Returns `false` if any diagnostics were reported.
+fn check_tick_duration(
+    dfg: &Dfg,
+    diagnostics: &mut DiagnosticCollector<'_>,
+    name: &str,
+    duration: &Located<AstDfgRef>,
+    min_nanos: i64,
+) -> bool {
+    let literal = dfg.literal(duration.value());
+    if literal
+        .and_then(duration_nanos)
+        .map_or(false, |nanos| nanos >= min_nanos)
+    {
+        return true;
+    }
+
+    let literal = literal.map_or_else(|| "?".to_owned(), |literal| literal.to_string());
+    let expected = if min_nanos > 0 {
+        "positive"
+    } else {
+        "non-negative"
+    };
+    DiagnosticCode::InvalidArguments
+        .builder()
+        .with_label(
+            duration
+                .location()
+                .primary_label()
+                .with_message(format!("Invalid tick {name}: '{literal}'")),
+        )
+        .with_note(format!(
+            "Tick {name}s must be {expected} fixed durations, such as 'seconds(900)'"
+        ))
+        .emit(diagnostics);
+    false
+}
@@ -34,6 +34,7 @@ pub(super) fn dfg_to_plan(
                 InstKind::Simple(inst) => inst.name().to_owned(),
                 InstKind::FieldRef => "field_ref".to_owned(),
                 InstKind::Record => "record".to_owned(),
+                InstKind::Case => "case".to_owned(),
                 InstKind::Cast(_) => "cast".to_owned(),
             };
 
@@ -292,12 +292,19 @@ pub(super) fn dfg_to_plan<'plan>(
                 }),
             )
         }
-        Operation::Tick(behavior) => {
+        Operation::Tick(schedule) => {
             let result_type = DataType::new_primitive(PrimitiveType::Bool);
 
-            let behavior = *behavior as i32;
+            let behavior = schedule.behavior as i32;
             let input = plan_builder.schedule.operation(children[0])?;
-            let operator = operation_plan::Operator::Tick(TickOperation { behavior, input });
+            let operator = operation_plan::Operator::Tick(TickOperation {
+                behavior,
+                input,
+                time_zone: schedule.time_zone.clone(),
+                week_start: schedule.week_start,
+                interval: schedule.interval,
+                offset: schedule.offset,
+            });
 
             let operation = plan_builder.add_operation(id, operation_index, operator)?;
 
@@ -115,6 +115,33 @@ pub(crate) fn instantiate(
     Ok((instantiated_arguments, instantiated_return))
 }
 
+/// Infer the type of the parameter of a lambda passed to a list function.
+///
+/// The lambda is applied to each element of the list, so the parameter has
+/// the type of the elements.
+pub(crate) fn lambda_parameter_type(
+    call: &Located<String>,
+    list: &Located<FenlType>,
+) -> Result<FenlType, DiagnosticBuilder> {
+    match list.inner() {
+        FenlType::Concrete(DataType::List(field)) => {
+            Ok(FenlType::Concrete(field.data_type().clone()))
+        }
+        FenlType::Error => Ok(FenlType::Error),
+        other => Err(DiagnosticCode::InvalidArgumentType
+            .builder()
+            .with_label(call.location().primary_label().with_message(format!(
+                "Invalid types for parameter 'list' in call to '{call}'"
+            )))
+            .with_label(
+                list.location()
+                    .secondary_label()
+                    .with_message(format!("Actual type: {other}")),
+            )
+            .with_note("Expected type: list<T>".to_owned())),
+    }
+}
+
 /// Validate an instantiation.
 ///
 /// If the instantiation was valid, returns the argument type.
@@ -582,6 +609,13 @@ fn promote_concrete(concrete: FenlType, type_class: &TypeClass) -> Option<FenlTy
         (TypeClass::Float, FenlType::Concrete(Float16 | Float32 | Float64)) => Some(concrete),
         (TypeClass::Float, FenlType::Concrete(_)) => None,
 
+        // Only integer types satisfy `int`. Floats are not implicitly truncated.
+        (
+            TypeClass::Int,
+            FenlType::Concrete(Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64),
+        ) => Some(concrete),
+        (TypeClass::Int, FenlType::Concrete(_)) => None,
+
         // Duration and Interval types are time deltas.
         (TypeClass::TimeDelta, FenlType::Concrete(Duration(_) | Interval(_))) => Some(concrete),
         (TypeClass::TimeDelta, FenlType::Concrete(_)) => None,
@@ -758,6 +792,7 @@ impl TypeConstraints {
 #[cfg(test)]
 mod tests {
     use std::borrow::Cow;
+    use std::sync::Arc;
 
     use sparrow_syntax::{FeatureSetPart, FenlType, Resolved, Signature};
 
@@ -855,6 +890,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_instantiate_int() {
+        const MOD_SIGNATURE: &str = "mod<I: int>(a: I, b: I) -> I";
+
+        // i32 should be widened to i64
+        assert_eq!(
+            instantiate_types(MOD_SIGNATURE, &["i32", "i64"]),
+            Ok("(a: i64, b: i64) -> i64".to_owned())
+        );
+        // unsigned integers are integers
+        assert_eq!(
+            instantiate_types(MOD_SIGNATURE, &["u32", "u32"]),
+            Ok("(a: u32, b: u32) -> u32".to_owned())
+        );
+        // floats are not implicitly truncated
+        assert!(instantiate_types(MOD_SIGNATURE, &["i64", "f64"]).is_err());
+    }
+
     #[test]
     fn test_instantiate_get_map() {
         const GET_SIGNATURE: &str = "get<K: key, V: any>(key: K, map: map<K, V>) -> V";
@@ -885,6 +938,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_lambda_parameter_type() {
+        let call = Located::internal_string("list_map");
+        let list_type = |s: &'static str| {
+            let part_id = FeatureSetPart::Internal(s);
+            Located::internal_str(s).transform(|s| FenlType::try_from_str(part_id, s).unwrap())
+        };
+
+        assert_eq!(
+            lambda_parameter_type(&call, &list_type("list<f32>")),
+            Ok(FenlType::Concrete(DataType::Float32))
+        );
+        assert_eq!(
+            lambda_parameter_type(&call, &list_type("list<list<i64>>")),
+            Ok(FenlType::Concrete(DataType::List(Arc::new(Field::new(
+                "item",
+                DataType::Int64,
+                true
+            )))))
+        );
+        assert!(lambda_parameter_type(&call, &list_type("i64")).is_err());
+        assert_eq!(
+            lambda_parameter_type(
+                &call,
+                &Located::internal_str("error").with_value(FenlType::Error)
+            ),
+            Ok(FenlType::Error)
+        );
+    }
+
     fn validate_instantiation(
         signature_str: &'static str,
         argument_types: &[&'static str],
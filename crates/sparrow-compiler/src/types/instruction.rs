@@ -112,6 +112,44 @@ pub(crate) fn typecheck_inst(
             let result_type = DataType::Struct(fields.into());
             Ok(FenlType::Concrete(result_type))
         }
+        InstKind::Case => {
+            anyhow::ensure!(
+                argument_types.len() % 2 == 1 && argument_types.len() > 1,
+                "Expected (condition, value) pairs and a default, but was {:?}",
+                argument_types.len(),
+            );
+
+            if argument_types.iter().contains(&FenlType::Error) {
+                return Ok(FenlType::Error);
+            }
+
+            // 0, 2, 4, ... (excluding the last) = the conditions.
+            let conditions = argument_types
+                .iter()
+                .step_by(2)
+                .take(argument_types.len() / 2);
+            for condition in conditions {
+                anyhow::ensure!(
+                    condition == &FenlType::Concrete(DataType::Boolean),
+                    "Expected case condition to be boolean, but was {:?}",
+                    condition
+                );
+            }
+
+            // 1, 3, 5, ... and the last = the values. They should all have the same type.
+            let result_type = &argument_types[argument_types.len() - 1];
+            let values = argument_types.iter().skip(1).step_by(2);
+            for value in values {
+                anyhow::ensure!(
+                    value == result_type,
+                    "Expected case values to have the same type, but was {:?} and {:?}",
+                    value,
+                    result_type
+                );
+            }
+
+            Ok(result_type.clone())
+        }
     }
 }
 
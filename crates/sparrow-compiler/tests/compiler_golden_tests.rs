@@ -845,6 +845,113 @@ async fn test_undefined_formula() {
     "###);
 }
 
+#[tokio::test]
+async fn test_formula_function() {
+    let warnings = compile_warnings(TestScript {
+        tables: vec![account_sent_table()],
+        feature_set: FeatureSet {
+            formulas: vec![
+                formula("half", "ratio(Sent.amount, 2.0)"),
+                formula("ratio", "def ratio(a, b) = a / b"),
+            ],
+            query: "{ half, whole: ratio(Sent.amount, 1.0) }".to_owned(),
+        },
+    })
+    .await;
+
+    insta::assert_snapshot!(warnings, @"");
+}
+
+#[tokio::test]
+async fn test_formula_function_body_error() {
+    // The body is checked where the function is defined, so the error is
+    // reported once rather than at each call.
+    let error = compile_err(TestScript {
+        tables: vec![account_sent_table()],
+        feature_set: FeatureSet {
+            formulas: vec![formula("shout", "def shout(s: string) = s - s")],
+            query: "{ a: shout(\"a\"), b: shout(\"b\") }".to_owned(),
+        },
+    })
+    .await;
+
+    insta::assert_snapshot!(error, @r###"
+    error[E0010]: Invalid argument type(s)
+      --> 'shout':1:26
+      |
+    1 | def shout(s: string) = s - s
+      |                        - ^ Invalid types for call to 'sub'
+      |                        |  
+      |                        Type: string
+      |
+      = Expected 'number'
+    "###);
+}
+
+#[tokio::test]
+async fn test_formula_function_invalid_call() {
+    let error = compile_err(TestScript {
+        tables: vec![account_sent_table()],
+        feature_set: FeatureSet {
+            formulas: vec![formula("double", "def double(x: number) = x + x")],
+            query: "{ a: double(Sent.amount, 2) }".to_owned(),
+        },
+    })
+    .await;
+
+    insta::assert_snapshot!(error, @r###"
+    error[E0008]: Invalid arguments
+      --> Query:1:26
+      |
+    1 | { a: double(Sent.amount, 2) }
+      |                          ^ First unexpected argument
+      |
+      = Expected 1 but got 2
+    "###);
+}
+
+#[tokio::test]
+async fn test_formula_function_recursive() {
+    let error = compile_err(TestScript {
+        tables: vec![account_sent_table()],
+        feature_set: FeatureSet {
+            formulas: vec![formula("f", "def f(x) = f(x) + 1")],
+            query: "{ a: f(Sent.amount) }".to_owned(),
+        },
+    })
+    .await;
+
+    insta::assert_snapshot!(error, @r###"
+    error[E0012]: Circular dependency
+      --> 'f':1:12
+      |
+    1 | def f(x) = f(x) + 1
+      |            ^ Function 'f' called here in 'f'
+    "###);
+}
+
+#[tokio::test]
+async fn test_formula_function_name_mismatch() {
+    let error = compile_err(TestScript {
+        tables: vec![account_sent_table()],
+        feature_set: FeatureSet {
+            formulas: vec![formula("ratio", "def divide(a, b) = a / b")],
+            query: "{ a: Sent.amount }".to_owned(),
+        },
+    })
+    .await;
+
+    insta::assert_snapshot!(error, @r###"
+    error[E0003]: Illegal identifier
+      --> 'ratio':1:5
+      |
+    1 | def divide(a, b) = a / b
+      |     ^^^^^^ Function 'divide' is defined in formula 'ratio'
+      |
+      = A function must be defined in a formula with the same name
+    "###);
+}
+
 // Tests that a function that is rewritten into another fenl function
 // correctly displays the error on the original signature.
 #[tokio::test]
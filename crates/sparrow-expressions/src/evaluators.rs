@@ -12,6 +12,7 @@ use crate::evaluator::Evaluator;
 use crate::values::{ArrayRefValue, BooleanValue, PrimitiveValue, StringValue, StructValue};
 use crate::Error;
 
+mod case;
 mod cast;
 mod coalesce;
 mod column;
@@ -104,20 +105,27 @@ impl<'a> StaticInfo<'a> {
         Ok(&self.literal_args[0])
     }
 
-    /// Return the string value corresponding to the exactly-one literal arguments.
-    fn literal_string(&self) -> error_stack::Result<&'a str, Error> {
-        match self.literal()? {
-            ScalarValue::Utf8(Some(string)) => Ok(string),
-            ScalarValue::LargeUtf8(Some(string)) => Ok(string),
-            other => {
-                error_stack::bail!(Error::InvalidLiteral {
-                    expected: "non-null string",
-                    actual: other.clone()
-                })
+    /// Return the scalar values corresponding to the literal arguments.
+    fn unpack_literals<T: itertools::traits::HomogeneousTuple<Item = &'a ScalarValue>>(
+        &self,
+    ) -> error_stack::Result<T, Error> {
+        match self.literal_args.iter().next_tuple() {
+            Some(t) => Ok(t),
+            None => {
+                error_stack::bail!(Error::InvalidLiteralCount {
+                    name: self.name.clone(),
+                    expected: T::num_items(),
+                    actual: self.literal_args.len()
+                });
             }
         }
     }
 
+    /// Return the string value corresponding to the exactly-one literal arguments.
+    fn literal_string(&self) -> error_stack::Result<&'a str, Error> {
+        as_literal_string(self.literal()?)
+    }
+
     fn unpack_argument(mut self) -> error_stack::Result<&'a StaticArg<'a>, Error> {
         error_stack::ensure!(
             self.args.len() == 1,
@@ -148,6 +156,20 @@ impl<'a> StaticInfo<'a> {
     }
 }
 
+/// Return the string value of a literal argument.
+fn as_literal_string(literal: &ScalarValue) -> error_stack::Result<&str, Error> {
+    match literal {
+        ScalarValue::Utf8(Some(string)) => Ok(string),
+        ScalarValue::LargeUtf8(Some(string)) => Ok(string),
+        other => {
+            error_stack::bail!(Error::InvalidLiteral {
+                expected: "non-null string",
+                actual: other.clone()
+            })
+        }
+    }
+}
+
 /// Create the evaluators for the given expressions.
 pub(super) fn create_evaluators(
     input_schema: &Schema,
@@ -0,0 +1,123 @@
+use arrow_array::{Array, ArrayRef, BooleanArray};
+use error_stack::{IntoReport, ResultExt};
+
+use crate::evaluator::Evaluator;
+use crate::evaluators::StaticInfo;
+use crate::values::{ArrayRefValue, BooleanValue};
+use crate::work_area::WorkArea;
+use crate::Error;
+
+inventory::submit!(crate::evaluators::EvaluatorFactory {
+    name: "case",
+    create: &create
+});
+
+/// Evaluator for the `case` instruction.
+struct CaseEvaluator {
+    /// The condition and value of each arm, in order.
+    arms: Vec<(BooleanValue, ArrayRefValue)>,
+    default: ArrayRefValue,
+}
+
+impl Evaluator for CaseEvaluator {
+    fn evaluate(&self, info: &WorkArea<'_>) -> error_stack::Result<ArrayRef, Error> {
+        let arms: Vec<_> = self
+            .arms
+            .iter()
+            .map(|(condition, value)| (info.expression(*condition), info.expression(*value)))
+            .collect();
+        let default = info.expression(self.default);
+        case(&arms, default)
+    }
+}
+
+fn create(info: StaticInfo<'_>) -> error_stack::Result<Box<dyn Evaluator>, Error> {
+    // The arguments are pairs of conditions and values, followed by the default.
+    let actual = info.args.len();
+    error_stack::ensure!(
+        actual % 2 == 1,
+        Error::InvalidArgumentCount {
+            name: info.name.clone(),
+            expected: actual + 1,
+            actual
+        }
+    );
+
+    for value in info.args.iter().skip(1).step_by(2) {
+        error_stack::ensure!(
+            info.result_type == value.data_type,
+            Error::InvalidArgumentType {
+                expected: info.result_type.clone(),
+                actual: value.data_type.clone()
+            }
+        );
+    }
+
+    let default = info.args[actual - 1].array_ref();
+    let arms = info.args[..actual - 1]
+        .chunks(2)
+        .map(|arm| Ok((arm[0].boolean()?, arm[1].array_ref())))
+        .collect::<error_stack::Result<_, Error>>()?;
+    Ok(Box::new(CaseEvaluator { arms, default }))
+}
+
+/// Return the value of the first arm whose condition is true, or the default.
+///
+/// A null condition is treated as false.
+fn case(
+    arms: &[(&BooleanArray, &ArrayRef)],
+    default: &ArrayRef,
+) -> error_stack::Result<ArrayRef, Error> {
+    // Apply the arms in reverse order, so that the first arm with a true
+    // condition determines the result.
+    let mut result = default.clone();
+    for (condition, value) in arms.iter().rev() {
+        let condition = if condition.null_count() > 0 {
+            arrow_select::filter::prep_null_mask_filter(condition)
+        } else {
+            (*condition).clone()
+        };
+        result = arrow_select::zip::zip(&condition, value.as_ref(), result.as_ref())
+            .into_report()
+            .change_context(Error::ExprEvaluation)?;
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use arrow_array::Int64Array;
+
+    #[test]
+    fn test_case_first_true_arm() {
+        let c1 = BooleanArray::from(vec![true, false, true, false]);
+        let c2 = BooleanArray::from(vec![true, true, false, false]);
+        let v1: ArrayRef = Arc::new(Int64Array::from(vec![1, 1, 1, 1]));
+        let v2: ArrayRef = Arc::new(Int64Array::from(vec![2, 2, 2, 2]));
+        let default: ArrayRef = Arc::new(Int64Array::from(vec![Some(3), Some(3), None, None]));
+
+        let result = case(&[(&c1, &v1), (&c2, &v2)], &default).unwrap();
+        assert_eq!(
+            result.as_ref(),
+            &Int64Array::from(vec![Some(1), Some(2), Some(1), None])
+        )
+    }
+
+    #[test]
+    fn test_case_null_conditions() {
+        let c1 = BooleanArray::from(vec![Some(true), None, None, Some(false)]);
+        let c2 = BooleanArray::from(vec![None, Some(true), None, None]);
+        let v1: ArrayRef = Arc::new(Int64Array::from(vec![Some(1), Some(1), Some(1), None]));
+        let v2: ArrayRef = Arc::new(Int64Array::from(vec![Some(2), None, Some(2), Some(2)]));
+        let default: ArrayRef = Arc::new(Int64Array::from(vec![3, 3, 3, 3]));
+
+        let result = case(&[(&c1, &v1), (&c2, &v2)], &default).unwrap();
+        assert_eq!(
+            result.as_ref(),
+            &Int64Array::from(vec![Some(1), None, Some(3), Some(3)])
+        )
+    }
+}
@@ -10,8 +10,8 @@
 /// - A list of `(ty_case, ty_name)` pairs, where `ty_case` is the data type case
 ///   (eg., `Int32`) and `ty_name` is the corresponding array (eg., `Int32Type`).
 ///
-/// The third argument can also be one of the special types -- `number`, `float`, `ordered`
-/// or `signed`, which expands to the corresponding list of types.
+/// The third argument can also be one of the special types -- `number`, `float`, `int`,
+/// `ordered` or `signed`, which expands to the corresponding list of types.
 ///
 /// Omitting the third argument creates an evaluator for any datatype that is supported
 /// by `PrimitiveArray`.
@@ -34,6 +34,14 @@ macro_rules! create_primitive_evaluator {
           (arrow_schema::DataType::Float64, Float64Type)
         }
     };
+    ($type_index:expr, $evaluator:ident, int) => {
+        crate::evaluators::macros::create_primitive_evaluator! {$type_index, $evaluator,
+          (arrow_schema::DataType::Int32, Int32Type),
+          (arrow_schema::DataType::Int64, Int64Type),
+          (arrow_schema::DataType::UInt32, UInt32Type),
+          (arrow_schema::DataType::UInt64, UInt64Type)
+        }
+    };
     ($type_index:expr, $evaluator:ident, float) => {
         crate::evaluators::macros::create_primitive_evaluator! {$type_index, $evaluator,
           (arrow_schema::DataType::Float32, Float32Type),
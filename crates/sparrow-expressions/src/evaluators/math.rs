@@ -1,13 +1,302 @@
+mod abs;
+mod acos;
 mod add;
+mod asin;
+mod atan;
+mod atan2;
+mod bitwise_and;
+mod bitwise_not;
+mod bitwise_or;
+mod bitwise_xor;
 mod ceil;
 mod clamp;
+mod cos;
 mod div;
 mod exp;
 mod floor;
+mod floor_div;
 mod greatest;
 mod least;
+mod ln;
+mod log10;
+mod modulo;
 mod mul;
 mod neg;
 mod powf;
+mod rem;
 mod round;
+mod shift_left;
+mod shift_right;
+mod sign;
+mod sin;
 mod sub;
+mod tan;
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{E, FRAC_PI_2, FRAC_PI_4, PI};
+    use std::sync::Arc;
+
+    use approx::assert_relative_eq;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Float64Type, Int64Type};
+    use arrow_array::{
+        ArrayRef, Float64Array, Int64Array, RecordBatch, TimestampNanosecondArray, UInt64Array,
+    };
+    use arrow_schema::{DataType, Field, Schema};
+    use sparrow_arrow::scalar_value::ScalarValue;
+    use sparrow_arrow::{Batch, RowTime};
+    use sparrow_physical::{Expr, ExprId};
+
+    use crate::{Error, ExpressionExecutor};
+
+    /// Return the expressions applying `name` to a column for each argument.
+    fn exprs(name: &'static str, schema: &Schema) -> Vec<Expr> {
+        let mut exprs: Vec<_> = schema
+            .fields()
+            .iter()
+            .map(|field| Expr {
+                name: "column".into(),
+                literal_args: vec![ScalarValue::Utf8(Some(field.name().clone()))],
+                args: vec![],
+                result_type: field.data_type().clone(),
+            })
+            .collect();
+        exprs.push(Expr {
+            name: name.into(),
+            literal_args: vec![],
+            args: (0..exprs.len()).map(ExprId::from).collect(),
+            result_type: schema.field(0).data_type().clone(),
+        });
+        exprs
+    }
+
+    fn schema(args: &[ArrayRef]) -> Schema {
+        let fields: Vec<_> = args
+            .iter()
+            .enumerate()
+            .map(|(index, arg)| Field::new(format!("arg{index}"), arg.data_type().clone(), true))
+            .collect();
+        Schema::new(fields)
+    }
+
+    /// Evaluate the function `name` on the arguments using the executor.
+    fn evaluate(name: &'static str, args: Vec<ArrayRef>) -> ArrayRef {
+        let schema = schema(&args);
+        let executor = ExpressionExecutor::try_new(&schema, &exprs(name, &schema)).unwrap();
+
+        let num_rows = args[0].len();
+        let batch = RecordBatch::try_new(Arc::new(schema), args).unwrap();
+        let time = Arc::new(TimestampNanosecondArray::from(vec![0; num_rows]));
+        let subsort = Arc::new(UInt64Array::from_iter_values(0..num_rows as u64));
+        let key_hash = Arc::new(UInt64Array::from(vec![0; num_rows]));
+        let input = Batch::new_with_data(
+            batch,
+            time,
+            subsort,
+            key_hash,
+            RowTime::from_timestamp_ns(0),
+        );
+
+        executor.execute(&input).unwrap().pop().unwrap()
+    }
+
+    #[test]
+    fn test_integer_functions() {
+        #[allow(clippy::type_complexity)]
+        let cases: Vec<(&'static str, Vec<Vec<Option<i64>>>, Vec<Option<i64>>)> = vec![
+            (
+                "abs",
+                vec![vec![Some(-5), Some(0), Some(3), None, Some(i64::MIN)]],
+                vec![Some(5), Some(0), Some(3), None, Some(i64::MIN)],
+            ),
+            (
+                "sign",
+                vec![vec![Some(-7), Some(0), Some(9), None]],
+                vec![Some(-1), Some(0), Some(1), None],
+            ),
+            (
+                "rem",
+                vec![
+                    vec![Some(7), Some(-7), Some(7), Some(7), None],
+                    vec![Some(3), Some(3), Some(-3), Some(0), Some(1)],
+                ],
+                vec![Some(1), Some(-1), Some(1), None, None],
+            ),
+            (
+                "mod",
+                vec![
+                    vec![Some(7), Some(-7), Some(7), Some(-7), Some(7)],
+                    vec![Some(3), Some(3), Some(-3), Some(-3), Some(0)],
+                ],
+                vec![Some(1), Some(2), Some(-2), Some(-1), None],
+            ),
+            (
+                "floor_div",
+                vec![
+                    vec![Some(7), Some(-7), Some(7), Some(-7), Some(7)],
+                    vec![Some(3), Some(3), Some(-3), Some(-3), Some(0)],
+                ],
+                vec![Some(2), Some(-3), Some(-3), Some(2), None],
+            ),
+            (
+                "bitwise_and",
+                vec![
+                    vec![Some(12), Some(5), None],
+                    vec![Some(10), Some(3), Some(1)],
+                ],
+                vec![Some(8), Some(1), None],
+            ),
+            (
+                "bitwise_or",
+                vec![
+                    vec![Some(12), Some(5), None],
+                    vec![Some(10), Some(3), Some(1)],
+                ],
+                vec![Some(14), Some(7), None],
+            ),
+            (
+                "bitwise_xor",
+                vec![
+                    vec![Some(12), Some(5), None],
+                    vec![Some(10), Some(3), Some(1)],
+                ],
+                vec![Some(6), Some(6), None],
+            ),
+            (
+                "bitwise_not",
+                vec![vec![Some(0), Some(-1), Some(5), None]],
+                vec![Some(-1), Some(0), Some(-6), None],
+            ),
+            // Shift amounts wrap around the bit width of the type.
+            (
+                "shift_left",
+                vec![
+                    vec![Some(1), Some(1), Some(-1), None],
+                    vec![Some(3), Some(65), Some(1), Some(1)],
+                ],
+                vec![Some(8), Some(2), Some(-2), None],
+            ),
+            (
+                "shift_right",
+                vec![
+                    vec![Some(8), Some(8), Some(-8), None],
+                    vec![Some(3), Some(65), Some(1), Some(1)],
+                ],
+                vec![Some(1), Some(4), Some(-4), None],
+            ),
+        ];
+
+        for (name, args, expected) in cases {
+            let args = args
+                .into_iter()
+                .map(|arg| -> ArrayRef { Arc::new(Int64Array::from(arg)) })
+                .collect();
+            let actual = evaluate(name, args);
+            assert_eq!(
+                actual.as_primitive::<Int64Type>(),
+                &Int64Array::from(expected),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_float_functions() {
+        #[allow(clippy::type_complexity)]
+        let cases: Vec<(&'static str, Vec<Vec<Option<f64>>>, Vec<Option<f64>>)> = vec![
+            (
+                "sin",
+                vec![vec![Some(0.0), Some(FRAC_PI_2), Some(-FRAC_PI_2), None]],
+                vec![Some(0.0), Some(1.0), Some(-1.0), None],
+            ),
+            (
+                "cos",
+                vec![vec![Some(0.0), Some(PI), None]],
+                vec![Some(1.0), Some(-1.0), None],
+            ),
+            (
+                "tan",
+                vec![vec![Some(0.0), Some(FRAC_PI_4), Some(-FRAC_PI_4), None]],
+                vec![Some(0.0), Some(1.0), Some(-1.0), None],
+            ),
+            (
+                "asin",
+                vec![vec![Some(0.0), Some(1.0), Some(-1.0), None]],
+                vec![Some(0.0), Some(FRAC_PI_2), Some(-FRAC_PI_2), None],
+            ),
+            (
+                "acos",
+                vec![vec![Some(1.0), Some(0.0), Some(-1.0), None]],
+                vec![Some(0.0), Some(FRAC_PI_2), Some(PI), None],
+            ),
+            (
+                "atan",
+                vec![vec![Some(0.0), Some(1.0), Some(-1.0), None]],
+                vec![Some(0.0), Some(FRAC_PI_4), Some(-FRAC_PI_4), None],
+            ),
+            (
+                "atan2",
+                vec![
+                    vec![Some(1.0), Some(1.0), Some(-1.0), None],
+                    vec![Some(1.0), Some(-1.0), Some(0.0), Some(1.0)],
+                ],
+                vec![
+                    Some(FRAC_PI_4),
+                    Some(3.0 * FRAC_PI_4),
+                    Some(-FRAC_PI_2),
+                    None,
+                ],
+            ),
+            (
+                "ln",
+                vec![vec![Some(1.0), Some(E), Some(0.0), None]],
+                vec![Some(0.0), Some(1.0), Some(f64::NEG_INFINITY), None],
+            ),
+            (
+                "log10",
+                vec![vec![Some(1.0), Some(100.0), Some(0.0), None]],
+                vec![Some(0.0), Some(2.0), Some(f64::NEG_INFINITY), None],
+            ),
+        ];
+
+        for (name, args, expected) in cases {
+            let args = args
+                .into_iter()
+                .map(|arg| -> ArrayRef { Arc::new(Float64Array::from(arg)) })
+                .collect();
+            let actual = evaluate(name, args);
+            let actual = actual.as_primitive::<Float64Type>();
+            assert_eq!(actual.len(), expected.len(), "{name}");
+            for (actual, expected) in actual.iter().zip(expected) {
+                match (actual, expected) {
+                    (Some(actual), Some(expected)) => assert_relative_eq!(actual, expected),
+                    (actual, expected) => assert_eq!(actual, expected, "{name}"),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_int_functions_reject_floats() {
+        let schema = Schema::new(vec![
+            Field::new("arg0", DataType::Float64, true),
+            Field::new("arg1", DataType::Float64, true),
+        ]);
+        for name in ["mod", "floor_div", "bitwise_and", "shift_left"] {
+            let error = ExpressionExecutor::try_new(&schema, &exprs(name, &schema))
+                .err()
+                .unwrap();
+            assert!(
+                matches!(
+                    error.current_context(),
+                    Error::UnsupportedArgumentType {
+                        actual: DataType::Float64,
+                        ..
+                    }
+                ),
+                "{name}: {error:?}"
+            );
+        }
+    }
+}
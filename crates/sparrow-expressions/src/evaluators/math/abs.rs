@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, ArrowNativeTypeOp, ArrowNumericType};
+
+use crate::evaluator::Evaluator;
+use crate::evaluators::StaticInfo;
+use crate::values::PrimitiveValue;
+use crate::work_area::WorkArea;
+use crate::Error;
+
+inventory::submit!(crate::evaluators::EvaluatorFactory {
+    name: "abs",
+    create: &crate::evaluators::macros::create_primitive_evaluator!(0, create, number)
+});
+
+/// Evaluator for the `abs` expression.
+///
+/// Returns the absolute value of a number.
+struct AbsEvaluator<T: ArrowNumericType>
+where
+    T::Native: ArrowNativeTypeOp + PartialOrd,
+{
+    input: PrimitiveValue<T>,
+}
+
+impl<T> Evaluator for AbsEvaluator<T>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp + PartialOrd,
+{
+    fn evaluate(&self, info: &WorkArea<'_>) -> error_stack::Result<ArrayRef, Error> {
+        let input = info.expression(self.input);
+        let result = sparrow_kernels::math::abs(input).unwrap_or_else(|never| match never {});
+        Ok(Arc::new(result))
+    }
+}
+
+fn create<T>(info: StaticInfo<'_>) -> error_stack::Result<Box<dyn Evaluator>, Error>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp + PartialOrd,
+{
+    let input = info.unpack_argument()?;
+    Ok(Box::new(AbsEvaluator::<T> {
+        input: input.primitive()?,
+    }))
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, ArrowNumericType};
+use num::Float;
+
+use crate::evaluator::Evaluator;
+use crate::evaluators::StaticInfo;
+use crate::values::PrimitiveValue;
+use crate::work_area::WorkArea;
+use crate::Error;
+
+inventory::submit!(crate::evaluators::EvaluatorFactory {
+    name: "acos",
+    create: &crate::evaluators::macros::create_primitive_evaluator!(0, create, float)
+});
+
+/// Evaluator for the `acos` expression.
+///
+/// Returns the arccosine of a number, in radians.
+struct AcosEvaluator<T: ArrowNumericType>
+where
+    T::Native: Float,
+{
+    input: PrimitiveValue<T>,
+}
+
+impl<T> Evaluator for AcosEvaluator<T>
+where
+    T: ArrowNumericType,
+    T::Native: Float,
+{
+    fn evaluate(&self, info: &WorkArea<'_>) -> error_stack::Result<ArrayRef, Error> {
+        let input = info.expression(self.input);
+        let result = sparrow_kernels::math::acos(input).unwrap_or_else(|never| match never {});
+        Ok(Arc::new(result))
+    }
+}
+
+fn create<T>(info: StaticInfo<'_>) -> error_stack::Result<Box<dyn Evaluator>, Error>
+where
+    T: ArrowNumericType,
+    T::Native: Float,
+{
+    let input = info.unpack_argument()?;
+    Ok(Box::new(AcosEvaluator::<T> {
+        input: input.primitive()?,
+    }))
+}
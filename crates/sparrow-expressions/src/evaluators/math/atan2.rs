@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, ArrowNumericType};
+use error_stack::{IntoReport, ResultExt};
+use num::Float;
+
+use crate::evaluator::Evaluator;
+use crate::evaluators::StaticInfo;
+use crate::values::PrimitiveValue;
+use crate::work_area::WorkArea;
+use crate::Error;
+
+inventory::submit!(crate::evaluators::EvaluatorFactory {
+    name: "atan2",
+    create: &crate::evaluators::macros::create_primitive_evaluator!(0, create, float)
+});
+
+/// Evaluator for the `atan2` expression.
+///
+/// Returns the four quadrant arctangent of `y` and `x`, in radians.
+struct Atan2Evaluator<T: ArrowNumericType>
+where
+    T::Native: Float,
+{
+    lhs: PrimitiveValue<T>,
+    rhs: PrimitiveValue<T>,
+}
+
+impl<T> Evaluator for Atan2Evaluator<T>
+where
+    T: ArrowNumericType,
+    T::Native: Float,
+{
+    fn evaluate(&self, info: &WorkArea<'_>) -> error_stack::Result<ArrayRef, Error> {
+        let lhs = info.expression(self.lhs);
+        let rhs = info.expression(self.rhs);
+        let result = sparrow_kernels::math::atan2(lhs, rhs)
+            .into_report()
+            .change_context(Error::ExprEvaluation)?;
+        Ok(Arc::new(result))
+    }
+}
+
+fn create<T>(info: StaticInfo<'_>) -> error_stack::Result<Box<dyn Evaluator>, Error>
+where
+    T: ArrowNumericType,
+    T::Native: Float,
+{
+    let (lhs, rhs) = info.unpack_arguments()?;
+    Ok(Box::new(Atan2Evaluator::<T> {
+        lhs: lhs.primitive()?,
+        rhs: rhs.primitive()?,
+    }))
+}
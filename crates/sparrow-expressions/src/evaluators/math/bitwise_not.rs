@@ -0,0 +1,49 @@
+use std::ops::Not;
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, ArrowNumericType};
+use error_stack::{IntoReport, ResultExt};
+
+use crate::evaluator::Evaluator;
+use crate::evaluators::StaticInfo;
+use crate::values::PrimitiveValue;
+use crate::work_area::WorkArea;
+use crate::Error;
+
+inventory::submit!(crate::evaluators::EvaluatorFactory {
+    name: "bitwise_not",
+    create: &crate::evaluators::macros::create_primitive_evaluator!(0, create, int)
+});
+
+/// Evaluator for the `bitwise_not` expression.
+struct BitwiseNotEvaluator<T: ArrowNumericType>
+where
+    T::Native: Not<Output = T::Native>,
+{
+    input: PrimitiveValue<T>,
+}
+
+impl<T> Evaluator for BitwiseNotEvaluator<T>
+where
+    T: ArrowNumericType,
+    T::Native: Not<Output = T::Native>,
+{
+    fn evaluate(&self, info: &WorkArea<'_>) -> error_stack::Result<ArrayRef, Error> {
+        let input = info.expression(self.input);
+        let result = arrow_arith::bitwise::bitwise_not(input)
+            .into_report()
+            .change_context(Error::ExprEvaluation)?;
+        Ok(Arc::new(result))
+    }
+}
+
+fn create<T>(info: StaticInfo<'_>) -> error_stack::Result<Box<dyn Evaluator>, Error>
+where
+    T: ArrowNumericType,
+    T::Native: Not<Output = T::Native>,
+{
+    let input = info.unpack_argument()?;
+    Ok(Box::new(BitwiseNotEvaluator::<T> {
+        input: input.primitive()?,
+    }))
+}
@@ -0,0 +1,52 @@
+use std::ops::BitOr;
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, ArrowNumericType};
+use error_stack::{IntoReport, ResultExt};
+
+use crate::evaluator::Evaluator;
+use crate::evaluators::StaticInfo;
+use crate::values::PrimitiveValue;
+use crate::work_area::WorkArea;
+use crate::Error;
+
+inventory::submit!(crate::evaluators::EvaluatorFactory {
+    name: "bitwise_or",
+    create: &crate::evaluators::macros::create_primitive_evaluator!(0, create, int)
+});
+
+/// Evaluator for the `bitwise_or` expression.
+struct BitwiseOrEvaluator<T: ArrowNumericType>
+where
+    T::Native: BitOr<Output = T::Native>,
+{
+    lhs: PrimitiveValue<T>,
+    rhs: PrimitiveValue<T>,
+}
+
+impl<T> Evaluator for BitwiseOrEvaluator<T>
+where
+    T: ArrowNumericType,
+    T::Native: BitOr<Output = T::Native>,
+{
+    fn evaluate(&self, info: &WorkArea<'_>) -> error_stack::Result<ArrayRef, Error> {
+        let lhs = info.expression(self.lhs);
+        let rhs = info.expression(self.rhs);
+        let result = arrow_arith::bitwise::bitwise_or(lhs, rhs)
+            .into_report()
+            .change_context(Error::ExprEvaluation)?;
+        Ok(Arc::new(result))
+    }
+}
+
+fn create<T>(info: StaticInfo<'_>) -> error_stack::Result<Box<dyn Evaluator>, Error>
+where
+    T: ArrowNumericType,
+    T::Native: BitOr<Output = T::Native>,
+{
+    let (lhs, rhs) = info.unpack_arguments()?;
+    Ok(Box::new(BitwiseOrEvaluator::<T> {
+        lhs: lhs.primitive()?,
+        rhs: rhs.primitive()?,
+    }))
+}
@@ -0,0 +1,52 @@
+use std::ops::BitXor;
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, ArrowNumericType};
+use error_stack::{IntoReport, ResultExt};
+
+use crate::evaluator::Evaluator;
+use crate::evaluators::StaticInfo;
+use crate::values::PrimitiveValue;
+use crate::work_area::WorkArea;
+use crate::Error;
+
+inventory::submit!(crate::evaluators::EvaluatorFactory {
+    name: "bitwise_xor",
+    create: &crate::evaluators::macros::create_primitive_evaluator!(0, create, int)
+});
+
+/// Evaluator for the `bitwise_xor` expression.
+struct BitwiseXorEvaluator<T: ArrowNumericType>
+where
+    T::Native: BitXor<Output = T::Native>,
+{
+    lhs: PrimitiveValue<T>,
+    rhs: PrimitiveValue<T>,
+}
+
+impl<T> Evaluator for BitwiseXorEvaluator<T>
+where
+    T: ArrowNumericType,
+    T::Native: BitXor<Output = T::Native>,
+{
+    fn evaluate(&self, info: &WorkArea<'_>) -> error_stack::Result<ArrayRef, Error> {
+        let lhs = info.expression(self.lhs);
+        let rhs = info.expression(self.rhs);
+        let result = arrow_arith::bitwise::bitwise_xor(lhs, rhs)
+            .into_report()
+            .change_context(Error::ExprEvaluation)?;
+        Ok(Arc::new(result))
+    }
+}
+
+fn create<T>(info: StaticInfo<'_>) -> error_stack::Result<Box<dyn Evaluator>, Error>
+where
+    T: ArrowNumericType,
+    T::Native: BitXor<Output = T::Native>,
+{
+    let (lhs, rhs) = info.unpack_arguments()?;
+    Ok(Box::new(BitwiseXorEvaluator::<T> {
+        lhs: lhs.primitive()?,
+        rhs: rhs.primitive()?,
+    }))
+}
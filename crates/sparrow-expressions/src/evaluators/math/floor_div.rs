@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, ArrowNativeTypeOp, ArrowNumericType};
+
+use crate::evaluator::Evaluator;
+use crate::evaluators::StaticInfo;
+use crate::values::PrimitiveValue;
+use crate::work_area::WorkArea;
+use crate::Error;
+
+inventory::submit!(crate::evaluators::EvaluatorFactory {
+    name: "floor_div",
+    create: &crate::evaluators::macros::create_primitive_evaluator!(0, create, int)
+});
+
+/// Evaluator for the `floor_div` expression.
+///
+/// Returns the quotient of a division, rounded towards negative infinity.
+struct FloorDivEvaluator<T: ArrowNumericType>
+where
+    T::Native: ArrowNativeTypeOp + PartialOrd,
+{
+    lhs: PrimitiveValue<T>,
+    rhs: PrimitiveValue<T>,
+}
+
+impl<T> Evaluator for FloorDivEvaluator<T>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp + PartialOrd,
+{
+    fn evaluate(&self, info: &WorkArea<'_>) -> error_stack::Result<ArrayRef, Error> {
+        let lhs = info.expression(self.lhs);
+        let rhs = info.expression(self.rhs);
+        let result =
+            sparrow_kernels::math::floor_div(lhs, rhs).unwrap_or_else(|never| match never {});
+        Ok(Arc::new(result))
+    }
+}
+
+fn create<T>(info: StaticInfo<'_>) -> error_stack::Result<Box<dyn Evaluator>, Error>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp + PartialOrd,
+{
+    let (lhs, rhs) = info.unpack_arguments()?;
+    Ok(Box::new(FloorDivEvaluator::<T> {
+        lhs: lhs.primitive()?,
+        rhs: rhs.primitive()?,
+    }))
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, ArrowNativeTypeOp, ArrowNumericType};
+
+use crate::evaluator::Evaluator;
+use crate::evaluators::StaticInfo;
+use crate::values::PrimitiveValue;
+use crate::work_area::WorkArea;
+use crate::Error;
+
+inventory::submit!(crate::evaluators::EvaluatorFactory {
+    name: "rem",
+    create: &crate::evaluators::macros::create_primitive_evaluator!(0, create, number)
+});
+
+/// Evaluator for the `rem` expression.
+///
+/// Returns the remainder of a division, with the sign of the dividend.
+struct RemEvaluator<T: ArrowNumericType>
+where
+    T::Native: ArrowNativeTypeOp,
+{
+    lhs: PrimitiveValue<T>,
+    rhs: PrimitiveValue<T>,
+}
+
+impl<T> Evaluator for RemEvaluator<T>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    fn evaluate(&self, info: &WorkArea<'_>) -> error_stack::Result<ArrayRef, Error> {
+        let lhs = info.expression(self.lhs);
+        let rhs = info.expression(self.rhs);
+        let result = sparrow_kernels::math::rem(lhs, rhs).unwrap_or_else(|never| match never {});
+        Ok(Arc::new(result))
+    }
+}
+
+fn create<T>(info: StaticInfo<'_>) -> error_stack::Result<Box<dyn Evaluator>, Error>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    let (lhs, rhs) = info.unpack_arguments()?;
+    Ok(Box::new(RemEvaluator::<T> {
+        lhs: lhs.primitive()?,
+        rhs: rhs.primitive()?,
+    }))
+}
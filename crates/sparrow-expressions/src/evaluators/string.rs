@@ -1,4 +1,24 @@
+use error_stack::{IntoReport, ResultExt};
+use regex::Regex;
+use sparrow_arrow::scalar_value::ScalarValue;
+
+use crate::evaluators::as_literal_string;
+use crate::Error;
+
 mod len;
 mod lower;
+mod regex_extract;
+mod regex_match;
+mod regex_replace;
 mod substring;
 mod upper;
+
+/// Compile the regular expression from a literal pattern.
+fn compile_regex(pattern: &ScalarValue) -> error_stack::Result<Regex, Error> {
+    Regex::new(as_literal_string(pattern)?)
+        .into_report()
+        .change_context_lazy(|| Error::InvalidLiteral {
+            expected: "valid regular expression",
+            actual: pattern.clone(),
+        })
+}
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use arrow_array::ArrayRef;
+use regex::Regex;
+use sparrow_arrow::scalar_value::ScalarValue;
+
+use crate::evaluator::Evaluator;
+use crate::evaluators::StaticInfo;
+use crate::values::StringValue;
+use crate::work_area::WorkArea;
+use crate::Error;
+
+inventory::submit!(crate::evaluators::EvaluatorFactory {
+    name: "regex_extract",
+    create: &create
+});
+
+/// Evaluator for `regex_extract`.
+struct RegexExtractEvaluator {
+    input: StringValue,
+    regex: Regex,
+    group: usize,
+}
+
+impl Evaluator for RegexExtractEvaluator {
+    fn evaluate(&self, info: &WorkArea<'_>) -> error_stack::Result<ArrayRef, Error> {
+        let input = info.expression(self.input);
+        let result = sparrow_kernels::string::regex_extract(input, &self.regex, self.group)
+            .unwrap_or_else(|never| match never {});
+        Ok(Arc::new(result))
+    }
+}
+
+fn create(info: StaticInfo<'_>) -> error_stack::Result<Box<dyn Evaluator>, Error> {
+    let (pattern, group) = info.unpack_literals()?;
+    let regex = super::compile_regex(pattern)?;
+    let group = match group {
+        ScalarValue::Int64(Some(group)) if *group >= 0 => *group as usize,
+        other => error_stack::bail!(Error::InvalidLiteral {
+            expected: "non-negative group index",
+            actual: other.clone()
+        }),
+    };
+
+    let input = info.unpack_argument()?;
+    Ok(Box::new(RegexExtractEvaluator {
+        input: input.string()?,
+        regex,
+        group,
+    }))
+}
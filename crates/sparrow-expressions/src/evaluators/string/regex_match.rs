@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use arrow_array::ArrayRef;
+use regex::Regex;
+
+use crate::evaluator::Evaluator;
+use crate::evaluators::StaticInfo;
+use crate::values::StringValue;
+use crate::work_area::WorkArea;
+use crate::Error;
+
+inventory::submit!(crate::evaluators::EvaluatorFactory {
+    name: "regex_match",
+    create: &create
+});
+
+/// Evaluator for `regex_match`.
+struct RegexMatchEvaluator {
+    input: StringValue,
+    regex: Regex,
+}
+
+impl Evaluator for RegexMatchEvaluator {
+    fn evaluate(&self, info: &WorkArea<'_>) -> error_stack::Result<ArrayRef, Error> {
+        let input = info.expression(self.input);
+        let result = sparrow_kernels::string::regex_match(input, &self.regex)
+            .unwrap_or_else(|never| match never {});
+        Ok(Arc::new(result))
+    }
+}
+
+fn create(info: StaticInfo<'_>) -> error_stack::Result<Box<dyn Evaluator>, Error> {
+    let regex = super::compile_regex(info.literal()?)?;
+    let input = info.unpack_argument()?;
+    Ok(Box::new(RegexMatchEvaluator {
+        input: input.string()?,
+        regex,
+    }))
+}
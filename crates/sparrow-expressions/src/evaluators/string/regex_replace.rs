@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use arrow_array::ArrayRef;
+use regex::Regex;
+
+use crate::evaluator::Evaluator;
+use crate::evaluators::StaticInfo;
+use crate::values::StringValue;
+use crate::work_area::WorkArea;
+use crate::Error;
+
+inventory::submit!(crate::evaluators::EvaluatorFactory {
+    name: "regex_replace",
+    create: &create
+});
+
+/// Evaluator for `regex_replace`.
+struct RegexReplaceEvaluator {
+    input: StringValue,
+    regex: Regex,
+    replacement: StringValue,
+}
+
+impl Evaluator for RegexReplaceEvaluator {
+    fn evaluate(&self, info: &WorkArea<'_>) -> error_stack::Result<ArrayRef, Error> {
+        let input = info.expression(self.input);
+        let replacement = info.expression(self.replacement);
+        let result = sparrow_kernels::string::regex_replace(input, &self.regex, replacement)
+            .unwrap_or_else(|never| match never {});
+        Ok(Arc::new(result))
+    }
+}
+
+fn create(info: StaticInfo<'_>) -> error_stack::Result<Box<dyn Evaluator>, Error> {
+    let regex = super::compile_regex(info.literal()?)?;
+    let (input, replacement) = info.unpack_arguments()?;
+    Ok(Box::new(RegexReplaceEvaluator {
+        input: input.string()?,
+        regex,
+        replacement: replacement.string()?,
+    }))
+}
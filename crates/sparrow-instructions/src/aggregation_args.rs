@@ -1,11 +1,14 @@
-use anyhow::anyhow;
+use sparrow_arrow::scalar_value::ScalarValue;
 use sparrow_plan::ValueRef;
+use sparrow_syntax::WindowBehavior;
 
 use crate::StaticArg;
 
 /// Enum for working with the arguments to an aggregation in plans.
 ///
-/// Specifically, there are 3 arguments -- `input`, `ticks` and `duration`.
+/// Specifically, there are 4 arguments -- `input`, `ticks`, `duration` and
+/// `window_kind`. The window kind is the label of the window (such as
+/// `"sliding"`), or null for unwindowed aggregations.
 pub enum AggregationArgs<T> {
     /// Unwindowed aggregations have null ticks and duration.
     NoWindow { input: T },
@@ -15,6 +18,17 @@ pub enum AggregationArgs<T> {
     Since { input: T, ticks: T },
     /// Sliding windowed aggregations have both non-null ticks and duration.
     Sliding { input: T, ticks: T, duration: T },
+    /// Trailing windowed aggregations have null ticks and a non-null duration.
+    ///
+    /// The duration is the length of the window in nanoseconds. Inputs are
+    /// evicted once they are older than the duration.
+    Trailing { input: T, duration: T },
+    /// Session windowed aggregations have non-null events and gap.
+    ///
+    /// The events are `true` at each row extending the session, regardless of
+    /// whether the input is null. The gap is the inactivity gap in
+    /// nanoseconds. The session closes once the gap passes without an event.
+    Session { input: T, events: T, gap: T },
 }
 
 impl AggregationArgs<ValueRef> {
@@ -22,31 +36,53 @@ impl AggregationArgs<ValueRef> {
     /// should be applied to this aggregation based on the `input`.
     pub fn from_input(input: Vec<StaticArg>) -> anyhow::Result<Self> {
         // With the new operation-based plan, we flatten the arguments in the dfg.
-        // [input, tick, duration]
+        // [input, tick, duration, window_kind]
         anyhow::ensure!(
-            input.len() == 3,
-            "Aggregations should have 3 arguments. Saw {:?}",
+            input.len() == 4,
+            "Aggregations should have 4 arguments. Saw {:?}",
             input.len()
         );
 
-        match (input[1].is_literal_null(), input[2].is_literal_null()) {
-            (true, true) => Ok(AggregationArgs::NoWindow {
+        let window = match input[3].value_ref.literal_value() {
+            Some(ScalarValue::Null) | Some(ScalarValue::Utf8(None)) => None,
+            Some(ScalarValue::Utf8(Some(label))) => Some(
+                WindowBehavior::from_label(label)
+                    .ok_or_else(|| anyhow::anyhow!("Unknown window kind '{label}'"))?,
+            ),
+            other => anyhow::bail!("Expected literal string window kind, saw {other:?}"),
+        };
+
+        let has_ticks = !input[1].is_literal_null();
+        let has_duration = !input[2].is_literal_null();
+        match (window, has_ticks, has_duration) {
+            // A since window with null ticks never resets, so it is unwindowed.
+            (None | Some(WindowBehavior::Since), false, false) => Ok(AggregationArgs::NoWindow {
                 input: input[0].value_ref.clone(),
             }),
-            (false, true) => Ok(AggregationArgs::Since {
+            (Some(WindowBehavior::Since), true, false) => Ok(AggregationArgs::Since {
                 input: input[0].value_ref.clone(),
                 ticks: input[1].value_ref.clone(),
             }),
-            (false, false) => Ok(AggregationArgs::Sliding {
+            (Some(WindowBehavior::Sliding), true, true) => Ok(AggregationArgs::Sliding {
                 input: input[0].value_ref.clone(),
                 ticks: input[1].value_ref.clone(),
                 duration: input[2].value_ref.clone(),
             }),
-
-            invalid => Err(anyhow!(
-                "Saw invalid combination of windowed aggregation parameters: {:?}",
-                invalid
-            )),
+            (Some(WindowBehavior::Trailing), false, true) => Ok(AggregationArgs::Trailing {
+                input: input[0].value_ref.clone(),
+                duration: input[2].value_ref.clone(),
+            }),
+            (Some(WindowBehavior::Session), true, true) => Ok(AggregationArgs::Session {
+                input: input[0].value_ref.clone(),
+                events: input[1].value_ref.clone(),
+                gap: input[2].value_ref.clone(),
+            }),
+            (window, has_ticks, has_duration) => anyhow::bail!(
+                "Invalid arguments for {} window: ticks {}, duration {}",
+                window.map_or("unwindowed", |window| window.label()),
+                if has_ticks { "present" } else { "null" },
+                if has_duration { "present" } else { "null" },
+            ),
         }
     }
 }
@@ -61,6 +97,12 @@ impl<T> AggregationArgs<T> {
                 ticks,
                 duration,
             } => vec![Some(input), Some(ticks), Some(duration)],
+            AggregationArgs::Trailing { input, duration } => {
+                vec![Some(input), None, Some(duration)]
+            }
+            AggregationArgs::Session { input, events, gap } => {
+                vec![Some(input), Some(events), Some(gap)]
+            }
         }
     }
 }
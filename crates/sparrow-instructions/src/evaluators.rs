@@ -4,12 +4,13 @@ use itertools::Itertools;
 use sparrow_plan::{InstKind, InstOp};
 
 use crate::evaluators::macros::{
-    create_float_evaluator, create_number_evaluator, create_ordered_evaluator,
-    create_signed_evaluator, create_typed_evaluator,
+    create_float_evaluator, create_int_evaluator, create_number_evaluator,
+    create_ordered_evaluator, create_signed_evaluator, create_typed_evaluator,
 };
 use crate::{ColumnarValue, ComputeStore, GroupingIndices};
 
 pub mod aggregation;
+mod case;
 mod cast;
 mod comparison;
 mod equality;
@@ -26,6 +27,7 @@ mod string;
 mod time;
 
 pub use aggregation::*;
+use case::*;
 pub use cast::*;
 use comparison::*;
 use equality::*;
@@ -161,6 +163,7 @@ pub fn create_evaluator(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluato
             Ok(CastEvaluator::try_new(info)?)
         }
         InstKind::Record => Ok(RecordEvaluator::try_new(info)?),
+        InstKind::Case => Ok(CaseEvaluator::try_new(info)?),
     }
 }
 
@@ -185,10 +188,39 @@ fn create_simple_evaluator(
     mut info: StaticInfo<'_>,
 ) -> anyhow::Result<Box<dyn Evaluator>> {
     match op {
+        InstOp::Abs => {
+            create_number_evaluator!(&info.args[0].data_type, AbsEvaluator, info)
+        }
+        InstOp::Acos => {
+            create_float_evaluator!(&info.args[0].data_type, AcosEvaluator, info)
+        }
         InstOp::Add => {
             create_number_evaluator!(&info.args[0].data_type, AddEvaluator, info)
         }
         InstOp::AddTime => AddTimeEvaluator::try_new(info),
+        InstOp::ApproxCountDistinct => ApproxCountDistinctEvaluator::try_new(info),
+        InstOp::ApproxPercentile => ApproxPercentileEvaluator::try_new(info),
+        InstOp::Asin => {
+            create_float_evaluator!(&info.args[0].data_type, AsinEvaluator, info)
+        }
+        InstOp::Atan => {
+            create_float_evaluator!(&info.args[0].data_type, AtanEvaluator, info)
+        }
+        InstOp::Atan2 => {
+            create_float_evaluator!(&info.args[0].data_type, Atan2Evaluator, info)
+        }
+        InstOp::BitwiseAnd => {
+            create_int_evaluator!(&info.args[0].data_type, BitwiseAndEvaluator, info)
+        }
+        InstOp::BitwiseNot => {
+            create_int_evaluator!(&info.args[0].data_type, BitwiseNotEvaluator, info)
+        }
+        InstOp::BitwiseOr => {
+            create_int_evaluator!(&info.args[0].data_type, BitwiseOrEvaluator, info)
+        }
+        InstOp::BitwiseXor => {
+            create_int_evaluator!(&info.args[0].data_type, BitwiseXorEvaluator, info)
+        }
         InstOp::Ceil => CeilEvaluator::try_new(info),
         InstOp::Clamp => {
             create_number_evaluator!(&info.args[0].data_type, ClampEvaluator, info)
@@ -206,17 +238,30 @@ fn create_simple_evaluator(
                 info
             )
         }
+        InstOp::CollectDistinct => CollectValuesEvaluator::<CollectDistinct>::try_new(info),
+        InstOp::CollectMap => CollectMapEvaluator::try_new(info),
+        InstOp::Concat => ConcatEvaluator::try_new(info),
+        InstOp::Contains => ContainsEvaluator::try_new(info),
+        InstOp::Cos => {
+            create_float_evaluator!(&info.args[0].data_type, CosEvaluator, info)
+        }
         InstOp::CountIf => CountIfEvaluator::try_new(info),
         InstOp::DayOfMonth => DayOfMonthEvaluator::try_new(info),
         InstOp::DayOfMonth0 => DayOfMonth0Evaluator::try_new(info),
+        InstOp::DayOfWeek => DayOfWeekEvaluator::try_new(info),
+        InstOp::DayOfWeek0 => DayOfWeek0Evaluator::try_new(info),
         InstOp::DayOfYear => DayOfYearEvaluator::try_new(info),
         InstOp::DayOfYear0 => DayOfYear0Evaluator::try_new(info),
         InstOp::Days => DaysEvaluator::try_new(info),
         InstOp::DaysBetween => DaysBetweenEvaluator::try_new(info),
+        InstOp::DecayedCount => DecayedEvaluator::<DecayedCount>::try_new(info),
+        InstOp::DecayedSum => DecayedEvaluator::<DecayedSum>::try_new(info),
         InstOp::Div => {
             create_number_evaluator!(&info.args[0].data_type, DivEvaluator, info)
         }
+        InstOp::EndsWith => EndsWithEvaluator::try_new(info),
         InstOp::Eq => EqEvaluatorFactory::try_new(info),
+        InstOp::Ewma => DecayedEvaluator::<Ewma>::try_new(info),
         InstOp::Exp => {
             create_float_evaluator!(&info.args[0].data_type, ExpEvaluator, info)
         }
@@ -235,6 +280,10 @@ fn create_simple_evaluator(
         }
         InstOp::Flatten => FlattenEvaluator::try_new(info),
         InstOp::Floor => FloorEvaluator::try_new(info),
+        InstOp::FloorDiv => {
+            create_int_evaluator!(&info.args[0].data_type, FloorDivEvaluator, info)
+        }
+        InstOp::Format => FormatEvaluator::try_new(info),
         InstOp::Get => GetEvaluator::try_new(info),
         InstOp::Index => IndexEvaluator::try_new(info),
         InstOp::Gt => match (info.args[0].is_literal(), info.args[1].is_literal()) {
@@ -286,10 +335,29 @@ fn create_simple_evaluator(
             )
         }
         InstOp::Len => LenEvaluator::try_new(info),
+        InstOp::ListBroadcast => ListBroadcastEvaluator::try_new(info),
+        InstOp::ListContains => ListContainsEvaluator::try_new(info),
+        InstOp::ListDistinct => ListDistinctEvaluator::try_new(info),
+        InstOp::ListElements => ListElementsEvaluator::try_new(info),
+        InstOp::ListFilter => ListFilterEvaluator::try_new(info),
         InstOp::ListLen => ListLenEvaluator::try_new(info),
+        InstOp::ListMap => ListMapEvaluator::try_new(info),
+        InstOp::ListMean => ListMeanEvaluator::try_new(info),
+        InstOp::ListSlice => ListSliceEvaluator::try_new(info),
+        InstOp::ListSort => ListSortEvaluator::try_new(info),
+        InstOp::ListSum => {
+            create_number_evaluator!(info.result_type, ListSumEvaluator, info)
+        }
+        InstOp::Ln => {
+            create_float_evaluator!(&info.args[0].data_type, LnEvaluator, info)
+        }
+        InstOp::Log10 => {
+            create_float_evaluator!(&info.args[0].data_type, Log10Evaluator, info)
+        }
         InstOp::LogicalAnd => LogicalAndKleeneEvaluator::try_new(info),
         InstOp::LogicalOr => LogicalOrKleeneEvaluator::try_new(info),
         InstOp::Lower => LowerEvaluator::try_new(info),
+        InstOp::Lpad => LpadEvaluator::try_new(info),
         InstOp::Lt => match (info.args[0].is_literal(), info.args[1].is_literal()) {
             (_, true) => {
                 create_ordered_evaluator!(&info.args[0].data_type, LtScalarEvaluator, info)
@@ -316,19 +384,30 @@ fn create_simple_evaluator(
                 create_ordered_evaluator!(&info.args[0].data_type, LteEvaluator, info)
             }
         },
+        InstOp::MapContains => MapContainsEvaluator::try_new(info),
+        InstOp::MapFromLists => MapFromListsEvaluator::try_new(info),
+        InstOp::MapKeys => MapKeysEvaluator::try_new(info),
+        InstOp::MapLen => MapLenEvaluator::try_new(info),
+        InstOp::MapValues => MapValuesEvaluator::try_new(info),
         InstOp::Max => {
             create_ordered_evaluator!(&info.args[0].data_type, ArrowAggEvaluator, Max, info)
         }
+        InstOp::MaxBy => SelectByEvaluator::<MaxBy>::try_new(info),
         InstOp::Mean => {
             create_number_evaluator!(&info.args[0].data_type, ArrowAggEvaluator, Mean, info)
         }
         InstOp::Min => {
             create_ordered_evaluator!(&info.args[0].data_type, ArrowAggEvaluator, Min, info)
         }
+        InstOp::MinBy => SelectByEvaluator::<MinBy>::try_new(info),
+        InstOp::Mod => {
+            create_int_evaluator!(&info.args[0].data_type, ModEvaluator, info)
+        }
         InstOp::MonthOfYear => MonthOfYearEvaluator::try_new(info),
         InstOp::MonthOfYear0 => MonthOfYear0Evaluator::try_new(info),
         InstOp::Months => MonthsEvaluator::try_new(info),
         InstOp::MonthsBetween => MonthsBetweenEvaluator::try_new(info),
+        InstOp::MostFrequent => CollectValuesEvaluator::<MostFrequent>::try_new(info),
         InstOp::Mul => {
             create_number_evaluator!(&info.args[0].data_type, MulEvaluator, info)
         }
@@ -341,9 +420,31 @@ fn create_simple_evaluator(
         InstOp::Powf => {
             create_float_evaluator!(&info.args[0].data_type, PowfEvaluator, info)
         }
+        InstOp::RegexExtract => RegexExtractEvaluator::try_new(info),
+        InstOp::RegexMatch => RegexMatchEvaluator::try_new(info),
+        InstOp::RegexReplace => RegexReplaceEvaluator::try_new(info),
+        InstOp::Rem => {
+            create_number_evaluator!(&info.args[0].data_type, RemEvaluator, info)
+        }
+        InstOp::Replace => ReplaceEvaluator::try_new(info),
         InstOp::Round => RoundEvaluator::try_new(info),
+        InstOp::Rpad => RpadEvaluator::try_new(info),
         InstOp::Seconds => SecondsEvaluator::try_new(info),
         InstOp::SecondsBetween => SecondsBetweenEvaluator::try_new(info),
+        InstOp::ShiftLeft => {
+            create_int_evaluator!(&info.args[0].data_type, ShiftLeftEvaluator, info)
+        }
+        InstOp::ShiftRight => {
+            create_int_evaluator!(&info.args[0].data_type, ShiftRightEvaluator, info)
+        }
+        InstOp::Sign => {
+            create_number_evaluator!(&info.args[0].data_type, SignEvaluator, info)
+        }
+        InstOp::Sin => {
+            create_float_evaluator!(&info.args[0].data_type, SinEvaluator, info)
+        }
+        InstOp::Split => SplitEvaluator::try_new(info),
+        InstOp::StartsWith => StartsWithEvaluator::try_new(info),
         InstOp::Sub => {
             create_number_evaluator!(&info.args[0].data_type, SubEvaluator, info)
         }
@@ -351,7 +452,12 @@ fn create_simple_evaluator(
         InstOp::Sum => {
             create_number_evaluator!(&info.args[0].data_type, ArrowAggEvaluator, Sum, info)
         }
+        InstOp::Tan => {
+            create_float_evaluator!(&info.args[0].data_type, TanEvaluator, info)
+        }
         InstOp::TimeOf => TimeOfEvaluator::try_new(info),
+        InstOp::TopK => CollectValuesEvaluator::<TopK>::try_new(info),
+        InstOp::Trim => TrimEvaluator::try_new(info),
         InstOp::Upper => UpperEvaluator::try_new(info),
         InstOp::Union => UnionEvaluator::try_new(info),
         InstOp::Variance => {
@@ -1,21 +1,25 @@
 mod boolean;
 mod function;
 mod generic;
+mod hyperloglog;
 mod list;
 mod map;
 mod numeric_properties;
 mod primitive;
 mod string;
+mod tdigest;
 mod token;
 mod two_stacks;
 
 pub use boolean::*;
 pub use function::*;
 pub use generic::*;
+pub use hyperloglog::*;
 pub use list::*;
 pub use map::*;
 pub use numeric_properties::*;
 pub use primitive::*;
 pub use string::*;
+pub use tdigest::*;
 pub use token::*;
 pub use two_stacks::*;
@@ -7,8 +7,8 @@ use sparrow_plan::ValueRef;
 
 use super::two_stacks_first_boolean_evaluator::TwoStacksFirstBooleanEvaluator;
 use crate::{
-    AggregationArgs, BooleanAccumToken, Evaluator, EvaluatorFactory, RuntimeInfo, StateToken,
-    StaticInfo, TwoStacksBooleanAccumToken,
+    AggregationArgs, BooleanAccumToken, Evaluator, EvaluatorFactory, FirstBoolean, RuntimeInfo,
+    SessionAggEvaluator, StateToken, StaticInfo, TrailingAggEvaluator, TwoStacksBooleanAccumToken,
 };
 
 /// Evaluator for the `First` instruction on booleans.
@@ -44,8 +44,13 @@ impl Evaluator for FirstBooleanEvaluator {
                 );
                 result
             }
-            AggregationArgs::Sliding { .. } => {
-                unreachable!("Expected Non-windowed or Since windowed aggregation, saw Sliding.")
+            AggregationArgs::Sliding { .. }
+            | AggregationArgs::Trailing { .. }
+            | AggregationArgs::Session { .. } => {
+                unreachable!(
+                    "Expected Non-windowed or Since windowed aggregation, saw Sliding, Trailing \
+                     or Session."
+                )
             }
         }
     }
@@ -71,6 +76,15 @@ impl EvaluatorFactory for FirstBooleanEvaluator {
                 let token = TwoStacksBooleanAccumToken::new();
                 Ok(Box::new(TwoStacksFirstBooleanEvaluator { token, args }))
             }
+            AggregationArgs::Trailing { input, duration } => {
+                Ok(Box::new(TrailingAggEvaluator::<FirstBoolean>::new(
+                    input, duration,
+                )))
+            }
+            AggregationArgs::Session { input, events, gap } => {
+                let evaluator = SessionAggEvaluator::<FirstBoolean>::new(input, events, gap);
+                Ok(Box::new(evaluator))
+            }
         }
     }
 }
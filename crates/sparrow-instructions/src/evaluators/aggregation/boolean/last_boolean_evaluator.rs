@@ -7,8 +7,8 @@ use sparrow_plan::ValueRef;
 
 use super::two_stacks_last_boolean_evaluator::TwoStacksLastBooleanEvaluator;
 use crate::{
-    AggregationArgs, BooleanAccumToken, Evaluator, EvaluatorFactory, RuntimeInfo, StateToken,
-    StaticInfo, TwoStacksBooleanAccumToken,
+    AggregationArgs, BooleanAccumToken, Evaluator, EvaluatorFactory, LastBoolean, RuntimeInfo,
+    SessionAggEvaluator, StateToken, StaticInfo, TrailingAggEvaluator, TwoStacksBooleanAccumToken,
 };
 
 /// Evaluator for the `last` instruction on booleans.
@@ -44,8 +44,13 @@ impl Evaluator for LastBooleanEvaluator {
                 );
                 result
             }
-            AggregationArgs::Sliding { .. } => {
-                unreachable!("Expected Non-windowed or Since windowed aggregation, saw Sliding.")
+            AggregationArgs::Sliding { .. }
+            | AggregationArgs::Trailing { .. }
+            | AggregationArgs::Session { .. } => {
+                unreachable!(
+                    "Expected Non-windowed or Since windowed aggregation, saw Sliding, Trailing \
+                     or Session."
+                )
             }
         }
     }
@@ -71,6 +76,15 @@ impl EvaluatorFactory for LastBooleanEvaluator {
                 let token = TwoStacksBooleanAccumToken::new();
                 Ok(Box::new(TwoStacksLastBooleanEvaluator { token, args }))
             }
+            AggregationArgs::Trailing { input, duration } => {
+                Ok(Box::new(TrailingAggEvaluator::<LastBoolean>::new(
+                    input, duration,
+                )))
+            }
+            AggregationArgs::Session { input, events, gap } => {
+                let evaluator = SessionAggEvaluator::<LastBoolean>::new(input, events, gap);
+                Ok(Box::new(evaluator))
+            }
         }
     }
 }
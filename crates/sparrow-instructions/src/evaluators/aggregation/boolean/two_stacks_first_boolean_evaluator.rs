@@ -56,9 +56,13 @@ impl Evaluator for TwoStacksFirstBooleanEvaluator {
 
                 result
             }
-            AggregationArgs::Since { .. } | AggregationArgs::NoWindow { .. } => {
+            AggregationArgs::Since { .. }
+            | AggregationArgs::NoWindow { .. }
+            | AggregationArgs::Trailing { .. }
+            | AggregationArgs::Session { .. } => {
                 unreachable!(
-                    "Expected sliding-windowed aggregation, saw non-windowed or since windowed."
+                    "Expected sliding-windowed aggregation, saw non-windowed, since, trailing or \
+                     session windowed."
                 )
             }
         }
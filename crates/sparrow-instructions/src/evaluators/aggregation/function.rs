@@ -1,13 +1,23 @@
 //! Aggregation functions.
 
 pub mod agg_fn;
+pub mod approx_distinct_agg_fn;
+pub mod array_agg_fn;
 pub mod boolean_agg_fn;
+pub mod collect_values_agg_fn;
 pub mod count_agg_fn;
+pub mod decayed_agg_fn;
+pub mod ordered_value_agg_fn;
 pub mod primitive_agg_fn;
 pub mod string_agg_fn;
 
 pub use agg_fn::*;
+pub use approx_distinct_agg_fn::*;
+pub use array_agg_fn::*;
 pub use boolean_agg_fn::*;
+pub use collect_values_agg_fn::*;
 pub use count_agg_fn::*;
+pub use decayed_agg_fn::*;
+pub use ordered_value_agg_fn::*;
 pub use primitive_agg_fn::*;
 pub use string_agg_fn::*;
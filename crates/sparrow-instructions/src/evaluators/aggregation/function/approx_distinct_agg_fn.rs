@@ -0,0 +1,33 @@
+use super::agg_fn::AggFn;
+use crate::HyperLogLog;
+
+/// Placeholder struct for the implementation of the [[AggFn]] for
+/// `approx_count_distinct`.
+///
+/// Inputs are the hashes of the values being counted.
+pub struct ApproxCountDistinct;
+impl AggFn for ApproxCountDistinct {
+    type InT = u64;
+    type AccT = HyperLogLog;
+    type OutT = u32;
+
+    fn zero() -> Self::AccT {
+        HyperLogLog::default()
+    }
+
+    fn merge(acc1: &mut Self::AccT, acc2: &Self::AccT) {
+        acc1.merge(acc2)
+    }
+
+    fn extract(acc: &Self::AccT) -> Option<Self::OutT> {
+        Some(u32::try_from(acc.estimate()).unwrap_or(u32::MAX))
+    }
+
+    fn add_one(acc: &mut Self::AccT, input: &Self::InT) {
+        acc.add_hash(*input)
+    }
+
+    fn name() -> &'static str {
+        "approx_count_distinct"
+    }
+}
@@ -0,0 +1,75 @@
+use arrow::array::ArrayRef;
+use serde::{Deserialize, Serialize};
+
+use super::agg_fn::AggFn;
+
+/// A single non-primitive value, such as a list or map, stored as an array of
+/// length 1.
+///
+/// Wrapped so that accumulators containing the value may be serialized.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArrayValue(#[serde(with = "sparrow_arrow::serde::array_ref")] pub ArrayRef);
+
+/// Placeholder struct for the implementation of the [[AggFn]] for `First` on
+/// lists and maps.
+pub struct FirstArray {}
+impl AggFn for FirstArray {
+    type InT = ArrayValue;
+    type AccT = Option<ArrayValue>;
+    type OutT = ArrayValue;
+
+    fn zero() -> Self::AccT {
+        None
+    }
+
+    fn merge(acc1: &mut Self::AccT, acc2: &Self::AccT) {
+        if acc1.is_none() {
+            *acc1 = acc2.to_owned()
+        }
+    }
+
+    fn extract(acc: &Self::AccT) -> Option<Self::OutT> {
+        acc.to_owned()
+    }
+
+    fn add_one(acc: &mut Self::AccT, input: &Self::InT) {
+        if acc.is_none() {
+            *acc = Some(input.to_owned())
+        }
+    }
+
+    fn name() -> &'static str {
+        "first_array"
+    }
+}
+
+/// Placeholder struct for the implementation of the [[AggFn]] for `Last` on
+/// lists and maps.
+pub struct LastArray {}
+impl AggFn for LastArray {
+    type InT = ArrayValue;
+    type AccT = Option<ArrayValue>;
+    type OutT = ArrayValue;
+
+    fn zero() -> Self::AccT {
+        None
+    }
+
+    fn merge(acc1: &mut Self::AccT, acc2: &Self::AccT) {
+        if acc2.is_some() {
+            *acc1 = acc2.to_owned()
+        }
+    }
+
+    fn extract(acc: &Self::AccT) -> Option<Self::OutT> {
+        acc.to_owned()
+    }
+
+    fn add_one(acc: &mut Self::AccT, input: &Self::InT) {
+        *acc = Some(input.to_owned())
+    }
+
+    fn name() -> &'static str {
+        "last_array"
+    }
+}
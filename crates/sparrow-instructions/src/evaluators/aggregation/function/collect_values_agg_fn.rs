@@ -0,0 +1,136 @@
+use std::cmp::Reverse;
+
+use arrow::row::Rows;
+
+/// A value collected by a [CollectValuesAggFn].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollectedValue {
+    /// The index of the value within the values being aggregated.
+    pub index: u32,
+    /// The number of times the value has been seen.
+    ///
+    /// This is only incremented by `most_frequent`.
+    pub count: u64,
+}
+
+/// Aggregation functions collecting a bounded list of values for each entity.
+///
+/// Values are compared using the row format, so values of any type may be
+/// collected.
+pub trait CollectValuesAggFn {
+    /// Add the value at `index` to the values collected for an entity.
+    ///
+    /// The `limit` is the (non-zero) number of values to produce.
+    fn add_one(values: &mut Vec<CollectedValue>, index: u32, rows: &Rows, limit: usize);
+
+    /// Return the indices of the values to produce, in order.
+    fn extract(values: &[CollectedValue], limit: usize) -> Vec<u32>;
+
+    fn name() -> &'static str;
+}
+
+/// Return the position of the collected value equal to the value at `index`.
+fn position(values: &[CollectedValue], index: u32, rows: &Rows) -> Option<usize> {
+    let row = rows.row(index as usize);
+    values
+        .iter()
+        .position(|value| rows.row(value.index as usize) == row)
+}
+
+/// Placeholder struct for the implementation of the [[CollectValuesAggFn]]
+/// for `top_k`.
+///
+/// The values are kept in descending order, including any duplicates.
+pub struct TopK {}
+impl CollectValuesAggFn for TopK {
+    fn add_one(values: &mut Vec<CollectedValue>, index: u32, rows: &Rows, limit: usize) {
+        let row = rows.row(index as usize);
+        let position = values.partition_point(|value| rows.row(value.index as usize) >= row);
+        if position < limit {
+            values.insert(position, CollectedValue { index, count: 1 });
+            values.truncate(limit);
+        }
+    }
+
+    fn extract(values: &[CollectedValue], _limit: usize) -> Vec<u32> {
+        values.iter().map(|value| value.index).collect()
+    }
+
+    fn name() -> &'static str {
+        "top_k"
+    }
+}
+
+/// Placeholder struct for the implementation of the [[CollectValuesAggFn]]
+/// for `collect_distinct`.
+///
+/// The values are kept in the order they were most recently seen, oldest
+/// first. Once the limit is reached, the least recently seen value is
+/// removed.
+pub struct CollectDistinct {}
+impl CollectValuesAggFn for CollectDistinct {
+    fn add_one(values: &mut Vec<CollectedValue>, index: u32, rows: &Rows, limit: usize) {
+        if let Some(position) = position(values, index, rows) {
+            values.remove(position);
+        } else if values.len() == limit {
+            values.remove(0);
+        }
+        values.push(CollectedValue { index, count: 1 });
+    }
+
+    fn extract(values: &[CollectedValue], _limit: usize) -> Vec<u32> {
+        values.iter().map(|value| value.index).collect()
+    }
+
+    fn name() -> &'static str {
+        "collect_distinct"
+    }
+}
+
+/// The number of values counted by `most_frequent`, relative to `k`.
+const MOST_FREQUENT_CAPACITY_FACTOR: usize = 10;
+
+/// Placeholder struct for the implementation of the [[CollectValuesAggFn]]
+/// for `most_frequent`.
+///
+/// Values are counted using the Space-Saving algorithm, which keeps counts
+/// for a bounded number of values. Once the capacity is reached, the value
+/// with the lowest count is replaced by the new value, which inherits that
+/// count. Counts are exact until more distinct values than the capacity are
+/// seen, and the most frequent values are reliably retained.
+pub struct MostFrequent {}
+impl CollectValuesAggFn for MostFrequent {
+    fn add_one(values: &mut Vec<CollectedValue>, index: u32, rows: &Rows, limit: usize) {
+        if let Some(position) = position(values, index, rows) {
+            values[position].count += 1;
+            return;
+        }
+
+        let capacity = limit.saturating_mul(MOST_FREQUENT_CAPACITY_FACTOR);
+        let count = if values.len() < capacity {
+            1
+        } else {
+            let (position, min) = values
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, value)| value.count)
+                .expect("non-zero capacity");
+            let count = min.count + 1;
+            values.remove(position);
+            count
+        };
+        values.push(CollectedValue { index, count });
+    }
+
+    /// Return the `limit` values with the highest counts, in descending
+    /// order of count. Ties are broken by which value was counted first.
+    fn extract(values: &[CollectedValue], limit: usize) -> Vec<u32> {
+        let mut values = values.to_vec();
+        values.sort_by_key(|value| Reverse(value.count));
+        values.iter().take(limit).map(|value| value.index).collect()
+    }
+
+    fn name() -> &'static str {
+        "most_frequent"
+    }
+}
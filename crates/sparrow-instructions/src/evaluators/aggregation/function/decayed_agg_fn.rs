@@ -0,0 +1,142 @@
+use arrow::array::{Array, ArrayRef, AsArray, Float64Array};
+use arrow::datatypes::{DataType, Float64Type};
+use serde::{Deserialize, Serialize};
+
+/// The state of an exponentially time-decayed aggregation.
+///
+/// The state is decayed lazily -- the `value` and `weight` are as of the
+/// `time` of the most recent input, and are decayed to the time of each row
+/// when the result is produced.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DecayedAccum {
+    /// The aggregated value, as of `time`.
+    pub value: f64,
+    /// The decayed number of inputs, as of `time`.
+    pub weight: f64,
+    /// The time of the most recent input, in nanoseconds.
+    pub time: i64,
+}
+
+impl DecayedAccum {
+    /// Return the factor by which a contribution at `from` has decayed at `to`.
+    pub fn decay(from: i64, to: i64, half_life: i64) -> f64 {
+        0.5f64.powf((to - from) as f64 / half_life as f64)
+    }
+}
+
+/// Aggregation functions whose inputs decay exponentially with elapsed time.
+///
+/// A contribution made one `half_life` ago counts for half as much as one
+/// made now.
+pub trait DecayedAggFn {
+    /// Return the values to aggregate for the given input.
+    ///
+    /// Rows with a `null` value do not contribute to the aggregation.
+    fn input_values(input: &ArrayRef) -> anyhow::Result<Float64Array> {
+        let input = arrow::compute::cast(input, &DataType::Float64)?;
+        Ok(input.as_primitive::<Float64Type>().clone())
+    }
+
+    /// Return the state after adding the `input` at `time`.
+    fn add_one(acc: Option<DecayedAccum>, input: f64, time: i64, half_life: i64) -> DecayedAccum;
+
+    /// Return the result at `time` for the given state.
+    fn extract(acc: Option<&DecayedAccum>, time: i64, half_life: i64) -> Option<f64>;
+
+    fn name() -> &'static str;
+}
+
+/// Decay the existing state to `time` and add a contribution of `input`.
+///
+/// The `value` is updated using `add_value`, which is passed the decayed
+/// value, the decayed weight and the input.
+fn add_decayed(
+    acc: Option<DecayedAccum>,
+    input: f64,
+    time: i64,
+    half_life: i64,
+    add_value: impl FnOnce(f64, f64, f64) -> f64,
+) -> DecayedAccum {
+    let (value, weight) = match acc {
+        Some(acc) => {
+            let decay = DecayedAccum::decay(acc.time, time, half_life);
+            (acc.value * decay, acc.weight * decay)
+        }
+        None => (0.0, 0.0),
+    };
+    DecayedAccum {
+        value: add_value(value, weight, input),
+        weight: weight + 1.0,
+        time,
+    }
+}
+
+/// Placeholder struct for the implementation of the [[DecayedAggFn]] for
+/// `ewma`.
+///
+/// The value is the weighted mean of the inputs. Since the mean is unchanged
+/// by decaying every weight equally, it is only decayed when adding inputs.
+pub struct Ewma {}
+impl DecayedAggFn for Ewma {
+    fn add_one(acc: Option<DecayedAccum>, input: f64, time: i64, half_life: i64) -> DecayedAccum {
+        let mean = acc.map_or(0.0, |acc| acc.value);
+        add_decayed(acc, input, time, half_life, |_, weight, input| {
+            mean + (input - mean) / (weight + 1.0)
+        })
+    }
+
+    fn extract(acc: Option<&DecayedAccum>, _time: i64, _half_life: i64) -> Option<f64> {
+        acc.map(|acc| acc.value)
+    }
+
+    fn name() -> &'static str {
+        "ewma"
+    }
+}
+
+/// Placeholder struct for the implementation of the [[DecayedAggFn]] for
+/// `decayed_sum`.
+pub struct DecayedSum {}
+impl DecayedAggFn for DecayedSum {
+    fn add_one(acc: Option<DecayedAccum>, input: f64, time: i64, half_life: i64) -> DecayedAccum {
+        add_decayed(acc, input, time, half_life, |sum, _, input| sum + input)
+    }
+
+    fn extract(acc: Option<&DecayedAccum>, time: i64, half_life: i64) -> Option<f64> {
+        acc.map(|acc| acc.value * DecayedAccum::decay(acc.time, time, half_life))
+    }
+
+    fn name() -> &'static str {
+        "decayed_sum"
+    }
+}
+
+/// Placeholder struct for the implementation of the [[DecayedAggFn]] for
+/// `decayed_count`.
+///
+/// The result is `0` if there have been no inputs.
+pub struct DecayedCount {}
+impl DecayedAggFn for DecayedCount {
+    fn input_values(input: &ArrayRef) -> anyhow::Result<Float64Array> {
+        // Only the presence of each input is counted, so inputs of any type
+        // are supported.
+        Ok(Float64Array::new(
+            vec![0.0; input.len()].into(),
+            input.nulls().cloned(),
+        ))
+    }
+
+    fn add_one(acc: Option<DecayedAccum>, input: f64, time: i64, half_life: i64) -> DecayedAccum {
+        add_decayed(acc, input, time, half_life, |_, _, _| 0.0)
+    }
+
+    fn extract(acc: Option<&DecayedAccum>, time: i64, half_life: i64) -> Option<f64> {
+        Some(acc.map_or(0.0, |acc| {
+            acc.weight * DecayedAccum::decay(acc.time, time, half_life)
+        }))
+    }
+
+    fn name() -> &'static str {
+        "decayed_count"
+    }
+}
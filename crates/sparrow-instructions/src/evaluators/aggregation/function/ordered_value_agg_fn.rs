@@ -0,0 +1,127 @@
+use std::cmp::Ordering;
+
+use serde::{Deserialize, Serialize};
+
+use super::agg_fn::AggFn;
+use super::array_agg_fn::ArrayValue;
+
+/// A value along with the ordering it was observed with.
+///
+/// The ordering is stored in the row format, so orderings of any type may be
+/// compared as bytes. Ties are broken by the time of the row, so the most
+/// recent value is preferred.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderedValue {
+    pub ordering: Vec<u8>,
+    pub time: i64,
+    pub value: ArrayValue,
+}
+
+/// Aggregation functions selecting the value with the greatest or least
+/// ordering.
+pub trait SelectByAggFn:
+    AggFn<InT = OrderedValue, AccT = Option<OrderedValue>, OutT = ArrayValue>
+{
+    /// The result of comparing a candidate ordering to the current ordering
+    /// for which the candidate is selected.
+    const SELECTS: Ordering;
+
+    /// Return true if the `candidate` ordering should replace the `current`
+    /// ordering.
+    ///
+    /// Equal orderings are replaced only if the candidate is more recent, so
+    /// ties select the most recent row. This is the only tie-breaking rule,
+    /// shared by the windowed and sliding evaluators.
+    fn replaces(candidate: &[u8], current: &[u8], candidate_is_recent: bool) -> bool {
+        match candidate.cmp(current) {
+            Ordering::Equal => candidate_is_recent,
+            ordering => ordering == Self::SELECTS,
+        }
+    }
+}
+
+/// Replace the `acc` with the `candidate` if it is selected.
+///
+/// Accumulators may be merged in either order, so the times are compared to
+/// determine which is more recent.
+fn select<AggF: SelectByAggFn>(acc: &mut Option<OrderedValue>, candidate: &OrderedValue) {
+    let replace = match acc {
+        Some(current) => AggF::replaces(
+            &candidate.ordering,
+            &current.ordering,
+            candidate.time >= current.time,
+        ),
+        None => true,
+    };
+    if replace {
+        *acc = Some(candidate.clone())
+    }
+}
+
+/// Placeholder struct for the implementation of the [[AggFn]] for `MaxBy`.
+pub struct MaxBy {}
+impl AggFn for MaxBy {
+    type InT = OrderedValue;
+    type AccT = Option<OrderedValue>;
+    type OutT = ArrayValue;
+
+    fn zero() -> Self::AccT {
+        None
+    }
+
+    fn merge(acc1: &mut Self::AccT, acc2: &Self::AccT) {
+        if let Some(acc2) = acc2 {
+            select::<Self>(acc1, acc2)
+        }
+    }
+
+    fn extract(acc: &Self::AccT) -> Option<Self::OutT> {
+        acc.as_ref().map(|acc| acc.value.clone())
+    }
+
+    fn add_one(acc: &mut Self::AccT, input: &Self::InT) {
+        select::<Self>(acc, input)
+    }
+
+    fn name() -> &'static str {
+        "max_by"
+    }
+}
+
+impl SelectByAggFn for MaxBy {
+    const SELECTS: Ordering = Ordering::Greater;
+}
+
+/// Placeholder struct for the implementation of the [[AggFn]] for `MinBy`.
+pub struct MinBy {}
+impl AggFn for MinBy {
+    type InT = OrderedValue;
+    type AccT = Option<OrderedValue>;
+    type OutT = ArrayValue;
+
+    fn zero() -> Self::AccT {
+        None
+    }
+
+    fn merge(acc1: &mut Self::AccT, acc2: &Self::AccT) {
+        if let Some(acc2) = acc2 {
+            select::<Self>(acc1, acc2)
+        }
+    }
+
+    fn extract(acc: &Self::AccT) -> Option<Self::OutT> {
+        acc.as_ref().map(|acc| acc.value.clone())
+    }
+
+    fn add_one(acc: &mut Self::AccT, input: &Self::InT) {
+        select::<Self>(acc, input)
+    }
+
+    fn name() -> &'static str {
+        "min_by"
+    }
+}
+
+impl SelectByAggFn for MinBy {
+    const SELECTS: Ordering = Ordering::Less;
+}
@@ -1,6 +1,26 @@
 //! Generic aggregation evaluators.
 
+mod approx_count_distinct_evaluator;
+mod approx_percentile_evaluator;
+mod collect_values_evaluator;
 mod count_evaluator;
+mod decayed_evaluator;
+mod select_by_evaluator;
+mod session_evaluator;
+mod trailing_evaluator;
+mod two_stacks_array_evaluator;
 mod two_stacks_count_evaluator;
+mod two_stacks_select_by_evaluator;
+mod window_agg_fn;
 
+pub use approx_count_distinct_evaluator::*;
+pub use approx_percentile_evaluator::*;
+pub use collect_values_evaluator::*;
 pub use count_evaluator::*;
+pub use decayed_evaluator::*;
+pub use select_by_evaluator::*;
+pub(crate) use session_evaluator::*;
+pub(crate) use trailing_evaluator::*;
+pub(crate) use two_stacks_array_evaluator::*;
+pub(crate) use two_stacks_select_by_evaluator::*;
+pub(crate) use window_agg_fn::*;
@@ -0,0 +1,219 @@
+use arrow::array::{ArrayRef, BooleanArray, UInt32Array};
+use itertools::izip;
+use sparrow_plan::ValueRef;
+
+use crate::{
+    AggFn, AggregationArgs, ApproxCountDistinct, Evaluator, EvaluatorFactory,
+    HyperLogLogAccumToken, RuntimeInfo, StateToken, StaticInfo, WindowAggFn,
+};
+
+/// Evaluator for the `approx_count_distinct` instruction.
+///
+/// Each entity has a HyperLogLog sketch containing the hashes of the
+/// non-null inputs.
+pub struct ApproxCountDistinctEvaluator {
+    args: AggregationArgs<ValueRef>,
+    token: HyperLogLogAccumToken,
+}
+
+impl Evaluator for ApproxCountDistinctEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        match &self.args {
+            AggregationArgs::NoWindow { input } => {
+                let grouping = info.grouping();
+                let input_vals = info.value(input)?.array_ref()?;
+                Self::aggregate(
+                    &mut self.token,
+                    grouping.num_groups(),
+                    grouping.group_indices(),
+                    &input_vals,
+                )
+            }
+            AggregationArgs::Since { ticks, input } => {
+                let grouping = info.grouping();
+                let input_vals = info.value(input)?.array_ref()?;
+                let ticks = info.value(ticks)?.boolean_array()?;
+                Self::aggregate_since(
+                    &mut self.token,
+                    grouping.num_groups(),
+                    grouping.group_indices(),
+                    &input_vals,
+                    ticks.as_ref(),
+                )
+            }
+            AggregationArgs::Sliding { .. }
+            | AggregationArgs::Trailing { .. }
+            | AggregationArgs::Session { .. } => {
+                unreachable!(
+                    "Expected Non-windowed or Since windowed aggregation, saw Sliding, Trailing \
+                     or Session."
+                )
+            }
+        }
+    }
+
+    fn state_token(&self) -> Option<&dyn StateToken> {
+        Some(&self.token)
+    }
+
+    fn state_token_mut(&mut self) -> Option<&mut dyn StateToken> {
+        Some(&mut self.token)
+    }
+}
+
+impl EvaluatorFactory for ApproxCountDistinctEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let args = AggregationArgs::from_input(info.args)?;
+        match args {
+            AggregationArgs::NoWindow { .. } | AggregationArgs::Since { .. } => {
+                let token = HyperLogLogAccumToken::default();
+                Ok(Box::new(Self { token, args }))
+            }
+            AggregationArgs::Sliding { .. } => {
+                anyhow::bail!("Sliding windows are not supported for 'approx_count_distinct'")
+            }
+            AggregationArgs::Trailing { .. } => {
+                anyhow::bail!("Trailing windows are not supported for 'approx_count_distinct'")
+            }
+            AggregationArgs::Session { .. } => {
+                anyhow::bail!("Session windows are not supported for 'approx_count_distinct'")
+            }
+        }
+    }
+}
+
+impl ApproxCountDistinctEvaluator {
+    /// Update the aggregation state with the given inputs and return the
+    /// aggregation.
+    ///
+    /// The `key_capacity` must be greater than all values in the
+    /// `entity_indices`.
+    ///
+    /// # Result
+    /// The result is an array containing the estimated number of distinct
+    /// non-null inputs for the entity of each row.
+    ///
+    /// # Assumptions
+    /// This assumes that the input data has been sorted by occurrence time.
+    fn aggregate(
+        token: &mut HyperLogLogAccumToken,
+        key_capacity: usize,
+        entity_indices: &UInt32Array,
+        input: &ArrayRef,
+    ) -> anyhow::Result<ArrayRef> {
+        token.resize(key_capacity);
+
+        let hashes = ApproxCountDistinct::inputs(input)?;
+        let result = izip!(entity_indices.values(), hashes).map(|(entity_index, hash)| {
+            if let Some(hash) = hash {
+                token.add_hash(*entity_index, hash);
+            }
+            ApproxCountDistinct::extract(token.get_value(*entity_index))
+        });
+
+        Ok(ApproxCountDistinct::outputs(result.collect()))
+    }
+
+    /// Update the aggregation state with the given inputs and return the
+    /// aggregation.
+    ///
+    /// The `key_capacity` must be greater than all values in the
+    /// `entity_indices`.
+    ///
+    /// # Window Behavior
+    /// This aggregation uses the `since` window behavior, which takes a single
+    /// predicate. If the predicate evaluates to true, the sketch is reset
+    /// after producing the result for that row.
+    ///
+    /// # Assumptions
+    /// This assumes that the input data has been sorted by occurrence time.
+    fn aggregate_since(
+        token: &mut HyperLogLogAccumToken,
+        key_capacity: usize,
+        entity_indices: &UInt32Array,
+        input: &ArrayRef,
+        ticks: &BooleanArray,
+    ) -> anyhow::Result<ArrayRef> {
+        token.resize(key_capacity);
+
+        let hashes = ApproxCountDistinct::inputs(input)?;
+        let result =
+            izip!(entity_indices.values(), hashes, ticks).map(|(entity_index, hash, tick)| {
+                if let Some(hash) = hash {
+                    token.add_hash(*entity_index, hash);
+                }
+                let value_to_emit = ApproxCountDistinct::extract(token.get_value(*entity_index));
+
+                if tick == Some(true) {
+                    token.reset_value(*entity_index);
+                }
+                value_to_emit
+            });
+
+        Ok(ApproxCountDistinct::outputs(result.collect()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::StringArray;
+    use arrow::datatypes::UInt32Type;
+    use sparrow_arrow::downcast::downcast_primitive_array;
+
+    use super::*;
+
+    #[test]
+    fn test_approx_count_distinct() {
+        let entity_indices = UInt32Array::from(vec![0, 1, 0, 1, 0, 0]);
+        let input: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("a"),
+            Some("a"),
+            Some("b"),
+            None,
+            Some("a"),
+            Some("c"),
+        ]));
+        let mut token = HyperLogLogAccumToken::default();
+        let output =
+            ApproxCountDistinctEvaluator::aggregate(&mut token, 2, &entity_indices, &input)
+                .unwrap();
+        let output = downcast_primitive_array::<UInt32Type>(output.as_ref()).unwrap();
+        assert_eq!(output, &UInt32Array::from(vec![1, 1, 2, 1, 2, 3]));
+
+        // The sketches carry over to the next batch.
+        let entity_indices = UInt32Array::from(vec![1, 0]);
+        let input: ArrayRef = Arc::new(StringArray::from(vec![Some("b"), Some("b")]));
+        let output =
+            ApproxCountDistinctEvaluator::aggregate(&mut token, 2, &entity_indices, &input)
+                .unwrap();
+        let output = downcast_primitive_array::<UInt32Type>(output.as_ref()).unwrap();
+        assert_eq!(output, &UInt32Array::from(vec![2, 3]));
+    }
+
+    #[test]
+    fn test_approx_count_distinct_since() {
+        let entity_indices = UInt32Array::from(vec![0, 0, 0, 0, 0]);
+        let input: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("a"),
+            Some("b"),
+            Some("a"),
+            None,
+            Some("a"),
+        ]));
+        let ticks =
+            BooleanArray::from(vec![Some(false), Some(true), None, Some(false), Some(true)]);
+        let mut token = HyperLogLogAccumToken::default();
+        let output = ApproxCountDistinctEvaluator::aggregate_since(
+            &mut token,
+            1,
+            &entity_indices,
+            &input,
+            &ticks,
+        )
+        .unwrap();
+        let output = downcast_primitive_array::<UInt32Type>(output.as_ref()).unwrap();
+        assert_eq!(output, &UInt32Array::from(vec![1, 2, 1, 1, 1]));
+    }
+}
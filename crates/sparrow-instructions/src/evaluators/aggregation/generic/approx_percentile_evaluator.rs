@@ -0,0 +1,277 @@
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, BooleanArray, Float64Array, UInt32Array};
+use arrow::datatypes::{DataType, Float64Type};
+use itertools::izip;
+use sparrow_arrow::downcast::downcast_primitive_array;
+use sparrow_arrow::scalar_value::ScalarValue;
+use sparrow_plan::ValueRef;
+
+use crate::{
+    AggregationArgs, Evaluator, EvaluatorFactory, RuntimeInfo, StateToken, StaticInfo,
+    TDigestAccumToken,
+};
+
+/// Evaluator for the `approx_percentile` instruction.
+///
+/// Each entity has a t-digest containing the non-null inputs, which is used
+/// to estimate the requested percentile.
+pub struct ApproxPercentileEvaluator {
+    /// The percentile to estimate, between 0 and 1.
+    ///
+    /// If `None`, the result is always `null`.
+    percentile: Option<f64>,
+    args: AggregationArgs<ValueRef>,
+    token: TDigestAccumToken,
+}
+
+impl Evaluator for ApproxPercentileEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        match &self.args {
+            AggregationArgs::NoWindow { input } => {
+                let grouping = info.grouping();
+                let input_vals = info.value(input)?.array_ref()?;
+                Self::aggregate(
+                    &mut self.token,
+                    self.percentile,
+                    grouping.num_groups(),
+                    grouping.group_indices(),
+                    &input_vals,
+                )
+            }
+            AggregationArgs::Since { ticks, input } => {
+                let grouping = info.grouping();
+                let input_vals = info.value(input)?.array_ref()?;
+                let ticks = info.value(ticks)?.boolean_array()?;
+                Self::aggregate_since(
+                    &mut self.token,
+                    self.percentile,
+                    grouping.num_groups(),
+                    grouping.group_indices(),
+                    &input_vals,
+                    ticks.as_ref(),
+                )
+            }
+            AggregationArgs::Sliding { .. }
+            | AggregationArgs::Trailing { .. }
+            | AggregationArgs::Session { .. } => {
+                unreachable!(
+                    "Expected Non-windowed or Since windowed aggregation, saw Sliding, Trailing \
+                     or Session."
+                )
+            }
+        }
+    }
+
+    fn state_token(&self) -> Option<&dyn StateToken> {
+        Some(&self.token)
+    }
+
+    fn state_token_mut(&mut self) -> Option<&mut dyn StateToken> {
+        Some(&mut self.token)
+    }
+}
+
+impl EvaluatorFactory for ApproxPercentileEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        // The arguments are `[input, percentile, tick, duration, window_kind]`.
+        let mut args = info.args;
+        anyhow::ensure!(
+            args.len() == 5,
+            "'approx_percentile' should have 5 arguments. Saw {:?}",
+            args.len()
+        );
+        let percentile = match args.remove(1).value_ref.literal_value() {
+            Some(ScalarValue::Float64(Some(p))) if (0.0..=1.0).contains(&p.into_inner()) => {
+                Some(p.into_inner())
+            }
+            Some(ScalarValue::Float64(Some(p))) => {
+                anyhow::bail!("unexpected percentile {p} -- must be between 0 and 1")
+            }
+            Some(ScalarValue::Float64(None)) => None,
+            Some(other) => anyhow::bail!("expected f64 for percentile, saw {:?}", other),
+            None => anyhow::bail!("expected literal value for percentile"),
+        };
+
+        let args = AggregationArgs::from_input(args)?;
+        match args {
+            AggregationArgs::NoWindow { .. } | AggregationArgs::Since { .. } => {
+                let token = TDigestAccumToken::default();
+                Ok(Box::new(Self {
+                    percentile,
+                    args,
+                    token,
+                }))
+            }
+            AggregationArgs::Sliding { .. } => {
+                anyhow::bail!("Sliding windows are not supported for 'approx_percentile'")
+            }
+            AggregationArgs::Trailing { .. } => {
+                anyhow::bail!("Trailing windows are not supported for 'approx_percentile'")
+            }
+            AggregationArgs::Session { .. } => {
+                anyhow::bail!("Session windows are not supported for 'approx_percentile'")
+            }
+        }
+    }
+}
+
+impl ApproxPercentileEvaluator {
+    /// Update the aggregation state with the given inputs and return the
+    /// aggregation.
+    ///
+    /// The `key_capacity` must be greater than all values in the
+    /// `entity_indices`.
+    ///
+    /// # Result
+    /// The result is an array containing the estimated percentile of the
+    /// non-null inputs for the entity of each row, or `null` if there have
+    /// been no such inputs.
+    ///
+    /// # Assumptions
+    /// This assumes that the input data has been sorted by occurrence time.
+    fn aggregate(
+        token: &mut TDigestAccumToken,
+        percentile: Option<f64>,
+        key_capacity: usize,
+        entity_indices: &UInt32Array,
+        input: &ArrayRef,
+    ) -> anyhow::Result<ArrayRef> {
+        token.resize(key_capacity);
+
+        let input = arrow::compute::cast(input, &DataType::Float64)?;
+        let input: &Float64Array = downcast_primitive_array::<Float64Type>(input.as_ref())?;
+
+        let result: Float64Array = izip!(entity_indices.values(), input)
+            .map(|(entity_index, input)| {
+                if let Some(input) = input {
+                    token.add_value(*entity_index, input);
+                }
+                percentile.and_then(|p| token.quantile(*entity_index, p))
+            })
+            .collect();
+
+        Ok(Arc::new(result))
+    }
+
+    /// Update the aggregation state with the given inputs and return the
+    /// aggregation.
+    ///
+    /// The `key_capacity` must be greater than all values in the
+    /// `entity_indices`.
+    ///
+    /// # Window Behavior
+    /// This aggregation uses the `since` window behavior, which takes a single
+    /// predicate. If the predicate evaluates to true, the digest is reset
+    /// after producing the result for that row.
+    ///
+    /// # Assumptions
+    /// This assumes that the input data has been sorted by occurrence time.
+    fn aggregate_since(
+        token: &mut TDigestAccumToken,
+        percentile: Option<f64>,
+        key_capacity: usize,
+        entity_indices: &UInt32Array,
+        input: &ArrayRef,
+        ticks: &BooleanArray,
+    ) -> anyhow::Result<ArrayRef> {
+        token.resize(key_capacity);
+
+        let input = arrow::compute::cast(input, &DataType::Float64)?;
+        let input: &Float64Array = downcast_primitive_array::<Float64Type>(input.as_ref())?;
+
+        let result: Float64Array = izip!(entity_indices.values(), input, ticks)
+            .map(|(entity_index, input, tick)| {
+                if let Some(input) = input {
+                    token.add_value(*entity_index, input);
+                }
+                let value_to_emit = percentile.and_then(|p| token.quantile(*entity_index, p));
+
+                if tick == Some(true) {
+                    token.reset_value(*entity_index);
+                }
+                value_to_emit
+            })
+            .collect();
+
+        Ok(Arc::new(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Array, Int64Array};
+
+    use super::*;
+
+    #[test]
+    fn test_approx_percentile() {
+        let entity_indices = UInt32Array::from(vec![0, 1, 0, 1, 0, 0]);
+        let input: ArrayRef = Arc::new(Int64Array::from(vec![
+            Some(5),
+            Some(10),
+            Some(1),
+            None,
+            Some(3),
+            Some(7),
+        ]));
+        let mut token = TDigestAccumToken::default();
+        let output =
+            ApproxPercentileEvaluator::aggregate(&mut token, Some(0.5), 2, &entity_indices, &input)
+                .unwrap();
+        let output = downcast_primitive_array::<Float64Type>(output.as_ref()).unwrap();
+        assert_eq!(
+            output,
+            &Float64Array::from(vec![5.0, 10.0, 3.0, 10.0, 3.0, 4.0])
+        );
+
+        // The digests carry over to the next batch.
+        let entity_indices = UInt32Array::from(vec![1, 0]);
+        let input: ArrayRef = Arc::new(Int64Array::from(vec![Some(20), None]));
+        let output =
+            ApproxPercentileEvaluator::aggregate(&mut token, Some(0.5), 2, &entity_indices, &input)
+                .unwrap();
+        let output = downcast_primitive_array::<Float64Type>(output.as_ref()).unwrap();
+        assert_eq!(output, &Float64Array::from(vec![15.0, 4.0]));
+    }
+
+    #[test]
+    fn test_approx_percentile_null_percentile() {
+        let entity_indices = UInt32Array::from(vec![0, 0]);
+        let input: ArrayRef = Arc::new(Int64Array::from(vec![Some(5), Some(10)]));
+        let mut token = TDigestAccumToken::default();
+        let output =
+            ApproxPercentileEvaluator::aggregate(&mut token, None, 1, &entity_indices, &input)
+                .unwrap();
+        assert_eq!(output.null_count(), 2);
+    }
+
+    #[test]
+    fn test_approx_percentile_since() {
+        let entity_indices = UInt32Array::from(vec![0, 0, 0, 0, 0]);
+        let input: ArrayRef = Arc::new(Int64Array::from(vec![
+            Some(1),
+            Some(3),
+            Some(8),
+            None,
+            Some(4),
+        ]));
+        let ticks =
+            BooleanArray::from(vec![Some(false), Some(true), None, Some(true), Some(false)]);
+        let mut token = TDigestAccumToken::default();
+        let output = ApproxPercentileEvaluator::aggregate_since(
+            &mut token,
+            Some(1.0),
+            1,
+            &entity_indices,
+            &input,
+            &ticks,
+        )
+        .unwrap();
+        let output = downcast_primitive_array::<Float64Type>(output.as_ref()).unwrap();
+        assert_eq!(
+            output,
+            &Float64Array::from(vec![Some(1.0), Some(3.0), Some(8.0), Some(8.0), Some(4.0)])
+        );
+    }
+}
@@ -0,0 +1,417 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use arrow::array::{new_empty_array, Array, ArrayRef, BooleanArray, ListArray, UInt32Array};
+use arrow::buffer::OffsetBuffer;
+use arrow::datatypes::{DataType, FieldRef};
+use arrow::row::{RowConverter, SortField};
+use sparrow_arrow::scalar_value::ScalarValue;
+use sparrow_plan::ValueRef;
+
+use crate::{
+    AggregationArgs, CollectValuesAggFn, CollectValuesToken, CollectedValue, Evaluator,
+    EvaluatorFactory, RuntimeInfo, StateToken, StaticInfo,
+};
+
+/// Evaluator for the `top_k`, `most_frequent` and `collect_distinct`
+/// instructions.
+///
+/// Each entity has a bounded list of values, which is updated by `AggF`.
+/// Rows with a `null` input are ignored. The result is an empty list if the
+/// entity has no values.
+pub struct CollectValuesEvaluator<AggF>
+where
+    AggF: CollectValuesAggFn,
+{
+    args: AggregationArgs<ValueRef>,
+    /// The number of values to produce.
+    limit: usize,
+    /// The field describing the items of the resulting list.
+    field: FieldRef,
+    /// Converts values to rows, for comparing values of any type.
+    row_converter: RowConverter,
+    token: CollectValuesToken,
+    _phantom: PhantomData<fn(AggF) -> AggF>,
+}
+
+impl<AggF> Evaluator for CollectValuesEvaluator<AggF>
+where
+    AggF: CollectValuesAggFn,
+{
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let (input, ticks) = match &self.args {
+            AggregationArgs::NoWindow { input } => (input, None),
+            AggregationArgs::Since { ticks, input } => (input, Some(ticks)),
+            AggregationArgs::Sliding { .. }
+            | AggregationArgs::Trailing { .. }
+            | AggregationArgs::Session { .. } => {
+                unreachable!(
+                    "Expected Non-windowed or Since windowed aggregation, saw Sliding, Trailing \
+                     or Session."
+                )
+            }
+        };
+
+        let grouping = info.grouping();
+        let input = info.value(input)?.array_ref()?;
+        let ticks = match ticks {
+            Some(ticks) => Some(info.value(ticks)?.boolean_array()?),
+            None => None,
+        };
+        self.aggregate(
+            grouping.num_groups(),
+            grouping.group_indices(),
+            &input,
+            ticks.as_deref(),
+        )
+    }
+
+    fn state_token(&self) -> Option<&dyn StateToken> {
+        Some(&self.token)
+    }
+
+    fn state_token_mut(&mut self) -> Option<&mut dyn StateToken> {
+        Some(&mut self.token)
+    }
+}
+
+impl<AggF> EvaluatorFactory for CollectValuesEvaluator<AggF>
+where
+    AggF: CollectValuesAggFn + 'static,
+{
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        // The arguments are `[input, limit, tick, duration, window_kind]`.
+        let mut args = info.args;
+        anyhow::ensure!(
+            args.len() == 5,
+            "'{}' should have 5 arguments. Saw {:?}",
+            AggF::name(),
+            args.len()
+        );
+        let limit = match args.remove(1).value_ref.literal_value() {
+            Some(ScalarValue::Int64(Some(v))) if *v <= 0 => {
+                anyhow::bail!("unexpected limit for '{}' -- must be > 0", AggF::name())
+            }
+            Some(ScalarValue::Int64(Some(v))) => *v as usize,
+            Some(ScalarValue::Int64(None)) => {
+                anyhow::bail!("unexpected null limit for '{}'", AggF::name())
+            }
+            Some(other) => anyhow::bail!("expected i64 for limit, saw {:?}", other),
+            None => anyhow::bail!("expected literal value for limit"),
+        };
+
+        let field = match info.result_type {
+            DataType::List(field) => field.clone(),
+            other => anyhow::bail!("expected list result type, saw {:?}", other),
+        };
+
+        let args = AggregationArgs::from_input(args)?;
+        match args {
+            AggregationArgs::NoWindow { .. } | AggregationArgs::Since { .. } => {
+                let row_converter =
+                    RowConverter::new(vec![SortField::new(field.data_type().clone())])?;
+                let token = CollectValuesToken::new(new_empty_array(info.result_type));
+                Ok(Box::new(Self {
+                    args,
+                    limit,
+                    field,
+                    row_converter,
+                    token,
+                    _phantom: PhantomData,
+                }))
+            }
+            AggregationArgs::Sliding { .. } => {
+                anyhow::bail!("Sliding windows are not supported for '{}'", AggF::name())
+            }
+            AggregationArgs::Trailing { .. } => {
+                anyhow::bail!("Trailing windows are not supported for '{}'", AggF::name())
+            }
+            AggregationArgs::Session { .. } => {
+                anyhow::bail!("Session windows are not supported for '{}'", AggF::name())
+            }
+        }
+    }
+}
+
+impl<AggF> CollectValuesEvaluator<AggF>
+where
+    AggF: CollectValuesAggFn,
+{
+    /// Update the aggregation state with the given inputs and return the
+    /// aggregation.
+    ///
+    /// The `key_capacity` must be greater than all values in the
+    /// `entity_indices`.
+    ///
+    /// The values of the output and new state are taken from the
+    /// concatenated values of the old state and the new input. Each entity
+    /// tracks the indices of its collected values.
+    ///
+    /// If `window_since` is provided, the values of an entity are cleared
+    /// after they are emitted for rows where the window is `true`.
+    fn aggregate(
+        &mut self,
+        key_capacity: usize,
+        entity_indices: &UInt32Array,
+        input: &ArrayRef,
+        window_since: Option<&BooleanArray>,
+    ) -> anyhow::Result<ArrayRef> {
+        if self.token.values.len() < key_capacity {
+            self.token.resize(key_capacity)?;
+        }
+        let state = self.token.list();
+
+        let all_values = arrow::compute::concat(&[state.values().as_ref(), input.as_ref()])?;
+        let rows = self
+            .row_converter
+            .convert_columns(std::slice::from_ref(&all_values))?;
+
+        // The values collected by each entity.
+        let mut entities: Vec<Vec<CollectedValue>> = (0..state.len())
+            .map(|entity_index| {
+                let start = state.value_offsets()[entity_index] as usize;
+                let end = state.value_offsets()[entity_index + 1] as usize;
+                (start..end)
+                    .map(|index| CollectedValue {
+                        index: index as u32,
+                        count: self.token.counts[index],
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let state_len = state.values().len();
+        let mut output_indices = Vec::new();
+        let mut output_offsets = Vec::with_capacity(input.len() + 1);
+        output_offsets.push(0);
+        for input_index in 0..input.len() {
+            let entity_index = entity_indices.value(input_index) as usize;
+            let values = &mut entities[entity_index];
+            if input.is_valid(input_index) {
+                let index = (state_len + input_index) as u32;
+                AggF::add_one(values, index, &rows, self.limit);
+            }
+
+            output_indices.extend(AggF::extract(values, self.limit));
+            output_offsets.push(output_indices.len() as i32);
+
+            let reset_window = window_since
+                .is_some_and(|since| since.is_valid(input_index) && since.value(input_index));
+            if reset_window {
+                values.clear();
+            }
+        }
+
+        let output = self.take_list(&all_values, output_offsets, output_indices)?;
+
+        let mut state_offsets = Vec::with_capacity(entities.len() + 1);
+        state_offsets.push(0);
+        let mut state_indices = Vec::new();
+        let mut counts = Vec::new();
+        for values in entities {
+            state_indices.extend(values.iter().map(|value| value.index));
+            counts.extend(values.iter().map(|value| value.count));
+            state_offsets.push(state_indices.len() as i32);
+        }
+        let new_state = self.take_list(&all_values, state_offsets, state_indices)?;
+        self.token.set_state(new_state, counts);
+
+        Ok(output)
+    }
+
+    /// Create a list with the values at the given indices.
+    fn take_list(
+        &self,
+        values: &ArrayRef,
+        offsets: Vec<i32>,
+        indices: Vec<u32>,
+    ) -> anyhow::Result<ArrayRef> {
+        let indices = UInt32Array::from(indices);
+        let values = arrow::compute::take(values.as_ref(), &indices, None)?;
+        let list = ListArray::try_new(
+            self.field.clone(),
+            OffsetBuffer::new(offsets.into()),
+            values,
+            None,
+        )?;
+        Ok(Arc::new(list))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{AsArray, Int64Array, StringArray};
+    use arrow::datatypes::{Field, Int64Type};
+
+    use super::*;
+    use crate::{CollectDistinct, MostFrequent, TopK};
+
+    fn evaluator<AggF: CollectValuesAggFn>(
+        data_type: DataType,
+        limit: usize,
+    ) -> CollectValuesEvaluator<AggF> {
+        let field = Arc::new(Field::new("item", data_type.clone(), true));
+        let list_type = DataType::List(field.clone());
+        CollectValuesEvaluator {
+            args: AggregationArgs::NoWindow {
+                input: ValueRef::Input(0),
+            },
+            limit,
+            field,
+            row_converter: RowConverter::new(vec![SortField::new(data_type)]).unwrap(),
+            token: CollectValuesToken::new(new_empty_array(&list_type)),
+            _phantom: PhantomData,
+        }
+    }
+
+    fn i64_lists(result: &ArrayRef) -> Vec<Vec<i64>> {
+        result
+            .as_list::<i32>()
+            .iter()
+            .map(|list| {
+                let list = list.unwrap();
+                list.as_primitive::<Int64Type>().values().to_vec()
+            })
+            .collect()
+    }
+
+    fn string_lists(result: &ArrayRef) -> Vec<Vec<String>> {
+        result
+            .as_list::<i32>()
+            .iter()
+            .map(|list| {
+                let list = list.unwrap();
+                let list = list.as_string::<i32>();
+                list.iter().map(|s| s.unwrap().to_owned()).collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_top_k_multiple_batches() {
+        let mut evaluator = evaluator::<TopK>(DataType::Int64, 3);
+
+        let input: ArrayRef = Arc::new(Int64Array::from(vec![
+            Some(5),
+            Some(3),
+            None,
+            Some(8),
+            Some(5),
+            Some(1),
+        ]));
+        let entity_indices = UInt32Array::from(vec![0, 0, 1, 0, 0, 0]);
+        let result = evaluator
+            .aggregate(2, &entity_indices, &input, None)
+            .unwrap();
+        assert_eq!(
+            i64_lists(&result),
+            vec![
+                vec![5],
+                vec![5, 3],
+                vec![],
+                vec![8, 5, 3],
+                vec![8, 5, 5],
+                vec![8, 5, 5],
+            ]
+        );
+
+        // Batch 2, resetting the first entity after the first row.
+        let input: ArrayRef = Arc::new(Int64Array::from(vec![6, 2, 4]));
+        let entity_indices = UInt32Array::from(vec![0, 0, 1]);
+        let window = BooleanArray::from(vec![true, false, false]);
+        let result = evaluator
+            .aggregate(2, &entity_indices, &input, Some(&window))
+            .unwrap();
+        assert_eq!(i64_lists(&result), vec![vec![8, 6, 5], vec![2], vec![4]]);
+    }
+
+    #[test]
+    fn test_collect_distinct() {
+        let mut evaluator = evaluator::<CollectDistinct>(DataType::Utf8, 3);
+
+        let input: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("a"),
+            Some("b"),
+            Some("a"),
+            None,
+            Some("c"),
+            Some("d"),
+        ]));
+        let entity_indices = UInt32Array::from(vec![0, 0, 0, 0, 0, 0]);
+        let result = evaluator
+            .aggregate(1, &entity_indices, &input, None)
+            .unwrap();
+        assert_eq!(
+            string_lists(&result),
+            vec![
+                vec!["a"],
+                vec!["a", "b"],
+                vec!["b", "a"],
+                vec!["b", "a"],
+                vec!["b", "a", "c"],
+                vec!["a", "c", "d"],
+            ]
+        );
+
+        // The values carry over to the next batch.
+        let input: ArrayRef = Arc::new(StringArray::from(vec!["c"]));
+        let result = evaluator
+            .aggregate(1, &UInt32Array::from(vec![0]), &input, None)
+            .unwrap();
+        assert_eq!(string_lists(&result), vec![vec!["a", "d", "c"]]);
+    }
+
+    #[test]
+    fn test_most_frequent() {
+        let mut evaluator = evaluator::<MostFrequent>(DataType::Int64, 2);
+
+        let input: ArrayRef = Arc::new(Int64Array::from(vec![1, 2, 2, 3, 3, 3]));
+        let entity_indices = UInt32Array::from(vec![0, 0, 0, 0, 0, 0]);
+        let result = evaluator
+            .aggregate(1, &entity_indices, &input, None)
+            .unwrap();
+        assert_eq!(
+            i64_lists(&result),
+            vec![
+                vec![1],
+                vec![1, 2],
+                vec![2, 1],
+                vec![2, 1],
+                vec![2, 3],
+                vec![3, 2],
+            ]
+        );
+
+        // The counts carry over to the next batch. After the first row, `1`
+        // ties with `2` but was counted first.
+        let input: ArrayRef = Arc::new(Int64Array::from(vec![1, 1, 1]));
+        let entity_indices = UInt32Array::from(vec![0, 0, 0]);
+        let result = evaluator
+            .aggregate(1, &entity_indices, &input, None)
+            .unwrap();
+        assert_eq!(i64_lists(&result), vec![vec![3, 1], vec![1, 3], vec![1, 3]]);
+    }
+
+    #[test]
+    fn test_most_frequent_replaces_least_frequent() {
+        // With a limit of 1, counts are kept for 10 values.
+        let mut evaluator = evaluator::<MostFrequent>(DataType::Int64, 1);
+
+        let mut values = vec![0, 0];
+        values.extend(1..=10);
+        let input: ArrayRef = Arc::new(Int64Array::from(values));
+        let entity_indices = UInt32Array::from(vec![0; 12]);
+        evaluator
+            .aggregate(1, &entity_indices, &input, None)
+            .unwrap();
+
+        // Counting `10` replaced `1`, so `10` has an estimated count of 2.
+        let counts: Vec<_> = evaluator.token.counts.clone();
+        assert_eq!(counts, vec![2, 1, 1, 1, 1, 1, 1, 1, 1, 2]);
+        let values = evaluator.token.list().value(0);
+        assert_eq!(
+            values.as_primitive::<Int64Type>().values().to_vec(),
+            vec![0, 2, 3, 4, 5, 6, 7, 8, 9, 10]
+        );
+    }
+}
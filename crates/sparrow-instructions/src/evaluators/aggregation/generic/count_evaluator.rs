@@ -8,8 +8,8 @@ use sparrow_plan::ValueRef;
 
 use super::two_stacks_count_evaluator::TwoStacksCountIfEvaluator;
 use crate::{
-    AggregationArgs, CountAccumToken, Evaluator, EvaluatorFactory, RuntimeInfo, StateToken,
-    StaticInfo, TwoStacksCountAccumToken,
+    AggregationArgs, Count, CountAccumToken, Evaluator, EvaluatorFactory, RuntimeInfo,
+    SessionAggEvaluator, StateToken, StaticInfo, TrailingAggEvaluator, TwoStacksCountAccumToken,
 };
 
 /// Evaluator for the `count_if` instruction.
@@ -45,8 +45,13 @@ impl Evaluator for CountIfEvaluator {
                 );
                 result
             }
-            AggregationArgs::Sliding { .. } => {
-                unreachable!("Expected Non-windowed or Since windowed aggregation, saw Sliding.")
+            AggregationArgs::Sliding { .. }
+            | AggregationArgs::Trailing { .. }
+            | AggregationArgs::Session { .. } => {
+                unreachable!(
+                    "Expected Non-windowed or Since windowed aggregation, saw Sliding, Trailing \
+                     or Session."
+                )
             }
         }
     }
@@ -72,6 +77,16 @@ impl EvaluatorFactory for CountIfEvaluator {
                 let token = TwoStacksCountAccumToken::new();
                 Ok(Box::new(TwoStacksCountIfEvaluator { token, args }))
             }
+            AggregationArgs::Trailing { input, duration } => {
+                Ok(Box::new(TrailingAggEvaluator::<Count>::new(
+                    input, duration,
+                )))
+            }
+            AggregationArgs::Session { input, events, gap } => {
+                Ok(Box::new(SessionAggEvaluator::<Count>::new(
+                    input, events, gap,
+                )))
+            }
         }
     }
 }
@@ -0,0 +1,279 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use arrow::array::{
+    Array, ArrayRef, BooleanArray, Float64Array, TimestampNanosecondArray, UInt32Array,
+};
+use arrow::datatypes::TimestampNanosecondType;
+use itertools::izip;
+use sparrow_arrow::downcast::downcast_primitive_array;
+use sparrow_arrow::scalar_value::ScalarValue;
+use sparrow_plan::ValueRef;
+
+use crate::{
+    AggregationArgs, DecayedAccum, DecayedAggFn, Evaluator, EvaluatorFactory, PrimitiveAccumToken,
+    RuntimeInfo, StateToken, StaticInfo,
+};
+
+/// Evaluator for the exponentially time-decayed aggregations `ewma`,
+/// `decayed_sum` and `decayed_count`.
+///
+/// Each entity has a single [DecayedAccum], which is decayed by the time
+/// elapsed between rows. Rows with a `null` input don't contribute to the
+/// aggregation, but still produce a result decayed to the time of the row.
+pub struct DecayedEvaluator<AggF>
+where
+    AggF: DecayedAggFn,
+{
+    /// The half-life of each contribution, in nanoseconds.
+    half_life: i64,
+    args: AggregationArgs<ValueRef>,
+    token: PrimitiveAccumToken<Option<DecayedAccum>>,
+    _phantom: PhantomData<fn(AggF) -> AggF>,
+}
+
+impl<AggF> Evaluator for DecayedEvaluator<AggF>
+where
+    AggF: DecayedAggFn,
+{
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let (input, ticks) = match &self.args {
+            AggregationArgs::NoWindow { input } => (input, None),
+            AggregationArgs::Since { ticks, input } => (input, Some(ticks)),
+            AggregationArgs::Sliding { .. }
+            | AggregationArgs::Trailing { .. }
+            | AggregationArgs::Session { .. } => {
+                unreachable!(
+                    "Expected Non-windowed or Since windowed aggregation, saw Sliding, Trailing \
+                     or Session."
+                )
+            }
+        };
+
+        let grouping = info.grouping();
+        let input = info.value(input)?.array_ref()?;
+        let ticks = match ticks {
+            Some(ticks) => Some(info.value(ticks)?.boolean_array()?),
+            None => None,
+        };
+        let times = info.time_column().array_ref()?;
+        let times = downcast_primitive_array::<TimestampNanosecondType>(times.as_ref())?;
+
+        let mut accum = self.token.get_primitive_accum()?;
+        let result = Self::aggregate(
+            &mut accum,
+            self.half_life,
+            grouping.num_groups(),
+            grouping.group_indices(),
+            times,
+            &input,
+            ticks.as_deref(),
+        );
+        self.token.put_primitive_accum(accum)?;
+        result
+    }
+
+    fn state_token(&self) -> Option<&dyn StateToken> {
+        Some(&self.token)
+    }
+
+    fn state_token_mut(&mut self) -> Option<&mut dyn StateToken> {
+        Some(&mut self.token)
+    }
+}
+
+impl<AggF> EvaluatorFactory for DecayedEvaluator<AggF>
+where
+    AggF: DecayedAggFn + 'static,
+{
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        // The arguments are `[input, half_life, tick, duration, window_kind]`.
+        let mut args = info.args;
+        anyhow::ensure!(
+            args.len() == 5,
+            "'{}' should have 5 arguments. Saw {:?}",
+            AggF::name(),
+            args.len()
+        );
+        let half_life = match args.remove(1).value_ref.literal_value() {
+            Some(ScalarValue::Int64(Some(half_life))) if *half_life > 0 => *half_life,
+            Some(other) => anyhow::bail!(
+                "expected positive i64 half-life for '{}', saw {:?}",
+                AggF::name(),
+                other
+            ),
+            None => anyhow::bail!("expected literal value for half-life"),
+        };
+
+        let args = AggregationArgs::from_input(args)?;
+        match args {
+            AggregationArgs::NoWindow { .. } | AggregationArgs::Since { .. } => {
+                Ok(Box::new(Self {
+                    half_life,
+                    args,
+                    token: PrimitiveAccumToken::default(),
+                    _phantom: PhantomData,
+                }))
+            }
+            AggregationArgs::Sliding { .. } => {
+                anyhow::bail!("Sliding windows are not supported for '{}'", AggF::name())
+            }
+            AggregationArgs::Trailing { .. } => {
+                anyhow::bail!("Trailing windows are not supported for '{}'", AggF::name())
+            }
+            AggregationArgs::Session { .. } => {
+                anyhow::bail!("Session windows are not supported for '{}'", AggF::name())
+            }
+        }
+    }
+}
+
+impl<AggF> DecayedEvaluator<AggF>
+where
+    AggF: DecayedAggFn,
+{
+    /// Update the aggregation state with the given inputs and return the
+    /// aggregation.
+    ///
+    /// The `key_capacity` must be greater than all values in the
+    /// `entity_indices`.
+    ///
+    /// # Window Behavior
+    /// If `window_since` is provided, the state of an entity is reset after
+    /// producing the result for rows where the window is `true`.
+    ///
+    /// # Assumptions
+    /// This assumes that the input data has been sorted by occurrence time.
+    fn aggregate(
+        accum: &mut Vec<Option<DecayedAccum>>,
+        half_life: i64,
+        key_capacity: usize,
+        entity_indices: &UInt32Array,
+        times: &TimestampNanosecondArray,
+        input: &ArrayRef,
+        window_since: Option<&BooleanArray>,
+    ) -> anyhow::Result<ArrayRef> {
+        assert_eq!(entity_indices.len(), input.len());
+        if accum.len() < key_capacity {
+            accum.resize(key_capacity, None);
+        }
+
+        let input = AggF::input_values(input)?;
+        let result: Float64Array = izip!(entity_indices.values(), times.values(), &input)
+            .enumerate()
+            .map(|(index, (entity_index, time, input))| {
+                let accum = &mut accum[*entity_index as usize];
+                if let Some(input) = input {
+                    *accum = Some(AggF::add_one(*accum, input, *time, half_life));
+                }
+                let value_to_emit = AggF::extract(accum.as_ref(), *time, half_life);
+
+                let reset_window =
+                    window_since.is_some_and(|since| since.is_valid(index) && since.value(index));
+                if reset_window {
+                    *accum = None;
+                }
+                value_to_emit
+            })
+            .collect();
+
+        Ok(Arc::new(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::Int64Array;
+    use arrow::datatypes::Float64Type;
+
+    use super::*;
+    use crate::{DecayedCount, DecayedSum, Ewma};
+
+    fn aggregate<AggF: DecayedAggFn>(
+        accum: &mut Vec<Option<DecayedAccum>>,
+        entity_indices: Vec<u32>,
+        times: Vec<i64>,
+        input: Vec<Option<i64>>,
+        window_since: Option<&BooleanArray>,
+    ) -> Vec<Option<f64>> {
+        let key_capacity = entity_indices
+            .iter()
+            .max()
+            .map_or(0, |max| *max as usize + 1);
+        let input: ArrayRef = Arc::new(Int64Array::from(input));
+        let result = DecayedEvaluator::<AggF>::aggregate(
+            accum,
+            10,
+            key_capacity,
+            &UInt32Array::from(entity_indices),
+            &TimestampNanosecondArray::from(times),
+            &input,
+            window_since,
+        )
+        .unwrap();
+        let result = downcast_primitive_array::<Float64Type>(result.as_ref()).unwrap();
+        result.iter().collect()
+    }
+
+    #[test]
+    fn test_decayed_sum() {
+        let mut accum = Vec::new();
+        let result = aggregate::<DecayedSum>(
+            &mut accum,
+            vec![0, 0, 1, 0, 0],
+            vec![0, 10, 10, 20, 30],
+            vec![Some(8), Some(4), None, None, Some(1)],
+            None,
+        );
+        assert_eq!(
+            result,
+            vec![Some(8.0), Some(8.0), None, Some(4.0), Some(3.0)]
+        );
+
+        // The state carries over to the next batch.
+        let result = aggregate::<DecayedSum>(&mut accum, vec![0], vec![40], vec![None], None);
+        assert_eq!(result, vec![Some(1.5)]);
+    }
+
+    #[test]
+    fn test_decayed_count() {
+        let mut accum = Vec::new();
+        let result = aggregate::<DecayedCount>(
+            &mut accum,
+            vec![0, 1, 0, 0],
+            vec![0, 0, 10, 20],
+            vec![Some(8), None, Some(4), None],
+            None,
+        );
+        assert_eq!(result, vec![Some(1.0), Some(0.0), Some(1.5), Some(0.75)]);
+    }
+
+    #[test]
+    fn test_ewma() {
+        let mut accum = Vec::new();
+        let result = aggregate::<Ewma>(
+            &mut accum,
+            vec![0, 0, 0, 0],
+            vec![0, 10, 10, 100],
+            vec![Some(6), Some(12), None, None],
+            None,
+        );
+        // The first input has half the weight of the second, so the mean is
+        // `(6 * 0.5 + 12) / 1.5 = 10`. The mean is unchanged without inputs.
+        assert_eq!(result, vec![Some(6.0), Some(10.0), Some(10.0), Some(10.0)]);
+    }
+
+    #[test]
+    fn test_decayed_sum_since() {
+        let mut accum = Vec::new();
+        let window = BooleanArray::from(vec![Some(false), Some(true), None, Some(false)]);
+        let result = aggregate::<DecayedSum>(
+            &mut accum,
+            vec![0, 0, 0, 0],
+            vec![0, 10, 20, 30],
+            vec![Some(8), Some(4), None, Some(2)],
+            Some(&window),
+        );
+        assert_eq!(result, vec![Some(8.0), Some(8.0), None, Some(2.0)]);
+    }
+}
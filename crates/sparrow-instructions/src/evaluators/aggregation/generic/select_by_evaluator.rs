@@ -0,0 +1,366 @@
+use std::marker::PhantomData;
+
+use arrow::array::{new_empty_array, Array, ArrayRef, BooleanArray, UInt32Array};
+use arrow::row::{RowConverter, SortField};
+use sparrow_plan::ValueRef;
+
+use crate::{
+    AggregationArgs, Evaluator, EvaluatorFactory, OrderedValueAccumToken, RuntimeInfo,
+    SelectByAggFn, StateToken, StaticInfo, TwoStacksArrayAccumToken, TwoStacksSelectByEvaluator,
+};
+
+/// Evaluator for the `max_by` and `min_by` instructions.
+///
+/// Each entity selects the value from the row with the greatest (or least)
+/// ordering. Rows with a `null` ordering are ignored. If multiple rows have
+/// the same ordering, the most recent is selected.
+pub struct SelectByEvaluator<AggF>
+where
+    AggF: SelectByAggFn,
+{
+    args: AggregationArgs<ValueRef>,
+    ordering: ValueRef,
+    /// Converts orderings to rows, for comparing orderings of any type.
+    row_converter: RowConverter,
+    token: OrderedValueAccumToken,
+    _phantom: PhantomData<fn(AggF) -> AggF>,
+}
+
+impl<AggF> Evaluator for SelectByEvaluator<AggF>
+where
+    AggF: SelectByAggFn,
+{
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let (input, ticks) = match &self.args {
+            AggregationArgs::NoWindow { input } => (input, None),
+            AggregationArgs::Since { ticks, input } => (input, Some(ticks)),
+            AggregationArgs::Sliding { .. }
+            | AggregationArgs::Trailing { .. }
+            | AggregationArgs::Session { .. } => {
+                unreachable!(
+                    "Expected Non-windowed or Since windowed aggregation, saw Sliding, Trailing \
+                     or Session."
+                )
+            }
+        };
+
+        let grouping = info.grouping();
+        let values = info.value(input)?.array_ref()?;
+        let orderings = info.value(&self.ordering)?.array_ref()?;
+        let ticks = match ticks {
+            Some(ticks) => Some(info.value(ticks)?.boolean_array()?),
+            None => None,
+        };
+        self.aggregate(
+            grouping.num_groups(),
+            grouping.group_indices(),
+            &values,
+            &orderings,
+            ticks.as_deref(),
+        )
+    }
+
+    fn state_token(&self) -> Option<&dyn StateToken> {
+        Some(&self.token)
+    }
+
+    fn state_token_mut(&mut self) -> Option<&mut dyn StateToken> {
+        Some(&mut self.token)
+    }
+}
+
+impl<AggF> EvaluatorFactory for SelectByEvaluator<AggF>
+where
+    AggF: SelectByAggFn + 'static,
+{
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        // The arguments are `[value, ordering, tick, duration, window_kind]`.
+        let mut args = info.args;
+        anyhow::ensure!(
+            args.len() == 5,
+            "'{}' should have 5 arguments. Saw {:?}",
+            AggF::name(),
+            args.len()
+        );
+        let ordering = args.remove(1);
+        let row_converter = RowConverter::new(vec![SortField::new(ordering.data_type.clone())])?;
+
+        let args = AggregationArgs::from_input(args)?;
+        match args {
+            AggregationArgs::NoWindow { .. } | AggregationArgs::Since { .. } => {
+                let token = OrderedValueAccumToken::new(
+                    new_empty_array(info.result_type),
+                    new_empty_array(&ordering.data_type),
+                );
+                Ok(Box::new(Self {
+                    args,
+                    ordering: ordering.value_ref,
+                    row_converter,
+                    token,
+                    _phantom: PhantomData,
+                }))
+            }
+            AggregationArgs::Sliding { .. } => Ok(Box::new(TwoStacksSelectByEvaluator::<AggF> {
+                args,
+                ordering: ordering.value_ref,
+                row_converter,
+                token: TwoStacksArrayAccumToken::new(),
+                result_type: info.result_type.clone(),
+            })),
+            AggregationArgs::Trailing { .. } => {
+                anyhow::bail!("Trailing windows are not supported for '{}'", AggF::name())
+            }
+            AggregationArgs::Session { .. } => {
+                anyhow::bail!("Session windows are not supported for '{}'", AggF::name())
+            }
+        }
+    }
+}
+
+impl<AggF> SelectByEvaluator<AggF>
+where
+    AggF: SelectByAggFn,
+{
+    /// Update the aggregation state with the given inputs and return the
+    /// aggregation.
+    ///
+    /// The `key_capacity` must be greater than all values in the
+    /// `entity_indices`.
+    ///
+    /// The output and new state are taken from the concatenation of the old
+    /// state and the new input. The take index for each entity is either the
+    /// index of its old state, the length of the old state plus the index of
+    /// the selected input, or null if the entity has no value.
+    ///
+    /// If `window_since` is provided, the selected value of an entity is
+    /// reset after it is emitted for rows where the window is `true`.
+    fn aggregate(
+        &mut self,
+        key_capacity: usize,
+        entity_indices: &UInt32Array,
+        values: &ArrayRef,
+        orderings: &ArrayRef,
+        window_since: Option<&BooleanArray>,
+    ) -> anyhow::Result<ArrayRef> {
+        if self.token.value.len() < key_capacity {
+            self.token.resize(key_capacity)?;
+        }
+        let state_len = self.token.value.len();
+
+        let all_orderings = arrow::compute::concat(&[self.token.ordering.as_ref(), orderings])?;
+        let rows = self
+            .row_converter
+            .convert_columns(std::slice::from_ref(&all_orderings))?;
+
+        let mut take_new_state: Vec<Option<u32>> = (0..state_len)
+            .map(|index| self.token.ordering.is_valid(index).then_some(index as u32))
+            .collect();
+        let mut take_output_builder = UInt32Array::builder(values.len());
+        for input_index in 0..values.len() {
+            let entity_index = entity_indices.value(input_index) as usize;
+            if orderings.is_valid(input_index) {
+                let candidate = state_len + input_index;
+                // Rows are processed in order, so the candidate is always the
+                // more recent.
+                let replace = match take_new_state[entity_index] {
+                    Some(current) => AggF::replaces(
+                        rows.row(candidate).as_ref(),
+                        rows.row(current as usize).as_ref(),
+                        true,
+                    ),
+                    None => true,
+                };
+                if replace {
+                    take_new_state[entity_index] = Some(candidate as u32);
+                }
+            }
+            take_output_builder.append_option(take_new_state[entity_index]);
+
+            let reset_window = window_since
+                .is_some_and(|since| since.is_valid(input_index) && since.value(input_index));
+            if reset_window {
+                take_new_state[entity_index] = None;
+            }
+        }
+
+        // Gather the output, using the previous state and the new input
+        let output =
+            sparrow_arrow::concat_take(&self.token.value, values, &take_output_builder.finish())?;
+
+        // Update the state token with the new state
+        let take_new_state = UInt32Array::from(take_new_state);
+        let new_values = sparrow_arrow::concat_take(&self.token.value, values, &take_new_state)?;
+        let new_orderings =
+            sparrow_arrow::concat_take(&self.token.ordering, orderings, &take_new_state)?;
+        self.token.set_state(new_values, new_orderings);
+
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::{AsArray, Int64Array, StringArray, TimestampNanosecondArray};
+    use arrow::datatypes::DataType;
+
+    use super::*;
+    use crate::{MaxBy, MinBy};
+
+    fn evaluator<AggF: SelectByAggFn>() -> SelectByEvaluator<AggF> {
+        SelectByEvaluator {
+            args: AggregationArgs::NoWindow {
+                input: ValueRef::Input(0),
+            },
+            ordering: ValueRef::Input(1),
+            row_converter: RowConverter::new(vec![SortField::new(DataType::Int64)]).unwrap(),
+            token: OrderedValueAccumToken::new(
+                new_empty_array(&DataType::Utf8),
+                new_empty_array(&DataType::Int64),
+            ),
+            _phantom: PhantomData,
+        }
+    }
+
+    #[test]
+    fn test_max_by_multiple_batches() {
+        let mut evaluator = evaluator::<MaxBy>();
+
+        let values: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("a"),
+            Some("b"),
+            Some("c"),
+            None,
+            Some("e"),
+        ]));
+        let orderings: ArrayRef = Arc::new(Int64Array::from(vec![
+            Some(5),
+            Some(3),
+            Some(5),
+            Some(7),
+            None,
+        ]));
+        let entity_indices = UInt32Array::from(vec![0, 0, 0, 0, 1]);
+        let result = evaluator
+            .aggregate(2, &entity_indices, &values, &orderings, None)
+            .unwrap();
+        assert_eq!(
+            result.as_string::<i32>(),
+            &StringArray::from(vec![Some("a"), Some("a"), Some("c"), None, None])
+        );
+
+        // The maximum for the first entity is retained, with a `null` value.
+        let values: ArrayRef = Arc::new(StringArray::from(vec!["f", "g", "h"]));
+        let orderings: ArrayRef = Arc::new(Int64Array::from(vec![6, 1, 2]));
+        let entity_indices = UInt32Array::from(vec![0, 1, 1]);
+        let window = BooleanArray::from(vec![false, true, false]);
+        let result = evaluator
+            .aggregate(2, &entity_indices, &values, &orderings, Some(&window))
+            .unwrap();
+        assert_eq!(
+            result.as_string::<i32>(),
+            &StringArray::from(vec![None, Some("g"), Some("h")])
+        );
+    }
+
+    #[test]
+    fn test_min_by() {
+        let mut evaluator = evaluator::<MinBy>();
+
+        let values: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c", "d"]));
+        let orderings: ArrayRef = Arc::new(Int64Array::from(vec![Some(5), Some(3), None, Some(3)]));
+        let entity_indices = UInt32Array::from(vec![0, 0, 0, 0]);
+        let result = evaluator
+            .aggregate(1, &entity_indices, &values, &orderings, None)
+            .unwrap();
+        assert_eq!(
+            result.as_string::<i32>(),
+            &StringArray::from(vec!["a", "b", "b", "d"])
+        );
+    }
+
+    /// Run the windowed and sliding evaluators over the same rows, without
+    /// any sliding window being closed, and return both outputs.
+    fn windowed_and_sliding<AggF: SelectByAggFn>() -> (Vec<String>, Vec<String>) {
+        let entity_indices = UInt32Array::from(vec![0, 0, 0, 0, 0, 0]);
+        let times = TimestampNanosecondArray::from(vec![1, 2, 3, 4, 5, 6]);
+        let values: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c", "d", "e", "f"]));
+        let orderings: ArrayRef = Arc::new(Int64Array::from(vec![
+            Some(3),
+            Some(5),
+            Some(3),
+            None,
+            Some(5),
+            Some(2),
+        ]));
+        let sliding = BooleanArray::from(vec![false; 6]);
+
+        let mut windowed = evaluator::<AggF>();
+        let mut two_stacks = TwoStacksSelectByEvaluator::<AggF> {
+            args: AggregationArgs::Sliding {
+                input: ValueRef::Input(0),
+                ticks: ValueRef::Input(2),
+                duration: ValueRef::Input(3),
+            },
+            ordering: ValueRef::Input(1),
+            row_converter: RowConverter::new(vec![SortField::new(DataType::Int64)]).unwrap(),
+            token: TwoStacksArrayAccumToken::new(),
+            result_type: DataType::Utf8,
+        };
+
+        // Split the rows across batches, so ties are also broken against the
+        // stored state.
+        let mut windowed_output = Vec::new();
+        let mut sliding_output = Vec::new();
+        for (offset, length) in [(0, 3), (3, 3)] {
+            let output = windowed
+                .aggregate(
+                    1,
+                    &entity_indices.slice(offset, length),
+                    &values.slice(offset, length),
+                    &orderings.slice(offset, length),
+                    None,
+                )
+                .unwrap();
+            windowed_output.extend(
+                output
+                    .as_string::<i32>()
+                    .iter()
+                    .map(|s| s.unwrap().to_owned()),
+            );
+
+            let output = two_stacks
+                .aggregate(
+                    1,
+                    &entity_indices.slice(offset, length),
+                    &times.slice(offset, length),
+                    &values.slice(offset, length),
+                    &orderings.slice(offset, length),
+                    2,
+                    &sliding.slice(offset, length),
+                )
+                .unwrap();
+            sliding_output.extend(
+                output
+                    .as_string::<i32>()
+                    .iter()
+                    .map(|s| s.unwrap().to_owned()),
+            );
+        }
+        (windowed_output, sliding_output)
+    }
+
+    #[test]
+    fn test_ties_select_same_row_with_and_without_sliding() {
+        // Ties select the most recent row, so "e" is selected over "b" for
+        // `max_by` and "c" over "a" for `min_by`.
+        let (windowed, sliding) = windowed_and_sliding::<MaxBy>();
+        assert_eq!(windowed, vec!["a", "b", "b", "b", "e", "e"]);
+        assert_eq!(windowed, sliding);
+
+        let (windowed, sliding) = windowed_and_sliding::<MinBy>();
+        assert_eq!(windowed, vec!["a", "a", "c", "c", "c", "f"]);
+        assert_eq!(windowed, sliding);
+    }
+}
@@ -0,0 +1,331 @@
+use anyhow::anyhow;
+use arrow::array::{Array, ArrayRef, BooleanArray, TimestampNanosecondArray, UInt32Array};
+use arrow::datatypes::{Int64Type, TimestampNanosecondType};
+use itertools::izip;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sparrow_arrow::downcast::{downcast_boolean_array, downcast_primitive_array};
+use sparrow_plan::ValueRef;
+
+use crate::{Evaluator, RuntimeInfo, SessionAccumToken, StateToken, WindowAggFn};
+
+/// Evaluator for aggregations over sessions.
+///
+/// A session is a sequence of events for an entity in which each event
+/// occurs within the inactivity `gap` of the previous event. Events are the
+/// rows of the aggregated input, including those where the input is null,
+/// so every aggregation over the same input sees the same sessions.
+///
+/// Each row produces the aggregation of the inputs in the current session.
+/// A row occurring more than `gap` after the previous event closes the
+/// session. If the row is an event it starts a new session, otherwise (such
+/// as a tick) it produces the aggregation of the empty session.
+///
+/// Sessions are only closed by rows of the aggregation. For final results,
+/// the aggregation includes a (non-event) row at the final tick, so sessions
+/// which ended more than `gap` before the final tick are closed.
+pub(crate) struct SessionAggEvaluator<AggF>
+where
+    AggF: WindowAggFn,
+{
+    input: ValueRef,
+    /// Boolean indicating which rows are events extending the session.
+    events: ValueRef,
+    /// The inactivity gap, in nanoseconds.
+    gap: ValueRef,
+    token: SessionAccumToken<AggF>,
+}
+
+impl<AggF> Evaluator for SessionAggEvaluator<AggF>
+where
+    AggF: WindowAggFn,
+    AggF::AccT: Serialize + DeserializeOwned + Sync,
+{
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let gap = info
+            .value(&self.gap)?
+            .try_primitive_literal::<Int64Type>()?
+            .ok_or_else(|| anyhow!("Expected non-null literal gap"))?;
+        if gap <= 0 {
+            anyhow::bail!("Expected positive gap for session window, saw {gap:?}");
+        }
+
+        // Get the stored state of the accum
+        let mut accum = self.token.get_accum()?;
+
+        let grouping = info.grouping();
+        let input_vals = info.value(&self.input)?.array_ref()?;
+        let events = info.value(&self.events)?.array_ref()?;
+        let times = info.time_column().array_ref()?;
+        let times = downcast_primitive_array::<TimestampNanosecondType>(times.as_ref())?;
+        let result = Self::aggregate(
+            &mut accum,
+            grouping.num_groups(),
+            grouping.group_indices(),
+            times,
+            downcast_boolean_array(events.as_ref())?,
+            &input_vals,
+            gap,
+        );
+
+        // Store the new state
+        self.token.put_accum(accum)?;
+
+        result
+    }
+
+    fn state_token(&self) -> Option<&dyn StateToken> {
+        Some(&self.token)
+    }
+
+    fn state_token_mut(&mut self) -> Option<&mut dyn StateToken> {
+        Some(&mut self.token)
+    }
+}
+
+impl<AggF> SessionAggEvaluator<AggF>
+where
+    AggF: WindowAggFn,
+{
+    pub(crate) fn new(input: ValueRef, events: ValueRef, gap: ValueRef) -> Self {
+        Self {
+            input,
+            events,
+            gap,
+            token: SessionAccumToken::new(),
+        }
+    }
+
+    /// Update the aggregation state with the given inputs and return the
+    /// aggregation.
+    ///
+    /// The `key_capacity` must be greater than all values in the
+    /// `entity_indices`.
+    ///
+    /// # Assumptions
+    /// This assumes that the input data has been sorted by occurrence time.
+    fn aggregate(
+        accum: &mut Vec<(Option<i64>, AggF::AccT)>,
+        key_capacity: usize,
+        entity_indices: &UInt32Array,
+        times: &TimestampNanosecondArray,
+        events: &BooleanArray,
+        input: &ArrayRef,
+        gap: i64,
+    ) -> anyhow::Result<ArrayRef> {
+        assert_eq!(entity_indices.len(), input.len());
+        assert_eq!(times.len(), input.len());
+        assert_eq!(events.len(), input.len());
+
+        if key_capacity > accum.len() {
+            accum.resize(key_capacity, (None, AggF::zero()));
+        }
+
+        let inputs = AggF::inputs(input)?;
+        let outputs = izip!(entity_indices.values(), times.values(), events, inputs)
+            .map(|(entity_index, time, is_event, input)| {
+                let (last_event, accum) = &mut accum[*entity_index as usize];
+
+                // Close the session if the previous event is more than the gap
+                // before this row.
+                if last_event.is_some_and(|last_event| time.saturating_sub(last_event) > gap) {
+                    *accum = AggF::zero();
+                    *last_event = None;
+                }
+
+                if is_event == Some(true) {
+                    if let Some(input) = input {
+                        AggF::add_one(accum, &input);
+                    }
+                    *last_event = Some(*time);
+                }
+
+                AggF::extract(accum)
+            })
+            .collect();
+
+        Ok(AggF::outputs(outputs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::{Float64Array, Int64Array, StringArray};
+    use arrow::datatypes::Float64Type;
+    use sparrow_arrow::downcast::downcast_string_array;
+
+    use super::*;
+    use crate::{Count, FirstString, Max, Sum};
+
+    fn times(times: Vec<i64>) -> TimestampNanosecondArray {
+        TimestampNanosecondArray::from(times)
+    }
+
+    fn events(len: usize) -> BooleanArray {
+        BooleanArray::from(vec![true; len])
+    }
+
+    #[test]
+    fn test_session_sum_f64() {
+        let entity_indices = UInt32Array::from(vec![0; 6]);
+        let times = times(vec![0, 5, 10, 21, 25, 33]);
+        let input: ArrayRef = Arc::new(Float64Array::from(vec![
+            Some(1.0),
+            Some(2.0),
+            Some(3.0),
+            Some(4.0),
+            None,
+            Some(5.0),
+        ]));
+        let mut accum = Vec::new();
+
+        let output = SessionAggEvaluator::<Sum<Float64Type>>::aggregate(
+            &mut accum,
+            1,
+            &entity_indices,
+            &times,
+            &events(6),
+            &input,
+            10,
+        )
+        .unwrap();
+
+        // The input at time 21 starts a new session, since it is more than 10
+        // after the input at time 10. The null at time 25 is still an event, so
+        // it extends the session to include the input at time 33.
+        let output = downcast_primitive_array::<Float64Type>(output.as_ref()).unwrap();
+        assert_eq!(
+            output,
+            &Float64Array::from(vec![
+                Some(1.0),
+                Some(3.0),
+                Some(6.0),
+                Some(4.0),
+                Some(4.0),
+                Some(9.0)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_session_gap_is_inclusive() {
+        let entity_indices = UInt32Array::from(vec![0; 3]);
+        let times = times(vec![0, 10, 21]);
+        let input: ArrayRef = Arc::new(Int64Array::from(vec![3, 1, 2]));
+        let mut accum = Vec::new();
+
+        let output = SessionAggEvaluator::<Max<Int64Type>>::aggregate(
+            &mut accum,
+            1,
+            &entity_indices,
+            &times,
+            &events(3),
+            &input,
+            10,
+        )
+        .unwrap();
+
+        let output = downcast_primitive_array::<Int64Type>(output.as_ref()).unwrap();
+        assert_eq!(output, &Int64Array::from(vec![3, 3, 2]));
+    }
+
+    #[test]
+    fn test_session_count_multiple_keys_across_batches() {
+        let mut accum = Vec::new();
+
+        let entity_indices = UInt32Array::from(vec![0, 1, 0, 1]);
+        let input: ArrayRef = Arc::new(BooleanArray::from(vec![true, true, true, false]));
+        let output = SessionAggEvaluator::<Count>::aggregate(
+            &mut accum,
+            2,
+            &entity_indices,
+            &times(vec![0, 1, 2, 10]),
+            &events(4),
+            &input,
+            5,
+        )
+        .unwrap();
+        let output: &UInt32Array = downcast_primitive_array(output.as_ref()).unwrap();
+        assert_eq!(output, &UInt32Array::from(vec![1, 1, 2, 0]));
+
+        // The `false` input at time 10 started a new session for key 1, which
+        // includes the input at time 12.
+        let input: ArrayRef = Arc::new(BooleanArray::from(vec![true, true]));
+        let output = SessionAggEvaluator::<Count>::aggregate(
+            &mut accum,
+            2,
+            &UInt32Array::from(vec![0, 1]),
+            &times(vec![7, 12]),
+            &events(2),
+            &input,
+            5,
+        )
+        .unwrap();
+        let output: &UInt32Array = downcast_primitive_array(output.as_ref()).unwrap();
+        assert_eq!(output, &UInt32Array::from(vec![3, 1]));
+    }
+
+    #[test]
+    fn test_session_first_string() {
+        let entity_indices = UInt32Array::from(vec![0; 4]);
+        let times = times(vec![0, 1, 20, 21]);
+        let input: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("a"),
+            Some("b"),
+            Some("c"),
+            None,
+        ]));
+        let mut accum = Vec::new();
+
+        let output = SessionAggEvaluator::<FirstString>::aggregate(
+            &mut accum,
+            1,
+            &entity_indices,
+            &times,
+            &events(4),
+            &input,
+            10,
+        )
+        .unwrap();
+
+        let output = downcast_string_array::<i32>(output.as_ref()).unwrap();
+        assert_eq!(output, &StringArray::from(vec!["a", "a", "c", "c"]));
+    }
+
+    #[test]
+    fn test_session_closed_by_non_events() {
+        let entity_indices = UInt32Array::from(vec![0; 5]);
+        let times = times(vec![0, 5, 20, 21, 25]);
+        let events = BooleanArray::from(vec![Some(true), None, None, Some(true), None]);
+        let input: ArrayRef = Arc::new(Float64Array::from(vec![
+            Some(1.0),
+            None,
+            None,
+            Some(2.0),
+            None,
+        ]));
+        let mut accum = Vec::new();
+
+        let output = SessionAggEvaluator::<Sum<Float64Type>>::aggregate(
+            &mut accum,
+            1,
+            &entity_indices,
+            &times,
+            &events,
+            &input,
+            10,
+        )
+        .unwrap();
+
+        // Rows which aren't events, such as ticks, report the current session
+        // until the gap has passed, and then report the closed session as
+        // empty. They do not extend the session.
+        let output = downcast_primitive_array::<Float64Type>(output.as_ref()).unwrap();
+        assert_eq!(
+            output,
+            &Float64Array::from(vec![Some(1.0), Some(1.0), None, Some(2.0), Some(2.0)])
+        );
+    }
+}
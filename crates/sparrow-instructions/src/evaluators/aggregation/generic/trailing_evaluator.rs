@@ -0,0 +1,287 @@
+use anyhow::anyhow;
+use arrow::array::{Array, ArrayRef, TimestampNanosecondArray, UInt32Array};
+use arrow::datatypes::{Int64Type, TimestampNanosecondType};
+use itertools::izip;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sparrow_arrow::downcast::downcast_primitive_array;
+use sparrow_plan::ValueRef;
+
+use crate::{
+    Evaluator, RuntimeInfo, StateToken, TrailingAccumToken, TrailingTwoStacks, WindowAggFn,
+};
+
+/// Evaluator for aggregations over a trailing duration.
+///
+/// Each row produces the aggregation of the inputs within the window
+/// `(time - duration, time]`. Inputs are evicted once they fall outside
+/// of the window.
+pub(crate) struct TrailingAggEvaluator<AggF>
+where
+    AggF: WindowAggFn,
+{
+    input: ValueRef,
+    duration: ValueRef,
+    token: TrailingAccumToken<AggF>,
+}
+
+impl<AggF> Evaluator for TrailingAggEvaluator<AggF>
+where
+    AggF: WindowAggFn,
+    AggF::AccT: Serialize + DeserializeOwned + Sync,
+{
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let duration = info
+            .value(&self.duration)?
+            .try_primitive_literal::<Int64Type>()?
+            .ok_or_else(|| anyhow!("Expected non-null literal duration"))?;
+        if duration <= 0 {
+            anyhow::bail!(
+                "Expected positive duration for trailing window, saw {:?}",
+                duration
+            );
+        }
+
+        // Get the stored state of the accum
+        let mut accum = self.token.get_accum()?;
+
+        let grouping = info.grouping();
+        let input_vals = info.value(&self.input)?.array_ref()?;
+        let times = info.time_column().array_ref()?;
+        let times = downcast_primitive_array::<TimestampNanosecondType>(times.as_ref())?;
+        let result = Self::aggregate(
+            &mut accum,
+            grouping.num_groups(),
+            grouping.group_indices(),
+            times,
+            &input_vals,
+            duration,
+        );
+
+        // Store the new state
+        self.token.put_accum(accum)?;
+
+        result
+    }
+
+    fn state_token(&self) -> Option<&dyn StateToken> {
+        Some(&self.token)
+    }
+
+    fn state_token_mut(&mut self) -> Option<&mut dyn StateToken> {
+        Some(&mut self.token)
+    }
+}
+
+impl<AggF> TrailingAggEvaluator<AggF>
+where
+    AggF: WindowAggFn,
+{
+    pub(crate) fn new(input: ValueRef, duration: ValueRef) -> Self {
+        Self {
+            input,
+            duration,
+            token: TrailingAccumToken::new(),
+        }
+    }
+
+    /// Update the aggregation state with the given inputs and return the
+    /// aggregation.
+    ///
+    /// The `key_capacity` must be greater than all values in the
+    /// `entity_indices`.
+    ///
+    /// # Assumptions
+    /// This assumes that the input data has been sorted by occurrence time.
+    fn aggregate(
+        accum: &mut Vec<TrailingTwoStacks<AggF>>,
+        key_capacity: usize,
+        entity_indices: &UInt32Array,
+        times: &TimestampNanosecondArray,
+        input: &ArrayRef,
+        duration: i64,
+    ) -> anyhow::Result<ArrayRef> {
+        assert_eq!(entity_indices.len(), input.len());
+        assert_eq!(times.len(), input.len());
+
+        if key_capacity > accum.len() {
+            accum.resize(key_capacity, TrailingTwoStacks::default());
+        }
+
+        let inputs = AggF::inputs(input)?;
+        let outputs = izip!(entity_indices.values(), times.values(), inputs)
+            .map(|(entity_index, time, input)| {
+                let accum = &mut accum[*entity_index as usize];
+
+                // Evict inputs that are no longer within `(time - duration, time]`.
+                accum.evict_until(time.saturating_sub(duration));
+                if let Some(input) = input {
+                    accum.add_input(*time, &input);
+                }
+
+                AggF::extract(&accum.accum_value())
+            })
+            .collect();
+
+        Ok(AggF::outputs(outputs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::{BooleanArray, Float64Array, Int64Array, StringArray};
+    use arrow::datatypes::Float64Type;
+    use sparrow_arrow::downcast::downcast_string_array;
+
+    use super::*;
+    use crate::{Count, FirstPrimitive, LastString, Max, Sum};
+
+    fn times(times: Vec<i64>) -> TimestampNanosecondArray {
+        TimestampNanosecondArray::from(times)
+    }
+
+    #[test]
+    fn test_trailing_sum_f64() {
+        let entity_indices = UInt32Array::from(vec![0; 6]);
+        let times = times(vec![0, 5, 10, 10, 16, 40]);
+        let input: ArrayRef = Arc::new(Float64Array::from(vec![
+            Some(1.0),
+            Some(2.0),
+            Some(3.0),
+            None,
+            Some(4.0),
+            Some(5.0),
+        ]));
+        let mut accum = Vec::new();
+
+        let output = TrailingAggEvaluator::<Sum<Float64Type>>::aggregate(
+            &mut accum,
+            1,
+            &entity_indices,
+            &times,
+            &input,
+            10,
+        )
+        .unwrap();
+
+        // The window at time 10 excludes the input at time 0.
+        let output = downcast_primitive_array::<Float64Type>(output.as_ref()).unwrap();
+        assert_eq!(
+            output,
+            &Float64Array::from(vec![
+                Some(1.0),
+                Some(3.0),
+                Some(5.0),
+                Some(5.0),
+                Some(7.0),
+                Some(5.0)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_trailing_max_i64_multiple_keys() {
+        let entity_indices = UInt32Array::from(vec![0, 1, 0, 1, 0]);
+        let times = times(vec![0, 1, 2, 5, 6]);
+        let input: ArrayRef = Arc::new(Int64Array::from(vec![10, 3, 2, 1, 4]));
+        let mut accum = Vec::new();
+
+        let output = TrailingAggEvaluator::<Max<Int64Type>>::aggregate(
+            &mut accum,
+            2,
+            &entity_indices,
+            &times,
+            &input,
+            5,
+        )
+        .unwrap();
+
+        let output = downcast_primitive_array::<Int64Type>(output.as_ref()).unwrap();
+        assert_eq!(output, &Int64Array::from(vec![10, 3, 10, 3, 4]));
+    }
+
+    #[test]
+    fn test_trailing_first_f64_across_batches() {
+        let mut accum = Vec::new();
+
+        let entity_indices = UInt32Array::from(vec![0, 0]);
+        let input: ArrayRef = Arc::new(Float64Array::from(vec![1.0, 2.0]));
+        let output = TrailingAggEvaluator::<FirstPrimitive<Float64Type>>::aggregate(
+            &mut accum,
+            1,
+            &entity_indices,
+            &times(vec![0, 3]),
+            &input,
+            5,
+        )
+        .unwrap();
+        let output = downcast_primitive_array::<Float64Type>(output.as_ref()).unwrap();
+        assert_eq!(output, &Float64Array::from(vec![1.0, 1.0]));
+
+        let input: ArrayRef = Arc::new(Float64Array::from(vec![3.0, 4.0]));
+        let output = TrailingAggEvaluator::<FirstPrimitive<Float64Type>>::aggregate(
+            &mut accum,
+            1,
+            &entity_indices,
+            &times(vec![6, 9]),
+            &input,
+            5,
+        )
+        .unwrap();
+        let output = downcast_primitive_array::<Float64Type>(output.as_ref()).unwrap();
+        assert_eq!(output, &Float64Array::from(vec![2.0, 3.0]));
+    }
+
+    #[test]
+    fn test_trailing_count() {
+        let entity_indices = UInt32Array::from(vec![0; 5]);
+        let times = times(vec![0, 1, 2, 3, 10]);
+        let input: ArrayRef = Arc::new(BooleanArray::from(vec![
+            Some(true),
+            Some(false),
+            None,
+            Some(true),
+            Some(true),
+        ]));
+        let mut accum = Vec::new();
+
+        let output = TrailingAggEvaluator::<Count>::aggregate(
+            &mut accum,
+            1,
+            &entity_indices,
+            &times,
+            &input,
+            3,
+        )
+        .unwrap();
+
+        let output: &UInt32Array = downcast_primitive_array(output.as_ref()).unwrap();
+        assert_eq!(output, &UInt32Array::from(vec![1, 1, 1, 1, 1]));
+    }
+
+    #[test]
+    fn test_trailing_last_string() {
+        let entity_indices = UInt32Array::from(vec![0; 4]);
+        let times = times(vec![0, 1, 2, 20]);
+        let input: ArrayRef = Arc::new(StringArray::from(vec![Some("a"), Some("b"), None, None]));
+        let mut accum = Vec::new();
+
+        let output = TrailingAggEvaluator::<LastString>::aggregate(
+            &mut accum,
+            1,
+            &entity_indices,
+            &times,
+            &input,
+            10,
+        )
+        .unwrap();
+
+        let output = downcast_string_array::<i32>(output.as_ref()).unwrap();
+        assert_eq!(
+            output,
+            &StringArray::from(vec![Some("a"), Some("b"), Some("b"), None])
+        );
+    }
+}
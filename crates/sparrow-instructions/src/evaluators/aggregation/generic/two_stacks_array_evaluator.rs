@@ -0,0 +1,239 @@
+use anyhow::anyhow;
+use arrow::array::{new_empty_array, new_null_array, Array, ArrayRef, BooleanArray, UInt32Array};
+use arrow::datatypes::{DataType, Int64Type};
+use sparrow_plan::ValueRef;
+
+use crate::{
+    AggFn, AggregationArgs, ArrayValue, Evaluator, RuntimeInfo, StateToken, TwoStacks,
+    TwoStacksArrayAccumToken,
+};
+
+/// Evaluator for sliding-windowed aggregations over lists and maps.
+///
+/// Each value is accumulated as an array of length 1, allowing the same
+/// evaluator to be used for any non-primitive type.
+pub(crate) struct TwoStacksArrayEvaluator<AggF>
+where
+    AggF: AggFn<InT = ArrayValue, AccT = Option<ArrayValue>>,
+{
+    pub args: AggregationArgs<ValueRef>,
+    pub token: TwoStacksArrayAccumToken<AggF>,
+    pub result_type: DataType,
+}
+
+impl<AggF> Evaluator for TwoStacksArrayEvaluator<AggF>
+where
+    AggF: AggFn<InT = ArrayValue, AccT = Option<ArrayValue>>,
+{
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        match &self.args {
+            AggregationArgs::Sliding {
+                input,
+                ticks,
+                duration,
+            } => {
+                let grouping = info.grouping();
+                let input_vals = info.value(input)?.array_ref()?;
+                let ticks = info.value(ticks)?.boolean_array()?;
+                let duration = info
+                    .value(duration)?
+                    .try_primitive_literal::<Int64Type>()?
+                    .ok_or_else(|| anyhow!("Expected non-null literal duration"))?;
+                if duration <= 0 {
+                    anyhow::bail!(
+                        "Expected positive duration for sliding window, saw {:?}",
+                        duration
+                    );
+                }
+                Self::aggregate(
+                    &mut self.token,
+                    &self.result_type,
+                    grouping.num_groups(),
+                    grouping.group_indices(),
+                    &input_vals,
+                    duration,
+                    ticks.as_ref(),
+                )
+            }
+            AggregationArgs::Since { .. }
+            | AggregationArgs::NoWindow { .. }
+            | AggregationArgs::Trailing { .. }
+            | AggregationArgs::Session { .. } => {
+                unreachable!(
+                    "Expected sliding-windowed aggregation, saw non-windowed, since, trailing or \
+                     session windowed."
+                )
+            }
+        }
+    }
+
+    fn state_token(&self) -> Option<&dyn StateToken> {
+        Some(&self.token)
+    }
+
+    fn state_token_mut(&mut self) -> Option<&mut dyn StateToken> {
+        Some(&mut self.token)
+    }
+}
+
+impl<AggF> TwoStacksArrayEvaluator<AggF>
+where
+    AggF: AggFn<InT = ArrayValue, AccT = Option<ArrayValue>>,
+{
+    /// Update the aggregation state with the given inputs and return the
+    /// aggregation.
+    ///
+    /// The `key_capacity` must be greater than all values in the
+    /// `entity_indices`.
+    ///
+    /// # Window Behavior
+    /// This aggregation uses the `sliding` window behavior. Accumulator
+    /// behavior is to update -> emit -> evict, resulting in exclusive start
+    /// bounds and inclusive end bounds.
+    ///
+    /// # Result
+    /// The result is an array containing the result of the aggregation for each
+    /// input row.
+    fn aggregate(
+        token: &mut TwoStacksArrayAccumToken<AggF>,
+        result_type: &DataType,
+        key_capacity: usize,
+        key_indices: &UInt32Array,
+        input: &ArrayRef,
+        sliding_duration: i64,
+        sliding_window: &BooleanArray,
+    ) -> anyhow::Result<ArrayRef> {
+        assert_eq!(key_indices.len(), input.len());
+        if input.is_empty() {
+            return Ok(new_empty_array(result_type));
+        }
+
+        token.resize(key_capacity, sliding_duration);
+
+        let mut outputs = Vec::with_capacity(input.len());
+        for (input_index, entity_index) in key_indices.values().iter().enumerate() {
+            let mut accum = match token.get_value(*entity_index)? {
+                Some(accum) => accum,
+                None => TwoStacks::new(sliding_duration),
+            };
+
+            if input.is_valid(input_index) {
+                // Take a copy of the value, so the accumulator doesn't retain
+                // the entire input batch.
+                let index = UInt32Array::from(vec![input_index as u32]);
+                let value = arrow::compute::take(input.as_ref(), &index, None)?;
+                accum.add_input(&ArrayValue(value));
+            }
+
+            outputs.push(accum.accum_value());
+
+            if sliding_window.is_valid(input_index) && sliding_window.value(input_index) {
+                accum.evict();
+            }
+            token.put_value(*entity_index, accum)?;
+        }
+
+        let null = new_null_array(result_type, 1);
+        let outputs: Vec<&dyn Array> = outputs
+            .iter()
+            .map(|output| match output {
+                Some(ArrayValue(value)) => value.as_ref(),
+                None => null.as_ref(),
+            })
+            .collect();
+        Ok(arrow::compute::concat(&outputs)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::{AsArray, Int64Builder, ListBuilder};
+    use arrow::datatypes::Field;
+
+    use super::*;
+    use crate::{FirstArray, LastArray};
+
+    fn list_type() -> DataType {
+        DataType::List(Arc::new(Field::new("item", DataType::Int64, true)))
+    }
+
+    fn list_array(values: &[Option<Vec<i64>>]) -> ArrayRef {
+        let mut builder = ListBuilder::new(Int64Builder::new());
+        for value in values {
+            match value {
+                Some(value) => builder.append_value(value.iter().copied().map(Some)),
+                None => builder.append(false),
+            }
+        }
+        Arc::new(builder.finish())
+    }
+
+    #[test]
+    fn test_sliding_first_list() {
+        let entity_indices = UInt32Array::from(vec![0, 0, 1, 0, 0]);
+        let input = list_array(&[Some(vec![1, 2]), Some(vec![3]), None, Some(vec![]), None]);
+        let sliding = BooleanArray::from(vec![false, true, false, true, false]);
+
+        let mut token = TwoStacksArrayAccumToken::<FirstArray>::new();
+        let output = TwoStacksArrayEvaluator::aggregate(
+            &mut token,
+            &list_type(),
+            2,
+            &entity_indices,
+            &input,
+            1,
+            &sliding,
+        )
+        .unwrap();
+
+        let expected = list_array(&[Some(vec![1, 2]), Some(vec![1, 2]), None, Some(vec![]), None]);
+        assert_eq!(output.as_list::<i32>(), expected.as_list::<i32>());
+    }
+
+    #[test]
+    fn test_sliding_last_list() {
+        let entity_indices = UInt32Array::from(vec![0, 0, 0, 0, 0]);
+        let input = list_array(&[Some(vec![1, 2]), Some(vec![3]), None, None, Some(vec![4])]);
+        let sliding = BooleanArray::from(vec![false, false, true, false, true]);
+
+        let mut token = TwoStacksArrayAccumToken::<LastArray>::new();
+        let output = TwoStacksArrayEvaluator::aggregate(
+            &mut token,
+            &list_type(),
+            1,
+            &entity_indices,
+            &input,
+            2,
+            &sliding,
+        )
+        .unwrap();
+
+        let expected = list_array(&[
+            Some(vec![1, 2]),
+            Some(vec![3]),
+            Some(vec![3]),
+            Some(vec![3]),
+            Some(vec![4]),
+        ]);
+        assert_eq!(output.as_list::<i32>(), expected.as_list::<i32>());
+
+        // The window containing `[4]` is evicted by the next tick.
+        let input = list_array(&[None, None]);
+        let sliding = BooleanArray::from(vec![true, false]);
+        let output = TwoStacksArrayEvaluator::aggregate(
+            &mut token,
+            &list_type(),
+            1,
+            &UInt32Array::from(vec![0, 0]),
+            &input,
+            2,
+            &sliding,
+        )
+        .unwrap();
+
+        let expected = list_array(&[Some(vec![4]), None]);
+        assert_eq!(output.as_list::<i32>(), expected.as_list::<i32>());
+    }
+}
@@ -0,0 +1,215 @@
+use anyhow::anyhow;
+use arrow::array::{
+    new_empty_array, new_null_array, Array, ArrayRef, BooleanArray, TimestampNanosecondArray,
+    UInt32Array,
+};
+use arrow::datatypes::{DataType, Int64Type, TimestampNanosecondType};
+use arrow::row::RowConverter;
+use sparrow_arrow::downcast::downcast_primitive_array;
+use sparrow_plan::ValueRef;
+
+use crate::{
+    AggregationArgs, ArrayValue, Evaluator, OrderedValue, RuntimeInfo, SelectByAggFn, StateToken,
+    TwoStacks, TwoStacksArrayAccumToken,
+};
+
+/// Evaluator for the sliding-windowed `max_by` and `min_by` aggregations.
+///
+/// Each selected value is accumulated as an array of length 1, along with
+/// its ordering in the row format.
+pub(crate) struct TwoStacksSelectByEvaluator<AggF>
+where
+    AggF: SelectByAggFn,
+{
+    pub args: AggregationArgs<ValueRef>,
+    pub ordering: ValueRef,
+    pub row_converter: RowConverter,
+    pub token: TwoStacksArrayAccumToken<AggF>,
+    pub result_type: DataType,
+}
+
+impl<AggF> Evaluator for TwoStacksSelectByEvaluator<AggF>
+where
+    AggF: SelectByAggFn,
+{
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        match &self.args {
+            AggregationArgs::Sliding {
+                input,
+                ticks,
+                duration,
+            } => {
+                let grouping = info.grouping();
+                let values = info.value(input)?.array_ref()?;
+                let orderings = info.value(&self.ordering)?.array_ref()?;
+                let ticks = info.value(ticks)?.boolean_array()?;
+                let duration = info
+                    .value(duration)?
+                    .try_primitive_literal::<Int64Type>()?
+                    .ok_or_else(|| anyhow!("Expected non-null literal duration"))?;
+                if duration <= 0 {
+                    anyhow::bail!(
+                        "Expected positive duration for sliding window, saw {:?}",
+                        duration
+                    );
+                }
+                let times = info.time_column().array_ref()?;
+                let times = downcast_primitive_array::<TimestampNanosecondType>(times.as_ref())?;
+                self.aggregate(
+                    grouping.num_groups(),
+                    grouping.group_indices(),
+                    times,
+                    &values,
+                    &orderings,
+                    duration,
+                    ticks.as_ref(),
+                )
+            }
+            AggregationArgs::Since { .. }
+            | AggregationArgs::NoWindow { .. }
+            | AggregationArgs::Trailing { .. }
+            | AggregationArgs::Session { .. } => {
+                unreachable!(
+                    "Expected sliding-windowed aggregation, saw non-windowed, since, trailing or \
+                     session windowed."
+                )
+            }
+        }
+    }
+
+    fn state_token(&self) -> Option<&dyn StateToken> {
+        Some(&self.token)
+    }
+
+    fn state_token_mut(&mut self) -> Option<&mut dyn StateToken> {
+        Some(&mut self.token)
+    }
+}
+
+impl<AggF> TwoStacksSelectByEvaluator<AggF>
+where
+    AggF: SelectByAggFn,
+{
+    /// Update the aggregation state with the given inputs and return the
+    /// aggregation.
+    ///
+    /// The `key_capacity` must be greater than all values in the
+    /// `entity_indices`.
+    ///
+    /// # Window Behavior
+    /// This aggregation uses the `sliding` window behavior. Accumulator
+    /// behavior is to update -> emit -> evict, resulting in exclusive start
+    /// bounds and inclusive end bounds.
+    ///
+    /// # Result
+    /// The result is an array containing the result of the aggregation for each
+    /// input row.
+    #[allow(clippy::too_many_arguments)]
+    fn aggregate(
+        &mut self,
+        key_capacity: usize,
+        entity_indices: &UInt32Array,
+        times: &TimestampNanosecondArray,
+        values: &ArrayRef,
+        orderings: &ArrayRef,
+        sliding_duration: i64,
+        sliding_window: &BooleanArray,
+    ) -> anyhow::Result<ArrayRef> {
+        assert_eq!(entity_indices.len(), values.len());
+        if values.is_empty() {
+            return Ok(new_empty_array(&self.result_type));
+        }
+
+        self.token.resize(key_capacity, sliding_duration);
+        let rows = self
+            .row_converter
+            .convert_columns(std::slice::from_ref(orderings))?;
+
+        let mut outputs = Vec::with_capacity(values.len());
+        for (input_index, entity_index) in entity_indices.values().iter().enumerate() {
+            let mut accum = match self.token.get_value(*entity_index)? {
+                Some(accum) => accum,
+                None => TwoStacks::new(sliding_duration),
+            };
+
+            if orderings.is_valid(input_index) {
+                // Take a copy of the value, so the accumulator doesn't retain
+                // the entire input batch.
+                let index = UInt32Array::from(vec![input_index as u32]);
+                let value = arrow::compute::take(values.as_ref(), &index, None)?;
+                accum.add_input(&OrderedValue {
+                    ordering: rows.row(input_index).as_ref().to_vec(),
+                    time: times.value(input_index),
+                    value: ArrayValue(value),
+                });
+            }
+
+            outputs.push(AggF::extract(&accum.accum_value()));
+
+            if sliding_window.is_valid(input_index) && sliding_window.value(input_index) {
+                accum.evict();
+            }
+            self.token.put_value(*entity_index, accum)?;
+        }
+
+        let null = new_null_array(&self.result_type, 1);
+        let outputs: Vec<&dyn Array> = outputs
+            .iter()
+            .map(|output| match output {
+                Some(ArrayValue(value)) => value.as_ref(),
+                None => null.as_ref(),
+            })
+            .collect();
+        Ok(arrow::compute::concat(&outputs)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::{AsArray, Int64Array, StringArray};
+    use arrow::row::SortField;
+
+    use super::*;
+    use crate::MaxBy;
+
+    #[test]
+    fn test_sliding_max_by() {
+        let mut evaluator = TwoStacksSelectByEvaluator::<MaxBy> {
+            args: AggregationArgs::Sliding {
+                input: ValueRef::Input(0),
+                ticks: ValueRef::Input(2),
+                duration: ValueRef::Input(3),
+            },
+            ordering: ValueRef::Input(1),
+            row_converter: RowConverter::new(vec![SortField::new(DataType::Int64)]).unwrap(),
+            token: TwoStacksArrayAccumToken::new(),
+            result_type: DataType::Utf8,
+        };
+
+        let entity_indices = UInt32Array::from(vec![0, 0, 0, 0, 0, 0]);
+        let times = TimestampNanosecondArray::from(vec![1, 2, 3, 4, 5, 6]);
+        let values: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c", "d", "e", "f"]));
+        let orderings: ArrayRef = Arc::new(Int64Array::from(vec![
+            Some(3),
+            Some(5),
+            Some(5),
+            None,
+            Some(5),
+            Some(2),
+        ]));
+        let sliding = BooleanArray::from(vec![false, true, false, true, false, true]);
+
+        let output = evaluator
+            .aggregate(1, &entity_indices, &times, &values, &orderings, 2, &sliding)
+            .unwrap();
+
+        // Ties select the most recent value, so "c" is selected over "b"
+        // and "e" over "c".
+        assert_eq!(
+            output.as_string::<i32>(),
+            &StringArray::from(vec!["a", "b", "c", "c", "e", "e"])
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, BooleanArray, PrimitiveArray, StringArray, UInt32Array};
+use sparrow_arrow::downcast::{
+    downcast_boolean_array, downcast_primitive_array, downcast_string_array,
+};
+
+use crate::{
+    AggFn, ApproxCountDistinct, ArrowAggFn, Count, FirstBoolean, FirstString, LastBoolean,
+    LastString,
+};
+
+/// Trait for converting between Arrow arrays and the inputs and outputs of
+/// an aggregation function.
+///
+/// This allows a single evaluator to implement trailing and session windows
+/// for all aggregation functions.
+pub(crate) trait WindowAggFn: AggFn {
+    /// Returns the input for each row, or `None` if the row should be ignored.
+    fn inputs(input: &ArrayRef) -> anyhow::Result<Vec<Option<Self::InT>>>;
+
+    /// Returns an array containing the output for each row.
+    fn outputs(outputs: Vec<Option<Self::OutT>>) -> ArrayRef;
+}
+
+impl<AggF: ArrowAggFn> WindowAggFn for AggF {
+    fn inputs(input: &ArrayRef) -> anyhow::Result<Vec<Option<Self::InT>>> {
+        let input = downcast_primitive_array::<AggF::InArrowT>(input.as_ref())?;
+        Ok(input.iter().collect())
+    }
+
+    fn outputs(outputs: Vec<Option<Self::OutT>>) -> ArrayRef {
+        let outputs: PrimitiveArray<AggF::OutArrowT> = outputs.into_iter().collect();
+        Arc::new(outputs)
+    }
+}
+
+impl WindowAggFn for Count {
+    fn inputs(input: &ArrayRef) -> anyhow::Result<Vec<Option<u32>>> {
+        // `count_if` counts the rows where the input is `true`.
+        let input = downcast_boolean_array(input.as_ref())?;
+        Ok(input
+            .iter()
+            .map(|input| input.and_then(|input| input.then_some(1)))
+            .collect())
+    }
+
+    fn outputs(outputs: Vec<Option<u32>>) -> ArrayRef {
+        Arc::new(UInt32Array::from(outputs))
+    }
+}
+
+impl WindowAggFn for ApproxCountDistinct {
+    fn inputs(input: &ArrayRef) -> anyhow::Result<Vec<Option<u64>>> {
+        // Null values are not counted, so their hashes are ignored.
+        let hashes = sparrow_arrow::hash::hash(input.as_ref()).map_err(|e| e.into_error())?;
+        Ok(hashes
+            .values()
+            .iter()
+            .enumerate()
+            .map(|(index, hash)| input.is_valid(index).then_some(*hash))
+            .collect())
+    }
+
+    fn outputs(outputs: Vec<Option<u32>>) -> ArrayRef {
+        Arc::new(UInt32Array::from(outputs))
+    }
+}
+
+macro_rules! boolean_window_agg_fn {
+    ($agg_fn:ty) => {
+        impl WindowAggFn for $agg_fn {
+            fn inputs(input: &ArrayRef) -> anyhow::Result<Vec<Option<bool>>> {
+                Ok(downcast_boolean_array(input.as_ref())?.iter().collect())
+            }
+
+            fn outputs(outputs: Vec<Option<bool>>) -> ArrayRef {
+                Arc::new(BooleanArray::from(outputs))
+            }
+        }
+    };
+}
+
+macro_rules! string_window_agg_fn {
+    ($agg_fn:ty) => {
+        impl WindowAggFn for $agg_fn {
+            fn inputs(input: &ArrayRef) -> anyhow::Result<Vec<Option<String>>> {
+                let input = downcast_string_array::<i32>(input.as_ref())?;
+                Ok(input.iter().map(|s| s.map(|s| s.to_owned())).collect())
+            }
+
+            fn outputs(outputs: Vec<Option<String>>) -> ArrayRef {
+                Arc::new(StringArray::from(outputs))
+            }
+        }
+    };
+}
+
+boolean_window_agg_fn!(FirstBoolean);
+boolean_window_agg_fn!(LastBoolean);
+string_window_agg_fn!(FirstString);
+string_window_agg_fn!(LastString);
@@ -0,0 +1,148 @@
+use serde::{Deserialize, Serialize};
+
+/// Number of bits of each hash used to select a register.
+const PRECISION: u32 = 12;
+
+/// Number of registers in each sketch.
+const NUM_REGISTERS: usize = 1 << PRECISION;
+
+/// A HyperLogLog sketch estimating the number of distinct values added to it.
+///
+/// Values are added by their 64-bit hash. The sketch uses 4096 registers,
+/// giving a standard error of roughly 1.6%. Small cardinalities are estimated
+/// using linear counting, which is nearly exact.
+///
+/// Registers are allocated on the first insertion, so sketches that haven't
+/// seen any values (such as those for entities without inputs in a window)
+/// take no space when stored.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    /// Add a value to the sketch, identified by its hash.
+    pub fn add_hash(&mut self, hash: u64) {
+        if self.registers.is_empty() {
+            self.registers.resize(NUM_REGISTERS, 0);
+        }
+
+        // The high bits select the register. The register records the
+        // maximum position of the first set bit in the remaining bits. The
+        // sentinel bit bounds the position if all remaining bits are zero.
+        let index = (hash >> (64 - PRECISION)) as usize;
+        let rank = ((hash << PRECISION) | (1 << (PRECISION - 1))).leading_zeros() + 1;
+        let register = &mut self.registers[index];
+        *register = (*register).max(rank as u8);
+    }
+
+    /// Merge the values in `other` into this sketch.
+    pub fn merge(&mut self, other: &HyperLogLog) {
+        if other.registers.is_empty() {
+            return;
+        }
+        if self.registers.is_empty() {
+            self.registers.clone_from(&other.registers);
+            return;
+        }
+
+        for (register, other) in self.registers.iter_mut().zip(&other.registers) {
+            *register = (*register).max(*other);
+        }
+    }
+
+    /// Remove all values from the sketch.
+    pub fn clear(&mut self) {
+        self.registers.clear();
+    }
+
+    /// Return the estimated number of distinct values in the sketch.
+    pub fn estimate(&self) -> u64 {
+        if self.registers.is_empty() {
+            return 0;
+        }
+
+        let m = NUM_REGISTERS as f64;
+        let (sum, zeros) = self
+            .registers
+            .iter()
+            .fold((0.0, 0), |(sum, zeros), register| {
+                (
+                    sum + 2f64.powi(-(*register as i32)),
+                    zeros + usize::from(*register == 0),
+                )
+            });
+
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let estimate = alpha * m * m / sum;
+        let estimate = if estimate <= 2.5 * m && zeros > 0 {
+            // Use linear counting for small cardinalities.
+            m * (m / zeros as f64).ln()
+        } else {
+            estimate
+        };
+        estimate.round() as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::UInt64Array;
+
+    use super::*;
+
+    fn sketch_of(values: impl IntoIterator<Item = u64>) -> HyperLogLog {
+        let values = UInt64Array::from_iter_values(values);
+        let hashes = sparrow_arrow::hash::hash(&values).unwrap();
+
+        let mut sketch = HyperLogLog::default();
+        hashes
+            .values()
+            .iter()
+            .for_each(|hash| sketch.add_hash(*hash));
+        sketch
+    }
+
+    #[test]
+    fn test_empty_sketch() {
+        let sketch = HyperLogLog::default();
+        assert_eq!(sketch.estimate(), 0);
+    }
+
+    #[test]
+    fn test_small_cardinality_is_exact() {
+        let sketch = sketch_of([5, 8, 5, 13, 8, 5]);
+        assert_eq!(sketch.estimate(), 3);
+    }
+
+    #[test]
+    fn test_large_cardinality_is_approximate() {
+        let sketch = sketch_of(0..100_000);
+        let estimate = sketch.estimate() as f64;
+        assert!(
+            (estimate - 100_000.0).abs() < 5_000.0,
+            "estimate {estimate} not within 5% of 100000"
+        );
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut sketch = sketch_of(0..10);
+        sketch.merge(&sketch_of(5..20));
+        assert_eq!(sketch, sketch_of(0..20));
+
+        let mut empty = HyperLogLog::default();
+        empty.merge(&sketch);
+        assert_eq!(empty, sketch);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut sketch = sketch_of(0..10);
+        sketch.clear();
+        assert_eq!(sketch.estimate(), 0);
+
+        sketch.add_hash(57);
+        assert_eq!(sketch.estimate(), 1);
+    }
+}
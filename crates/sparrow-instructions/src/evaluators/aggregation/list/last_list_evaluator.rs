@@ -1,13 +1,12 @@
 use std::sync::Arc;
 
+use arrow::array::{new_empty_array, Array, ArrayRef, AsArray, BooleanArray, UInt32Array};
+use sparrow_plan::ValueRef;
+
 use crate::{
-    AggregationArgs, Evaluator, EvaluatorFactory, ListAccumToken, RuntimeInfo, StateToken,
-    StaticInfo,
-};
-use arrow::array::{
-    as_list_array, new_empty_array, Array, ArrayRef, AsArray, PrimitiveArray, UInt32Array,
+    AggregationArgs, Evaluator, EvaluatorFactory, LastArray, ListAccumToken, RuntimeInfo,
+    StateToken, StaticInfo, TwoStacksArrayAccumToken, TwoStacksArrayEvaluator,
 };
-use sparrow_plan::ValueRef;
 
 /// Evaluator for the `Last` instruction on lists
 pub struct LastListEvaluator {
@@ -21,20 +20,33 @@ impl Evaluator for LastListEvaluator {
             AggregationArgs::NoWindow { input } => {
                 let grouping = info.grouping();
                 let input_vals = info.value(input)?.array_ref()?;
-                let result = Self::aggregate(
+                Self::aggregate(
                     &mut self.token,
                     grouping.num_groups(),
                     grouping.group_indices(),
                     &input_vals,
-                );
-
-                result
+                    None,
+                )
             }
-            AggregationArgs::Since { ticks: _, input: _ } => {
-                unimplemented!("windowed aggregation over lists")
+            AggregationArgs::Since { ticks, input } => {
+                let grouping = info.grouping();
+                let input_vals = info.value(input)?.array_ref()?;
+                let ticks = info.value(ticks)?.boolean_array()?;
+                Self::aggregate(
+                    &mut self.token,
+                    grouping.num_groups(),
+                    grouping.group_indices(),
+                    &input_vals,
+                    Some(ticks.as_ref()),
+                )
             }
-            AggregationArgs::Sliding { .. } => {
-                panic!("expected non-windowed or since-windowed aggregation, saw sliding.")
+            AggregationArgs::Sliding { .. }
+            | AggregationArgs::Trailing { .. }
+            | AggregationArgs::Session { .. } => {
+                unreachable!(
+                    "Expected Non-windowed or Since windowed aggregation, saw Sliding, Trailing \
+                     or Session."
+                )
             }
         }
     }
@@ -59,7 +71,17 @@ impl EvaluatorFactory for LastListEvaluator {
                 Ok(Box::new(Self { token, args }))
             }
             AggregationArgs::Sliding { .. } => {
-                unimplemented!("sliding window aggregation over list unsupported")
+                let token = TwoStacksArrayAccumToken::new();
+                let result_type = info.result_type.clone();
+                Ok(Box::new(TwoStacksArrayEvaluator::<LastArray> {
+                    args,
+                    token,
+                    result_type,
+                }))
+            }
+            AggregationArgs::Trailing { .. } | AggregationArgs::Session { .. } => {
+                // These are reported as errors during compilation.
+                anyhow::bail!("trailing and session window aggregation over lists unsupported")
             }
         }
     }
@@ -71,34 +93,45 @@ impl LastListEvaluator {
         token.resize(len)
     }
 
-    /// Returns the existing value for an entity if it exists, or a new value from the
-    /// input if it exists, or null if neither.
+    /// Returns the new value from the input for an entity if it exists, or the existing
+    /// value if it exists, or null if neither.
     ///
     /// Takes advantage of the `take` and `concat` kernels to avoid having to type the
     /// evaluator, keeping everything as ArrayRefs.
     ///
-    /// The output is taken from the concatenated batch of the old state and the new input.
-    /// If the old state's value is null, then the take index for that entity is the length
-    /// of the old state plus the current index (i.e. the index into the new input).
-    /// If not, then we keep the take index as the old state's index.
+    /// The output and new state are taken from the concatenated batch of the old state
+    /// and the new input. The take index for each entity is either the index of its old
+    /// state, the length of the old state plus the index into the new input, or null if
+    /// the entity has no value.
+    ///
+    /// If `window_since` is provided, the value of an entity is reset after it is
+    /// emitted for rows where the window is `true`.
     fn aggregate(
         token: &mut ListAccumToken,
         key_capacity: usize,
         key_indices: &UInt32Array,
         input: &ArrayRef,
+        window_since: Option<&BooleanArray>,
     ) -> anyhow::Result<ArrayRef> {
         Self::ensure_entity_capacity(token, key_capacity)?;
-        let list_input = as_list_array(input);
+        let state_len = token.accum.len();
 
-        let mut take_new_state: Vec<u32> = (0..token.accum.len() as u32).collect();
+        let mut take_new_state: Vec<Option<u32>> = (0..state_len)
+            .map(|index| token.accum.is_valid(index).then_some(index as u32))
+            .collect();
         let mut take_output_builder = UInt32Array::builder(input.len());
+        for input_index in 0..input.len() {
+            let entity_index = key_indices.value(input_index) as usize;
+            if input.is_valid(input_index) {
+                take_new_state[entity_index] = Some((state_len + input_index) as u32);
+            }
+            take_output_builder.append_option(take_new_state[entity_index]);
 
-        for input_index in 0..list_input.len() {
-            let entity_index = key_indices.value(input_index);
-            if list_input.is_valid(input_index) {
-                take_new_state[entity_index as usize] = (input_index + take_new_state.len()) as u32;
+            let reset_window = window_since
+                .is_some_and(|since| since.is_valid(input_index) && since.value(input_index));
+            if reset_window {
+                take_new_state[entity_index] = None;
             }
-            take_output_builder.append_value(take_new_state[entity_index as usize])
         }
 
         // Gather the output, using the previous state and the new input
@@ -106,7 +139,7 @@ impl LastListEvaluator {
             sparrow_arrow::concat_take(&token.accum, input, &take_output_builder.finish())?;
 
         // Update the state token with the new state
-        let take_new_state = PrimitiveArray::from_iter_values(take_new_state);
+        let take_new_state = UInt32Array::from(take_new_state);
         let new_state = sparrow_arrow::concat_take(&token.accum, input, &take_new_state)?;
         token.set_state(new_state);
 
@@ -147,7 +180,8 @@ mod tests {
 
         let input: ArrayRef = Arc::new(array);
         let result =
-            LastListEvaluator::aggregate(&mut token, key_capacity, &key_indices, &input).unwrap();
+            LastListEvaluator::aggregate(&mut token, key_capacity, &key_indices, &input, None)
+                .unwrap();
         let result = result.as_list();
 
         let mut builder = ListBuilder::new(Int64Builder::new());
@@ -177,7 +211,8 @@ mod tests {
         let key_indices = UInt32Array::from(vec![0, 1, 2, 1, 0, 1]);
         let key_capacity = 3;
         let result =
-            LastListEvaluator::aggregate(&mut token, key_capacity, &key_indices, &input).unwrap();
+            LastListEvaluator::aggregate(&mut token, key_capacity, &key_indices, &input, None)
+                .unwrap();
         let result = result.as_list();
 
         let mut builder = ListBuilder::new(Int64Builder::new());
@@ -191,4 +226,47 @@ mod tests {
 
         assert_eq!(&expected, result);
     }
+
+    #[test]
+    fn test_last_list_since() {
+        let mut token = default_token();
+        let key_indices = UInt32Array::from(vec![0, 0, 0, 0]);
+
+        let mut builder = ListBuilder::new(Int64Builder::new());
+        builder.append_value([Some(1)]);
+        builder.append_value([Some(2)]);
+        builder.append(false);
+        builder.append_value([Some(3)]);
+        let input: ArrayRef = Arc::new(builder.finish());
+        let since = BooleanArray::from(vec![false, true, false, true]);
+
+        let result =
+            LastListEvaluator::aggregate(&mut token, 1, &key_indices, &input, Some(&since))
+                .unwrap();
+        let result = result.as_list();
+
+        let mut builder = ListBuilder::new(Int64Builder::new());
+        builder.append_value([Some(1)]);
+        builder.append_value([Some(2)]);
+        builder.append(false);
+        builder.append_value([Some(3)]);
+        let expected = builder.finish();
+
+        assert_eq!(&expected, result);
+
+        // The value was reset by the last row of the previous batch.
+        let mut builder = ListBuilder::new(Int64Builder::new());
+        builder.append(false);
+        let input: ArrayRef = Arc::new(builder.finish());
+        let since = BooleanArray::from(vec![false]);
+        let result = LastListEvaluator::aggregate(
+            &mut token,
+            1,
+            &UInt32Array::from(vec![0]),
+            &input,
+            Some(&since),
+        )
+        .unwrap();
+        assert!(result.is_null(0));
+    }
 }
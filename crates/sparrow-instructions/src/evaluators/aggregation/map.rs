@@ -1,7 +1,9 @@
 //! Map aggregation evaluators.
 
+mod collect_map_evaluator;
 mod first_map_evaluator;
 mod last_map_evaluator;
 
+pub use collect_map_evaluator::*;
 pub use first_map_evaluator::*;
 pub use last_map_evaluator::*;
@@ -0,0 +1,312 @@
+use std::sync::Arc;
+
+use arrow::array::{new_empty_array, Array, ArrayRef, AsArray, BooleanArray, UInt32Array};
+use arrow::buffer::OffsetBuffer;
+use arrow::datatypes::{DataType, FieldRef};
+use arrow::row::{RowConverter, SortField};
+use sparrow_plan::ValueRef;
+
+use crate::evaluators::map::new_map_array;
+use crate::{
+    AggregationArgs, Evaluator, EvaluatorFactory, MapAccumToken, RuntimeInfo, StateToken,
+    StaticInfo,
+};
+
+/// Evaluator for the `collect_map` instruction.
+///
+/// Each entity has a map containing the latest value seen for each key.
+/// Rows with a `null` key or a `null` value are ignored. The result is an
+/// empty map if the entity has no entries.
+pub struct CollectMapEvaluator {
+    key: ValueRef,
+    args: AggregationArgs<ValueRef>,
+    /// The field describing the entries of the resulting map.
+    field: FieldRef,
+    /// Converts keys to rows, for comparing keys of any type.
+    row_converter: RowConverter,
+    token: MapAccumToken,
+}
+
+impl Evaluator for CollectMapEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let (input, ticks) = match &self.args {
+            AggregationArgs::NoWindow { input } => (input, None),
+            AggregationArgs::Since { ticks, input } => (input, Some(ticks)),
+            AggregationArgs::Sliding { .. }
+            | AggregationArgs::Trailing { .. }
+            | AggregationArgs::Session { .. } => {
+                unreachable!(
+                    "Expected Non-windowed or Since windowed aggregation, saw Sliding, Trailing \
+                     or Session."
+                )
+            }
+        };
+
+        let grouping = info.grouping();
+        let keys = info.value(&self.key)?.array_ref()?;
+        let values = info.value(input)?.array_ref()?;
+        let ticks = match ticks {
+            Some(ticks) => Some(info.value(ticks)?.boolean_array()?),
+            None => None,
+        };
+        self.aggregate(
+            grouping.num_groups(),
+            grouping.group_indices(),
+            &keys,
+            &values,
+            ticks.as_deref(),
+        )
+    }
+
+    fn state_token(&self) -> Option<&dyn StateToken> {
+        Some(&self.token)
+    }
+
+    fn state_token_mut(&mut self) -> Option<&mut dyn StateToken> {
+        Some(&mut self.token)
+    }
+}
+
+impl EvaluatorFactory for CollectMapEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        // The arguments are `[key, value, tick, duration, window_kind]`.
+        let mut args = info.args;
+        anyhow::ensure!(
+            args.len() == 5,
+            "'collect_map' should have 5 arguments. Saw {:?}",
+            args.len()
+        );
+        let key = args.remove(0).value_ref;
+
+        let field = match info.result_type {
+            DataType::Map(field, _) => field.clone(),
+            other => anyhow::bail!("expected map result type, saw {:?}", other),
+        };
+        let key_type = match field.data_type() {
+            DataType::Struct(fields) if fields.len() == 2 => fields[0].data_type().clone(),
+            other => anyhow::bail!("expected struct entries in map, saw {:?}", other),
+        };
+
+        let args = AggregationArgs::from_input(args)?;
+        match args {
+            AggregationArgs::NoWindow { .. } | AggregationArgs::Since { .. } => {
+                let row_converter = RowConverter::new(vec![SortField::new(key_type)])?;
+                let accum = new_empty_array(info.result_type);
+                let token = MapAccumToken::new(accum);
+                Ok(Box::new(Self {
+                    key,
+                    args,
+                    field,
+                    row_converter,
+                    token,
+                }))
+            }
+            AggregationArgs::Sliding { .. } => {
+                anyhow::bail!("Sliding windows are not supported for 'collect_map'")
+            }
+            AggregationArgs::Trailing { .. } => {
+                anyhow::bail!("Trailing windows are not supported for 'collect_map'")
+            }
+            AggregationArgs::Session { .. } => {
+                anyhow::bail!("Session windows are not supported for 'collect_map'")
+            }
+        }
+    }
+}
+
+impl CollectMapEvaluator {
+    /// Update the aggregation state with the given inputs and return the
+    /// aggregation.
+    ///
+    /// The `key_capacity` must be greater than all values in the
+    /// `entity_indices`.
+    ///
+    /// The entries of the output and new state are taken from the
+    /// concatenated entries of the old state and the new input. Each entity
+    /// tracks the indices of its current entries, replacing the index of an
+    /// existing entry when a key is seen again.
+    ///
+    /// If `window_since` is provided, the map of an entity is cleared after
+    /// it is emitted for rows where the window is `true`.
+    fn aggregate(
+        &mut self,
+        key_capacity: usize,
+        entity_indices: &UInt32Array,
+        keys: &ArrayRef,
+        values: &ArrayRef,
+        window_since: Option<&BooleanArray>,
+    ) -> anyhow::Result<ArrayRef> {
+        if self.token.accum.len() < key_capacity {
+            self.token.resize(key_capacity)?;
+        }
+        let state = self.token.accum.as_map();
+
+        let all_keys = arrow::compute::concat(&[state.keys().as_ref(), keys.as_ref()])?;
+        let all_values = arrow::compute::concat(&[state.values().as_ref(), values.as_ref()])?;
+        let key_rows = self
+            .row_converter
+            .convert_columns(std::slice::from_ref(&all_keys))?;
+
+        // The indices of the current entries of each entity.
+        let mut entries: Vec<Vec<u32>> = (0..state.len())
+            .map(|entity_index| {
+                let start = state.value_offsets()[entity_index] as u32;
+                let end = state.value_offsets()[entity_index + 1] as u32;
+                (start..end).collect()
+            })
+            .collect();
+
+        let state_len = state.keys().len();
+        let mut output_indices = Vec::new();
+        let mut output_offsets = Vec::with_capacity(keys.len() + 1);
+        output_offsets.push(0);
+        for input_index in 0..keys.len() {
+            let entity_index = entity_indices.value(input_index) as usize;
+            let entity_entries = &mut entries[entity_index];
+            if keys.is_valid(input_index) && values.is_valid(input_index) {
+                let index = (state_len + input_index) as u32;
+                let key = key_rows.row(index as usize);
+                match entity_entries
+                    .iter_mut()
+                    .find(|existing| key_rows.row(**existing as usize) == key)
+                {
+                    Some(existing) => *existing = index,
+                    None => entity_entries.push(index),
+                }
+            }
+
+            output_indices.extend_from_slice(entity_entries);
+            output_offsets.push(output_indices.len() as i32);
+
+            let reset_window = window_since
+                .is_some_and(|since| since.is_valid(input_index) && since.value(input_index));
+            if reset_window {
+                entity_entries.clear();
+            }
+        }
+
+        let output = self.take_map(&all_keys, &all_values, output_offsets, output_indices)?;
+
+        let mut state_offsets = Vec::with_capacity(entries.len() + 1);
+        state_offsets.push(0);
+        let mut state_indices = Vec::new();
+        for entity_entries in entries {
+            state_indices.extend(entity_entries);
+            state_offsets.push(state_indices.len() as i32);
+        }
+        let new_state = self.take_map(&all_keys, &all_values, state_offsets, state_indices)?;
+        self.token.set_state(new_state);
+
+        Ok(output)
+    }
+
+    /// Create a map with the entries at the given indices.
+    fn take_map(
+        &self,
+        keys: &ArrayRef,
+        values: &ArrayRef,
+        offsets: Vec<i32>,
+        indices: Vec<u32>,
+    ) -> anyhow::Result<ArrayRef> {
+        let indices = UInt32Array::from(indices);
+        let keys = arrow::compute::take(keys.as_ref(), &indices, None)?;
+        let values = arrow::compute::take(values.as_ref(), &indices, None)?;
+        let map = new_map_array(
+            &self.field,
+            OffsetBuffer::new(offsets.into()),
+            keys,
+            values,
+            None,
+        )?;
+        Ok(Arc::new(map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Int64Array, MapArray, StringArray};
+    use arrow::datatypes::{Field, Fields, Int64Type};
+
+    use super::*;
+
+    fn evaluator() -> CollectMapEvaluator {
+        let fields = Fields::from(vec![
+            Field::new("keys", DataType::Utf8, false),
+            Field::new("values", DataType::Int64, true),
+        ]);
+        let field = Arc::new(Field::new("entries", DataType::Struct(fields), false));
+        let map_type = DataType::Map(field.clone(), false);
+        CollectMapEvaluator {
+            key: ValueRef::Input(0),
+            args: AggregationArgs::NoWindow {
+                input: ValueRef::Input(1),
+            },
+            field,
+            row_converter: RowConverter::new(vec![SortField::new(DataType::Utf8)]).unwrap(),
+            token: MapAccumToken::new(new_empty_array(&map_type)),
+        }
+    }
+
+    fn entries(map: &MapArray, index: usize) -> Vec<(String, i64)> {
+        let entries = map.value(index);
+        let keys = entries.column(0).as_string::<i32>();
+        let values = entries.column(1).as_primitive::<Int64Type>();
+        keys.iter()
+            .zip(values.iter())
+            .map(|(key, value)| (key.unwrap().to_owned(), value.unwrap()))
+            .collect()
+    }
+
+    fn entry(key: &str, value: i64) -> (String, i64) {
+        (key.to_owned(), value)
+    }
+
+    #[test]
+    fn test_collect_map_multiple_batches() {
+        let mut evaluator = evaluator();
+
+        // Batch 1
+        let keys: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("a"),
+            Some("b"),
+            Some("a"),
+            None,
+            Some("c"),
+        ]));
+        let values: ArrayRef = Arc::new(Int64Array::from(vec![
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(4),
+            None,
+        ]));
+        let entity_indices = UInt32Array::from(vec![0, 0, 0, 1, 1]);
+        let result = evaluator
+            .aggregate(2, &entity_indices, &keys, &values, None)
+            .unwrap();
+        let result = result.as_map();
+
+        assert_eq!(result.null_count(), 0);
+        assert_eq!(entries(result, 0), vec![entry("a", 1)]);
+        assert_eq!(entries(result, 1), vec![entry("a", 1), entry("b", 2)]);
+        assert_eq!(entries(result, 2), vec![entry("a", 3), entry("b", 2)]);
+        assert_eq!(entries(result, 3), vec![]);
+        assert_eq!(entries(result, 4), vec![]);
+
+        // Batch 2, introducing a third entity and resetting after the
+        // second row.
+        let keys: ArrayRef = Arc::new(StringArray::from(vec!["c", "b", "d", "e"]));
+        let values: ArrayRef = Arc::new(Int64Array::from(vec![5, 6, 7, 8]));
+        let entity_indices = UInt32Array::from(vec![1, 0, 0, 2]);
+        let window = BooleanArray::from(vec![false, true, false, false]);
+        let result = evaluator
+            .aggregate(3, &entity_indices, &keys, &values, Some(&window))
+            .unwrap();
+        let result = result.as_map();
+
+        assert_eq!(entries(result, 0), vec![entry("c", 5)]);
+        assert_eq!(entries(result, 1), vec![entry("a", 3), entry("b", 6)]);
+        assert_eq!(entries(result, 2), vec![entry("d", 7)]);
+        assert_eq!(entries(result, 3), vec![entry("e", 8)]);
+    }
+}
@@ -11,8 +11,9 @@ use sparrow_plan::ValueRef;
 use super::two_stacks_arrow_agg_evaluator::TwoStacksArrowAggEvaluator;
 use crate::evaluators::aggregation::function::agg_fn::ArrowAggFn;
 use crate::{
-    AggregationArgs, Evaluator, EvaluatorFactory, PrimitiveAccumToken, RuntimeInfo, StateToken,
-    StaticInfo, TwoStacksPrimitiveAccumToken,
+    AggregationArgs, Evaluator, EvaluatorFactory, PrimitiveAccumToken, RuntimeInfo,
+    SessionAggEvaluator, StateToken, StaticInfo, TrailingAggEvaluator,
+    TwoStacksPrimitiveAccumToken,
 };
 
 /// Evaluator for arrow aggregations.
@@ -76,8 +77,13 @@ where
 
                 result
             }
-            AggregationArgs::Sliding { .. } => {
-                unreachable!("Expected Non-windowed or Since windowed aggregation, saw Sliding.")
+            AggregationArgs::Sliding { .. }
+            | AggregationArgs::Trailing { .. }
+            | AggregationArgs::Session { .. } => {
+                unreachable!(
+                    "Expected Non-windowed or Since windowed aggregation, saw Sliding, Trailing \
+                     or Session."
+                )
             }
         }
     }
@@ -108,6 +114,14 @@ where
                 let token = TwoStacksPrimitiveAccumToken::new();
                 Ok(Box::new(TwoStacksArrowAggEvaluator::<AggF> { token, args }))
             }
+            AggregationArgs::Trailing { input, duration } => {
+                Ok(Box::new(TrailingAggEvaluator::<AggF>::new(input, duration)))
+            }
+            AggregationArgs::Session { input, events, gap } => {
+                Ok(Box::new(SessionAggEvaluator::<AggF>::new(
+                    input, events, gap,
+                )))
+            }
         }
     }
 }
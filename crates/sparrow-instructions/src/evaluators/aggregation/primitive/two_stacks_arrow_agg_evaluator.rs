@@ -75,10 +75,13 @@ where
 
                 result
             }
-            AggregationArgs::NoWindow { .. } | AggregationArgs::Since { .. } => {
+            AggregationArgs::NoWindow { .. }
+            | AggregationArgs::Since { .. }
+            | AggregationArgs::Trailing { .. }
+            | AggregationArgs::Session { .. } => {
                 unreachable!(
-                    "Expected Sliding Window aggregation, saw Non-windowed or Since window \
-                     aggregation."
+                    "Expected Sliding Window aggregation, saw Non-windowed, Since, Trailing or \
+                     Session window aggregation."
                 )
             }
         }
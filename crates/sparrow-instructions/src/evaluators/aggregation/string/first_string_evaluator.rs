@@ -7,8 +7,8 @@ use sparrow_plan::ValueRef;
 
 use super::two_stacks_first_string_evaluator::TwoStacksFirstStringEvaluator;
 use crate::{
-    AggregationArgs, Evaluator, EvaluatorFactory, RuntimeInfo, StateToken, StaticInfo,
-    StringAccumToken, TwoStacksStringAccumToken,
+    AggregationArgs, Evaluator, EvaluatorFactory, FirstString, RuntimeInfo, SessionAggEvaluator,
+    StateToken, StaticInfo, StringAccumToken, TrailingAggEvaluator, TwoStacksStringAccumToken,
 };
 
 /// Evaluator for the `First` instruction on strings.
@@ -46,8 +46,13 @@ impl Evaluator for FirstStringEvaluator {
 
                 result
             }
-            AggregationArgs::Sliding { .. } => {
-                unreachable!("Expected Non-windowed or Since windowed aggregation, saw Sliding.")
+            AggregationArgs::Sliding { .. }
+            | AggregationArgs::Trailing { .. }
+            | AggregationArgs::Session { .. } => {
+                unreachable!(
+                    "Expected Non-windowed or Since windowed aggregation, saw Sliding, Trailing \
+                     or Session."
+                )
             }
         }
     }
@@ -73,6 +78,15 @@ impl EvaluatorFactory for FirstStringEvaluator {
                 let token = TwoStacksStringAccumToken::new();
                 Ok(Box::new(TwoStacksFirstStringEvaluator { token, args }))
             }
+            AggregationArgs::Trailing { input, duration } => {
+                Ok(Box::new(TrailingAggEvaluator::<FirstString>::new(
+                    input, duration,
+                )))
+            }
+            AggregationArgs::Session { input, events, gap } => {
+                let evaluator = SessionAggEvaluator::<FirstString>::new(input, events, gap);
+                Ok(Box::new(evaluator))
+            }
         }
     }
 }
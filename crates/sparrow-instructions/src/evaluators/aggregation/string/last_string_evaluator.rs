@@ -7,8 +7,8 @@ use sparrow_plan::ValueRef;
 
 use super::two_stacks_last_string_evaluator::TwoStacksLastStringEvaluator;
 use crate::{
-    AggregationArgs, Evaluator, EvaluatorFactory, RuntimeInfo, StateToken, StaticInfo,
-    StringAccumToken, TwoStacksStringAccumToken,
+    AggregationArgs, Evaluator, EvaluatorFactory, LastString, RuntimeInfo, SessionAggEvaluator,
+    StateToken, StaticInfo, StringAccumToken, TrailingAggEvaluator, TwoStacksStringAccumToken,
 };
 
 /// Evaluator for the `last` instruction on strings.
@@ -46,8 +46,13 @@ impl Evaluator for LastStringEvaluator {
 
                 result
             }
-            AggregationArgs::Sliding { .. } => {
-                unreachable!("Expected Non-windowed or Since windowed aggregation, saw Sliding.")
+            AggregationArgs::Sliding { .. }
+            | AggregationArgs::Trailing { .. }
+            | AggregationArgs::Session { .. } => {
+                unreachable!(
+                    "Expected Non-windowed or Since windowed aggregation, saw Sliding, Trailing \
+                     or Session."
+                )
             }
         }
     }
@@ -73,6 +78,16 @@ impl EvaluatorFactory for LastStringEvaluator {
                 let token = TwoStacksStringAccumToken::new();
                 Ok(Box::new(TwoStacksLastStringEvaluator { token, args }))
             }
+            AggregationArgs::Trailing { input, duration } => {
+                Ok(Box::new(TrailingAggEvaluator::<LastString>::new(
+                    input, duration,
+                )))
+            }
+            AggregationArgs::Session { input, events, gap } => {
+                Ok(Box::new(SessionAggEvaluator::<LastString>::new(
+                    input, events, gap,
+                )))
+            }
         }
     }
 }
@@ -0,0 +1,262 @@
+use std::f64::consts::PI;
+
+use serde::{Deserialize, Serialize};
+
+/// Compression parameter bounding the number of centroids in a digest.
+///
+/// Larger values produce more accurate quantiles using more space.
+const COMPRESSION: f64 = 100.0;
+
+/// Number of values buffered before they are merged into the centroids.
+const BUFFER_SIZE: usize = 500;
+
+/// A centroid summarizing `weight` values with the given `mean`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// A merging t-digest estimating quantiles of the values added to it.
+///
+/// Values are buffered and periodically merged into a bounded number of
+/// centroids. Centroids near the tails summarize fewer values than those
+/// near the median, so extreme quantiles (such as the 99th percentile) remain
+/// accurate. Small numbers of values are kept exactly.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TDigest {
+    /// Merged centroids, sorted by mean.
+    centroids: Vec<Centroid>,
+    /// Values added since the centroids were last merged.
+    buffer: Vec<f64>,
+    min: f64,
+    max: f64,
+}
+
+impl Default for TDigest {
+    fn default() -> Self {
+        Self {
+            centroids: Vec::new(),
+            buffer: Vec::new(),
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+}
+
+impl TDigest {
+    /// Add a value to the digest. `NaN` values are ignored.
+    pub fn add(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.buffer.push(value);
+        if self.buffer.len() >= BUFFER_SIZE {
+            self.compress();
+        }
+    }
+
+    /// Merge the values in `other` into this digest.
+    pub fn merge(&mut self, other: &TDigest) {
+        if other.is_empty() {
+            return;
+        }
+
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.buffer.extend_from_slice(&other.buffer);
+        self.centroids.extend_from_slice(&other.centroids);
+        self.compress();
+    }
+
+    /// Remove all values from the digest.
+    pub fn clear(&mut self) {
+        self.centroids.clear();
+        self.buffer.clear();
+        self.min = f64::INFINITY;
+        self.max = f64::NEG_INFINITY;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.centroids.is_empty() && self.buffer.is_empty()
+    }
+
+    /// Merge any buffered values into the centroids.
+    pub fn compress(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+
+        let mut centroids = std::mem::take(&mut self.centroids);
+        centroids.extend(
+            self.buffer
+                .drain(..)
+                .map(|mean| Centroid { mean, weight: 1.0 }),
+        );
+        self.centroids = merge_centroids(centroids);
+    }
+
+    /// Return the estimated value at quantile `q`, between 0 and 1.
+    ///
+    /// Any buffered values are merged into the centroids first.
+    ///
+    /// Returns `None` if the digest is empty.
+    pub fn quantile(&mut self, q: f64) -> Option<f64> {
+        self.compress();
+        self.quantile_of_centroids(q)
+    }
+
+    fn quantile_of_centroids(&self, q: f64) -> Option<f64> {
+        let (first, last) = match (self.centroids.first(), self.centroids.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return None,
+        };
+        if self.centroids.len() == 1 {
+            return Some(first.mean);
+        }
+
+        // Each centroid is treated as if its values were centered on the mean.
+        // Values between the centers of adjacent centroids are interpolated.
+        let total: f64 = self.centroids.iter().map(|c| c.weight).sum();
+        let target = q.clamp(0.0, 1.0) * total;
+
+        let first_center = first.weight / 2.0;
+        if target < first_center {
+            return Some(interpolate(self.min, first.mean, target / first_center));
+        }
+
+        let mut prev_center = first_center;
+        let mut cumulative = first.weight;
+        for (prev, next) in self.centroids.iter().zip(&self.centroids[1..]) {
+            let center = cumulative + next.weight / 2.0;
+            if target < center {
+                let fraction = (target - prev_center) / (center - prev_center);
+                return Some(interpolate(prev.mean, next.mean, fraction));
+            }
+            prev_center = center;
+            cumulative += next.weight;
+        }
+
+        let fraction = (target - prev_center) / (total - prev_center);
+        Some(interpolate(last.mean, self.max, fraction))
+    }
+}
+
+fn interpolate(a: f64, b: f64, fraction: f64) -> f64 {
+    a * (1.0 - fraction) + b * fraction
+}
+
+/// The scale function determining how many values a centroid may summarize.
+///
+/// Adjacent values are only merged if the scale of the resulting centroid
+/// spans at most 1. This limits centroids near the tails to fewer values.
+fn scale(q: f64) -> f64 {
+    COMPRESSION / (2.0 * PI) * (2.0 * q - 1.0).clamp(-1.0, 1.0).asin()
+}
+
+fn merge_centroids(mut centroids: Vec<Centroid>) -> Vec<Centroid> {
+    centroids.sort_by(|a, b| a.mean.total_cmp(&b.mean));
+    let total: f64 = centroids.iter().map(|c| c.weight).sum();
+
+    let mut merged = Vec::with_capacity(centroids.len());
+    let mut centroids = centroids.into_iter();
+    let Some(mut current) = centroids.next() else {
+        return merged;
+    };
+
+    // The weight of the centroids before `current`.
+    let mut weight_before = 0.0;
+    for next in centroids {
+        let q_start = weight_before / total;
+        let q_end = (weight_before + current.weight + next.weight) / total;
+        if scale(q_end) - scale(q_start) <= 1.0 {
+            current.weight += next.weight;
+            current.mean += (next.mean - current.mean) * next.weight / current.weight;
+        } else {
+            weight_before += current.weight;
+            merged.push(current);
+            current = next;
+        }
+    }
+    merged.push(current);
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digest_of(values: impl IntoIterator<Item = f64>) -> TDigest {
+        let mut digest = TDigest::default();
+        values.into_iter().for_each(|value| digest.add(value));
+        digest
+    }
+
+    #[test]
+    fn test_empty_digest() {
+        let mut digest = TDigest::default();
+        assert_eq!(digest.quantile(0.5), None);
+    }
+
+    #[test]
+    fn test_small_digest_is_exact() {
+        let mut digest = digest_of([5.0, 1.0, 3.0]);
+        assert_eq!(digest.quantile(0.0), Some(1.0));
+        assert_eq!(digest.quantile(0.5), Some(3.0));
+        assert_eq!(digest.quantile(1.0), Some(5.0));
+
+        let mut digest = digest_of([4.0, 1.0, 3.0, 2.0]);
+        assert_eq!(digest.quantile(0.5), Some(2.5));
+    }
+
+    #[test]
+    fn test_large_digest_is_approximate() {
+        let mut digest = digest_of((0..100_000).map(|i| i as f64));
+        assert_eq!(digest.quantile(0.0), Some(0.0));
+        assert_eq!(digest.quantile(1.0), Some(99_999.0));
+
+        for q in [0.01, 0.25, 0.5, 0.95, 0.99] {
+            let estimate = digest.quantile(q).unwrap();
+            let expected = q * 100_000.0;
+            assert!(
+                (estimate - expected).abs() < 500.0,
+                "quantile {q} was {estimate}, expected about {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_quantile_compresses_buffer() {
+        let mut digest = digest_of([5.0, 1.0, 3.0]);
+        assert_eq!(digest.quantile(0.5), Some(3.0));
+        assert!(digest.buffer.is_empty());
+
+        // Values added after computing a quantile are still included.
+        digest.add(7.0);
+        assert_eq!(digest.quantile(0.5), Some(4.0));
+        assert_eq!(digest.quantile(1.0), Some(7.0));
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut digest = digest_of((0..1000).map(|i| i as f64));
+        digest.merge(&digest_of((1000..2000).map(|i| i as f64)));
+
+        let median = digest.quantile(0.5).unwrap();
+        assert!((median - 1000.0).abs() < 20.0, "median was {median}");
+        assert_eq!(digest.quantile(1.0), Some(1999.0));
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut digest = digest_of([1.0, 2.0]);
+        digest.clear();
+        assert_eq!(digest.quantile(0.5), None);
+
+        digest.add(7.0);
+        assert_eq!(digest.quantile(0.5), Some(7.0));
+    }
+}
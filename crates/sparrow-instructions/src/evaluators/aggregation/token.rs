@@ -3,11 +3,18 @@
 mod boolean_accum_token;
 mod collect_struct_token;
 mod collect_token;
+mod collect_values_token;
 mod count_accum_token;
+mod hyperloglog_accum_token;
 mod list_accum_token;
 mod map_accum_token;
+mod ordered_value_accum_token;
 mod primitive_accum_token;
+mod session_accum_token;
 mod string_accum_token;
+mod tdigest_accum_token;
+mod trailing_accum_token;
+mod two_stacks_array_accum_token;
 mod two_stacks_boolean_accum_token;
 mod two_stacks_count_accum_token;
 mod two_stacks_primitive_accum_token;
@@ -16,11 +23,18 @@ mod two_stacks_string_accum_token;
 pub use boolean_accum_token::*;
 pub use collect_struct_token::*;
 pub use collect_token::*;
+pub use collect_values_token::*;
 pub use count_accum_token::*;
+pub use hyperloglog_accum_token::*;
 pub use list_accum_token::*;
 pub use map_accum_token::*;
+pub use ordered_value_accum_token::*;
 pub use primitive_accum_token::*;
+pub use session_accum_token::*;
 pub use string_accum_token::*;
+pub use tdigest_accum_token::*;
+pub use trailing_accum_token::*;
+pub use two_stacks_array_accum_token::*;
 pub use two_stacks_boolean_accum_token::*;
 pub use two_stacks_count_accum_token::*;
 pub use two_stacks_primitive_accum_token::*;
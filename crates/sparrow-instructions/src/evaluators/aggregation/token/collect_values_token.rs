@@ -0,0 +1,92 @@
+use arrow::array::{new_null_array, Array, ArrayRef, AsArray, ListArray};
+
+use crate::{ComputeStore, StateToken, StoreKey};
+
+/// Token used for the values collected by `top_k`, `most_frequent` and
+/// `collect_distinct`.
+///
+/// The values are serialized as an [ArrayRef], working directly with Arrow.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct CollectValuesToken {
+    /// A [ListArray] containing the values collected for each entity.
+    #[serde(with = "sparrow_arrow::serde::array_ref")]
+    pub values: ArrayRef,
+    /// The number of times each value has been seen.
+    ///
+    /// This is aligned with the (flattened) values of the list array.
+    pub counts: Vec<u64>,
+}
+
+impl StateToken for CollectValuesToken {
+    fn restore(&mut self, key: &StoreKey, store: &ComputeStore) -> anyhow::Result<()> {
+        if let Some(state) = store.get(key)? {
+            let state: CollectValuesToken = state;
+            self.values = state.values;
+            self.counts = state.counts;
+        };
+        Ok(())
+    }
+
+    fn store(&self, key: &StoreKey, store: &ComputeStore) -> anyhow::Result<()> {
+        store.put(key, &self)
+    }
+}
+
+impl CollectValuesToken {
+    pub fn new(values: ArrayRef) -> Self {
+        Self {
+            values,
+            counts: Vec::new(),
+        }
+    }
+
+    pub fn list(&self) -> &ListArray {
+        self.values.as_list()
+    }
+
+    /// Concat nulls to the end of the current values to grow the size.
+    pub fn resize(&mut self, len: usize) -> anyhow::Result<()> {
+        let diff = len - self.values.len();
+
+        let null_array = new_null_array(self.values.data_type(), diff);
+        self.values = arrow::compute::concat(&[&self.values, &null_array])?;
+        Ok(())
+    }
+
+    pub fn set_state(&mut self, values: ArrayRef, counts: Vec<u64>) {
+        debug_assert_eq!(values.as_list::<i32>().values().len(), counts.len());
+        self.values = values;
+        self.counts = counts;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::new_empty_array;
+    use arrow::datatypes::Int64Type;
+
+    use super::*;
+
+    #[test]
+    fn test_store_restore() {
+        let values = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![Some(3)]),
+        ]);
+        let mut token = CollectValuesToken::new(new_empty_array(values.data_type()));
+        token.set_state(Arc::new(values.clone()), vec![2, 1, 5]);
+
+        let tempdir = tempfile::Builder::new().tempdir().unwrap();
+        let store = ComputeStore::try_new_from_path(tempdir.path()).unwrap();
+        let key = StoreKey::new_accumulator(0, 0);
+        token.store(&key, &store).unwrap();
+
+        let mut restored = CollectValuesToken::new(new_empty_array(values.data_type()));
+        restored.restore(&key, &store).unwrap();
+        assert_eq!(restored.list(), &values);
+        assert_eq!(restored.counts, vec![2, 1, 5]);
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{ComputeStore, HyperLogLog, StateToken, StoreKey};
+
+/// Token used for the HyperLogLog sketches of `approx_count_distinct`.
+///
+/// Values are stored as `[pass_id, instruction_id] -> Vec<HyperLogLog>`.
+#[derive(Default)]
+pub struct HyperLogLogAccumToken {
+    /// Stores the state for in-memory usage.
+    accum: Vec<HyperLogLog>,
+}
+
+impl StateToken for HyperLogLogAccumToken {
+    fn restore(&mut self, key: &StoreKey, store: &ComputeStore) -> anyhow::Result<()> {
+        store.get_to_vec(key, &mut self.accum)
+    }
+
+    fn store(&self, key: &StoreKey, store: &ComputeStore) -> anyhow::Result<()> {
+        store.put(key, &self.accum)
+    }
+}
+
+impl HyperLogLogAccumToken {
+    pub(crate) fn resize(&mut self, len: usize) {
+        self.accum.resize(len, HyperLogLog::default());
+    }
+
+    pub(crate) fn get_value(&self, entity_index: u32) -> &HyperLogLog {
+        &self.accum[entity_index as usize]
+    }
+
+    pub(crate) fn add_hash(&mut self, entity_index: u32, hash: u64) {
+        self.accum[entity_index as usize].add_hash(hash);
+    }
+
+    pub(crate) fn reset_value(&mut self, entity_index: u32) {
+        self.accum[entity_index as usize].clear();
+    }
+}
@@ -0,0 +1,56 @@
+use arrow::array::{new_null_array, Array, ArrayRef};
+
+use crate::{ComputeStore, StateToken, StoreKey};
+
+/// Token used for accumulators selecting a value by an ordering.
+///
+/// Stores the selected value and its ordering for each entity, serialized
+/// as [ArrayRef]s.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct OrderedValueAccumToken {
+    /// The selected value for each entity.
+    #[serde(with = "sparrow_arrow::serde::array_ref")]
+    pub value: ArrayRef,
+    /// The ordering of the selected value for each entity.
+    ///
+    /// This is `null` if the entity has no selected value.
+    #[serde(with = "sparrow_arrow::serde::array_ref")]
+    pub ordering: ArrayRef,
+}
+
+impl StateToken for OrderedValueAccumToken {
+    fn restore(&mut self, key: &StoreKey, store: &ComputeStore) -> anyhow::Result<()> {
+        if let Some(state) = store.get(key)? {
+            let state: OrderedValueAccumToken = state;
+            self.value = state.value;
+            self.ordering = state.ordering;
+        };
+        Ok(())
+    }
+
+    fn store(&self, key: &StoreKey, store: &ComputeStore) -> anyhow::Result<()> {
+        store.put(key, &self)
+    }
+}
+
+impl OrderedValueAccumToken {
+    pub fn new(value: ArrayRef, ordering: ArrayRef) -> Self {
+        Self { value, ordering }
+    }
+
+    /// Concat nulls to the end of the current accumulators to grow the size.
+    pub fn resize(&mut self, len: usize) -> anyhow::Result<()> {
+        let diff = len - self.value.len();
+
+        let null_values = new_null_array(self.value.data_type(), diff);
+        self.value = arrow::compute::concat(&[&self.value, &null_values])?;
+        let null_orderings = new_null_array(self.ordering.data_type(), diff);
+        self.ordering = arrow::compute::concat(&[&self.ordering, &null_orderings])?;
+        Ok(())
+    }
+
+    pub fn set_state(&mut self, value: ArrayRef, ordering: ArrayRef) {
+        self.value = value;
+        self.ordering = ordering;
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{AggFn, ComputeStore, StateToken, StoreKey};
+
+/// Key used for accumulators of aggregations over sessions.
+///
+/// Each entity stores the time of the most recent input in the current
+/// session, along with the accumulator for the session.
+///
+/// Stored as `[pass_id, inst_id] -> Vec<T>`
+pub struct SessionAccumToken<AggF>
+where
+    AggF: AggFn,
+{
+    /// Stores the state for in-memory usage.
+    accum: Vec<(Option<i64>, AggF::AccT)>,
+}
+
+impl<AggF> StateToken for SessionAccumToken<AggF>
+where
+    AggF: AggFn,
+    Vec<(Option<i64>, AggF::AccT)>: serde::ser::Serialize + serde::de::DeserializeOwned,
+{
+    fn restore(&mut self, key: &StoreKey, store: &ComputeStore) -> anyhow::Result<()> {
+        store.get_to_vec(key, &mut self.accum)
+    }
+
+    fn store(&self, key: &StoreKey, store: &ComputeStore) -> anyhow::Result<()> {
+        store.put(key, &self.accum)
+    }
+}
+
+impl<AggF> SessionAccumToken<AggF>
+where
+    AggF: AggFn,
+{
+    pub(crate) fn new() -> Self {
+        Self { accum: Vec::new() }
+    }
+
+    pub(crate) fn get_accum(&mut self) -> anyhow::Result<Vec<(Option<i64>, AggF::AccT)>> {
+        Ok(std::mem::take(&mut self.accum))
+    }
+
+    pub(crate) fn put_accum(
+        &mut self,
+        accum: Vec<(Option<i64>, AggF::AccT)>,
+    ) -> anyhow::Result<()> {
+        self.accum = accum;
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{ComputeStore, StateToken, StoreKey, TDigest};
+
+/// Token used for the t-digests of `approx_percentile`.
+///
+/// Values are stored as `[pass_id, instruction_id] -> Vec<TDigest>`.
+#[derive(Default)]
+pub struct TDigestAccumToken {
+    /// Stores the state for in-memory usage.
+    accum: Vec<TDigest>,
+}
+
+impl StateToken for TDigestAccumToken {
+    fn restore(&mut self, key: &StoreKey, store: &ComputeStore) -> anyhow::Result<()> {
+        store.get_to_vec(key, &mut self.accum)
+    }
+
+    fn store(&self, key: &StoreKey, store: &ComputeStore) -> anyhow::Result<()> {
+        store.put(key, &self.accum)
+    }
+}
+
+impl TDigestAccumToken {
+    pub(crate) fn resize(&mut self, len: usize) {
+        self.accum.resize(len, TDigest::default());
+    }
+
+    pub(crate) fn quantile(&mut self, entity_index: u32, q: f64) -> Option<f64> {
+        self.accum[entity_index as usize].quantile(q)
+    }
+
+    pub(crate) fn add_value(&mut self, entity_index: u32, value: f64) {
+        self.accum[entity_index as usize].add(value);
+    }
+
+    pub(crate) fn reset_value(&mut self, entity_index: u32) {
+        self.accum[entity_index as usize].clear();
+    }
+}
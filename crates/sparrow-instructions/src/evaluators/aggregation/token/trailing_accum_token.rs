@@ -0,0 +1,44 @@
+use crate::{AggFn, ComputeStore, StateToken, StoreKey, TrailingTwoStacks};
+
+/// Key used for accumulators of aggregations over a trailing duration.
+///
+/// Stored as `[pass_id, inst_id] -> Vec<T>`
+pub struct TrailingAccumToken<AggF>
+where
+    AggF: AggFn,
+{
+    /// Stores the state for in-memory usage.
+    accum: Vec<TrailingTwoStacks<AggF>>,
+}
+
+impl<AggF> StateToken for TrailingAccumToken<AggF>
+where
+    AggF: AggFn,
+    Vec<TrailingTwoStacks<AggF>>: serde::ser::Serialize + serde::de::DeserializeOwned,
+{
+    fn restore(&mut self, key: &StoreKey, store: &ComputeStore) -> anyhow::Result<()> {
+        store.get_to_vec(key, &mut self.accum)
+    }
+
+    fn store(&self, key: &StoreKey, store: &ComputeStore) -> anyhow::Result<()> {
+        store.put(key, &self.accum)
+    }
+}
+
+impl<AggF> TrailingAccumToken<AggF>
+where
+    AggF: AggFn,
+{
+    pub(crate) fn new() -> Self {
+        Self { accum: Vec::new() }
+    }
+
+    pub(crate) fn get_accum(&mut self) -> anyhow::Result<Vec<TrailingTwoStacks<AggF>>> {
+        Ok(std::mem::take(&mut self.accum))
+    }
+
+    pub(crate) fn put_accum(&mut self, accum: Vec<TrailingTwoStacks<AggF>>) -> anyhow::Result<()> {
+        self.accum = accum;
+        Ok(())
+    }
+}
@@ -151,3 +151,130 @@ impl<AggF: AggFn> TwoStacks<AggF> {
         self.outgoing.last()
     }
 }
+
+/// Accumulator for aggregation over a trailing duration using Two Stacks
+/// implementation.
+///
+/// Unlike [TwoStacks], which evicts a window part each time the window
+/// slides, each window part contains the inputs occurring at a single time.
+/// This allows contributions to be evicted once they are older than the
+/// duration of the window.
+#[derive(Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "AggF: AggFn, AggF::AccT: Serialize",
+    deserialize = "AggF: AggFn, AggF::AccT: DeserializeOwned"
+))]
+pub struct TrailingTwoStacks<AggF: AggFn> {
+    /// Window parts that haven't been "flipped", with the time of the inputs
+    /// they contain. Newest at the end.
+    incoming: Vec<(i64, WindowPart<AggF::AccT>)>,
+    /// Window parts that have been "flipped", with the time of the inputs
+    /// they contain. Oldest at the end.
+    outgoing: Vec<(i64, WindowPart<AggF::AccT>)>,
+}
+
+impl<AggF: AggFn> std::fmt::Debug for TrailingTwoStacks<AggF> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TrailingTwoStacks")
+            .field("incoming", &self.incoming)
+            .field("outgoing", &self.outgoing)
+            .finish()
+    }
+}
+
+impl<AggF> Clone for TrailingTwoStacks<AggF>
+where
+    AggF: AggFn,
+    AggF::AccT: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            incoming: self.incoming.clone(),
+            outgoing: self.outgoing.clone(),
+        }
+    }
+}
+
+impl<AggF: AggFn> Default for TrailingTwoStacks<AggF> {
+    fn default() -> Self {
+        Self {
+            incoming: Vec::new(),
+            outgoing: Vec::new(),
+        }
+    }
+}
+
+impl<AggF: AggFn> TrailingTwoStacks<AggF> {
+    /// Returns the aggregate value of all inputs within the window.
+    pub fn accum_value(&self) -> AggF::AccT {
+        // The `outgoing` stack contains values occurring earlier than the
+        // `incoming` stack, so we merge the `incoming` into the `outgoing`.
+        let mut accum = match self.outgoing.last() {
+            Some((_, outgoing)) => outgoing.cumulative.clone(),
+            None => AggF::zero(),
+        };
+        if let Some((_, incoming)) = self.incoming.last() {
+            AggF::merge(&mut accum, &incoming.cumulative);
+        }
+        accum
+    }
+
+    /// Adds a single input occurring at `time` to the window.
+    ///
+    /// Inputs must be added in order of non-decreasing time.
+    pub fn add_input(&mut self, time: i64, input: &AggF::InT) {
+        match self.incoming.last_mut() {
+            Some((part_time, part)) if *part_time == time => {
+                AggF::add_one(&mut part.accum, input);
+                AggF::add_one(&mut part.cumulative, input);
+            }
+            last => {
+                debug_assert!(!matches!(&last, Some(part) if part.0 > time));
+                let mut cumulative = match last {
+                    Some((_, part)) => part.cumulative.clone(),
+                    None => AggF::zero(),
+                };
+                AggF::add_one(&mut cumulative, input);
+                self.incoming
+                    .push((time, WindowPart::new(AggF::one(input), cumulative)));
+            }
+        }
+    }
+
+    /// Evicts all inputs occurring at or before `bound`.
+    pub fn evict_until(&mut self, bound: i64) {
+        loop {
+            if self.outgoing.is_empty() {
+                // Only flip if the oldest incoming part needs to be evicted.
+                match self.incoming.first() {
+                    Some((time, _)) if *time <= bound => self.flip(),
+                    _ => return,
+                }
+            }
+
+            match self.outgoing.last() {
+                Some((time, _)) if *time <= bound => {
+                    self.outgoing.pop();
+                }
+                _ => return,
+            }
+        }
+    }
+
+    fn flip(&mut self) {
+        debug_assert!(self.outgoing.is_empty());
+        std::mem::swap(&mut self.incoming, &mut self.outgoing);
+        self.outgoing.reverse();
+
+        // Fix up the cumulatives to reflect the new reversed order.
+        // Each item should be the aggregate of its accumulator and the
+        // (newer) accumulators below it.
+        let mut newer = AggF::zero();
+        for (_, outgoing) in &mut self.outgoing {
+            let mut cumulative = outgoing.accum.clone();
+            AggF::merge(&mut cumulative, &newer);
+            outgoing.cumulative = cumulative.clone();
+            newer = cumulative;
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! The case instruction isn't a "normal" instruction since it takes
+//! a variable number of arguments alternating between conditions and
+//! values.
+
+use anyhow::Context;
+use arrow::array::{Array, ArrayRef};
+use itertools::Itertools;
+use sparrow_plan::ValueRef;
+
+use crate::{Evaluator, EvaluatorFactory, RuntimeInfo, StaticInfo};
+
+/// Evaluator for the `case` instruction.
+#[derive(Debug)]
+pub struct CaseEvaluator {
+    /// The condition and value of each arm, in order.
+    arms: Vec<(ValueRef, ValueRef)>,
+    default: ValueRef,
+}
+
+impl Evaluator for CaseEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        // Apply the arms in reverse order, so that the first arm with a true
+        // condition determines the result.
+        let mut result = info.value(&self.default)?.array_ref()?;
+        for (condition, value) in self.arms.iter().rev() {
+            let condition = info.value(condition)?.boolean_array()?;
+            let value = info.value(value)?.array_ref()?;
+
+            // A null condition is treated as false.
+            let condition = if condition.null_count() > 0 {
+                arrow::compute::prep_null_mask_filter(&condition)
+            } else {
+                (*condition).clone()
+            };
+            result =
+                arrow::compute::kernels::zip::zip(&condition, value.as_ref(), result.as_ref())?;
+        }
+        Ok(result)
+    }
+}
+
+impl EvaluatorFactory for CaseEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let num_args = info.args.len();
+        anyhow::ensure!(
+            num_args % 2 == 1,
+            "Expected odd number of arguments (condition and value pairs, then default) for \
+             case, but was {num_args}",
+        );
+
+        let result_type = info.result_type;
+        for value in info.args.iter().skip(1).step_by(2) {
+            anyhow::ensure!(
+                &value.data_type == result_type,
+                "Expected case value type ({:?}) to match result type ({:?})",
+                value.data_type,
+                result_type
+            );
+        }
+
+        let mut args = info.args.into_iter().map(|arg| arg.value_ref);
+        let default = args.next_back().context("default for case")?;
+        let arms = args.tuples().collect();
+        Ok(Box::new(Self { arms, default }))
+    }
+}
@@ -7,7 +7,7 @@ use std::sync::Arc;
 use anyhow::anyhow;
 use arrow::array::{ArrayRef, Int32Array, IntervalDayTimeArray, IntervalYearMonthArray};
 use arrow::datatypes::DataType;
-use sparrow_arrow::downcast::downcast_primitive_array;
+use sparrow_arrow::downcast::{downcast_primitive_array, downcast_string_array};
 use sparrow_kernels::time::i64_to_two_i32;
 use sparrow_plan::ValueRef;
 use sparrow_syntax::FenlType;
@@ -46,6 +46,8 @@ impl CastEvaluator {
                 ),
                 ty,
             ) if CastEvaluator::is_supported_interval_cast(ty) => true,
+            // Strings are cast to structs by parsing them as JSON.
+            (DataType::Utf8 | DataType::LargeUtf8, DataType::Struct(_)) => true,
             _ => false,
         }
     }
@@ -89,6 +91,11 @@ impl CastEvaluator {
                     input.data_type()
                 )),
             },
+            (DataType::Utf8 | DataType::LargeUtf8, DataType::Struct(_)) => {
+                let input = arrow::compute::cast(input, &DataType::Utf8)?;
+                let input = downcast_string_array(input.as_ref())?;
+                sparrow_kernels::json::parse_json(input, to)
+            }
             _ => arrow::compute::cast(input, to).map_err(|e| e.into()),
         }
     }
@@ -61,7 +61,16 @@ impl JsonFieldEvaluator {
             .iter()
             .map(|json| {
                 if let Some(json) = json {
-                    let value = &json[&self.field_name];
+                    let value = match json {
+                        // Elements of arrays are accessed by their (zero-based) index.
+                        serde_json::Value::Array(elements) => self
+                            .field_name
+                            .parse::<usize>()
+                            .ok()
+                            .and_then(|index| elements.get(index))
+                            .unwrap_or(&serde_json::Value::Null),
+                        _ => &json[&self.field_name],
+                    };
                     if value.is_null() {
                         // No value - field does not exist in this json object.
                         None
@@ -164,6 +173,25 @@ mod tests {
         assert!(unwrapped.is_null())
     }
 
+    #[test]
+    fn test_values_from_array_index() {
+        let jsons = vec![
+            Some(serde_json::json!([10, {"a": "dog"}])),
+            Some(serde_json::json!(["cat"])),
+            Some(serde_json::json!({"1": "bird"})),
+            None,
+        ];
+        let evaluator = JsonFieldEvaluator {
+            json_string: ValueRef::Inst(0u32),
+            field_name: "1".to_owned(),
+        };
+        let result = evaluator.values_from_field(jsons).unwrap();
+        assert_eq!(
+            result,
+            StringArray::from(vec![Some("{\"a\":\"dog\"}"), None, Some("bird"), None])
+        );
+    }
+
     #[test]
     fn test_creating_with_valid_field_name_type() {
         let node = ValueRef::Inst(0u32);
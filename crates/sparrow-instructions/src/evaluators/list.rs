@@ -4,7 +4,16 @@ mod collect_string;
 mod collect_struct;
 mod flatten;
 mod index;
+mod list_contains;
+mod list_distinct;
+mod list_elements;
+mod list_filter;
 mod list_len;
+mod list_map;
+mod list_mean;
+mod list_slice;
+mod list_sort;
+mod list_sum;
 mod union;
 
 pub(super) use collect_boolean::*;
@@ -13,5 +22,14 @@ pub(super) use collect_string::*;
 pub(super) use collect_struct::*;
 pub(super) use flatten::*;
 pub(super) use index::*;
+pub(super) use list_contains::*;
+pub(super) use list_distinct::*;
+pub(super) use list_elements::*;
+pub(super) use list_filter::*;
 pub(super) use list_len::*;
+pub(super) use list_map::*;
+pub(super) use list_mean::*;
+pub(super) use list_slice::*;
+pub(super) use list_sort::*;
+pub(super) use list_sum::*;
 pub(super) use union::*;
@@ -78,7 +78,7 @@ where
         };
         debug_assert!(min <= max, "min must be less than max");
 
-        let (input, _, _, tick, duration) = info.unpack_arguments()?;
+        let (input, _, _, tick, duration, _) = info.unpack_arguments()?;
         Ok(Box::new(Self {
             min,
             max,
@@ -64,7 +64,7 @@ impl EvaluatorFactory for CollectStringEvaluator {
         };
         assert!(min < max, "min must be less than max");
 
-        let (input, _, _, tick, duration) = info.unpack_arguments()?;
+        let (input, _, _, tick, duration, _) = info.unpack_arguments()?;
         Ok(Box::new(Self {
             min,
             max,
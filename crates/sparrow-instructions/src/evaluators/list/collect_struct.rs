@@ -74,7 +74,7 @@ impl EvaluatorFactory for CollectStructEvaluator {
 
         let accum = new_empty_array(result_type).as_list::<i32>().to_owned();
         let token = CollectStructToken::new(Arc::new(accum));
-        let (input, _, _, tick, duration) = info.unpack_arguments()?;
+        let (input, _, _, tick, duration, _) = info.unpack_arguments()?;
         Ok(Box::new(Self {
             min,
             max,
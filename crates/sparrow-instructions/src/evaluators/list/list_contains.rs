@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, AsArray, BooleanArray, ListArray};
+use arrow::row::{RowConverter, SortField};
+use arrow_schema::DataType;
+use itertools::Itertools;
+use sparrow_plan::ValueRef;
+
+use crate::{Evaluator, EvaluatorFactory, RuntimeInfo, StaticInfo};
+
+/// Evaluator for the `list_contains` instruction.
+///
+/// Produces `true` if the list contains an element equal to the value. The
+/// result is `null` if the list or the value is `null`.
+#[derive(Debug)]
+pub(in crate::evaluators) struct ListContainsEvaluator {
+    list: ValueRef,
+    value: ValueRef,
+    row_converter: RowConverter,
+}
+
+impl EvaluatorFactory for ListContainsEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let DataType::List(field) = info.args[0].data_type() else {
+            anyhow::bail!("expected list type, saw {:?}", info.args[0].data_type())
+        };
+        anyhow::ensure!(field.data_type() == info.args[1].data_type());
+        let row_converter = RowConverter::new(vec![SortField::new(field.data_type().clone())])?;
+
+        let (list, value) = info.unpack_arguments()?;
+        Ok(Box::new(Self {
+            list,
+            value,
+            row_converter,
+        }))
+    }
+}
+
+impl Evaluator for ListContainsEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let list = info.value(&self.list)?.array_ref()?;
+        let value = info.value(&self.value)?.array_ref()?;
+        let result = list_contains(&mut self.row_converter, list.as_list(), &value)?;
+        Ok(Arc::new(result))
+    }
+}
+
+fn list_contains(
+    row_converter: &mut RowConverter,
+    list: &ListArray,
+    value: &ArrayRef,
+) -> anyhow::Result<BooleanArray> {
+    anyhow::ensure!(list.len() == value.len());
+
+    let elements = row_converter.convert_columns(&[list.values().clone()])?;
+    let values = row_converter.convert_columns(std::slice::from_ref(value))?;
+
+    let result =
+        list.value_offsets()
+            .iter()
+            .map(|offset| *offset as usize)
+            .tuple_windows()
+            .enumerate()
+            .map(|(index, (start, end))| {
+                if list.is_null(index) || value.is_null(index) {
+                    return None;
+                }
+
+                let value = values.row(index);
+                Some((start..end).any(|element| {
+                    list.values().is_valid(element) && elements.row(element) == value
+                }))
+            })
+            .collect::<Vec<_>>();
+    Ok(BooleanArray::from(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{ListBuilder, StringArray, StringBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_list_contains() {
+        let mut builder = ListBuilder::new(StringBuilder::new());
+        builder.append_value([Some("a"), Some("b")]);
+        builder.append_value([Some("a"), None]);
+        builder.append(false);
+        builder.append_value([Some("c")]);
+        builder.append_value(Vec::<Option<String>>::new());
+        let list = builder.finish();
+
+        let value: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("b"),
+            Some("b"),
+            Some("a"),
+            None,
+            Some("a"),
+        ]));
+        let mut row_converter = RowConverter::new(vec![SortField::new(DataType::Utf8)]).unwrap();
+        let result = list_contains(&mut row_converter, &list, &value).unwrap();
+        assert_eq!(
+            result,
+            BooleanArray::from(vec![Some(true), Some(false), None, None, Some(false)])
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, AsArray, ListArray, UInt32Array};
+use arrow::buffer::OffsetBuffer;
+use arrow::row::{RowConverter, SortField};
+use arrow_schema::{DataType, FieldRef};
+use hashbrown::HashSet;
+use itertools::Itertools;
+use sparrow_plan::ValueRef;
+
+use crate::{Evaluator, EvaluatorFactory, RuntimeInfo, StaticInfo};
+
+/// Evaluator for the `list_distinct` instruction.
+///
+/// Removes duplicate elements from each list, keeping the first occurrence
+/// of each element.
+#[derive(Debug)]
+pub(in crate::evaluators) struct ListDistinctEvaluator {
+    list: ValueRef,
+    field: FieldRef,
+    row_converter: RowConverter,
+}
+
+impl EvaluatorFactory for ListDistinctEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let field = match info.args[0].data_type() {
+            DataType::List(field) => field.clone(),
+            other => anyhow::bail!("expected list type, saw {:?}", other),
+        };
+        let row_converter = RowConverter::new(vec![SortField::new(field.data_type().clone())])?;
+
+        let list = info.unpack_argument()?;
+        Ok(Box::new(Self {
+            list,
+            field,
+            row_converter,
+        }))
+    }
+}
+
+impl Evaluator for ListDistinctEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let list = info.value(&self.list)?.array_ref()?;
+        list_distinct(&mut self.row_converter, list.as_list(), self.field.clone())
+    }
+}
+
+fn list_distinct(
+    row_converter: &mut RowConverter,
+    list: &ListArray,
+    field: FieldRef,
+) -> anyhow::Result<ArrayRef> {
+    let rows = row_converter.convert_columns(&[list.values().clone()])?;
+
+    let mut indices = Vec::new();
+    let mut lengths = Vec::with_capacity(list.len());
+    let mut included = HashSet::new();
+    for (start, end) in list
+        .value_offsets()
+        .iter()
+        .map(|offset| *offset as usize)
+        .tuple_windows()
+    {
+        let before = indices.len();
+        for index in start..end {
+            if included.insert(rows.row(index)) {
+                indices.push(index as u32);
+            }
+        }
+        lengths.push(indices.len() - before);
+        included.clear();
+    }
+
+    let values = arrow::compute::take(list.values().as_ref(), &UInt32Array::from(indices), None)?;
+    let result = ListArray::try_new(
+        field,
+        OffsetBuffer::from_lengths(lengths),
+        values,
+        list.nulls().cloned(),
+    )?;
+    Ok(Arc::new(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Int32Array, ListBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_list_distinct() {
+        let mut builder = ListBuilder::new(Int32Array::builder(8));
+        builder.append_value([Some(3), Some(1), Some(3), None, Some(1), None]);
+        builder.append(false);
+        builder.append_value([]);
+        builder.append_value([Some(5)]);
+        let list = builder.finish();
+        let DataType::List(field) = list.data_type() else {
+            panic!("expected list")
+        };
+
+        let mut row_converter = RowConverter::new(vec![SortField::new(DataType::Int32)]).unwrap();
+        let result = list_distinct(&mut row_converter, &list, field.clone()).unwrap();
+
+        let mut expected = ListBuilder::new(Int32Array::builder(8));
+        expected.append_value([Some(3), Some(1), None]);
+        expected.append(false);
+        expected.append_value([]);
+        expected.append_value([Some(5)]);
+        assert_eq!(result.as_list::<i32>(), &expected.finish());
+    }
+}
@@ -0,0 +1,184 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, AsArray, ListArray, UInt32Array};
+use arrow::buffer::OffsetBuffer;
+use arrow_schema::DataType;
+use itertools::Itertools;
+use sparrow_plan::ValueRef;
+
+use crate::{ColumnarValue, Evaluator, EvaluatorFactory, RuntimeInfo, StaticInfo};
+
+/// Evaluator for the `list_elements` instruction.
+///
+/// Produces the flattened elements of each list. This is the value the
+/// parameter of a lambda is bound to, so the body of the lambda is evaluated
+/// once for each element rather than once for each row.
+#[derive(Debug)]
+pub(in crate::evaluators) struct ListElementsEvaluator {
+    list: ValueRef,
+}
+
+impl EvaluatorFactory for ListElementsEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        match info.args[0].data_type() {
+            DataType::List(field) => anyhow::ensure!(field.data_type() == info.result_type),
+            other => anyhow::bail!("expected list type, saw {:?}", other),
+        };
+
+        let list = info.unpack_argument()?;
+        Ok(Box::new(Self { list }))
+    }
+}
+
+impl Evaluator for ListElementsEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let list = info.value(&self.list)?.array_ref()?;
+        Ok(elements(list.as_list()))
+    }
+}
+
+/// Evaluator for the `list_broadcast` instruction.
+///
+/// Repeats the value of each row once for each element of the list in that
+/// row. This allows values from outside of a lambda to be combined with the
+/// elements the lambda is applied to.
+#[derive(Debug)]
+pub(in crate::evaluators) struct ListBroadcastEvaluator {
+    list: ValueRef,
+    value: ValueRef,
+}
+
+impl EvaluatorFactory for ListBroadcastEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        match info.args[0].data_type() {
+            DataType::List(_) => (),
+            other => anyhow::bail!("expected list type, saw {:?}", other),
+        };
+
+        let (list, value) = info.unpack_arguments()?;
+        Ok(Box::new(Self { list, value }))
+    }
+}
+
+impl Evaluator for ListBroadcastEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let list = info.value(&self.list)?.array_ref()?;
+        let list: &ListArray = list.as_list();
+        match info.value(&self.value)? {
+            ColumnarValue::Literal { literal, .. } => Ok(literal.to_array(num_elements(list))),
+            ColumnarValue::Array(value) => {
+                anyhow::ensure!(value.len() == list.len());
+                let indices = element_rows(list);
+                Ok(arrow::compute::take(value.as_ref(), &indices, None)?)
+            }
+        }
+    }
+}
+
+/// Return the elements of all the lists in the array.
+///
+/// Unlike `ListArray::values`, this only includes the elements referenced by
+/// the (possibly sliced) list array.
+pub(super) fn elements(list: &ListArray) -> ArrayRef {
+    let offsets = list.value_offsets();
+    let start = offsets[0] as usize;
+    list.values().slice(start, num_elements(list))
+}
+
+/// Return the number of elements in all the lists in the array.
+pub(super) fn num_elements(list: &ListArray) -> usize {
+    let offsets = list.value_offsets();
+    (offsets[list.len()] - offsets[0]) as usize
+}
+
+/// Return the index of the row each element belongs to.
+pub(super) fn element_rows(list: &ListArray) -> UInt32Array {
+    let mut rows = Vec::with_capacity(num_elements(list));
+    for (row, (start, end)) in list.value_offsets().iter().tuple_windows().enumerate() {
+        rows.extend(std::iter::repeat_n(row as u32, (end - start) as usize));
+    }
+    UInt32Array::from(rows)
+}
+
+/// Return the offsets of each list relative to the elements.
+///
+/// Used when creating a list array from the (possibly transformed) result of
+/// `elements`.
+pub(super) fn element_offsets(list: &ListArray) -> OffsetBuffer<i32> {
+    let start = list.value_offsets()[0];
+    let offsets = list.value_offsets().iter().map(|offset| offset - start);
+    OffsetBuffer::new(offsets.collect())
+}
+
+/// Create a list array with the same lists (and null lists) as `list`
+/// containing the given elements.
+pub(super) fn with_elements(
+    list: &ListArray,
+    field: arrow_schema::FieldRef,
+    elements: ArrayRef,
+) -> anyhow::Result<ArrayRef> {
+    anyhow::ensure!(elements.len() == num_elements(list));
+    let result = ListArray::try_new(
+        field,
+        element_offsets(list),
+        elements,
+        list.nulls().cloned(),
+    )?;
+    Ok(Arc::new(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Array, AsArray, Int32Array, ListBuilder};
+    use arrow::datatypes::Int32Type;
+
+    use super::*;
+
+    #[test]
+    fn test_elements_of_sliced_list() {
+        let mut builder = ListBuilder::new(Int32Array::builder(8));
+        builder.append_value([Some(1), Some(2)]);
+        builder.append_value([Some(3)]);
+        builder.append(false);
+        builder.append_value([Some(4), None, Some(5)]);
+        let list = builder.finish();
+        let list = list.slice(1, 3);
+
+        let elements = elements(&list);
+        let elements: &Int32Array = elements.as_primitive::<Int32Type>();
+        assert_eq!(
+            elements,
+            &Int32Array::from(vec![Some(3), Some(4), None, Some(5)])
+        );
+
+        assert_eq!(element_rows(&list), UInt32Array::from(vec![0, 2, 2, 2]));
+        assert_eq!(&element_offsets(&list)[..], &[0, 1, 1, 4]);
+    }
+
+    #[test]
+    fn test_with_elements_keeps_null_lists() {
+        let mut builder = ListBuilder::new(Int32Array::builder(8));
+        builder.append_value([Some(1), Some(2)]);
+        builder.append(false);
+        builder.append_value([Some(3)]);
+        let list = builder.finish();
+
+        let DataType::List(field) = list.data_type() else {
+            panic!("expected list")
+        };
+        let doubled = Arc::new(Int32Array::from(vec![2, 4, 6]));
+        let result = with_elements(&list, field.clone(), doubled).unwrap();
+        let result = result.as_list::<i32>();
+
+        assert_eq!(result.len(), 3);
+        assert!(result.is_null(1));
+        assert_eq!(
+            result.value(0).as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![2, 4])
+        );
+        assert_eq!(
+            result.value(2).as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![6])
+        );
+    }
+}
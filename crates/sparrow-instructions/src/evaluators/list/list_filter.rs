@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, AsArray, BooleanArray, ListArray};
+use arrow::buffer::OffsetBuffer;
+use arrow_schema::{DataType, FieldRef};
+use itertools::Itertools;
+use sparrow_plan::ValueRef;
+
+use super::list_elements::{element_offsets, elements, num_elements};
+use crate::{ColumnarValue, Evaluator, EvaluatorFactory, RuntimeInfo, StaticInfo};
+
+/// Evaluator for the `list_filter` instruction.
+///
+/// The `predicate` argument is the body of the lambda, which has already been
+/// evaluated for each element of the list. Elements are kept if the predicate
+/// is `true`. Elements where the predicate is `false` or `null` are removed.
+#[derive(Debug)]
+pub(in crate::evaluators) struct ListFilterEvaluator {
+    list: ValueRef,
+    predicate: ValueRef,
+    field: FieldRef,
+}
+
+impl EvaluatorFactory for ListFilterEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let field = match info.args[0].data_type() {
+            DataType::List(field) => field.clone(),
+            other => anyhow::bail!("expected list type, saw {:?}", other),
+        };
+
+        let (list, predicate) = info.unpack_arguments()?;
+        Ok(Box::new(Self {
+            list,
+            predicate,
+            field,
+        }))
+    }
+}
+
+impl Evaluator for ListFilterEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let list = info.value(&self.list)?.array_ref()?;
+        let list: &ListArray = list.as_list();
+        let predicate = match info.value(&self.predicate)? {
+            ColumnarValue::Literal { literal, .. } => literal.to_array(num_elements(list)),
+            ColumnarValue::Array(predicate) => predicate,
+        };
+        let predicate: &BooleanArray = predicate.as_boolean();
+
+        filter(list, self.field.clone(), predicate)
+    }
+}
+
+fn filter(list: &ListArray, field: FieldRef, predicate: &BooleanArray) -> anyhow::Result<ArrayRef> {
+    anyhow::ensure!(predicate.len() == num_elements(list));
+
+    // Count the elements kept from each list to determine the new offsets.
+    let element_offsets = element_offsets(list);
+    let lengths = element_offsets
+        .iter()
+        .map(|offset| *offset as usize)
+        .tuple_windows()
+        .map(|(start, end)| {
+            (start..end)
+                .filter(|index| predicate.is_valid(*index) && predicate.value(*index))
+                .count()
+        });
+    let offsets = OffsetBuffer::from_lengths(lengths);
+
+    let values = arrow::compute::filter(elements(list).as_ref(), predicate)?;
+    let result = ListArray::try_new(field, offsets, values, list.nulls().cloned())?;
+    Ok(Arc::new(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Int32Array, ListBuilder};
+    use arrow::datatypes::Int32Type;
+
+    use super::*;
+
+    #[test]
+    fn test_filter_list() {
+        let mut builder = ListBuilder::new(Int32Array::builder(8));
+        builder.append_value([Some(1), Some(2), Some(3)]);
+        builder.append(false);
+        builder.append_value([]);
+        builder.append_value([Some(4), Some(5)]);
+        let list = builder.finish();
+        let DataType::List(field) = list.data_type() else {
+            panic!("expected list")
+        };
+
+        let predicate =
+            BooleanArray::from(vec![Some(true), None, Some(true), Some(false), Some(true)]);
+        let result = filter(&list, field.clone(), &predicate).unwrap();
+
+        let mut expected = ListBuilder::new(Int32Array::builder(8));
+        expected.append_value([Some(1), Some(3)]);
+        expected.append(false);
+        expected.append_value([]);
+        expected.append_value([Some(5)]);
+        let expected = expected.finish();
+
+        assert_eq!(result.as_list::<i32>(), &expected);
+        assert_eq!(
+            result.as_list::<i32>().value(3).as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![5])
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use arrow::array::{ArrayRef, AsArray, ListArray};
+use arrow_schema::{DataType, FieldRef};
+use sparrow_plan::ValueRef;
+
+use super::list_elements::{num_elements, with_elements};
+use crate::{ColumnarValue, Evaluator, EvaluatorFactory, RuntimeInfo, StaticInfo};
+
+/// Evaluator for the `list_map` instruction.
+///
+/// The `f` argument is the body of the lambda, which has already been
+/// evaluated for each element of the list. This collects the results back
+/// into lists.
+#[derive(Debug)]
+pub(in crate::evaluators) struct ListMapEvaluator {
+    list: ValueRef,
+    f: ValueRef,
+    field: FieldRef,
+}
+
+impl EvaluatorFactory for ListMapEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let field = match info.result_type {
+            DataType::List(field) => field.clone(),
+            other => anyhow::bail!("expected list result type, saw {:?}", other),
+        };
+
+        let (list, f) = info.unpack_arguments()?;
+        Ok(Box::new(Self { list, f, field }))
+    }
+}
+
+impl Evaluator for ListMapEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let list = info.value(&self.list)?.array_ref()?;
+        let list: &ListArray = list.as_list();
+        let elements = match info.value(&self.f)? {
+            ColumnarValue::Literal { literal, .. } => literal.to_array(num_elements(list)),
+            ColumnarValue::Array(elements) => elements,
+        };
+
+        with_elements(list, self.field.clone(), elements)
+    }
+}
@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, AsArray, Float64Array, ListArray};
+use arrow::datatypes::Float64Type;
+use arrow_schema::DataType;
+use itertools::Itertools;
+use sparrow_plan::ValueRef;
+
+use crate::{Evaluator, EvaluatorFactory, RuntimeInfo, StaticInfo};
+
+/// Evaluator for the `list_mean` instruction.
+///
+/// Produces the mean of the non-null elements of each list. The mean of an
+/// empty list (or a list containing only `null`) is `null`.
+#[derive(Debug)]
+pub(in crate::evaluators) struct ListMeanEvaluator {
+    list: ValueRef,
+}
+
+impl EvaluatorFactory for ListMeanEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        match info.args[0].data_type() {
+            DataType::List(_) => (),
+            other => anyhow::bail!("expected list type, saw {:?}", other),
+        };
+
+        let list = info.unpack_argument()?;
+        Ok(Box::new(Self { list }))
+    }
+}
+
+impl Evaluator for ListMeanEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let list = info.value(&self.list)?.array_ref()?;
+        let result = list_mean(list.as_list())?;
+        Ok(Arc::new(result))
+    }
+}
+
+fn list_mean(list: &ListArray) -> anyhow::Result<Float64Array> {
+    let values = arrow::compute::cast(list.values(), &DataType::Float64)?;
+    let values: &Float64Array = values.as_primitive::<Float64Type>();
+    let result = list
+        .value_offsets()
+        .iter()
+        .map(|offset| *offset as usize)
+        .tuple_windows()
+        .enumerate()
+        .map(|(index, (start, end))| {
+            if list.is_null(index) {
+                return None;
+            }
+
+            let values = values.slice(start, end - start);
+            let count = values.len() - values.null_count();
+            let sum = arrow::compute::sum(&values)?;
+            Some(sum / count as f64)
+        })
+        .collect();
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Int32Array, ListBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_list_mean() {
+        let mut builder = ListBuilder::new(Int32Array::builder(8));
+        builder.append_value([Some(1), Some(2)]);
+        builder.append(false);
+        builder.append_value([]);
+        builder.append_value([Some(4), None, Some(8)]);
+        let list = builder.finish();
+
+        let result = list_mean(&list).unwrap();
+        assert_eq!(
+            result,
+            Float64Array::from(vec![Some(1.5), None, None, Some(6.0)])
+        );
+    }
+}
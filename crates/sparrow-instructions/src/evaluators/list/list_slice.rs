@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, AsArray, Int64Array, ListArray, UInt32Array};
+use arrow::buffer::{NullBuffer, OffsetBuffer};
+use arrow::datatypes::Int64Type;
+use arrow_schema::{DataType, FieldRef};
+use itertools::{izip, Itertools};
+use sparrow_plan::ValueRef;
+
+use crate::{Evaluator, EvaluatorFactory, RuntimeInfo, StaticInfo};
+
+/// Evaluator for the `list_slice` instruction.
+///
+/// Produces the elements of each list from `start` (inclusive) to `end`
+/// (exclusive). Negative positions count from the end of the list, and
+/// positions outside the list are clamped to it. The result is `null` if the
+/// list, `start` or `end` is `null`.
+#[derive(Debug)]
+pub(in crate::evaluators) struct ListSliceEvaluator {
+    list: ValueRef,
+    start: ValueRef,
+    end: ValueRef,
+    field: FieldRef,
+}
+
+impl EvaluatorFactory for ListSliceEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let field = match info.args[0].data_type() {
+            DataType::List(field) => field.clone(),
+            other => anyhow::bail!("expected list type, saw {:?}", other),
+        };
+
+        let (list, start, end) = info.unpack_arguments()?;
+        Ok(Box::new(Self {
+            list,
+            start,
+            end,
+            field,
+        }))
+    }
+}
+
+impl Evaluator for ListSliceEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let list = info.value(&self.list)?.array_ref()?;
+        let start = info.value(&self.start)?.primitive_array::<Int64Type>()?;
+        let end = info.value(&self.end)?.primitive_array::<Int64Type>()?;
+        list_slice(list.as_list(), self.field.clone(), &start, &end)
+    }
+}
+
+/// Resolve a (possibly negative) position within a list of length `len`.
+fn position(position: i64, len: usize) -> usize {
+    if position < 0 {
+        len.saturating_sub(position.unsigned_abs() as usize)
+    } else {
+        (position as usize).min(len)
+    }
+}
+
+fn list_slice(
+    list: &ListArray,
+    field: FieldRef,
+    start: &Int64Array,
+    end: &Int64Array,
+) -> anyhow::Result<ArrayRef> {
+    anyhow::ensure!(list.len() == start.len() && list.len() == end.len());
+
+    let mut indices = Vec::new();
+    let mut lengths = Vec::with_capacity(list.len());
+    let mut valid = Vec::with_capacity(list.len());
+    let offsets = list
+        .value_offsets()
+        .iter()
+        .map(|offset| *offset as usize)
+        .tuple_windows();
+    for (index, (offset, next), start, end) in izip!(0.., offsets, start, end) {
+        match (list.is_valid(index), start, end) {
+            (true, Some(start), Some(end)) => {
+                let len = next - offset;
+                let start = position(start, len);
+                let end = position(end, len).max(start);
+                indices.extend((offset + start..offset + end).map(|index| index as u32));
+                lengths.push(end - start);
+                valid.push(true);
+            }
+            _ => {
+                lengths.push(0);
+                valid.push(false);
+            }
+        }
+    }
+
+    let values = arrow::compute::take(list.values().as_ref(), &UInt32Array::from(indices), None)?;
+    let result = ListArray::try_new(
+        field,
+        OffsetBuffer::from_lengths(lengths),
+        values,
+        Some(NullBuffer::from(valid)),
+    )?;
+    Ok(Arc::new(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::ListBuilder;
+
+    use super::*;
+
+    #[test]
+    fn test_list_slice() {
+        let mut builder = ListBuilder::new(Int64Array::builder(8));
+        for _ in 0..5 {
+            builder.append_value([Some(1), Some(2), Some(3), Some(4)]);
+        }
+        builder.append(false);
+        let list = builder.finish();
+        let DataType::List(field) = list.data_type() else {
+            panic!("expected list")
+        };
+
+        let start = Int64Array::from(vec![Some(1), Some(-2), Some(0), Some(3), None, Some(0)]);
+        let end = Int64Array::from(vec![Some(3), Some(10), Some(-3), Some(1), Some(2), Some(1)]);
+        let result = list_slice(&list, field.clone(), &start, &end).unwrap();
+
+        let mut expected = ListBuilder::new(Int64Array::builder(8));
+        expected.append_value([Some(2), Some(3)]);
+        expected.append_value([Some(3), Some(4)]);
+        expected.append_value([Some(1)]);
+        expected.append_value([]);
+        expected.append(false);
+        expected.append(false);
+        assert_eq!(result.as_list::<i32>(), &expected.finish());
+    }
+}
@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, AsArray, ListArray, UInt32Array};
+use arrow::compute::SortOptions;
+use arrow_schema::{DataType, FieldRef};
+use itertools::Itertools;
+use sparrow_plan::ValueRef;
+
+use super::list_elements::element_offsets;
+use crate::{Evaluator, EvaluatorFactory, RuntimeInfo, StaticInfo};
+
+/// Evaluator for the `list_sort` instruction.
+///
+/// Sorts the elements of each list in ascending order, with `null` elements
+/// placed last.
+#[derive(Debug)]
+pub(in crate::evaluators) struct ListSortEvaluator {
+    list: ValueRef,
+    field: FieldRef,
+}
+
+impl EvaluatorFactory for ListSortEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let field = match info.args[0].data_type() {
+            DataType::List(field) => field.clone(),
+            other => anyhow::bail!("expected list type, saw {:?}", other),
+        };
+
+        let list = info.unpack_argument()?;
+        Ok(Box::new(Self { list, field }))
+    }
+}
+
+impl Evaluator for ListSortEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let list = info.value(&self.list)?.array_ref()?;
+        list_sort(list.as_list(), self.field.clone())
+    }
+}
+
+fn list_sort(list: &ListArray, field: FieldRef) -> anyhow::Result<ArrayRef> {
+    let options = SortOptions {
+        descending: false,
+        nulls_first: false,
+    };
+
+    // The indices (within the values) of each element, in sorted order.
+    let mut indices = Vec::with_capacity(list.values().len());
+    for (start, end) in list
+        .value_offsets()
+        .iter()
+        .map(|offset| *offset as usize)
+        .tuple_windows()
+    {
+        let values = list.values().slice(start, end - start);
+        let sorted = arrow::compute::sort_to_indices(values.as_ref(), Some(options), None)?;
+        indices.extend(sorted.values().iter().map(|index| start as u32 + index));
+    }
+
+    let values = arrow::compute::take(list.values().as_ref(), &UInt32Array::from(indices), None)?;
+    let result = ListArray::try_new(field, element_offsets(list), values, list.nulls().cloned())?;
+    Ok(Arc::new(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Int64Array, ListBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_list_sort() {
+        let mut builder = ListBuilder::new(Int64Array::builder(8));
+        builder.append_value([Some(3), None, Some(1), Some(2)]);
+        builder.append(false);
+        builder.append_value([Some(5), Some(4)]);
+        let list = builder.finish();
+        let DataType::List(field) = list.data_type() else {
+            panic!("expected list")
+        };
+
+        let result = list_sort(&list, field.clone()).unwrap();
+
+        let mut expected = ListBuilder::new(Int64Array::builder(8));
+        expected.append_value([Some(1), Some(2), Some(3), None]);
+        expected.append(false);
+        expected.append_value([Some(4), Some(5)]);
+        assert_eq!(result.as_list::<i32>(), &expected.finish());
+    }
+}
@@ -0,0 +1,97 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, AsArray, ListArray, PrimitiveArray};
+use arrow::datatypes::{ArrowNativeTypeOp, ArrowNumericType};
+use arrow_schema::DataType;
+use itertools::Itertools;
+use sparrow_plan::ValueRef;
+
+use crate::{Evaluator, EvaluatorFactory, RuntimeInfo, StaticInfo};
+
+/// Evaluator for the `list_sum` instruction.
+///
+/// Produces the sum of the non-null elements of each list. The sum of an
+/// empty list (or a list containing only `null`) is `null`.
+pub(in crate::evaluators) struct ListSumEvaluator<T: ArrowNumericType> {
+    list: ValueRef,
+    // Use the type parameter and indicate it is invariant.
+    _phantom: PhantomData<fn(T) -> T>,
+}
+
+impl<T> EvaluatorFactory for ListSumEvaluator<T>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        match info.args[0].data_type() {
+            DataType::List(field) => anyhow::ensure!(field.data_type() == info.result_type),
+            other => anyhow::bail!("expected list type, saw {:?}", other),
+        };
+
+        let list = info.unpack_argument()?;
+        Ok(Box::new(Self {
+            list,
+            _phantom: PhantomData,
+        }))
+    }
+}
+
+impl<T> Evaluator for ListSumEvaluator<T>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let list = info.value(&self.list)?.array_ref()?;
+        let result: PrimitiveArray<T> = list_sum(list.as_list());
+        Ok(Arc::new(result))
+    }
+}
+
+fn list_sum<T>(list: &ListArray) -> PrimitiveArray<T>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    let values: &PrimitiveArray<T> = list.values().as_primitive();
+    list.value_offsets()
+        .iter()
+        .map(|offset| *offset as usize)
+        .tuple_windows()
+        .enumerate()
+        .map(|(index, (start, end))| {
+            if list.is_valid(index) {
+                arrow::compute::sum(&values.slice(start, end - start))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Int64Array, ListBuilder};
+    use arrow::datatypes::Int64Type;
+
+    use super::*;
+
+    #[test]
+    fn test_list_sum() {
+        let mut builder = ListBuilder::new(Int64Array::builder(8));
+        builder.append_value([Some(1), Some(2), Some(3)]);
+        builder.append(false);
+        builder.append_value([]);
+        builder.append_value([Some(4), None]);
+        builder.append_value([None]);
+        let list = builder.finish();
+
+        let result: PrimitiveArray<Int64Type> = list_sum(&list);
+        assert_eq!(
+            result,
+            Int64Array::from(vec![Some(6), None, None, Some(4), None])
+        );
+    }
+}
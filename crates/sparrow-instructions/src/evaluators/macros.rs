@@ -134,6 +134,47 @@ macro_rules! create_float_evaluator {
     }};
 }
 
+/// Create a `Box<dyn Evaluator>` for an integer instruction.
+///
+/// The `$input_type` must be a `&DataType` corresponding to the integer input
+/// to the instruction.
+///
+/// The `$evaluator` must be a `struct` that takes a single `T:
+/// ArrowPrimitiveType` generic and which implements `Evaluator`.
+///
+/// The `$info` should be a `StaticInfo`, which contains information relevant
+/// to creating a new evaluator.
+///
+/// # Example
+///
+/// ```no_run
+/// struct ModEvaluator<T: ArrowPrimitiveType>;
+/// impl<T: ArrowPrimitiveType> Evaluator for ModEvaluator<T> { ... }
+///
+/// fn create_evaluator(args: Vec<StaticArg>, result_type: &DataType) -> anyhow::Result<Self> {
+///   create_int_evaluator!(&args[0].data_type, ModEvaluator, args, result_type)
+/// }
+/// ```
+macro_rules! create_int_evaluator {
+    ($input_type:expr, $evaluator:ident, $info:expr) => {{
+        use arrow::datatypes::*;
+        match $input_type {
+            DataType::Int32 => $evaluator::<Int32Type>::try_new($info),
+            DataType::Int64 => $evaluator::<Int64Type>::try_new($info),
+            DataType::UInt32 => $evaluator::<UInt32Type>::try_new($info),
+            DataType::UInt64 => $evaluator::<UInt64Type>::try_new($info),
+            unsupported_type => {
+                // This macro should only be used on `int` numeric types.
+                Err(anyhow::anyhow!(format!(
+                    "Unsupported non-int input type {:?} for {}",
+                    unsupported_type,
+                    stringify!($evaluator)
+                )))
+            }
+        }
+    }};
+}
+
 /// Create a `Box<dyn Evaluator>` for an ordered instruction.
 ///
 /// The `$input_type` must be a `&DataType` corresponding to the ordered input
@@ -307,6 +348,6 @@ macro_rules! create_typed_evaluator {
 }
 
 pub(super) use {
-    create_float_evaluator, create_number_evaluator, create_ordered_evaluator,
-    create_signed_evaluator, create_typed_evaluator,
+    create_float_evaluator, create_int_evaluator, create_number_evaluator,
+    create_ordered_evaluator, create_signed_evaluator, create_typed_evaluator,
 };
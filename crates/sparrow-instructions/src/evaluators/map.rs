@@ -1,2 +1,11 @@
 mod get;
+mod map_contains;
+mod map_entries;
+mod map_from_lists;
+mod map_len;
+
 pub(super) use get::*;
+pub(super) use map_contains::*;
+pub(super) use map_entries::*;
+pub(super) use map_from_lists::*;
+pub(super) use map_len::*;
@@ -67,7 +67,7 @@ fn map_get(map: &MapArray, keys: &dyn Array) -> anyhow::Result<ArrayRef> {
 }
 
 /// Gets the indices in the map where the map keys match the next key in `keys`.
-fn map_indices(map: &MapArray, keys: &dyn Array) -> anyhow::Result<Int32Array> {
+pub(super) fn map_indices(map: &MapArray, keys: &dyn Array) -> anyhow::Result<Int32Array> {
     anyhow::ensure!(
         map.key_type() == keys.data_type(),
         "Expected map keys {} to be same type as keys {}",
@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, BooleanArray, MapArray};
+use arrow_schema::DataType;
+use sparrow_plan::ValueRef;
+
+use super::get::map_indices;
+use crate::{Evaluator, EvaluatorFactory, RuntimeInfo, StaticInfo};
+
+/// Evaluator for `map_contains`.
+///
+/// Produces `true` if the map contains an entry for the key.
+#[derive(Debug)]
+pub(in crate::evaluators) struct MapContainsEvaluator {
+    key: ValueRef,
+    map: ValueRef,
+}
+
+impl EvaluatorFactory for MapContainsEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        match info.args[1].data_type() {
+            DataType::Map(..) => (),
+            other => anyhow::bail!("expected map type, saw {:?}", other),
+        };
+
+        let (key, map) = info.unpack_arguments()?;
+        Ok(Box::new(Self { key, map }))
+    }
+}
+
+impl Evaluator for MapContainsEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let key = info.value(&self.key)?.array_ref()?;
+        let map = info.value(&self.map)?.map_array()?;
+        let result = map_contains(&map, key.as_ref())?;
+        Ok(Arc::new(result))
+    }
+}
+
+/// Return whether each map contains the corresponding key.
+///
+/// The result is `null` if either the map or the key is `null`.
+fn map_contains(map: &MapArray, keys: &dyn Array) -> anyhow::Result<BooleanArray> {
+    let indices = map_indices(map, keys)?;
+    let result = (0..map.len())
+        .map(|index| {
+            if map.is_null(index) || keys.is_null(index) {
+                None
+            } else {
+                Some(indices.is_valid(index))
+            }
+        })
+        .collect::<Vec<_>>();
+    Ok(BooleanArray::from(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Int64Builder, MapBuilder, StringArray, StringBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_map_contains() {
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), Int64Builder::new());
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.keys().append_value("b");
+        builder.values().append_value(2);
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.append(true).unwrap();
+        builder.keys().append_value("c");
+        builder.values().append_value(3);
+        builder.append(true).unwrap();
+        builder.keys().append_value("d");
+        builder.values().append_null();
+        builder.append(true).unwrap();
+        let map = builder.finish();
+
+        let keys = StringArray::from(vec![Some("b"), Some("a"), Some("a"), None, Some("d")]);
+        assert_eq!(
+            map_contains(&map, &keys).unwrap(),
+            BooleanArray::from(vec![Some(true), None, Some(false), None, Some(true)])
+        );
+    }
+}
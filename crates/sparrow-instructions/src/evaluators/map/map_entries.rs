@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, ListArray, MapArray};
+use arrow_schema::{DataType, FieldRef};
+use sparrow_plan::ValueRef;
+
+use crate::{Evaluator, EvaluatorFactory, RuntimeInfo, StaticInfo};
+
+/// Evaluator for `map_keys`.
+///
+/// Produces a list containing the keys of each map.
+#[derive(Debug)]
+pub(in crate::evaluators) struct MapKeysEvaluator {
+    map: ValueRef,
+    field: FieldRef,
+}
+
+impl EvaluatorFactory for MapKeysEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let field = list_field(&info)?;
+        let map = info.unpack_argument()?;
+        Ok(Box::new(Self { map, field }))
+    }
+}
+
+impl Evaluator for MapKeysEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let map = info.value(&self.map)?.map_array()?;
+        map_entries_to_list(&map, self.field.clone(), map.keys().clone())
+    }
+}
+
+/// Evaluator for `map_values`.
+///
+/// Produces a list containing the values of each map.
+#[derive(Debug)]
+pub(in crate::evaluators) struct MapValuesEvaluator {
+    map: ValueRef,
+    field: FieldRef,
+}
+
+impl EvaluatorFactory for MapValuesEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let field = list_field(&info)?;
+        let map = info.unpack_argument()?;
+        Ok(Box::new(Self { map, field }))
+    }
+}
+
+impl Evaluator for MapValuesEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let map = info.value(&self.map)?.map_array()?;
+        map_entries_to_list(&map, self.field.clone(), map.values().clone())
+    }
+}
+
+fn list_field(info: &StaticInfo<'_>) -> anyhow::Result<FieldRef> {
+    match info.args[0].data_type() {
+        DataType::Map(..) => (),
+        other => anyhow::bail!("expected map type, saw {:?}", other),
+    };
+    match info.result_type {
+        DataType::List(field) => Ok(field.clone()),
+        other => anyhow::bail!("expected list result type, saw {:?}", other),
+    }
+}
+
+/// Create a list for each map containing the corresponding `entries`.
+///
+/// The `entries` must be either the keys or values of the map.
+fn map_entries_to_list(
+    map: &MapArray,
+    field: FieldRef,
+    entries: ArrayRef,
+) -> anyhow::Result<ArrayRef> {
+    let result = ListArray::try_new(field, map.offsets().clone(), entries, map.nulls().cloned())?;
+    Ok(Arc::new(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Array, AsArray, Int64Builder, StringBuilder};
+    use arrow::array::{Int64Array, MapBuilder, StringArray};
+    use arrow::datatypes::Int64Type;
+    use arrow_schema::Field;
+
+    use super::*;
+
+    #[test]
+    fn test_map_keys_and_values() {
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), Int64Builder::new());
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.keys().append_value("b");
+        builder.values().append_value(2);
+        builder.keys().append_value("c");
+        builder.values().append_value(3);
+        builder.append(true).unwrap();
+        let map = builder.finish();
+        let map = map.slice(1, 2);
+
+        let field = Arc::new(Field::new("item", DataType::Utf8, true));
+        let keys = map_entries_to_list(&map, field, map.keys().clone()).unwrap();
+        let keys = keys.as_list::<i32>();
+        assert_eq!(keys.len(), 2);
+        assert!(keys.is_null(0));
+        assert_eq!(
+            keys.value(1).as_string::<i32>(),
+            &StringArray::from(vec!["b", "c"])
+        );
+
+        let field = Arc::new(Field::new("item", DataType::Int64, true));
+        let values = map_entries_to_list(&map, field, map.values().clone()).unwrap();
+        let values = values.as_list::<i32>();
+        assert!(values.is_null(0));
+        assert_eq!(
+            values.value(1).as_primitive::<Int64Type>(),
+            &Int64Array::from(vec![2, 3])
+        );
+    }
+}
@@ -0,0 +1,195 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, AsArray, ListArray, MapArray, StructArray, UInt32Array};
+use arrow::buffer::{NullBuffer, OffsetBuffer};
+use arrow::row::{RowConverter, SortField};
+use arrow_schema::{DataType, FieldRef};
+use itertools::Itertools;
+use sparrow_plan::ValueRef;
+
+use crate::{Evaluator, EvaluatorFactory, RuntimeInfo, StaticInfo};
+
+/// Evaluator for `map_from_lists`.
+///
+/// Produces a map from a list of keys and a list of values, pairing each key
+/// with the value at the same position. If a key appears more than once, the
+/// last value is used. Entries with a `null` key are skipped.
+///
+/// The result is `null` if either list is `null` or the lists have different
+/// lengths.
+#[derive(Debug)]
+pub(in crate::evaluators) struct MapFromListsEvaluator {
+    keys: ValueRef,
+    values: ValueRef,
+    field: FieldRef,
+    row_converter: RowConverter,
+}
+
+impl EvaluatorFactory for MapFromListsEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let key_type = match info.args[0].data_type() {
+            DataType::List(field) => field.data_type().clone(),
+            other => anyhow::bail!("expected list type, saw {:?}", other),
+        };
+        let field = match info.result_type {
+            DataType::Map(field, _) => field.clone(),
+            other => anyhow::bail!("expected map result type, saw {:?}", other),
+        };
+
+        let row_converter = RowConverter::new(vec![SortField::new(key_type)])?;
+        let (keys, values) = info.unpack_arguments()?;
+        Ok(Box::new(Self {
+            keys,
+            values,
+            field,
+            row_converter,
+        }))
+    }
+}
+
+impl Evaluator for MapFromListsEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let keys = info.value(&self.keys)?.array_ref()?;
+        let values = info.value(&self.values)?.array_ref()?;
+        let result = map_from_lists(
+            &mut self.row_converter,
+            &self.field,
+            keys.as_list(),
+            values.as_list(),
+        )?;
+        Ok(Arc::new(result))
+    }
+}
+
+fn map_from_lists(
+    row_converter: &mut RowConverter,
+    field: &FieldRef,
+    keys: &ListArray,
+    values: &ListArray,
+) -> anyhow::Result<MapArray> {
+    anyhow::ensure!(keys.len() == values.len());
+    let key_rows = row_converter.convert_columns(std::slice::from_ref(keys.values()))?;
+
+    // The index of the key and value for each entry.
+    let mut entries: Vec<(u32, u32)> = Vec::new();
+    let mut offsets = Vec::with_capacity(keys.len() + 1);
+    offsets.push(0);
+    let mut valid = Vec::with_capacity(keys.len());
+
+    let key_offsets = keys.value_offsets().iter().tuple_windows();
+    let value_offsets = values.value_offsets().iter().tuple_windows();
+    for (row, ((key_start, key_end), (value_start, _))) in
+        key_offsets.zip(value_offsets).enumerate()
+    {
+        let len = keys.value_length(row);
+        if keys.is_null(row) || values.is_null(row) || len != values.value_length(row) {
+            offsets.push(entries.len() as i32);
+            valid.push(false);
+            continue;
+        }
+
+        let row_start = entries.len();
+        for (key_index, value_index) in (*key_start..*key_end).zip(*value_start..) {
+            let (key_index, value_index) = (key_index as u32, value_index as u32);
+            if keys.values().is_null(key_index as usize) {
+                continue;
+            }
+
+            let key = key_rows.row(key_index as usize);
+            match entries[row_start..]
+                .iter_mut()
+                .find(|(existing, _)| key_rows.row(*existing as usize) == key)
+            {
+                Some(entry) => *entry = (key_index, value_index),
+                None => entries.push((key_index, value_index)),
+            }
+        }
+        offsets.push(entries.len() as i32);
+        valid.push(true);
+    }
+
+    let (key_indices, value_indices): (Vec<_>, Vec<_>) = entries.into_iter().unzip();
+    let keys = arrow::compute::take(keys.values(), &UInt32Array::from(key_indices), None)?;
+    let values = arrow::compute::take(values.values(), &UInt32Array::from(value_indices), None)?;
+    new_map_array(
+        field,
+        OffsetBuffer::new(offsets.into()),
+        keys,
+        values,
+        Some(NullBuffer::from(valid)),
+    )
+}
+
+/// Create a map array containing the given keys and values.
+pub(in crate::evaluators) fn new_map_array(
+    field: &FieldRef,
+    offsets: OffsetBuffer<i32>,
+    keys: ArrayRef,
+    values: ArrayRef,
+    nulls: Option<NullBuffer>,
+) -> anyhow::Result<MapArray> {
+    let DataType::Struct(fields) = field.data_type() else {
+        anyhow::bail!(
+            "expected struct entries in map, saw {:?}",
+            field.data_type()
+        )
+    };
+    let entries = StructArray::try_new(fields.clone(), vec![keys, values], None)?;
+    Ok(MapArray::try_new(
+        field.clone(),
+        offsets,
+        entries,
+        nulls,
+        false,
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Int64Array, Int64Builder, ListBuilder, StringArray, StringBuilder};
+    use arrow::datatypes::Int64Type;
+    use arrow_schema::{Field, Fields};
+
+    use super::*;
+
+    #[test]
+    fn test_map_from_lists() {
+        let mut keys = ListBuilder::new(StringBuilder::new());
+        keys.append_value([Some("a"), Some("b")]);
+        keys.append_value([Some("a"), None, Some("a")]);
+        keys.append_value([Some("a")]);
+        keys.append(false);
+        let keys = keys.finish();
+
+        let mut values = ListBuilder::new(Int64Builder::new());
+        values.append_value([Some(1), Some(2)]);
+        values.append_value([Some(3), Some(4), Some(5)]);
+        values.append_value([Some(6), Some(7)]);
+        values.append_value([Some(8)]);
+        let values = values.finish();
+
+        let fields = Fields::from(vec![
+            Field::new("keys", DataType::Utf8, false),
+            Field::new("values", DataType::Int64, true),
+        ]);
+        let field = Arc::new(Field::new("entries", DataType::Struct(fields), false));
+        let mut row_converter = RowConverter::new(vec![SortField::new(DataType::Utf8)]).unwrap();
+
+        let map = map_from_lists(&mut row_converter, &field, &keys, &values).unwrap();
+        assert_eq!(map.len(), 4);
+        assert!(map.is_valid(0));
+        assert!(map.is_valid(1));
+        assert!(map.is_null(2));
+        assert!(map.is_null(3));
+
+        assert_eq!(map.value_offsets(), &[0, 2, 3, 3, 3]);
+        assert_eq!(
+            map.keys().as_string::<i32>(),
+            &StringArray::from(vec!["a", "b", "a"])
+        );
+        assert_eq!(
+            map.values().as_primitive::<Int64Type>(),
+            &Int64Array::from(vec![1, 2, 5])
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, Int32Array, MapArray};
+use arrow_schema::DataType;
+use itertools::Itertools;
+use sparrow_plan::ValueRef;
+
+use crate::{Evaluator, EvaluatorFactory, RuntimeInfo, StaticInfo};
+
+/// Evaluator for `map_len`.
+///
+/// Produces the number of entries in each map.
+#[derive(Debug)]
+pub(in crate::evaluators) struct MapLenEvaluator {
+    map: ValueRef,
+}
+
+impl EvaluatorFactory for MapLenEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        match info.args[0].data_type() {
+            DataType::Map(..) => (),
+            other => anyhow::bail!("expected map type, saw {:?}", other),
+        };
+
+        let map = info.unpack_argument()?;
+        Ok(Box::new(Self { map }))
+    }
+}
+
+impl Evaluator for MapLenEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let map = info.value(&self.map)?.map_array()?;
+        Ok(Arc::new(map_len(&map)))
+    }
+}
+
+fn map_len(map: &MapArray) -> Int32Array {
+    let lengths = map
+        .value_offsets()
+        .iter()
+        .tuple_windows()
+        .map(|(start, end)| end - start)
+        .collect();
+    Int32Array::new(lengths, map.nulls().cloned())
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Int64Builder, MapBuilder, StringBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_map_len() {
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), Int64Builder::new());
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.append(true).unwrap();
+        builder.keys().append_value("b");
+        builder.values().append_value(2);
+        builder.keys().append_value("c");
+        builder.values().append_value(3);
+        builder.append(true).unwrap();
+        let map = builder.finish();
+
+        assert_eq!(
+            map_len(&map),
+            Int32Array::from(vec![Some(1), None, Some(0), Some(2)])
+        );
+        assert_eq!(
+            map_len(&map.slice(2, 2)),
+            Int32Array::from(vec![Some(0), Some(2)])
+        );
+    }
+}
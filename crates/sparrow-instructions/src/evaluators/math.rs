@@ -6,19 +6,122 @@ use arrow::datatypes::{ArrowNativeTypeOp, ArrowNumericType};
 use sparrow_arrow::scalar_value::NativeFromScalar;
 use sparrow_plan::ValueRef;
 
+/// Define an evaluator applying a unary primitive `$kernel` to its input.
+///
+/// The `$bounds` are the trait bounds the kernel requires of `T::Native`.
+macro_rules! unary_kernel_evaluator {
+    ($(#[$meta:meta])* $evaluator:ident, $kernel:path, $($bounds:tt)+) => {
+        $(#[$meta])*
+        pub(in crate::evaluators) struct $evaluator<T: arrow::datatypes::ArrowNumericType> {
+            input: sparrow_plan::ValueRef,
+            // Use the type parameter and indicate it is invariant.
+            _phantom: std::marker::PhantomData<fn(T) -> T>,
+        }
+
+        impl<T> $crate::Evaluator for $evaluator<T>
+        where
+            T: arrow::datatypes::ArrowNumericType,
+            T::Native: $($bounds)+,
+        {
+            fn evaluate(
+                &mut self,
+                info: &dyn $crate::RuntimeInfo,
+            ) -> anyhow::Result<arrow::array::ArrayRef> {
+                let input = info.value(&self.input)?.primitive_array::<T>()?;
+                let result = $kernel(&input)?;
+                Ok(std::sync::Arc::new(result))
+            }
+        }
+
+        impl<T> $crate::EvaluatorFactory for $evaluator<T>
+        where
+            T: arrow::datatypes::ArrowNumericType,
+            T::Native: $($bounds)+,
+        {
+            fn try_new(
+                info: $crate::StaticInfo<'_>,
+            ) -> anyhow::Result<Box<dyn $crate::Evaluator>> {
+                let input = info.unpack_argument()?;
+                Ok(Box::new(Self {
+                    input,
+                    _phantom: std::marker::PhantomData,
+                }))
+            }
+        }
+    };
+}
+
+/// Define an evaluator applying a binary primitive `$kernel` to its inputs.
+///
+/// The `$bounds` are the trait bounds the kernel requires of `T::Native`.
+macro_rules! binary_kernel_evaluator {
+    ($(#[$meta:meta])* $evaluator:ident, $kernel:path, $($bounds:tt)+) => {
+        $(#[$meta])*
+        pub(in crate::evaluators) struct $evaluator<T: arrow::datatypes::ArrowNumericType> {
+            lhs: sparrow_plan::ValueRef,
+            rhs: sparrow_plan::ValueRef,
+            // Use the type parameter and indicate it is invariant.
+            _phantom: std::marker::PhantomData<fn(T) -> T>,
+        }
+
+        impl<T> $crate::Evaluator for $evaluator<T>
+        where
+            T: arrow::datatypes::ArrowNumericType,
+            T::Native: $($bounds)+,
+        {
+            fn evaluate(
+                &mut self,
+                info: &dyn $crate::RuntimeInfo,
+            ) -> anyhow::Result<arrow::array::ArrayRef> {
+                let lhs = info.value(&self.lhs)?.primitive_array::<T>()?;
+                let rhs = info.value(&self.rhs)?.primitive_array::<T>()?;
+                let result = $kernel(&lhs, &rhs)?;
+                Ok(std::sync::Arc::new(result))
+            }
+        }
+
+        impl<T> $crate::EvaluatorFactory for $evaluator<T>
+        where
+            T: arrow::datatypes::ArrowNumericType,
+            T::Native: $($bounds)+,
+        {
+            fn try_new(
+                info: $crate::StaticInfo<'_>,
+            ) -> anyhow::Result<Box<dyn $crate::Evaluator>> {
+                let (lhs, rhs) = info.unpack_arguments()?;
+                Ok(Box::new(Self {
+                    lhs,
+                    rhs,
+                    _phantom: std::marker::PhantomData,
+                }))
+            }
+        }
+    };
+}
+
+mod bitwise;
 mod clamp;
+mod division;
 mod exp;
 mod floor_ceil;
+mod log;
 mod min_max;
 mod powf;
 mod round;
+mod sign;
+mod trig;
 
+pub(super) use bitwise::*;
 pub(super) use clamp::*;
+pub(super) use division::*;
 pub(super) use exp::*;
 pub(super) use floor_ceil::*;
+pub(super) use log::*;
 pub(super) use min_max::*;
 pub(super) use powf::*;
 pub(super) use round::*;
+pub(super) use sign::*;
+pub(super) use trig::*;
 
 use crate::evaluators::{Evaluator, RuntimeInfo};
 use crate::{EvaluatorFactory, StaticInfo};
@@ -0,0 +1,49 @@
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+
+use num::traits::{WrappingShl, WrappingShr};
+
+binary_kernel_evaluator!(
+    /// Evaluator for `bitwise_and`.
+    BitwiseAndEvaluator,
+    arrow::compute::kernels::bitwise::bitwise_and,
+    BitAnd<Output = T::Native>
+);
+
+binary_kernel_evaluator!(
+    /// Evaluator for `bitwise_or`.
+    BitwiseOrEvaluator,
+    arrow::compute::kernels::bitwise::bitwise_or,
+    BitOr<Output = T::Native>
+);
+
+binary_kernel_evaluator!(
+    /// Evaluator for `bitwise_xor`.
+    BitwiseXorEvaluator,
+    arrow::compute::kernels::bitwise::bitwise_xor,
+    BitXor<Output = T::Native>
+);
+
+unary_kernel_evaluator!(
+    /// Evaluator for `bitwise_not`.
+    BitwiseNotEvaluator,
+    arrow::compute::kernels::bitwise::bitwise_not,
+    Not<Output = T::Native>
+);
+
+binary_kernel_evaluator!(
+    /// Evaluator for `shift_left`.
+    ///
+    /// The shift amount wraps around the bit width of the type.
+    ShiftLeftEvaluator,
+    arrow::compute::kernels::bitwise::bitwise_shift_left,
+    WrappingShl<Output = T::Native>
+);
+
+binary_kernel_evaluator!(
+    /// Evaluator for `shift_right`.
+    ///
+    /// The shift amount wraps around the bit width of the type.
+    ShiftRightEvaluator,
+    arrow::compute::kernels::bitwise::bitwise_shift_right,
+    WrappingShr<Output = T::Native>
+);
@@ -0,0 +1,22 @@
+use arrow::datatypes::ArrowNativeTypeOp;
+
+binary_kernel_evaluator!(
+    /// Evaluator for the truncated remainder (`rem`).
+    RemEvaluator,
+    sparrow_kernels::math::rem,
+    ArrowNativeTypeOp
+);
+
+binary_kernel_evaluator!(
+    /// Evaluator for the floored modulus (`mod`).
+    ModEvaluator,
+    sparrow_kernels::math::modulo,
+    ArrowNativeTypeOp + PartialOrd
+);
+
+binary_kernel_evaluator!(
+    /// Evaluator for floored integer division (`floor_div`).
+    FloorDivEvaluator,
+    sparrow_kernels::math::floor_div,
+    ArrowNativeTypeOp + PartialOrd
+);
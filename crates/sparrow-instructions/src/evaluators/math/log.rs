@@ -0,0 +1,15 @@
+use num::Float;
+
+unary_kernel_evaluator!(
+    /// Evaluator for the natural logarithm (`ln`).
+    LnEvaluator,
+    sparrow_kernels::math::ln,
+    Float
+);
+
+unary_kernel_evaluator!(
+    /// Evaluator for the base 10 logarithm (`log10`).
+    Log10Evaluator,
+    sparrow_kernels::math::log10,
+    Float
+);
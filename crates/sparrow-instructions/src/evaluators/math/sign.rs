@@ -0,0 +1,15 @@
+use arrow::datatypes::ArrowNativeTypeOp;
+
+unary_kernel_evaluator!(
+    /// Evaluator for `abs`.
+    AbsEvaluator,
+    sparrow_kernels::math::abs,
+    ArrowNativeTypeOp + PartialOrd
+);
+
+unary_kernel_evaluator!(
+    /// Evaluator for `sign`.
+    SignEvaluator,
+    sparrow_kernels::math::sign,
+    ArrowNativeTypeOp + PartialOrd
+);
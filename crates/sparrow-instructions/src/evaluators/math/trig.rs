@@ -0,0 +1,50 @@
+use num::Float;
+
+unary_kernel_evaluator!(
+    /// Evaluator for `sin`.
+    SinEvaluator,
+    sparrow_kernels::math::sin,
+    Float
+);
+
+unary_kernel_evaluator!(
+    /// Evaluator for `cos`.
+    CosEvaluator,
+    sparrow_kernels::math::cos,
+    Float
+);
+
+unary_kernel_evaluator!(
+    /// Evaluator for `tan`.
+    TanEvaluator,
+    sparrow_kernels::math::tan,
+    Float
+);
+
+unary_kernel_evaluator!(
+    /// Evaluator for `asin`.
+    AsinEvaluator,
+    sparrow_kernels::math::asin,
+    Float
+);
+
+unary_kernel_evaluator!(
+    /// Evaluator for `acos`.
+    AcosEvaluator,
+    sparrow_kernels::math::acos,
+    Float
+);
+
+unary_kernel_evaluator!(
+    /// Evaluator for `atan`.
+    AtanEvaluator,
+    sparrow_kernels::math::atan,
+    Float
+);
+
+binary_kernel_evaluator!(
+    /// Evaluator for the four quadrant arctangent (`atan2`).
+    Atan2Evaluator,
+    sparrow_kernels::math::atan2,
+    Float
+);
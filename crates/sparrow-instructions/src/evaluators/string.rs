@@ -1,8 +1,13 @@
 use std::sync::Arc;
 
-use arrow::array::ArrayRef;
-use arrow::datatypes::DataType;
+use anyhow::Context;
+
+use arrow::array::{ArrayRef, StringArray};
+use arrow::datatypes::{DataType, Int64Type};
+use regex::Regex;
 use sparrow_arrow::downcast::downcast_primitive_array;
+use sparrow_arrow::scalar_value::ScalarValue;
+use sparrow_kernels::string::FormatTemplate;
 use sparrow_plan::ValueRef;
 
 use crate::{Evaluator, EvaluatorFactory, RuntimeInfo, StaticInfo};
@@ -99,3 +104,393 @@ impl EvaluatorFactory for SubstringEvaluator {
         Ok(Box::new(Self { string, start, end }))
     }
 }
+
+/// Compile the regular expression from the literal `pattern` argument.
+///
+/// Returns `None` if the pattern is `null`.
+fn regex_literal(pattern: &ValueRef) -> anyhow::Result<Option<Regex>> {
+    match pattern.literal_value() {
+        Some(ScalarValue::Utf8(Some(pattern))) => Ok(Some(Regex::new(pattern)?)),
+        Some(ScalarValue::Utf8(None)) => Ok(None),
+        Some(other) => anyhow::bail!("expected string for pattern, saw {:?}", other),
+        None => anyhow::bail!("expected literal value for pattern"),
+    }
+}
+
+/// Evaluator for the `regex_match` function.
+pub(super) struct RegexMatchEvaluator {
+    input: ValueRef,
+    /// The compiled pattern, or `None` if the pattern was `null`.
+    regex: Option<Regex>,
+}
+
+impl Evaluator for RegexMatchEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        match &self.regex {
+            Some(regex) => {
+                let input = info.value(&self.input)?.string_array()?;
+                let result = sparrow_kernels::string::regex_match(input.as_ref(), regex)?;
+                Ok(Arc::new(result))
+            }
+            // Matching a `null` pattern is always null.
+            None => Ok(arrow::array::new_null_array(
+                &DataType::Boolean,
+                info.num_rows(),
+            )),
+        }
+    }
+}
+
+impl EvaluatorFactory for RegexMatchEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let (input, pattern) = info.unpack_arguments()?;
+        let regex = regex_literal(&pattern)?;
+        Ok(Box::new(Self { input, regex }))
+    }
+}
+
+/// Evaluator for the `regex_extract` function.
+pub(super) struct RegexExtractEvaluator {
+    input: ValueRef,
+    /// The compiled pattern and group to extract, or `None` if either was
+    /// `null`.
+    regex_group: Option<(Regex, usize)>,
+}
+
+impl Evaluator for RegexExtractEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        match &self.regex_group {
+            Some((regex, group)) => {
+                let input = info.value(&self.input)?.string_array()?;
+                let result = sparrow_kernels::string::regex_extract(input.as_ref(), regex, *group)?;
+                Ok(Arc::new(result))
+            }
+            None => Ok(arrow::array::new_null_array(
+                &DataType::Utf8,
+                info.num_rows(),
+            )),
+        }
+    }
+}
+
+impl EvaluatorFactory for RegexExtractEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let (input, pattern, group) = info.unpack_arguments()?;
+        let regex = regex_literal(&pattern)?;
+        let group = match group.literal_value() {
+            Some(ScalarValue::Int64(Some(group))) => Some(usize::try_from(*group)?),
+            Some(ScalarValue::Int64(None)) => None,
+            Some(other) => anyhow::bail!("expected i64 for group, saw {:?}", other),
+            None => anyhow::bail!("expected literal value for group"),
+        };
+        let regex_group = regex.zip(group);
+        Ok(Box::new(Self { input, regex_group }))
+    }
+}
+
+/// Evaluator for the `regex_replace` function.
+pub(super) struct RegexReplaceEvaluator {
+    input: ValueRef,
+    /// The compiled pattern, or `None` if the pattern was `null`.
+    regex: Option<Regex>,
+    replacement: ValueRef,
+}
+
+impl Evaluator for RegexReplaceEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        match &self.regex {
+            Some(regex) => {
+                let input = info.value(&self.input)?.string_array()?;
+                let replacement = info.value(&self.replacement)?.string_array()?;
+                let result = sparrow_kernels::string::regex_replace(
+                    input.as_ref(),
+                    regex,
+                    replacement.as_ref(),
+                )?;
+                Ok(Arc::new(result))
+            }
+            None => Ok(arrow::array::new_null_array(
+                &DataType::Utf8,
+                info.num_rows(),
+            )),
+        }
+    }
+}
+
+impl EvaluatorFactory for RegexReplaceEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let (input, pattern, replacement) = info.unpack_arguments()?;
+        let regex = regex_literal(&pattern)?;
+        Ok(Box::new(Self {
+            input,
+            regex,
+            replacement,
+        }))
+    }
+}
+
+/// Evaluator for the `concat` function.
+pub(super) struct ConcatEvaluator {
+    strings: Vec<ValueRef>,
+}
+
+impl Evaluator for ConcatEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let strings: Vec<_> = self
+            .strings
+            .iter()
+            .map(|string| info.value(string)?.string_array::<i32>())
+            .collect::<anyhow::Result<_>>()?;
+        let strings: Vec<&StringArray> = strings.iter().map(|string| string.as_ref()).collect();
+        let result = sparrow_kernels::string::concat(&strings)?;
+        Ok(Arc::new(result))
+    }
+}
+
+impl EvaluatorFactory for ConcatEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let strings = info.args.iter().map(|arg| arg.value_ref.clone()).collect();
+        Ok(Box::new(Self { strings }))
+    }
+}
+
+/// Evaluator for the `split` function.
+pub(super) struct SplitEvaluator {
+    input: ValueRef,
+    separator: ValueRef,
+}
+
+impl Evaluator for SplitEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let input = info.value(&self.input)?.string_array()?;
+        let separator = info.value(&self.separator)?.string_array()?;
+        let result = sparrow_kernels::string::split(input.as_ref(), separator.as_ref())?;
+        Ok(Arc::new(result))
+    }
+}
+
+impl EvaluatorFactory for SplitEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let (input, separator) = info.unpack_arguments()?;
+        Ok(Box::new(Self { input, separator }))
+    }
+}
+
+/// Evaluator for the `trim` function.
+pub(super) struct TrimEvaluator {
+    input: ValueRef,
+}
+
+impl Evaluator for TrimEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let input = info.value(&self.input)?.string_array()?;
+        let result = sparrow_kernels::string::trim(input.as_ref())?;
+        Ok(Arc::new(result))
+    }
+}
+
+impl EvaluatorFactory for TrimEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let input = info.unpack_argument()?;
+        Ok(Box::new(Self { input }))
+    }
+}
+
+/// Evaluator for the `starts_with` function.
+pub(super) struct StartsWithEvaluator {
+    input: ValueRef,
+    prefix: ValueRef,
+}
+
+impl Evaluator for StartsWithEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let input = info.value(&self.input)?.string_array()?;
+        let prefix = info.value(&self.prefix)?.string_array()?;
+        let result = sparrow_kernels::string::starts_with(input.as_ref(), prefix.as_ref())?;
+        Ok(Arc::new(result))
+    }
+}
+
+impl EvaluatorFactory for StartsWithEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let (input, prefix) = info.unpack_arguments()?;
+        Ok(Box::new(Self { input, prefix }))
+    }
+}
+
+/// Evaluator for the `ends_with` function.
+pub(super) struct EndsWithEvaluator {
+    input: ValueRef,
+    suffix: ValueRef,
+}
+
+impl Evaluator for EndsWithEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let input = info.value(&self.input)?.string_array()?;
+        let suffix = info.value(&self.suffix)?.string_array()?;
+        let result = sparrow_kernels::string::ends_with(input.as_ref(), suffix.as_ref())?;
+        Ok(Arc::new(result))
+    }
+}
+
+impl EvaluatorFactory for EndsWithEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let (input, suffix) = info.unpack_arguments()?;
+        Ok(Box::new(Self { input, suffix }))
+    }
+}
+
+/// Evaluator for the `contains` function.
+pub(super) struct ContainsEvaluator {
+    input: ValueRef,
+    substring: ValueRef,
+}
+
+impl Evaluator for ContainsEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let input = info.value(&self.input)?.string_array()?;
+        let substring = info.value(&self.substring)?.string_array()?;
+        let result = sparrow_kernels::string::contains(input.as_ref(), substring.as_ref())?;
+        Ok(Arc::new(result))
+    }
+}
+
+impl EvaluatorFactory for ContainsEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let (input, substring) = info.unpack_arguments()?;
+        Ok(Box::new(Self { input, substring }))
+    }
+}
+
+/// Evaluator for the `replace` function.
+pub(super) struct ReplaceEvaluator {
+    input: ValueRef,
+    pattern: ValueRef,
+    replacement: ValueRef,
+}
+
+impl Evaluator for ReplaceEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let input = info.value(&self.input)?.string_array()?;
+        let pattern = info.value(&self.pattern)?.string_array()?;
+        let replacement = info.value(&self.replacement)?.string_array()?;
+        let result = sparrow_kernels::string::replace(
+            input.as_ref(),
+            pattern.as_ref(),
+            replacement.as_ref(),
+        )?;
+        Ok(Arc::new(result))
+    }
+}
+
+impl EvaluatorFactory for ReplaceEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let (input, pattern, replacement) = info.unpack_arguments()?;
+        Ok(Box::new(Self {
+            input,
+            pattern,
+            replacement,
+        }))
+    }
+}
+
+/// Evaluator for the `lpad` function.
+pub(super) struct LpadEvaluator {
+    input: ValueRef,
+    length: ValueRef,
+    pad: ValueRef,
+}
+
+impl Evaluator for LpadEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let input = info.value(&self.input)?.string_array()?;
+        let length = info.value(&self.length)?.primitive_array::<Int64Type>()?;
+        let pad = info.value(&self.pad)?.string_array()?;
+        let result = sparrow_kernels::string::lpad(input.as_ref(), length.as_ref(), pad.as_ref())?;
+        Ok(Arc::new(result))
+    }
+}
+
+impl EvaluatorFactory for LpadEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let (input, length, pad) = info.unpack_arguments()?;
+        Ok(Box::new(Self { input, length, pad }))
+    }
+}
+
+/// Evaluator for the `rpad` function.
+pub(super) struct RpadEvaluator {
+    input: ValueRef,
+    length: ValueRef,
+    pad: ValueRef,
+}
+
+impl Evaluator for RpadEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        let input = info.value(&self.input)?.string_array()?;
+        let length = info.value(&self.length)?.primitive_array::<Int64Type>()?;
+        let pad = info.value(&self.pad)?.string_array()?;
+        let result = sparrow_kernels::string::rpad(input.as_ref(), length.as_ref(), pad.as_ref())?;
+        Ok(Arc::new(result))
+    }
+}
+
+impl EvaluatorFactory for RpadEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let (input, length, pad) = info.unpack_arguments()?;
+        Ok(Box::new(Self { input, length, pad }))
+    }
+}
+
+/// Evaluator for the `format` function.
+pub(super) struct FormatEvaluator {
+    /// The parsed template, or `None` if the template was `null`.
+    template: Option<FormatTemplate>,
+    values: Vec<ValueRef>,
+}
+
+impl Evaluator for FormatEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        match &self.template {
+            Some(template) => {
+                let values: Vec<_> = self
+                    .values
+                    .iter()
+                    .map(|value| info.value(value)?.string_array::<i32>())
+                    .collect::<anyhow::Result<_>>()?;
+                let values: Vec<&StringArray> = values.iter().map(|value| value.as_ref()).collect();
+                let result = sparrow_kernels::string::format(template, info.num_rows(), &values)?;
+                Ok(Arc::new(result))
+            }
+            None => Ok(arrow::array::new_null_array(
+                &DataType::Utf8,
+                info.num_rows(),
+            )),
+        }
+    }
+}
+
+impl EvaluatorFactory for FormatEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let (template, values) = info
+            .args
+            .split_first()
+            .context("expected template for format")?;
+        let template = match template.value_ref.literal_value() {
+            Some(ScalarValue::Utf8(Some(template))) => Some(FormatTemplate::try_new(template)?),
+            Some(ScalarValue::Utf8(None)) => None,
+            Some(other) => anyhow::bail!("expected string for template, saw {:?}", other),
+            None => anyhow::bail!("expected literal value for template"),
+        };
+        if let Some(template) = &template {
+            anyhow::ensure!(
+                template.num_values() <= values.len(),
+                "template references {} values, but only {} provided",
+                template.num_values(),
+                values.len()
+            );
+        }
+        let values = values.iter().map(|arg| arg.value_ref.clone()).collect();
+        Ok(Box::new(Self { template, values }))
+    }
+}
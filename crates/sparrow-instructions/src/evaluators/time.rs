@@ -1,16 +1,14 @@
 use std::sync::Arc;
 
-use arrow::array::{
-    ArrayRef, Int32Array, IntervalDayTimeArray, IntervalYearMonthArray, TimestampNanosecondArray,
-    UInt32Array,
-};
+use arrow::array::{ArrayRef, IntervalDayTimeArray, IntervalYearMonthArray, PrimitiveArray};
 use arrow::datatypes::{
     ArrowPrimitiveType, DataType, DurationMicrosecondType, DurationMillisecondType,
-    DurationNanosecondType, DurationSecondType, Int64Type, IntervalDayTimeType, IntervalUnit,
-    IntervalYearMonthType, TimeUnit, TimestampNanosecondType,
+    DurationNanosecondType, DurationSecondType, Int32Type, Int64Type, IntervalDayTimeType,
+    IntervalUnit, IntervalYearMonthType, TimeUnit, TimestampNanosecondType, UInt32Type,
 };
-use arrow::temporal_conversions::timestamp_ns_to_datetime;
-use chrono::Datelike;
+use chrono::{Datelike, NaiveDateTime};
+use sparrow_arrow::scalar_value::ScalarValue;
+use sparrow_kernels::time::Tz;
 use sparrow_plan::ValueRef;
 
 use crate::evaluators::{Evaluator, RuntimeInfo};
@@ -65,97 +63,175 @@ impl EvaluatorFactory for DaysEvaluator {
     }
 }
 
-/// Evaluator for the `DayOfMonth` instruction.
-pub(super) struct DayOfMonthEvaluator {
-    input: ValueRef,
+/// Parse the time zone from the literal `tz` argument.
+///
+/// Returns `None` if the time zone is `null`.
+fn time_zone_literal(time_zone: &ValueRef) -> anyhow::Result<Option<Tz>> {
+    match time_zone.literal_value() {
+        Some(ScalarValue::Utf8(Some(time_zone))) => {
+            Ok(Some(sparrow_kernels::time::parse_time_zone(time_zone)?))
+        }
+        Some(ScalarValue::Utf8(None)) => Ok(None),
+        Some(other) => anyhow::bail!("expected string for time zone, saw {:?}", other),
+        None => anyhow::bail!("expected literal value for time zone"),
+    }
 }
 
-#[allow(clippy::redundant_closure)]
+/// Apply `f` to the local date time of each input time in `time_zone`.
+///
+/// Returns `null` for all rows if the time zone is `null`.
 fn evaluate_time_accessor<O, F>(
-    time: &TimestampNanosecondArray,
+    info: &dyn RuntimeInfo,
+    input: &ValueRef,
+    time_zone: Option<&Tz>,
     f: F,
-) -> arrow::array::PrimitiveArray<O>
+) -> anyhow::Result<ArrayRef>
 where
     O: ArrowPrimitiveType,
-    F: Fn(chrono::NaiveDateTime) -> O::Native,
+    F: Fn(NaiveDateTime) -> O::Native,
 {
-    time.unary_opt(|t| timestamp_ns_to_datetime(t).map(|t| f(t)))
+    match time_zone {
+        Some(time_zone) => {
+            let time = info
+                .value(input)?
+                .primitive_array::<TimestampNanosecondType>()?;
+            let result: PrimitiveArray<O> =
+                sparrow_kernels::time::local_time_accessor(time.as_ref(), time_zone, f);
+            Ok(Arc::new(result))
+        }
+        None => Ok(arrow::array::new_null_array(&O::DATA_TYPE, info.num_rows())),
+    }
+}
+
+/// Evaluator for the `DayOfMonth` instruction.
+pub(super) struct DayOfMonthEvaluator {
+    input: ValueRef,
+    time_zone: Option<Tz>,
 }
 
 impl Evaluator for DayOfMonthEvaluator {
     fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
-        let time = info.value(&self.input)?.primitive_array()?;
-        let result: UInt32Array = evaluate_time_accessor(time.as_ref(), |t| t.day());
-
-        Ok(Arc::new(result))
+        evaluate_time_accessor::<UInt32Type, _>(info, &self.input, self.time_zone.as_ref(), |t| {
+            t.day()
+        })
     }
 }
 
 impl EvaluatorFactory for DayOfMonthEvaluator {
     fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
-        let input = info.unpack_argument()?;
-        Ok(Box::new(Self { input }))
+        let (input, time_zone) = info.unpack_arguments()?;
+        let time_zone = time_zone_literal(&time_zone)?;
+        Ok(Box::new(Self { input, time_zone }))
     }
 }
 
 /// Evaluator for the `DayOfMonth0` instruction.
 pub(super) struct DayOfMonth0Evaluator {
     input: ValueRef,
+    time_zone: Option<Tz>,
 }
 
 impl Evaluator for DayOfMonth0Evaluator {
     fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
-        let time = info.value(&self.input)?.primitive_array()?;
-        let result: UInt32Array = evaluate_time_accessor(time.as_ref(), |t| t.day0());
-
-        Ok(Arc::new(result))
+        evaluate_time_accessor::<UInt32Type, _>(info, &self.input, self.time_zone.as_ref(), |t| {
+            t.day0()
+        })
     }
 }
 
 impl EvaluatorFactory for DayOfMonth0Evaluator {
     fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
-        let input = info.unpack_argument()?;
-        Ok(Box::new(Self { input }))
+        let (input, time_zone) = info.unpack_arguments()?;
+        let time_zone = time_zone_literal(&time_zone)?;
+        Ok(Box::new(Self { input, time_zone }))
+    }
+}
+
+/// Evaluator for the `DayOfWeek` instruction.
+pub(super) struct DayOfWeekEvaluator {
+    input: ValueRef,
+    time_zone: Option<Tz>,
+}
+
+impl Evaluator for DayOfWeekEvaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        evaluate_time_accessor::<UInt32Type, _>(info, &self.input, self.time_zone.as_ref(), |t| {
+            t.weekday().number_from_monday()
+        })
+    }
+}
+
+impl EvaluatorFactory for DayOfWeekEvaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let (input, time_zone) = info.unpack_arguments()?;
+        let time_zone = time_zone_literal(&time_zone)?;
+        Ok(Box::new(Self { input, time_zone }))
+    }
+}
+
+/// Evaluator for the `DayOfWeek0` instruction.
+pub(super) struct DayOfWeek0Evaluator {
+    input: ValueRef,
+    time_zone: Option<Tz>,
+}
+
+impl Evaluator for DayOfWeek0Evaluator {
+    fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
+        evaluate_time_accessor::<UInt32Type, _>(info, &self.input, self.time_zone.as_ref(), |t| {
+            t.weekday().num_days_from_monday()
+        })
+    }
+}
+
+impl EvaluatorFactory for DayOfWeek0Evaluator {
+    fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
+        let (input, time_zone) = info.unpack_arguments()?;
+        let time_zone = time_zone_literal(&time_zone)?;
+        Ok(Box::new(Self { input, time_zone }))
     }
 }
 
 /// Evaluator for the `DayOfYear` instruction.
 pub(super) struct DayOfYearEvaluator {
     input: ValueRef,
+    time_zone: Option<Tz>,
 }
 
 impl Evaluator for DayOfYearEvaluator {
     fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
-        let time = info.value(&self.input)?.primitive_array()?;
-        let result: UInt32Array = evaluate_time_accessor(time.as_ref(), |t| t.ordinal());
-        Ok(Arc::new(result))
+        evaluate_time_accessor::<UInt32Type, _>(info, &self.input, self.time_zone.as_ref(), |t| {
+            t.ordinal()
+        })
     }
 }
 
 impl EvaluatorFactory for DayOfYearEvaluator {
     fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
-        let input = info.unpack_argument()?;
-        Ok(Box::new(Self { input }))
+        let (input, time_zone) = info.unpack_arguments()?;
+        let time_zone = time_zone_literal(&time_zone)?;
+        Ok(Box::new(Self { input, time_zone }))
     }
 }
 
 /// Evaluator for the `DayOfYear0` instruction.
 pub(super) struct DayOfYear0Evaluator {
     input: ValueRef,
+    time_zone: Option<Tz>,
 }
 
 impl Evaluator for DayOfYear0Evaluator {
     fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
-        let time = info.value(&self.input)?.primitive_array()?;
-        let result: UInt32Array = evaluate_time_accessor(time.as_ref(), |t| t.ordinal0());
-        Ok(Arc::new(result))
+        evaluate_time_accessor::<UInt32Type, _>(info, &self.input, self.time_zone.as_ref(), |t| {
+            t.ordinal0()
+        })
     }
 }
 
 impl EvaluatorFactory for DayOfYear0Evaluator {
     fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
-        let input = info.unpack_argument()?;
-        Ok(Box::new(Self { input }))
+        let (input, time_zone) = info.unpack_arguments()?;
+        let time_zone = time_zone_literal(&time_zone)?;
+        Ok(Box::new(Self { input, time_zone }))
     }
 }
 
@@ -194,60 +270,66 @@ impl EvaluatorFactory for MonthsEvaluator {
 /// Evaluator for the `MonthOfYear` instruction.
 pub(super) struct MonthOfYearEvaluator {
     input: ValueRef,
+    time_zone: Option<Tz>,
 }
 
 impl Evaluator for MonthOfYearEvaluator {
     fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
-        let time = info.value(&self.input)?.primitive_array()?;
-        let result: UInt32Array = evaluate_time_accessor(time.as_ref(), |t| t.month());
-        Ok(Arc::new(result))
+        evaluate_time_accessor::<UInt32Type, _>(info, &self.input, self.time_zone.as_ref(), |t| {
+            t.month()
+        })
     }
 }
 
 impl EvaluatorFactory for MonthOfYearEvaluator {
     fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
-        let input = info.unpack_argument()?;
-        Ok(Box::new(Self { input }))
+        let (input, time_zone) = info.unpack_arguments()?;
+        let time_zone = time_zone_literal(&time_zone)?;
+        Ok(Box::new(Self { input, time_zone }))
     }
 }
 
 /// Evaluator for the `MonthOfYear0` instruction.
 pub(super) struct MonthOfYear0Evaluator {
     input: ValueRef,
+    time_zone: Option<Tz>,
 }
 
 impl Evaluator for MonthOfYear0Evaluator {
     fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
-        let time = info.value(&self.input)?.primitive_array()?;
-        let result: UInt32Array = evaluate_time_accessor(time.as_ref(), |t| t.month0());
-        Ok(Arc::new(result))
+        evaluate_time_accessor::<UInt32Type, _>(info, &self.input, self.time_zone.as_ref(), |t| {
+            t.month0()
+        })
     }
 }
 
 impl EvaluatorFactory for MonthOfYear0Evaluator {
     fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
-        let input = info.unpack_argument()?;
-        Ok(Box::new(Self { input }))
+        let (input, time_zone) = info.unpack_arguments()?;
+        let time_zone = time_zone_literal(&time_zone)?;
+        Ok(Box::new(Self { input, time_zone }))
     }
 }
 
 /// Evaluator for the `Year` instruction.
 pub(super) struct YearEvaluator {
     input: ValueRef,
+    time_zone: Option<Tz>,
 }
 
 impl Evaluator for YearEvaluator {
     fn evaluate(&mut self, info: &dyn RuntimeInfo) -> anyhow::Result<ArrayRef> {
-        let time = info.value(&self.input)?.primitive_array()?;
-        let result: Int32Array = evaluate_time_accessor(time.as_ref(), |t| t.year());
-        Ok(Arc::new(result))
+        evaluate_time_accessor::<Int32Type, _>(info, &self.input, self.time_zone.as_ref(), |t| {
+            t.year()
+        })
     }
 }
 
 impl EvaluatorFactory for YearEvaluator {
     fn try_new(info: StaticInfo<'_>) -> anyhow::Result<Box<dyn Evaluator>> {
-        let input = info.unpack_argument()?;
-        Ok(Box::new(Self { input }))
+        let (input, time_zone) = info.unpack_arguments()?;
+        let time_zone = time_zone_literal(&time_zone)?;
+        Ok(Box::new(Self { input, time_zone }))
     }
 }
 
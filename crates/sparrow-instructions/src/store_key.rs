@@ -11,6 +11,7 @@ use smallvec::SmallVec;
 ///   operation.
 /// - `otk<operation_index>` for the key hash enumeration in the tick operation.
 /// - `ots<operation_index>` for the tick state.
+/// - `otp<operation_index>` for the tick schedule (period).
 /// - `oss<operation_index>` for the shift subsort value.
 /// - `osrb<operation_index>` for the shift operation's pending or retained
 ///   batches.
@@ -68,6 +69,18 @@ impl StoreKey {
         Self { key }
     }
 
+    /// Create a `StoreKey` for the schedule of a tick operation.
+    ///
+    /// Schedules are encoded as `otp<operation_index>`. The operation ID is
+    /// a single `u8`.
+    pub fn new_tick_schedule(operation_index: u8) -> Self {
+        let mut key = SmallVec::with_capacity(4);
+        // (o)peration, (t)ick, (p)eriod
+        key.extend_from_slice(b"otp"); // 3
+        key.push(operation_index); // 1
+        Self { key }
+    }
+
     /// Create a `StoreKey` for an instruction.
     pub fn new_merge_state(operation_index: u8) -> Self {
         let mut key = SmallVec::with_capacity(4);
@@ -0,0 +1,161 @@
+//! Kernels for parsing JSON strings into Arrow values.
+
+use std::sync::Arc;
+
+use anyhow::Context;
+use arrow::array::{new_null_array, Array, ArrayRef, StringArray};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::json::reader::Decoder;
+use arrow::json::ReaderBuilder;
+use serde::de::IgnoredAny;
+
+/// Parse each element of `strings` as JSON into a value of `data_type`.
+///
+/// The result is `null` for elements that are `null`, are not valid JSON, or
+/// cannot be converted to `data_type`. Fields of objects not present in
+/// `data_type` are ignored, and fields of `data_type` missing from an object
+/// are `null`.
+pub fn parse_json(strings: &StringArray, data_type: &DataType) -> anyhow::Result<ArrayRef> {
+    if strings.is_empty() {
+        return Ok(new_null_array(data_type, 0));
+    }
+
+    // Each element is decoded as the `value` of a single-field row.
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "value",
+        data_type.clone(),
+        true,
+    )]));
+    let decoder = || {
+        ReaderBuilder::new(schema.clone())
+            .with_batch_size(strings.len())
+            .with_coerce_primitive(true)
+            .build_decoder()
+    };
+
+    let rows: Vec<String> = strings.iter().map(json_row).collect();
+    match decode(decoder()?, rows.iter()) {
+        Ok(result) => Ok(result),
+        Err(_) => {
+            // At least one element couldn't be converted to the requested type.
+            // Decode each element separately so only those elements are null.
+            let results = rows
+                .iter()
+                .map(|row| {
+                    decode(decoder()?, std::iter::once(row))
+                        .or_else(|_| Ok(new_null_array(data_type, 1)))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            let results: Vec<_> = results.iter().map(AsRef::as_ref).collect();
+            Ok(arrow::compute::concat(&results)?)
+        }
+    }
+}
+
+/// Return the row to decode for the given element.
+///
+/// Elements are validated before being wrapped, so a malformed element can't
+/// affect the rows around it.
+fn json_row(element: Option<&str>) -> String {
+    match element {
+        Some(json) if serde_json::from_str::<IgnoredAny>(json).is_ok() => {
+            format!("{{\"value\":{json}}}")
+        }
+        _ => "{\"value\":null}".to_owned(),
+    }
+}
+
+fn decode<'a>(
+    mut decoder: Decoder,
+    rows: impl Iterator<Item = &'a String>,
+) -> anyhow::Result<ArrayRef> {
+    for row in rows {
+        let read = decoder.decode(row.as_bytes())?;
+        anyhow::ensure!(read == row.len(), "expected to decode entire row");
+    }
+    let batch = decoder.flush()?.context("expected decoded rows")?;
+    Ok(batch.column(0).clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Int64Array, ListArray, StructArray};
+    use arrow::datatypes::Int64Type;
+    use sparrow_arrow::downcast::downcast_struct_array;
+
+    use super::*;
+
+    fn validity(array: &dyn Array) -> Vec<bool> {
+        (0..array.len()).map(|i| array.is_valid(i)).collect()
+    }
+
+    fn struct_type() -> DataType {
+        let list = DataType::List(Arc::new(Field::new("item", DataType::Int64, true)));
+        let inner = DataType::Struct(vec![Field::new("c", list, true)].into());
+        DataType::Struct(
+            vec![
+                Field::new("a", DataType::Int64, true),
+                Field::new("b", inner, true),
+            ]
+            .into(),
+        )
+    }
+
+    #[test]
+    fn test_parse_struct() {
+        let strings = StringArray::from(vec![
+            Some(r#"{"a": 5, "b": {"c": [1, 2]}}"#),
+            None,
+            Some(r#"{"a": 6, "d": "ignored"}"#),
+            Some("not json"),
+            Some(r#"{"b": {"c": []}}"#),
+        ]);
+        let result = parse_json(&strings, &struct_type()).unwrap();
+        let result: &StructArray = downcast_struct_array(result.as_ref()).unwrap();
+
+        assert_eq!(validity(result), vec![true, false, true, false, true]);
+
+        let a = result.column(0);
+        let a = a.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(
+            a,
+            &Int64Array::from(vec![Some(5), None, Some(6), None, None])
+        );
+
+        let b: &StructArray = downcast_struct_array(result.column(1).as_ref()).unwrap();
+        let c = b.column(0).as_any().downcast_ref::<ListArray>().unwrap();
+        let expected = ListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            None,
+            None,
+            Some(vec![]),
+        ]);
+        assert_eq!(c, &expected);
+    }
+
+    #[test]
+    fn test_parse_mismatched_types() {
+        let strings = StringArray::from(vec![
+            Some(r#"{"a": 5}"#),
+            Some(r#"{"a": "five"}"#),
+            Some("[1, 2]"),
+            Some(r#"{"a": 7}"#),
+        ]);
+        let result = parse_json(&strings, &struct_type()).unwrap();
+        let result: &StructArray = downcast_struct_array(result.as_ref()).unwrap();
+
+        assert_eq!(validity(result), vec![true, false, false, true]);
+        let a = result.column(0);
+        let a = a.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(a, &Int64Array::from(vec![Some(5), None, None, Some(7)]));
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        let strings = StringArray::from(Vec::<Option<&str>>::new());
+        let result = parse_json(&strings, &struct_type()).unwrap();
+        assert_eq!(result.len(), 0);
+        assert_eq!(result.data_type(), &struct_type());
+    }
+}
@@ -9,6 +9,8 @@
     clippy::print_stderr,
     clippy::undocumented_unsafe_blocks
 )]
+pub mod json;
+pub mod math;
 mod ordered_cast;
 pub mod string;
 pub mod time;
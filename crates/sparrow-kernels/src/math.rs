@@ -0,0 +1,9 @@
+mod division;
+mod log;
+mod sign;
+mod trig;
+
+pub use division::{floor_div, modulo, rem};
+pub use log::{ln, log10};
+pub use sign::{abs, sign};
+pub use trig::{acos, asin, atan, atan2, cos, sin, tan};
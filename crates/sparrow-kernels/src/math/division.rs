@@ -0,0 +1,154 @@
+use std::convert::Infallible;
+
+use arrow::array::PrimitiveArray;
+use arrow::datatypes::{ArrowNativeTypeOp, ArrowNumericType};
+use itertools::izip;
+
+/// Return the remainder of dividing each `a` by the corresponding `b`.
+///
+/// The remainder has the same sign as `a`. The result is `null` if either
+/// element is `null` or if `b` is `0`.
+pub fn rem<T>(a: &PrimitiveArray<T>, b: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>, Infallible>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp,
+{
+    Ok(checked_division(a, b, |a, b| a.mod_wrapping(b)))
+}
+
+/// Return the result of dividing each `a` by the corresponding `b`, rounded
+/// towards negative infinity.
+///
+/// The result is `null` if either element is `null` or if `b` is `0`.
+pub fn floor_div<T>(
+    a: &PrimitiveArray<T>,
+    b: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>, Infallible>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp + PartialOrd,
+{
+    Ok(checked_division(a, b, |a, b| {
+        let quotient = a.div_wrapping(b);
+        if needs_adjustment(a.mod_wrapping(b), b) {
+            quotient.sub_wrapping(T::Native::ONE)
+        } else {
+            quotient
+        }
+    }))
+}
+
+/// Return the modulus of each `a` by the corresponding `b`.
+///
+/// The modulus has the same sign as `b`, so that
+/// `floor_div(a, b) * b + modulo(a, b) == a`. The result is `null` if either
+/// element is `null` or if `b` is `0`.
+pub fn modulo<T>(
+    a: &PrimitiveArray<T>,
+    b: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>, Infallible>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp + PartialOrd,
+{
+    Ok(checked_division(a, b, |a, b| {
+        let remainder = a.mod_wrapping(b);
+        if needs_adjustment(remainder, b) {
+            remainder.add_wrapping(b)
+        } else {
+            remainder
+        }
+    }))
+}
+
+/// Whether the truncated `remainder` of a division by `divisor` has the
+/// opposite sign of the divisor, requiring adjustment to round down.
+fn needs_adjustment<N: ArrowNativeTypeOp + PartialOrd>(remainder: N, divisor: N) -> bool {
+    !remainder.is_zero() && ((remainder < N::ZERO) != (divisor < N::ZERO))
+}
+
+/// Apply `f` to the corresponding elements of `a` and `b`.
+///
+/// The result is `null` if either element is `null` or if `b` is `0`.
+fn checked_division<T: ArrowNumericType>(
+    a: &PrimitiveArray<T>,
+    b: &PrimitiveArray<T>,
+    f: impl Fn(T::Native, T::Native) -> T::Native,
+) -> PrimitiveArray<T>
+where
+    T::Native: ArrowNativeTypeOp,
+{
+    izip!(a.iter(), b.iter())
+        .map(|(a, b)| match (a, b) {
+            (Some(a), Some(b)) if !b.is_zero() => Some(f(a, b)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Float64Array, Int64Array, UInt64Array};
+
+    use super::*;
+
+    #[test]
+    fn test_rem_i64() {
+        let a = Int64Array::from(vec![Some(7), Some(-7), Some(7), Some(-7), Some(5), None]);
+        let b = Int64Array::from(vec![Some(3), Some(3), Some(-3), Some(-3), Some(0), Some(3)]);
+        let expected = Int64Array::from(vec![Some(1), Some(-1), Some(1), Some(-1), None, None]);
+        assert_eq!(rem(&a, &b).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_rem_f64() {
+        let a = Float64Array::from(vec![Some(7.5), Some(-7.5), Some(1.0)]);
+        let b = Float64Array::from(vec![Some(2.0), Some(2.0), Some(0.0)]);
+        let expected = Float64Array::from(vec![Some(1.5), Some(-1.5), None]);
+        assert_eq!(rem(&a, &b).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_floor_div_i64() {
+        let a = Int64Array::from(vec![Some(7), Some(-7), Some(7), Some(-7), Some(6), Some(5)]);
+        let b = Int64Array::from(vec![
+            Some(3),
+            Some(3),
+            Some(-3),
+            Some(-3),
+            Some(-3),
+            Some(0),
+        ]);
+        let expected = Int64Array::from(vec![Some(2), Some(-3), Some(-3), Some(2), Some(-2), None]);
+        assert_eq!(floor_div(&a, &b).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_modulo_i64() {
+        let a = Int64Array::from(vec![Some(7), Some(-7), Some(7), Some(-7), Some(6), None]);
+        let b = Int64Array::from(vec![
+            Some(3),
+            Some(3),
+            Some(-3),
+            Some(-3),
+            Some(-3),
+            Some(3),
+        ]);
+        let expected = Int64Array::from(vec![Some(1), Some(2), Some(-2), Some(-1), Some(0), None]);
+        assert_eq!(modulo(&a, &b).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_floor_div_modulo_u64() {
+        let a = UInt64Array::from(vec![Some(7), Some(2), Some(3)]);
+        let b = UInt64Array::from(vec![Some(3), Some(5), Some(0)]);
+        assert_eq!(
+            floor_div(&a, &b).unwrap(),
+            UInt64Array::from(vec![Some(2), Some(0), None])
+        );
+        assert_eq!(
+            modulo(&a, &b).unwrap(),
+            UInt64Array::from(vec![Some(1), Some(2), None])
+        );
+    }
+}
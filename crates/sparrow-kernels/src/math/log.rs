@@ -0,0 +1,56 @@
+use std::convert::Infallible;
+
+use arrow::array::PrimitiveArray;
+use arrow::datatypes::ArrowNumericType;
+use num::Float;
+
+/// Return the natural logarithm of each element.
+///
+/// The logarithm of `0` is negative infinity, and the logarithm of a
+/// negative number is `NaN`.
+pub fn ln<T>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>, Infallible>
+where
+    T: ArrowNumericType,
+    T::Native: Float,
+{
+    Ok(array.unary(|n| n.ln()))
+}
+
+/// Return the base 10 logarithm of each element.
+///
+/// The logarithm of `0` is negative infinity, and the logarithm of a
+/// negative number is `NaN`.
+pub fn log10<T>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>, Infallible>
+where
+    T: ArrowNumericType,
+    T::Native: Float,
+{
+    Ok(array.unary(|n| n.log10()))
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Array, Float64Array};
+
+    use super::*;
+
+    #[test]
+    fn test_ln() {
+        let array = Float64Array::from(vec![Some(1.0), Some(std::f64::consts::E), None, Some(0.0)]);
+        let actual = ln(&array).unwrap();
+        assert_eq!(actual.value(0), 0.0);
+        assert_eq!(actual.value(1), 1.0);
+        assert!(actual.is_null(2));
+        assert_eq!(actual.value(3), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_log10() {
+        let array = Float64Array::from(vec![Some(1000.0), Some(0.01), None, Some(-1.0)]);
+        let actual = log10(&array).unwrap();
+        assert_eq!(actual.value(0), 3.0);
+        assert_eq!(actual.value(1), -2.0);
+        assert!(actual.is_null(2));
+        assert!(actual.value(3).is_nan());
+    }
+}
@@ -0,0 +1,88 @@
+use std::convert::Infallible;
+
+use arrow::array::PrimitiveArray;
+use arrow::datatypes::{ArrowNativeTypeOp, ArrowNumericType};
+
+/// Return the absolute value of each element.
+///
+/// Unsigned values are returned unchanged. The absolute value of the
+/// minimum signed integer wraps around to itself.
+pub fn abs<T>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>, Infallible>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp + PartialOrd,
+{
+    Ok(array.unary(|n| {
+        if n < T::Native::ZERO {
+            n.neg_wrapping()
+        } else {
+            n
+        }
+    }))
+}
+
+/// Return the sign of each element.
+///
+/// The result is `1` for positive values, `-1` for negative values and `0`
+/// for zero. Floating point `NaN` is returned unchanged.
+pub fn sign<T>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>, Infallible>
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeTypeOp + PartialOrd,
+{
+    Ok(array.unary(|n| {
+        if n > T::Native::ZERO {
+            T::Native::ONE
+        } else if n < T::Native::ZERO {
+            T::Native::ONE.neg_wrapping()
+        } else {
+            // Zero and `NaN` are their own sign.
+            n
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{Float64Array, Int64Array, UInt32Array};
+
+    use super::*;
+
+    #[test]
+    fn test_abs_i64() {
+        let array = Int64Array::from(vec![Some(-5), Some(0), None, Some(7), Some(i64::MIN)]);
+        let expected = Int64Array::from(vec![Some(5), Some(0), None, Some(7), Some(i64::MIN)]);
+        assert_eq!(abs(&array).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_abs_f64() {
+        let array = Float64Array::from(vec![Some(-5.5), Some(2.25), None]);
+        let expected = Float64Array::from(vec![Some(5.5), Some(2.25), None]);
+        assert_eq!(abs(&array).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_sign_i64() {
+        let array = Int64Array::from(vec![Some(-5), Some(0), None, Some(7)]);
+        let expected = Int64Array::from(vec![Some(-1), Some(0), None, Some(1)]);
+        assert_eq!(sign(&array).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_sign_u32() {
+        let array = UInt32Array::from(vec![Some(0), Some(8), None]);
+        let expected = UInt32Array::from(vec![Some(0), Some(1), None]);
+        assert_eq!(sign(&array).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_sign_f64() {
+        let array = Float64Array::from(vec![Some(-0.5), Some(0.0), Some(3.5), Some(f64::NAN)]);
+        let actual = sign(&array).unwrap();
+        assert_eq!(actual.value(0), -1.0);
+        assert_eq!(actual.value(1), 0.0);
+        assert_eq!(actual.value(2), 1.0);
+        assert!(actual.value(3).is_nan());
+    }
+}
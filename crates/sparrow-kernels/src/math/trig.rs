@@ -0,0 +1,141 @@
+use std::convert::Infallible;
+
+use arrow::array::PrimitiveArray;
+use arrow::datatypes::ArrowNumericType;
+use arrow::error::ArrowError;
+use num::Float;
+
+/// Return the sine of each element, in radians.
+pub fn sin<T>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>, Infallible>
+where
+    T: ArrowNumericType,
+    T::Native: Float,
+{
+    Ok(array.unary(|n| n.sin()))
+}
+
+/// Return the cosine of each element, in radians.
+pub fn cos<T>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>, Infallible>
+where
+    T: ArrowNumericType,
+    T::Native: Float,
+{
+    Ok(array.unary(|n| n.cos()))
+}
+
+/// Return the tangent of each element, in radians.
+pub fn tan<T>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>, Infallible>
+where
+    T: ArrowNumericType,
+    T::Native: Float,
+{
+    Ok(array.unary(|n| n.tan()))
+}
+
+/// Return the arcsine of each element, in radians.
+///
+/// Elements outside of `[-1, 1]` produce `NaN`.
+pub fn asin<T>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>, Infallible>
+where
+    T: ArrowNumericType,
+    T::Native: Float,
+{
+    Ok(array.unary(|n| n.asin()))
+}
+
+/// Return the arccosine of each element, in radians.
+///
+/// Elements outside of `[-1, 1]` produce `NaN`.
+pub fn acos<T>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>, Infallible>
+where
+    T: ArrowNumericType,
+    T::Native: Float,
+{
+    Ok(array.unary(|n| n.acos()))
+}
+
+/// Return the arctangent of each element, in radians.
+pub fn atan<T>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>, Infallible>
+where
+    T: ArrowNumericType,
+    T::Native: Float,
+{
+    Ok(array.unary(|n| n.atan()))
+}
+
+/// Return the four quadrant arctangent of each `y` and `x`, in radians.
+///
+/// The result is `null` if either element is `null`.
+pub fn atan2<T>(
+    y: &PrimitiveArray<T>,
+    x: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>, ArrowError>
+where
+    T: ArrowNumericType,
+    T::Native: Float,
+{
+    arrow::compute::binary(y, x, |y, x| y.atan2(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    use approx::assert_relative_eq;
+    use arrow::array::{Array, Float64Array};
+
+    use super::*;
+
+    #[test]
+    fn test_sin_cos_tan() {
+        let array = Float64Array::from(vec![Some(0.0), Some(FRAC_PI_2), None]);
+
+        let actual = sin(&array).unwrap();
+        assert_relative_eq!(actual.value(0), 0.0);
+        assert_relative_eq!(actual.value(1), 1.0);
+        assert!(actual.is_null(2));
+
+        let actual = cos(&array).unwrap();
+        assert_relative_eq!(actual.value(0), 1.0);
+        assert_relative_eq!(actual.value(1), 0.0);
+        assert!(actual.is_null(2));
+
+        let actual = tan(&array).unwrap();
+        assert_relative_eq!(actual.value(0), 0.0);
+        assert!(actual.is_null(2));
+    }
+
+    #[test]
+    fn test_inverse() {
+        let array = Float64Array::from(vec![Some(1.0), Some(0.0), Some(2.0), None]);
+
+        let actual = asin(&array).unwrap();
+        assert_relative_eq!(actual.value(0), FRAC_PI_2);
+        assert_relative_eq!(actual.value(1), 0.0);
+        assert!(actual.value(2).is_nan());
+        assert!(actual.is_null(3));
+
+        let actual = acos(&array).unwrap();
+        assert_relative_eq!(actual.value(0), 0.0);
+        assert_relative_eq!(actual.value(1), FRAC_PI_2);
+        assert!(actual.value(2).is_nan());
+        assert!(actual.is_null(3));
+
+        let actual = atan(&array).unwrap();
+        assert_relative_eq!(actual.value(0), FRAC_PI_4);
+        assert_relative_eq!(actual.value(1), 0.0);
+        assert!(actual.is_null(3));
+    }
+
+    #[test]
+    fn test_atan2() {
+        let y = Float64Array::from(vec![Some(1.0), Some(0.0), Some(1.0), None]);
+        let x = Float64Array::from(vec![Some(1.0), Some(-1.0), None, Some(1.0)]);
+
+        let actual = atan2(&y, &x).unwrap();
+        assert_relative_eq!(actual.value(0), FRAC_PI_4);
+        assert_relative_eq!(actual.value(1), PI);
+        assert!(actual.is_null(2));
+        assert!(actual.is_null(3));
+    }
+}
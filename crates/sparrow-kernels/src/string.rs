@@ -1,8 +1,28 @@
+mod concat;
+mod format;
 mod lower;
+mod pad;
+mod predicates;
+mod regex_extract;
+mod regex_match;
+mod regex_replace;
+mod replace;
+mod split;
 mod substring;
+mod trim;
 mod upper;
 
+pub use concat::concat;
+pub use format::{format, FormatTemplate};
 pub use lower::lower;
+pub use pad::{lpad, rpad};
+pub use predicates::{contains, ends_with, starts_with};
+pub use regex_extract::regex_extract;
+pub use regex_match::regex_match;
+pub use regex_replace::regex_replace;
+pub use replace::replace;
+pub use split::split;
+pub use trim::trim;
 pub use upper::upper;
 
 pub use self::substring::substring;
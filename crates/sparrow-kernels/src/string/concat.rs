@@ -0,0 +1,48 @@
+use std::convert::Infallible;
+
+use arrow::array::{Array, StringArray, StringBuilder};
+
+/// Return the concatenation of the corresponding elements of each array.
+///
+/// The result is `null` if any of the elements are `null`.
+pub fn concat(strings: &[&StringArray]) -> Result<StringArray, Infallible> {
+    let len = strings.first().map_or(0, |s| s.len());
+    let capacity = strings.iter().map(|s| s.value_data().len()).sum();
+
+    let mut builder = StringBuilder::with_capacity(len, capacity);
+    let mut value = String::new();
+    for index in 0..len {
+        if strings.iter().any(|s| s.is_null(index)) {
+            builder.append_null();
+        } else {
+            value.clear();
+            strings.iter().for_each(|s| value.push_str(s.value(index)));
+            builder.append_value(&value);
+        }
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_concat() {
+        let a = StringArray::from(vec![Some("hello"), None, Some(""), Some("good")]);
+        let b = StringArray::from(vec![Some(" "), Some(" "), Some(""), Some("bye")]);
+        let c = StringArray::from(vec![Some("world"), Some("world"), Some("!"), None]);
+        let expected = StringArray::from(vec![Some("hello world"), None, Some("!"), None]);
+
+        let actual = concat(&[&a, &b, &c]).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_concat_single() {
+        let a = StringArray::from(vec![Some("hello"), None]);
+
+        let actual = concat(&[&a]).unwrap();
+        assert_eq!(a, actual);
+    }
+}
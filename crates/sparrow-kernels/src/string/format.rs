@@ -0,0 +1,187 @@
+use std::convert::Infallible;
+
+use arrow::array::{Array, StringArray, StringBuilder};
+
+/// A parsed template for [format].
+///
+/// Templates contain literal text and placeholders. The placeholder `{}`
+/// refers to the next value, while `{N}` refers to the value at index `N`.
+/// Literal braces are written as `{{` and `}}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatTemplate {
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    Value(usize),
+}
+
+impl FormatTemplate {
+    /// Parse the given template.
+    pub fn try_new(template: &str) -> anyhow::Result<Self> {
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+        let mut next_value = 0;
+
+        let mut chars = template.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '{' => {
+                    let mut index = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) if c.is_ascii_digit() => index.push(c),
+                            Some(c) => {
+                                anyhow::bail!("invalid character '{c}' in placeholder")
+                            }
+                            None => anyhow::bail!("unmatched '{{' in template"),
+                        }
+                    }
+
+                    let index = if index.is_empty() {
+                        next_value += 1;
+                        next_value - 1
+                    } else {
+                        index.parse()?
+                    };
+
+                    if !literal.is_empty() {
+                        segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                    }
+                    segments.push(Segment::Value(index));
+                }
+                '}' => anyhow::bail!("unmatched '}}' in template"),
+                c => literal.push(c),
+            }
+        }
+
+        if !literal.is_empty() {
+            segments.push(Segment::Literal(literal));
+        }
+        Ok(Self { segments })
+    }
+
+    /// Return the number of values needed to format this template.
+    ///
+    /// This is one more than the largest index referenced by a placeholder.
+    pub fn num_values(&self) -> usize {
+        self.segments
+            .iter()
+            .filter_map(|segment| match segment {
+                Segment::Value(index) => Some(index + 1),
+                Segment::Literal(_) => None,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Format the corresponding elements of `values` using the `template`.
+///
+/// The result is `null` if any of the referenced values are `null`.
+///
+/// # Panics
+/// Panics if fewer values are provided than the template references.
+pub fn format(
+    template: &FormatTemplate,
+    len: usize,
+    values: &[&StringArray],
+) -> Result<StringArray, Infallible> {
+    assert!(
+        values.len() >= template.num_values(),
+        "template references {} values, but only {} provided",
+        template.num_values(),
+        values.len()
+    );
+
+    let mut builder = StringBuilder::with_capacity(len, 0);
+    let mut result = String::new();
+    'rows: for row in 0..len {
+        result.clear();
+        for segment in &template.segments {
+            match segment {
+                Segment::Literal(literal) => result.push_str(literal),
+                Segment::Value(index) if values[*index].is_null(row) => {
+                    builder.append_null();
+                    continue 'rows;
+                }
+                Segment::Value(index) => result.push_str(values[*index].value(row)),
+            }
+        }
+        builder.append_value(&result);
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        let template = FormatTemplate::try_new("{} bought {} for ${{{}}}").unwrap();
+        assert_eq!(template.num_values(), 3);
+
+        let name = StringArray::from(vec![Some("Ben"), Some("Ryan"), None]);
+        let item = StringArray::from(vec![Some("apples"), Some("pears"), Some("kiwis")]);
+        let price = StringArray::from(vec![Some("5"), Some("7"), Some("9")]);
+        let expected = StringArray::from(vec![
+            Some("Ben bought apples for ${5}"),
+            Some("Ryan bought pears for ${7}"),
+            None,
+        ]);
+
+        let actual = format(&template, 3, &[&name, &item, &price]).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_format_indexed() {
+        let template = FormatTemplate::try_new("{1}, {0} {1}").unwrap();
+        assert_eq!(template.num_values(), 2);
+
+        let first = StringArray::from(vec!["James"]);
+        let last = StringArray::from(vec!["Bond"]);
+        let expected = StringArray::from(vec!["Bond, James Bond"]);
+
+        let actual = format(&template, 1, &[&first, &last]).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_format_no_values() {
+        let template = FormatTemplate::try_new("constant").unwrap();
+        assert_eq!(template.num_values(), 0);
+
+        let expected = StringArray::from(vec!["constant", "constant"]);
+        let actual = format(&template, 2, &[]).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_invalid_templates() {
+        assert_eq!(
+            FormatTemplate::try_new("{").unwrap_err().to_string(),
+            "unmatched '{' in template"
+        );
+        assert_eq!(
+            FormatTemplate::try_new("}").unwrap_err().to_string(),
+            "unmatched '}' in template"
+        );
+        assert_eq!(
+            FormatTemplate::try_new("{name}").unwrap_err().to_string(),
+            "invalid character 'n' in placeholder"
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use std::convert::Infallible;
+
+use arrow::array::{Int64Array, StringArray};
+use itertools::izip;
+
+/// Pad the start of each element of `base` with `pad` to `length` characters.
+pub fn lpad(
+    base: &StringArray,
+    length: &Int64Array,
+    pad: &StringArray,
+) -> Result<StringArray, Infallible> {
+    Ok(pad_with(base, length, pad, true))
+}
+
+/// Pad the end of each element of `base` with `pad` to `length` characters.
+pub fn rpad(
+    base: &StringArray,
+    length: &Int64Array,
+    pad: &StringArray,
+) -> Result<StringArray, Infallible> {
+    Ok(pad_with(base, length, pad, false))
+}
+
+/// Pad the elements of `base` to `length` characters.
+///
+/// Strings shorter than `length` are padded by repeating `pad` as many times
+/// as necessary, truncating the final repetition. Strings longer than
+/// `length` are truncated to the first `length` characters. Strings are
+/// unchanged if `pad` is empty.
+///
+/// The result is `null` if any of the elements are `null`.
+fn pad_with(base: &StringArray, length: &Int64Array, pad: &StringArray, left: bool) -> StringArray {
+    izip!(base.iter(), length.iter(), pad.iter())
+        .map(|(string, length, pad)| Some(pad_one(string?, length?, pad?, left)))
+        .collect()
+}
+
+fn pad_one(string: &str, length: i64, pad: &str, left: bool) -> String {
+    let length = length.max(0) as usize;
+    let strlen = string.chars().count();
+    if strlen >= length {
+        return string.chars().take(length).collect();
+    } else if pad.is_empty() {
+        return string.to_owned();
+    }
+
+    let padding = pad.chars().cycle().take(length - strlen);
+    if left {
+        padding.chain(string.chars()).collect()
+    } else {
+        string.chars().chain(padding).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lpad() {
+        let array = StringArray::from(vec![
+            Some("7"),
+            Some("hello"),
+            None,
+            Some("abc"),
+            Some("abc"),
+        ]);
+        let length = Int64Array::from(vec![Some(3), Some(3), Some(3), None, Some(8)]);
+        let pad = StringArray::from(vec!["0", "0", "0", "0", "xy"]);
+        let expected =
+            StringArray::from(vec![Some("007"), Some("hel"), None, None, Some("xyxyxabc")]);
+
+        let actual = lpad(&array, &length, &pad).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_rpad() {
+        let array = StringArray::from(vec![Some("7"), Some("héllo"), Some("abc"), Some("abc")]);
+        let length = Int64Array::from(vec![3, 2, 5, -1]);
+        let pad = StringArray::from(vec![Some("-"), Some("-"), Some(""), Some("-")]);
+        let expected = StringArray::from(vec!["7--", "hé", "abc", ""]);
+
+        let actual = rpad(&array, &length, &pad).unwrap();
+        assert_eq!(expected, actual);
+    }
+}
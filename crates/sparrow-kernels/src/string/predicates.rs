@@ -0,0 +1,76 @@
+use std::convert::Infallible;
+
+use arrow::array::{BooleanArray, StringArray};
+use itertools::izip;
+
+/// Return whether each element of `base` starts with the corresponding
+/// `prefix`.
+pub fn starts_with(base: &StringArray, prefix: &StringArray) -> Result<BooleanArray, Infallible> {
+    Ok(binary_predicate(base, prefix, |s, prefix| {
+        s.starts_with(prefix)
+    }))
+}
+
+/// Return whether each element of `base` ends with the corresponding
+/// `suffix`.
+pub fn ends_with(base: &StringArray, suffix: &StringArray) -> Result<BooleanArray, Infallible> {
+    Ok(binary_predicate(base, suffix, |s, suffix| {
+        s.ends_with(suffix)
+    }))
+}
+
+/// Return whether each element of `base` contains the corresponding
+/// `substring`.
+pub fn contains(base: &StringArray, substring: &StringArray) -> Result<BooleanArray, Infallible> {
+    Ok(binary_predicate(base, substring, |s, substring| {
+        s.contains(substring)
+    }))
+}
+
+/// Apply `f` to the corresponding elements of `base` and `other`.
+///
+/// The result is `null` if either element is `null`.
+fn binary_predicate(
+    base: &StringArray,
+    other: &StringArray,
+    f: impl Fn(&str, &str) -> bool,
+) -> BooleanArray {
+    izip!(base.iter(), other.iter())
+        .map(|(base, other)| Some(f(base?, other?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_starts_with() {
+        let array = StringArray::from(vec![Some("hello"), None, Some("hello"), Some("hello")]);
+        let prefix = StringArray::from(vec![Some("he"), Some("he"), Some("lo"), None]);
+        let expected = BooleanArray::from(vec![Some(true), None, Some(false), None]);
+
+        let actual = starts_with(&array, &prefix).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_ends_with() {
+        let array = StringArray::from(vec![Some("hello"), Some("hello"), Some("")]);
+        let suffix = StringArray::from(vec![Some("lo"), Some("he"), Some("")]);
+        let expected = BooleanArray::from(vec![true, false, true]);
+
+        let actual = ends_with(&array, &suffix).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_contains() {
+        let array = StringArray::from(vec![Some("hello"), Some("hello"), None]);
+        let substring = StringArray::from(vec![Some("ell"), Some("elo"), Some("")]);
+        let expected = BooleanArray::from(vec![Some(true), Some(false), None]);
+
+        let actual = contains(&array, &substring).unwrap();
+        assert_eq!(expected, actual);
+    }
+}
@@ -0,0 +1,71 @@
+use std::convert::Infallible;
+
+use arrow::array::StringArray;
+use regex::Regex;
+
+/// Return the text matched by capture `group` of the first match of the
+/// `regex` in each element.
+///
+/// Group `0` is the entire match. The result is `null` if the element is
+/// `null`, the regex doesn't match, or the group didn't participate in the
+/// match.
+pub fn regex_extract(
+    base: &StringArray,
+    regex: &Regex,
+    group: usize,
+) -> Result<StringArray, Infallible> {
+    Ok(base
+        .iter()
+        .map(|opt_s| {
+            opt_s
+                .and_then(|s| regex.captures(s))
+                .and_then(|captures| captures.get(group))
+                .map(|m| m.as_str())
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_regex_extract_match() {
+        let array = StringArray::from(vec![
+            Some("user@example.com"),
+            None,
+            Some("no email"),
+            Some("admin@kaskada.io"),
+        ]);
+        let regex = Regex::new(r"(\w+)@([\w.]+)").unwrap();
+        let expected = StringArray::from(vec![
+            Some("user@example.com"),
+            None,
+            None,
+            Some("admin@kaskada.io"),
+        ]);
+
+        let actual = regex_extract(&array, &regex, 0).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_regex_extract_group() {
+        let array = StringArray::from(vec![Some("user@example.com"), Some("admin@kaskada.io")]);
+        let regex = Regex::new(r"(\w+)@([\w.]+)").unwrap();
+        let expected = StringArray::from(vec!["example.com", "kaskada.io"]);
+
+        let actual = regex_extract(&array, &regex, 2).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_regex_extract_optional_group() {
+        let array = StringArray::from(vec![Some("ab"), Some("a")]);
+        let regex = Regex::new("a(b)?").unwrap();
+        let expected = StringArray::from(vec![Some("b"), None]);
+
+        let actual = regex_extract(&array, &regex, 1).unwrap();
+        assert_eq!(expected, actual);
+    }
+}
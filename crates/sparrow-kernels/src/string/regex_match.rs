@@ -0,0 +1,45 @@
+use std::convert::Infallible;
+
+use arrow::array::{BooleanArray, StringArray};
+use regex::Regex;
+
+/// Return whether each element contains a match of the `regex`.
+///
+/// The regex is not anchored, so it may match any part of the string. Use
+/// `^` and `$` to match the entire string.
+pub fn regex_match(base: &StringArray, regex: &Regex) -> Result<BooleanArray, Infallible> {
+    Ok(base
+        .iter()
+        .map(|opt_s| opt_s.map(|s| regex.is_match(s)))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_regex_match() {
+        let array = StringArray::from(vec![
+            Some("hello world"),
+            None,
+            Some("goodbye"),
+            Some("say hello"),
+        ]);
+        let regex = Regex::new("hel+o").unwrap();
+        let expected = BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]);
+
+        let actual = regex_match(&array, &regex).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_regex_match_anchored() {
+        let array = StringArray::from(vec![Some("hello world"), Some("say hello")]);
+        let regex = Regex::new("^hello").unwrap();
+        let expected = BooleanArray::from(vec![true, false]);
+
+        let actual = regex_match(&array, &regex).unwrap();
+        assert_eq!(expected, actual);
+    }
+}
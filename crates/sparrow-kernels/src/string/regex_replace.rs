@@ -0,0 +1,56 @@
+use std::convert::Infallible;
+
+use arrow::array::StringArray;
+use itertools::izip;
+use regex::Regex;
+
+/// Replace all matches of the `regex` in each element of `base` with the
+/// corresponding element of `replacement`.
+///
+/// The replacement may refer to capture groups using `$1` or `${name}`. Use
+/// `$$` for a literal `$`. The result is `null` if either the element or the
+/// replacement is `null`.
+pub fn regex_replace(
+    base: &StringArray,
+    regex: &Regex,
+    replacement: &StringArray,
+) -> Result<StringArray, Infallible> {
+    Ok(izip!(base.iter(), replacement.iter())
+        .map(|(string, replacement)| match (string, replacement) {
+            (Some(s), Some(replacement)) => Some(regex.replace_all(s, replacement)),
+            _ => None,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_regex_replace() {
+        let array = StringArray::from(vec![
+            Some("hello world"),
+            None,
+            Some("goodbye"),
+            Some("hello hello"),
+        ]);
+        let replacement = StringArray::from(vec![Some("bye"), Some("bye"), Some("bye"), None]);
+        let regex = Regex::new("hel+o").unwrap();
+        let expected = StringArray::from(vec![Some("bye world"), None, Some("goodbye"), None]);
+
+        let actual = regex_replace(&array, &regex, &replacement).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_regex_replace_groups() {
+        let array = StringArray::from(vec![Some("2023-07-04"), Some("1999-12-31")]);
+        let replacement = StringArray::from(vec!["$3/$2/$1", "$3/$2/$1"]);
+        let regex = Regex::new(r"(\d+)-(\d+)-(\d+)").unwrap();
+        let expected = StringArray::from(vec!["04/07/2023", "31/12/1999"]);
+
+        let actual = regex_replace(&array, &regex, &replacement).unwrap();
+        assert_eq!(expected, actual);
+    }
+}
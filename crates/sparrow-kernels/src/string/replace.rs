@@ -0,0 +1,55 @@
+use std::convert::Infallible;
+
+use arrow::array::StringArray;
+use itertools::izip;
+
+/// Replace all occurrences of `pattern` in each element of `base` with
+/// `replacement`.
+///
+/// The pattern is matched literally. An empty pattern leaves the element
+/// unchanged. The result is `null` if any of the elements are `null`.
+pub fn replace(
+    base: &StringArray,
+    pattern: &StringArray,
+    replacement: &StringArray,
+) -> Result<StringArray, Infallible> {
+    Ok(izip!(base.iter(), pattern.iter(), replacement.iter())
+        .map(
+            |(string, pattern, replacement)| match (string, pattern, replacement) {
+                (Some(s), Some(""), Some(_)) => Some(s.to_owned()),
+                (Some(s), Some(pattern), Some(replacement)) => {
+                    Some(s.replace(pattern, replacement))
+                }
+                _ => None,
+            },
+        )
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replace() {
+        let array = StringArray::from(vec![
+            Some("hello hello"),
+            None,
+            Some("hello"),
+            Some("hello"),
+            Some("a.b.c"),
+        ]);
+        let pattern = StringArray::from(vec![Some("ll"), Some("ll"), Some(""), None, Some(".")]);
+        let replacement = StringArray::from(vec!["LL", "LL", "x", "x", ""]);
+        let expected = StringArray::from(vec![
+            Some("heLLo heLLo"),
+            None,
+            Some("hello"),
+            None,
+            Some("abc"),
+        ]);
+
+        let actual = replace(&array, &pattern, &replacement).unwrap();
+        assert_eq!(expected, actual);
+    }
+}
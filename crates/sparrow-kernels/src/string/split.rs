@@ -0,0 +1,68 @@
+use std::convert::Infallible;
+
+use arrow::array::{ListArray, ListBuilder, StringArray, StringBuilder};
+use itertools::izip;
+
+/// Split each element of `base` into a list of the substrings separated by
+/// the corresponding `separator`.
+///
+/// An empty separator splits the string into its characters. The result is
+/// `null` if either the element or the separator is `null`.
+pub fn split(base: &StringArray, separator: &StringArray) -> Result<ListArray, Infallible> {
+    let mut builder = ListBuilder::new(StringBuilder::new());
+    for (string, separator) in izip!(base.iter(), separator.iter()) {
+        match (string, separator) {
+            (Some(s), Some("")) => {
+                let mut buffer = [0; 4];
+                s.chars()
+                    .for_each(|c| builder.values().append_value(c.encode_utf8(&mut buffer)));
+                builder.append(true);
+            }
+            (Some(s), Some(separator)) => {
+                s.split(separator)
+                    .for_each(|part| builder.values().append_value(part));
+                builder.append(true);
+            }
+            _ => builder.append(false),
+        }
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::Array;
+
+    use super::*;
+
+    fn parts(list: &ListArray, index: usize) -> Option<Vec<String>> {
+        if list.is_null(index) {
+            return None;
+        }
+
+        let values = list.value(index);
+        let values = values.as_any().downcast_ref::<StringArray>().unwrap();
+        Some(values.iter().map(|s| s.unwrap().to_owned()).collect())
+    }
+
+    #[test]
+    fn test_split() {
+        let array = StringArray::from(vec![Some("a,b,,c"), None, Some(""), Some("abc")]);
+        let separator = StringArray::from(vec![Some(","), Some(","), Some(","), None]);
+
+        let actual = split(&array, &separator).unwrap();
+        assert_eq!(parts(&actual, 0).unwrap(), vec!["a", "b", "", "c"]);
+        assert_eq!(parts(&actual, 1), None);
+        assert_eq!(parts(&actual, 2).unwrap(), vec![""]);
+        assert_eq!(parts(&actual, 3), None);
+    }
+
+    #[test]
+    fn test_split_empty_separator() {
+        let array = StringArray::from(vec!["héllo"]);
+        let separator = StringArray::from(vec![""]);
+
+        let actual = split(&array, &separator).unwrap();
+        assert_eq!(parts(&actual, 0).unwrap(), vec!["h", "é", "l", "l", "o"]);
+    }
+}
@@ -1,5 +1,7 @@
 mod time_delta;
 mod time_of;
+mod time_zone;
 
 pub use time_delta::*;
 pub use time_of::time_of;
+pub use time_zone::*;
@@ -0,0 +1,148 @@
+use arrow::array::{PrimitiveArray, TimestampNanosecondArray};
+use arrow::datatypes::ArrowPrimitiveType;
+use arrow::temporal_conversions::timestamp_ns_to_datetime;
+use chrono::{Duration, LocalResult, NaiveDateTime, Offset, TimeZone};
+pub use chrono_tz::{Tz, TZ_VARIANTS};
+
+/// Parse the name of an IANA time zone, such as `America/New_York` or `UTC`.
+pub fn parse_time_zone(name: &str) -> anyhow::Result<Tz> {
+    name.parse()
+        .map_err(|_| anyhow::anyhow!("Unknown time zone '{name}'"))
+}
+
+/// Convert a UTC date time to the local (wall clock) date time in `time_zone`.
+pub fn to_local(time: NaiveDateTime, time_zone: &Tz) -> NaiveDateTime {
+    time_zone.from_utc_datetime(&time).naive_local()
+}
+
+/// Convert a local (wall clock) date time in `time_zone` to the UTC date time.
+///
+/// Local times which occur twice (when clocks are set back) resolve to the
+/// earlier of the two instants. Local times which are skipped (when clocks
+/// are set forward) are shifted forward by the length of the gap. For
+/// instance, a local midnight which is skipped resolves to the instant the
+/// clocks change, which is 1AM local time for a one hour gap.
+pub fn from_local(local: NaiveDateTime, time_zone: &Tz) -> NaiveDateTime {
+    match time_zone.from_local_datetime(&local) {
+        LocalResult::Single(time) => time.naive_utc(),
+        LocalResult::Ambiguous(earliest, _) => earliest.naive_utc(),
+        LocalResult::None => {
+            // Interpret the local time using the offset in effect before the
+            // gap. Transitions are never within a day of each other, so the
+            // offset a day earlier is the offset before the gap.
+            let before = time_zone
+                .offset_from_utc_datetime(&(local - Duration::days(1)))
+                .fix();
+            local - Duration::seconds(before.local_minus_utc() as i64)
+        }
+    }
+}
+
+/// Apply `f` to the local (wall clock) date time of each timestamp in
+/// `time_zone`.
+///
+/// Returns `null` for rows where the time is `null`.
+pub fn local_time_accessor<O, F>(
+    times: &TimestampNanosecondArray,
+    time_zone: &Tz,
+    f: F,
+) -> PrimitiveArray<O>
+where
+    O: ArrowPrimitiveType,
+    F: Fn(NaiveDateTime) -> O::Native,
+{
+    times.unary_opt(|t| timestamp_ns_to_datetime(t).map(|t| f(to_local(t, time_zone))))
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::array::{TimestampNanosecondArray, UInt32Array};
+    use chrono::{Datelike, NaiveDate, NaiveDateTime};
+
+    use super::*;
+
+    fn date_time(y: i32, m: u32, d: u32, h: u32, min: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(h, min, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_parse_time_zone() {
+        assert_eq!(parse_time_zone("UTC").unwrap(), Tz::UTC);
+        assert_eq!(
+            parse_time_zone("America/New_York").unwrap(),
+            Tz::America__New_York
+        );
+        assert_eq!(
+            parse_time_zone("Mars/Olympus_Mons")
+                .unwrap_err()
+                .to_string(),
+            "Unknown time zone 'Mars/Olympus_Mons'"
+        );
+    }
+
+    #[test]
+    fn test_to_local() {
+        let new_york = Tz::America__New_York;
+        // Standard time is UTC-5.
+        assert_eq!(
+            to_local(date_time(2021, 1, 15, 3, 30), &new_york),
+            date_time(2021, 1, 14, 22, 30)
+        );
+        // Daylight time is UTC-4.
+        assert_eq!(
+            to_local(date_time(2021, 7, 15, 3, 30), &new_york),
+            date_time(2021, 7, 14, 23, 30)
+        );
+    }
+
+    #[test]
+    fn test_from_local() {
+        let new_york = Tz::America__New_York;
+        assert_eq!(
+            from_local(date_time(2021, 1, 14, 22, 30), &new_york),
+            date_time(2021, 1, 15, 3, 30)
+        );
+
+        // 2AM on 2021-03-14 is skipped, so 2:30AM is shifted to 3:30AM EDT.
+        assert_eq!(
+            from_local(date_time(2021, 3, 14, 2, 30), &new_york),
+            date_time(2021, 3, 14, 7, 30)
+        );
+
+        // 1AM on 2021-11-07 occurs twice, and the earlier (EDT) is used.
+        assert_eq!(
+            from_local(date_time(2021, 11, 7, 1, 30), &new_york),
+            date_time(2021, 11, 7, 5, 30)
+        );
+
+        // Midnight on 2021-09-05 is skipped in Santiago.
+        let santiago = Tz::America__Santiago;
+        assert_eq!(
+            from_local(date_time(2021, 9, 5, 0, 0), &santiago),
+            date_time(2021, 9, 5, 4, 0)
+        );
+        assert_eq!(
+            to_local(date_time(2021, 9, 5, 4, 0), &santiago),
+            date_time(2021, 9, 5, 1, 0)
+        );
+    }
+
+    #[test]
+    fn test_local_time_accessor() {
+        let times = TimestampNanosecondArray::from(vec![
+            Some(date_time(2021, 1, 15, 3, 30).timestamp_nanos()),
+            None,
+            Some(date_time(2021, 1, 15, 5, 0).timestamp_nanos()),
+        ]);
+
+        let utc: UInt32Array = local_time_accessor(&times, &Tz::UTC, |t| t.day());
+        assert_eq!(utc, UInt32Array::from(vec![Some(15), None, Some(15)]));
+
+        let new_york: UInt32Array =
+            local_time_accessor(&times, &Tz::America__New_York, |t| t.day());
+        assert_eq!(new_york, UInt32Array::from(vec![Some(14), None, Some(15)]));
+    }
+}
@@ -172,6 +172,10 @@ impl MaterializeCommand {
                                 rows_produced, topic_url
                             );
                         }
+                        Some(destination::Destination::Kafka(k)) => {
+                            let topic = k.config.as_ref().map_or("", |config| &config.topic);
+                            println!("{} rows produced so far to topic {}", rows_produced, topic);
+                        }
                         _ => (),
                     };
                 })
@@ -121,6 +121,7 @@ impl PrepareCommand {
             .change_context(Error::UnrecognizedInputFormat)?;
         let source_data = SourceData {
             source: Some(file_path),
+            schema: None,
         };
 
         if table.file_sets.is_empty() {
@@ -634,6 +634,7 @@ mod tests {
             &ObjectStoreRegistry::default(),
             &SourceData {
                 source: Some(input_path),
+                schema: None,
             },
             &table,
             &Some(slice_plan::Slice::Percent(slice_plan::PercentSlice {
@@ -743,6 +744,7 @@ mod tests {
             file_type: FileType::Parquet as i32,
             output_prefix_uri: format!("file:///{}", output_dir.path().display()),
             output_paths: None,
+            partitioning: None,
         };
         let output_to = Destination {
             destination: Some(destination::Destination::ObjectStore(store)),
@@ -173,6 +173,7 @@ mod tests {
                                 "file:///{}",
                                 path.display()
                             ))),
+                            schema: None,
                         },
                     ),
                 ),
@@ -200,6 +201,7 @@ mod tests {
                                 "file:///{}",
                                 path.display()
                             ))),
+                            schema: None,
                         },
                     ),
                 ),
@@ -221,6 +223,7 @@ mod tests {
                     sparrow_api::kaskada::v1alpha::get_metadata_request::Source::SourceData(
                         SourceData {
                             source: Some(source_data::Source::CsvData(csv_data.to_owned())),
+                            schema: None,
                         },
                     ),
                 ),
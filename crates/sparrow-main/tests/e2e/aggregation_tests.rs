@@ -275,6 +275,164 @@ async fn test_max_timestamp_ns() {
     "###);
 }
 
+#[tokio::test]
+async fn test_max_by_min_by_i64() {
+    insta::assert_snapshot!(QueryFixture::new("{ max_by: max_by(Numbers.m, Numbers.n), min_by: min_by(Numbers.m, Numbers.n) }").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,max_by,min_by
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,5,5
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,24,24
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,5,17
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,5,17
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,5,17
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,5,17
+    "###);
+}
+
+#[tokio::test]
+async fn test_min_by_string() {
+    // The fourth row has the same ordering as the third, so the more recent
+    // (null) value is selected.
+    insta::assert_snapshot!(QueryFixture::new("{ min_by: min_by(Strings.s, Strings.n) }").run_to_csv(&strings_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,min_by
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,hEllo
+    1996-12-20T00:40:57.000000000,9223372036854775808,2867199309159137213,B,World
+    1996-12-20T00:41:57.000000000,9223372036854775808,2867199309159137213,B,hello world
+    1996-12-20T00:42:57.000000000,9223372036854775808,2867199309159137213,B,
+    1996-12-20T00:43:57.000000000,9223372036854775808,2867199309159137213,B,
+    1996-12-20T00:44:57.000000000,9223372036854775808,2867199309159137213,B,
+    "###);
+}
+
+#[tokio::test]
+async fn test_min_by_record() {
+    insta::assert_snapshot!(QueryFixture::new("let selected = min_by(Numbers, Numbers.n) in { m: selected.m, n: selected.n }").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,m,n
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,5,10
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,24,3
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,17,6
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,17,6
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,17,6
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,17,6
+    "###);
+}
+
+#[tokio::test]
+async fn test_max_by_min_by_sliding_i64() {
+    insta::assert_snapshot!(QueryFixture::new("{ max_by: max_by(Numbers.m, Numbers.n, window=sliding(2, Numbers.n < 10)), min_by: min_by(Numbers.m, Numbers.n, window=sliding(2, Numbers.n < 10)) }").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,max_by,min_by
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,5,5
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,24,24
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,5,17
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,5,17
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,,
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,,
+    "###);
+}
+
+#[tokio::test]
+async fn test_max_by_trailing_window_produces_diagnostic() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ max_by: max_by(Numbers.m, Numbers.n, window=trailing(seconds(10))) }").run_to_csv(&i64_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0010
+        message: Invalid argument type(s)
+        formatted:
+          - "error[E0010]: Invalid argument type(s)"
+          - "  --> Query:1:47"
+          - "  |"
+          - "1 | { max_by: max_by(Numbers.m, Numbers.n, window=trailing(seconds(10))) }"
+          - "  |                                               ^^^^^^^^^^^^^^^^^^^^^ Trailing windows are not supported for 'max_by'"
+          - ""
+          - ""
+    "###);
+}
+
+#[tokio::test]
+async fn test_ewma_i64() {
+    insta::assert_snapshot!(QueryFixture::new("{ ewma: ewma(Numbers.m, seconds(1)) }").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,ewma
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,5.0
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,24.0
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,14.6
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,14.6
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,12.619047619047619
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,12.619047619047619
+    "###);
+}
+
+#[tokio::test]
+async fn test_decayed_sum_and_count_i64() {
+    insta::assert_snapshot!(QueryFixture::new("{ decayed_sum: decayed_sum(Numbers.m, seconds(1)), decayed_count: decayed_count(Numbers.m, seconds(1)) }").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,decayed_sum,decayed_count
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,5.0,1.0
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,24.0,1.0
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,18.25,1.25
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,9.125,0.625
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,16.5625,1.3125
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,8.28125,0.65625
+    "###);
+}
+
+#[tokio::test]
+async fn test_decayed_sum_and_count_since() {
+    insta::assert_snapshot!(QueryFixture::new("{ decayed_sum: decayed_sum(Numbers.m, seconds(1), window=since(Numbers.n > 7)), decayed_count: decayed_count(Numbers.m, seconds(1), window=since(Numbers.n > 7)) }").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,decayed_sum,decayed_count
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,5.0,1.0
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,24.0,1.0
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,17.0,1.0
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,8.5,0.5
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,12.0,1.0
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,6.0,0.5
+    "###);
+}
+
+#[tokio::test]
+async fn test_ewma_invalid_half_life() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ ewma: ewma(Numbers.m, months(1)) }").run_to_csv(&i64_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0010
+        message: Invalid argument type(s)
+        formatted:
+          - "error[E0010]: Invalid argument type(s)"
+          - "  --> Query:1:25"
+          - "  |"
+          - "1 | { ewma: ewma(Numbers.m, months(1)) }"
+          - "  |                         ^^^^^^^^^ Invalid half-life: 'interval_months:1'"
+          - "  |"
+          - "  = Half-lives must be a positive fixed duration, such as 'hours(1)' or 'days(7)'"
+          - ""
+          - ""
+    "###);
+}
+
+#[tokio::test]
+async fn test_decayed_sum_sliding_window_produces_diagnostic() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ decayed: decayed_sum(Numbers.m, seconds(1), window=sliding(2, Numbers.n < 10)) }").run_to_csv(&i64_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0010
+        message: Invalid argument type(s)
+        formatted:
+          - "error[E0010]: Invalid argument type(s)"
+          - "  --> Query:1:54"
+          - "  |"
+          - "1 | { decayed: decayed_sum(Numbers.m, seconds(1), window=sliding(2, Numbers.n < 10)) }"
+          - "  |                                                      ^^^^^^^^^^^^^^^^^^^^^^^^^^ Sliding windows are not supported for 'decayed_sum'"
+          - ""
+          - ""
+    "###);
+}
+
 #[tokio::test]
 async fn test_count_i64() {
     insta::assert_snapshot!(QueryFixture::new("{ count: count(Numbers.m)}").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
@@ -354,6 +512,157 @@ async fn test_count_timestamp_ns() {
     "###);
 }
 
+#[tokio::test]
+async fn test_approx_count_distinct_i64() {
+    insta::assert_snapshot!(QueryFixture::new("{ approx_count_distinct: approx_count_distinct(Strings.n)}").run_to_csv(&strings_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,approx_count_distinct
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,1
+    1996-12-20T00:40:57.000000000,9223372036854775808,2867199309159137213,B,1
+    1996-12-20T00:41:57.000000000,9223372036854775808,2867199309159137213,B,2
+    1996-12-20T00:42:57.000000000,9223372036854775808,2867199309159137213,B,2
+    1996-12-20T00:43:57.000000000,9223372036854775808,2867199309159137213,B,3
+    1996-12-20T00:44:57.000000000,9223372036854775808,2867199309159137213,B,3
+    "###);
+}
+
+#[tokio::test]
+async fn test_approx_count_distinct_since_predicate() {
+    insta::assert_snapshot!(QueryFixture::new("{ approx_count_distinct: approx_count_distinct(Strings.n, window=since(Strings.n < 0))}").run_to_csv(&strings_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,approx_count_distinct
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,1
+    1996-12-20T00:40:57.000000000,9223372036854775808,2867199309159137213,B,1
+    1996-12-20T00:41:57.000000000,9223372036854775808,2867199309159137213,B,2
+    1996-12-20T00:42:57.000000000,9223372036854775808,2867199309159137213,B,1
+    1996-12-20T00:43:57.000000000,9223372036854775808,2867199309159137213,B,1
+    1996-12-20T00:44:57.000000000,9223372036854775808,2867199309159137213,B,1
+    "###);
+}
+
+#[tokio::test]
+async fn test_approx_count_distinct_sliding_window_produces_diagnostic() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ approx_count_distinct: approx_count_distinct(Strings.n, window=sliding(2, Strings.n < 0)) }").run_to_csv(&strings_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0010
+        message: Invalid argument type(s)
+        formatted:
+          - "error[E0010]: Invalid argument type(s)"
+          - "  --> Query:1:66"
+          - "  |"
+          - "1 | { approx_count_distinct: approx_count_distinct(Strings.n, window=sliding(2, Strings.n < 0)) }"
+          - "  |                                                                  ^^^^^^^^^^^^^^^^^^^^^^^^^ Sliding windows are not supported for 'approx_count_distinct'"
+          - ""
+          - ""
+    "###);
+}
+
+#[tokio::test]
+async fn test_approx_count_distinct_trailing_window_produces_diagnostic() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ approx_count_distinct: approx_count_distinct(Strings.n, window=trailing(seconds(10))) }").run_to_csv(&strings_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0010
+        message: Invalid argument type(s)
+        formatted:
+          - "error[E0010]: Invalid argument type(s)"
+          - "  --> Query:1:66"
+          - "  |"
+          - "1 | { approx_count_distinct: approx_count_distinct(Strings.n, window=trailing(seconds(10))) }"
+          - "  |                                                                  ^^^^^^^^^^^^^^^^^^^^^ Trailing windows are not supported for 'approx_count_distinct'"
+          - ""
+          - ""
+    "###);
+}
+
+#[tokio::test]
+async fn test_median_i64() {
+    insta::assert_snapshot!(QueryFixture::new("{ median: median(Numbers.m)}").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,median
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,5.0
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,24.0
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,11.0
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,11.0
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,12.0
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,12.0
+    "###);
+}
+
+#[tokio::test]
+async fn test_median_since_predicate() {
+    insta::assert_snapshot!(QueryFixture::new("{ median: median(Numbers.m, window=since(Numbers.n > 7))}").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,median
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,5.0
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,24.0
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,17.0
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,17.0
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,12.0
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,12.0
+    "###);
+}
+
+#[tokio::test]
+async fn test_approx_percentile_i64() {
+    insta::assert_snapshot!(QueryFixture::new("{ approx_percentile: approx_percentile(Numbers.m, 0.9)}").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,approx_percentile
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,5.0
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,24.0
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,17.0
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,17.0
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,17.0
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,17.0
+    "###);
+}
+
+#[tokio::test]
+async fn test_approx_percentile_invalid_percentile() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ p: approx_percentile(Numbers.m, 1.5) }").run_to_csv(&i64_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0010
+        message: Invalid argument type(s)
+        formatted:
+          - "error[E0010]: Invalid argument type(s)"
+          - "  --> Query:1:35"
+          - "  |"
+          - "1 | { p: approx_percentile(Numbers.m, 1.5) }"
+          - "  |                                   ^^^ Invalid percentile: '1.5f64'"
+          - "  |"
+          - "  = Percentiles must be between 0 and 1, such as '0.95'"
+          - ""
+          - ""
+    "###);
+}
+
+#[tokio::test]
+async fn test_approx_percentile_sliding_window_produces_diagnostic() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ p: approx_percentile(Numbers.m, 0.5, window=sliding(2, daily())) }").run_to_csv(&i64_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0010
+        message: Invalid argument type(s)
+        formatted:
+          - "error[E0010]: Invalid argument type(s)"
+          - "  --> Query:1:47"
+          - "  |"
+          - "1 | { p: approx_percentile(Numbers.m, 0.5, window=sliding(2, daily())) }"
+          - "  |                                               ^^^^^^^^^^^^^^^^^^^ Sliding windows are not supported for 'approx_percentile'"
+          - ""
+          - ""
+    "###);
+}
+
 #[tokio::test]
 async fn test_count_if_condition() {
     insta::assert_snapshot!(QueryFixture::new("{ count_if: count_if(Numbers.m > 10)}").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
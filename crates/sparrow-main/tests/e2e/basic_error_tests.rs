@@ -400,7 +400,7 @@ async fn test_incompatible_actual_types_error() {
 async fn test_incompatible_lub_error() {
     // Test what happens when the solved type for a generic isn't compatible
     // with the constraint.
-    insta::assert_yaml_snapshot!(QueryFixture::new("{ s2: Strings.s + Strings.s }").run_to_csv(&strings_data_fixture().await).await.unwrap_err(), @r###"
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ s2: Strings.s - Strings.s }").run_to_csv(&strings_data_fixture().await).await.unwrap_err(), @r###"
     ---
     code: Client specified an invalid argument
     message: 1 errors in Fenl statements; see diagnostics
@@ -412,8 +412,8 @@ async fn test_incompatible_lub_error() {
           - "error[E0010]: Invalid argument type(s)"
           - "  --> Query:1:17"
           - "  |"
-          - "1 | { s2: Strings.s + Strings.s }"
-          - "  |       --------- ^ Invalid types for call to 'add'"
+          - "1 | { s2: Strings.s - Strings.s }"
+          - "  |       --------- ^ Invalid types for call to 'sub'"
           - "  |       |          "
           - "  |       Type: string"
           - "  |"
@@ -497,7 +497,7 @@ async fn test_parse_error_unrecognized() {
           - "1 | limit x = 5 in { n: Numbers.n}"
           - "  |       ^ Invalid token 'x'"
           - "  |"
-          - "  = Expected \"!=\", \"(\", \"*\", \"+\", \"-\", \".\", \"/\", \"<\", \"<=\", \"<>\", \"==\", \">\", \">=\", \"[\", \"and\", \"as\", \"or\", \"|\""
+          - "  = Expected \"!=\", \"%\", \"&\", \"(\", \"*\", \"+\", \"-\", \"->\", \".\", \"/\", \"<\", \"<=\", \"<>\", \"==\", \">\", \">=\", \"[\", \"^\", \"and\", \"as\", \"or\", \"|\""
           - ""
           - ""
       - severity: error
@@ -567,7 +567,7 @@ async fn test_invalid_let_binding_not_ident() {
           - "1 | let + = 5 in { n: Numbers.n + 1 } "
           - "  |       ^ Invalid token '='"
           - "  |"
-          - "  = Expected \"!\", \"$input\", \"(\", \"-\", \"{\", ident, literal"
+          - "  = Expected \"!\", \"$input\", \"(\", \"-\", \"case\", \"{\", \"~\", ident, literal"
           - ""
           - ""
       - severity: error
@@ -601,7 +601,7 @@ async fn test_invalid_expr() {
           - "1 | let x = 5 in { n: Numbers.n + $ } "
           - "  |                               ^ Invalid token '$'"
           - "  |"
-          - "  = Expected \"!\", \"$input\", \"(\", \"-\", \"{\", ident, literal"
+          - "  = Expected \"!\", \"$input\", \"(\", \"-\", \"case\", \"{\", \"~\", ident, literal"
           - ""
           - ""
     "###);
@@ -662,7 +662,7 @@ async fn test_invalid_type() {
           - "1 | { n: Numbers.n as + } "
           - "  |                     ^ Invalid token '}'"
           - "  |"
-          - "  = Expected \"!\", \"$input\", \"(\", \"-\", \"{\", ident, literal"
+          - "  = Expected \"!\", \"$input\", \"(\", \"-\", \"case\", \"{\", \"~\", ident, literal"
           - ""
           - ""
     "###);
@@ -685,7 +685,7 @@ async fn test_invalid_arguments_missing_named_value() {
           - "1 | { n: ceil(x:) } "
           - "  |            ^ Invalid token ':'"
           - "  |"
-          - "  = Expected \"!=\", \"(\", \")\", \"*\", \"+\", \",\", \"-\", \".\", \"/\", \"<\", \"<=\", \"<>\", \"=\", \"=\", \"==\", \">\", \">=\", \"[\", \"and\", \"as\", \"or\", \"|\""
+          - "  = Expected \"!=\", \"%\", \"&\", \"(\", \")\", \"*\", \"+\", \",\", \"-\", \"->\", \".\", \"/\", \"<\", \"<=\", \"<>\", \"=\", \"=\", \"==\", \">\", \">=\", \"[\", \"^\", \"and\", \"as\", \"or\", \"|\""
           - ""
           - ""
     "###);
@@ -708,7 +708,7 @@ async fn test_invalid_arguments_unexpected_operator() {
           - "1 | { n: ceil(+ Numbers.n) } "
           - "  |           ^ Invalid token '+'"
           - "  |"
-          - "  = Expected \"!\", \"$input\", \"(\", \")\", \",\", \"-\", \"let\", \"{\", ident, literal"
+          - "  = Expected \"!\", \"$input\", \"(\", \")\", \",\", \"-\", \"case\", \"def\", \"let\", \"{\", \"~\", ident, literal"
           - ""
           - ""
     "###);
@@ -617,3 +617,165 @@ async fn test_min_must_be_lte_max() {
           - ""
     "###);
 }
+
+#[tokio::test]
+async fn test_top_k_i64() {
+    insta::assert_snapshot!(QueryFixture::new("{ f1: Collect.n | top_k(k=2) | index(0), f2: Collect.n | top_k(k=2) | index(1) }").run_to_csv(&collect_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,f1,f2
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,0,
+    1996-12-20T00:40:57.000000000,9223372036854775808,12960666915911099378,A,2,0
+    1996-12-20T00:41:57.000000000,9223372036854775808,12960666915911099378,A,9,2
+    1996-12-20T00:42:00.000000000,9223372036854775808,12960666915911099378,A,9,2
+    1996-12-20T00:42:57.000000000,9223372036854775808,12960666915911099378,A,9,2
+    1996-12-20T00:43:57.000000000,9223372036854775808,12960666915911099378,A,10,9
+    1996-12-21T00:40:57.000000000,9223372036854775808,2867199309159137213,B,5,
+    1996-12-21T00:41:57.000000000,9223372036854775808,2867199309159137213,B,5,-2
+    1996-12-21T00:42:57.000000000,9223372036854775808,2867199309159137213,B,5,-2
+    1996-12-21T00:43:57.000000000,9223372036854775808,2867199309159137213,B,5,2
+    1996-12-21T00:44:57.000000000,9223372036854775808,2867199309159137213,B,5,2
+    1996-12-21T01:44:57.000000000,9223372036854775808,2867199309159137213,B,5,5
+    1996-12-22T00:44:57.000000000,9223372036854775808,2521269998124177631,C,1,
+    1996-12-22T00:45:57.000000000,9223372036854775808,2521269998124177631,C,2,1
+    1996-12-22T00:46:57.000000000,9223372036854775808,2521269998124177631,C,3,2
+    1996-12-22T00:47:57.000000000,9223372036854775808,2521269998124177631,C,4,3
+    "###);
+}
+
+#[tokio::test]
+async fn test_top_k_since_hourly() {
+    insta::assert_snapshot!(QueryFixture::new("{ f1: Collect.n | top_k(k=2, window=since(hourly())) | index(1) | when(is_valid($input)) }").run_to_csv(&collect_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,f1
+    1996-12-20T00:40:57.000000000,9223372036854775808,12960666915911099378,A,0
+    1996-12-20T00:41:57.000000000,9223372036854775808,12960666915911099378,A,2
+    1996-12-20T00:42:00.000000000,9223372036854775808,12960666915911099378,A,2
+    1996-12-20T00:42:57.000000000,9223372036854775808,12960666915911099378,A,2
+    1996-12-20T00:43:57.000000000,9223372036854775808,12960666915911099378,A,9
+    1996-12-20T01:00:00.000000000,18446744073709551615,12960666915911099378,A,9
+    1996-12-21T00:41:57.000000000,9223372036854775808,2867199309159137213,B,-2
+    1996-12-21T00:42:57.000000000,9223372036854775808,2867199309159137213,B,-2
+    1996-12-21T00:43:57.000000000,9223372036854775808,2867199309159137213,B,2
+    1996-12-21T00:44:57.000000000,9223372036854775808,2867199309159137213,B,2
+    1996-12-21T01:00:00.000000000,18446744073709551615,2867199309159137213,B,2
+    1996-12-22T00:45:57.000000000,9223372036854775808,2521269998124177631,C,1
+    1996-12-22T00:46:57.000000000,9223372036854775808,2521269998124177631,C,2
+    1996-12-22T00:47:57.000000000,9223372036854775808,2521269998124177631,C,3
+    "###);
+}
+
+#[tokio::test]
+async fn test_most_frequent_boolean() {
+    // Ties are broken in favor of the value which was counted first.
+    insta::assert_snapshot!(QueryFixture::new("{ f1: Collect.b | most_frequent(k=1) | index(0), f2: Collect.b | most_frequent(k=2) | list_len() }").run_to_csv(&collect_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,f1,f2
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,true,1
+    1996-12-20T00:40:57.000000000,9223372036854775808,12960666915911099378,A,true,2
+    1996-12-20T00:41:57.000000000,9223372036854775808,12960666915911099378,A,true,2
+    1996-12-20T00:42:00.000000000,9223372036854775808,12960666915911099378,A,false,2
+    1996-12-20T00:42:57.000000000,9223372036854775808,12960666915911099378,A,true,2
+    1996-12-20T00:43:57.000000000,9223372036854775808,12960666915911099378,A,true,2
+    1996-12-21T00:40:57.000000000,9223372036854775808,2867199309159137213,B,false,1
+    1996-12-21T00:41:57.000000000,9223372036854775808,2867199309159137213,B,false,1
+    1996-12-21T00:42:57.000000000,9223372036854775808,2867199309159137213,B,false,2
+    1996-12-21T00:43:57.000000000,9223372036854775808,2867199309159137213,B,false,2
+    1996-12-21T00:44:57.000000000,9223372036854775808,2867199309159137213,B,false,2
+    1996-12-21T01:44:57.000000000,9223372036854775808,2867199309159137213,B,true,2
+    1996-12-22T00:44:57.000000000,9223372036854775808,2521269998124177631,C,true,1
+    1996-12-22T00:45:57.000000000,9223372036854775808,2521269998124177631,C,true,1
+    1996-12-22T00:46:57.000000000,9223372036854775808,2521269998124177631,C,true,1
+    1996-12-22T00:47:57.000000000,9223372036854775808,2521269998124177631,C,true,1
+    "###);
+}
+
+#[tokio::test]
+async fn test_collect_distinct_i64() {
+    // The least recently seen value is dropped once `max` values are collected.
+    insta::assert_snapshot!(QueryFixture::new("{ f1: Collect.index | collect_distinct(max=2) | index(0), f2: Collect.index | collect_distinct(max=2) | index(1) }").run_to_csv(&collect_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,f1,f2
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,0,
+    1996-12-20T00:40:57.000000000,9223372036854775808,12960666915911099378,A,0,1
+    1996-12-20T00:41:57.000000000,9223372036854775808,12960666915911099378,A,1,2
+    1996-12-20T00:42:00.000000000,9223372036854775808,12960666915911099378,A,1,2
+    1996-12-20T00:42:57.000000000,9223372036854775808,12960666915911099378,A,2,1
+    1996-12-20T00:43:57.000000000,9223372036854775808,12960666915911099378,A,2,1
+    1996-12-21T00:40:57.000000000,9223372036854775808,2867199309159137213,B,0,
+    1996-12-21T00:41:57.000000000,9223372036854775808,2867199309159137213,B,0,1
+    1996-12-21T00:42:57.000000000,9223372036854775808,2867199309159137213,B,1,2
+    1996-12-21T00:43:57.000000000,9223372036854775808,2867199309159137213,B,2,1
+    1996-12-21T00:44:57.000000000,9223372036854775808,2867199309159137213,B,2,1
+    1996-12-21T01:44:57.000000000,9223372036854775808,2867199309159137213,B,1,0
+    1996-12-22T00:44:57.000000000,9223372036854775808,2521269998124177631,C,2,
+    1996-12-22T00:45:57.000000000,9223372036854775808,2521269998124177631,C,2,0
+    1996-12-22T00:46:57.000000000,9223372036854775808,2521269998124177631,C,2,0
+    1996-12-22T00:47:57.000000000,9223372036854775808,2521269998124177631,C,0,1
+    "###);
+}
+
+#[tokio::test]
+async fn test_top_k_non_positive_k() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ f1: Collect.n | top_k(k=0) | index(0) }")
+        .run_to_csv(&collect_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0010
+        message: Invalid argument type(s)
+        formatted:
+          - "error[E0010]: Invalid argument type(s)"
+          - "  --> Query:1:27"
+          - "  |"
+          - "1 | { f1: Collect.n | top_k(k=0) | index(0) }"
+          - "  |                           ^ Invalid k: '0'"
+          - "  |"
+          - "  = 'top_k' requires a positive number of values, such as '10'"
+          - ""
+          - ""
+    "###);
+}
+
+#[tokio::test]
+async fn test_collect_distinct_null_max() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ f1: Collect.n | collect_distinct(max=null) | index(0) }")
+        .run_to_csv(&collect_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0010
+        message: Invalid argument type(s)
+        formatted:
+          - "error[E0010]: Invalid argument type(s)"
+          - "  --> Query:1:40"
+          - "  |"
+          - "1 | { f1: Collect.n | collect_distinct(max=null) | index(0) }"
+          - "  |                                        ^^^^ Invalid max: 'null'"
+          - "  |"
+          - "  = 'collect_distinct' requires a positive number of values, such as '10'"
+          - ""
+          - ""
+    "###);
+}
+
+#[tokio::test]
+async fn test_most_frequent_sliding_window_produces_diagnostic() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ f1: Collect.b | most_frequent(k=1, window=sliding(2, hourly())) }")
+        .run_to_csv(&collect_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0010
+        message: Invalid argument type(s)
+        formatted:
+          - "error[E0010]: Invalid argument type(s)"
+          - "  --> Query:1:45"
+          - "  |"
+          - "1 | { f1: Collect.b | most_frequent(k=1, window=sliding(2, hourly())) }"
+          - "  |                                             ^^^^^^^^^^^^^^^^^^^^ Sliding windows are not supported for 'most_frequent'"
+          - ""
+          - ""
+    "###);
+}
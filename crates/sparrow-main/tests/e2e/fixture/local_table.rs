@@ -73,6 +73,7 @@ impl LocalTestTable {
         tracing::info!("Adding file source: {:?}", raw_file_path);
         let source_data = SourceData {
             source: Some(raw_file_path.clone()),
+            schema: None,
         };
 
         self.add_source(&source_data).await
@@ -195,6 +195,21 @@ impl QueryFixture {
         Ok(std::fs::read_to_string(output_file).unwrap())
     }
 
+    /// Run a query and return the results as a newline-delimited JSON string.
+    pub async fn run_to_json(&self, data: &DataFixture) -> Result<String, crate::EndToEndError> {
+        let output_dir = tempfile::TempDir::new().unwrap();
+        let result = self.run(data, FileType::Json, output_dir.path()).await?;
+        let output_file = result
+            .inner
+            .into_iter()
+            .exactly_one()
+            .expect("multiple output file not yet supported");
+        let output_file = output_file.to_string_lossy().to_string();
+        let output_file = output_file.strip_prefix("file://").expect("file:// prefix");
+
+        Ok(std::fs::read_to_string(output_file).unwrap())
+    }
+
     /// Run a query writing to a temporary Parquet file, and return it.
     pub async fn run_to_parquet(
         &self,
@@ -261,6 +276,7 @@ impl QueryFixture {
             output_prefix_uri: format!("file:///{}", output_dir.display()),
             file_type: output_format.into(),
             output_paths: None,
+            partitioning: None,
         };
         let output_to = Destination {
             destination: Some(destination::Destination::ObjectStore(destination)),
@@ -64,7 +64,7 @@ async fn test_invalid_formula_not_rereported() {
           - 1 | Numbers.n + $$
           - "  |             ^ Invalid token '$'"
           - "  |"
-          - "  = Expected \"!\", \"$input\", \"(\", \"-\", \"{\", ident, literal"
+          - "  = Expected \"!\", \"$input\", \"(\", \"-\", \"case\", \"{\", \"~\", ident, literal"
           - ""
           - ""
     "###);
@@ -90,7 +90,7 @@ async fn test_invalid_formula_still_reports_query_error() {
           - "1 | { n: Invalid + $$ } "
           - "  |                ^ Invalid token '$'"
           - "  |"
-          - "  = Expected \"!\", \"$input\", \"(\", \"-\", \"{\", ident, literal"
+          - "  = Expected \"!\", \"$input\", \"(\", \"-\", \"case\", \"{\", \"~\", ident, literal"
           - ""
           - ""
     "###);
@@ -64,6 +64,34 @@ pub(crate) async fn invalid_json_data_fixture() -> DataFixture {
         .unwrap()
 }
 
+/// Create a table with a json string column 'json' containing nested objects
+/// and arrays.
+///
+/// This csv parser escapes quotes with double quotes.
+pub(crate) async fn nested_json_data_fixture() -> DataFixture {
+    DataFixture::new()
+        .with_table_from_csv(
+            TableConfig::new_with_table_source(
+                "Json",
+                &Uuid::new_v4(),
+                "time",
+                Some("subsort"),
+                "key",
+                "",
+            ),
+            indoc! { r#"
+    time,subsort,key,json
+    1996-12-19T16:39:57-08:00,0,A,"{""a"": {""b"": ""dog"", ""c"": [1, 2]}, ""d"": 10}"
+    1996-12-19T16:40:57-08:00,0,B,"{""a"": {""b"": ""cat"", ""c"": [3]}, ""d"": 4}"
+    1996-12-19T16:41:57-08:00,0,B,"{""a"": {""c"": []}}"
+    1996-12-19T16:42:57-08:00,0,A,"{""d"": 7}"
+    1996-12-19T16:43:57-08:00,0,B,"{""a"": ""flat"", ""d"": 2}"
+    "#},
+        )
+        .await
+        .unwrap()
+}
+
 #[tokio::test]
 async fn test_json_parses_field() {
     insta::assert_snapshot!(QueryFixture::new("let json = json(Json.json) in { a_test: json.a as i64, b_test: json(Json.json).b }").run_to_csv(&json_data_fixture().await).await.unwrap(), @r###"
@@ -161,27 +189,57 @@ async fn test_json_of_json_object_errors() {
 }
 
 #[tokio::test]
-async fn test_nested_json_produces_error() {
-    // There's a way we can probably produce a better error message,
-    // but, on the other hand, it's marked as an experimental feature, plus
-    // this returns an error rather than incorrect results :shrug:
-    //
-    // The `dfg` would need to check if it recursively encounters the pattern
-    // `(field_ref (json ?value ?op) ?field ?op)`
-    insta::assert_yaml_snapshot!(QueryFixture::new("{ out: json(Json.json).a.b }").run_to_csv(&json_data_fixture().await).await.unwrap_err(), @r###"
+async fn test_nested_json_field_access() {
+    insta::assert_snapshot!(QueryFixture::new("let json = json(Json.json) in { b: json.a.b, c0: json.a.c[0], c1: json.a.c[1] }").run_to_csv(&nested_json_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,b,c0,c1
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,dog,1,2
+    1996-12-20T00:40:57.000000000,9223372036854775808,2867199309159137213,B,cat,3,
+    1996-12-20T00:41:57.000000000,9223372036854775808,2867199309159137213,B,,,
+    1996-12-20T00:42:57.000000000,9223372036854775808,12960666915911099378,A,,,
+    1996-12-20T00:43:57.000000000,9223372036854775808,2867199309159137213,B,,,
+    "###);
+}
+
+#[tokio::test]
+async fn test_nested_json_array_as_number() {
+    insta::assert_snapshot!(QueryFixture::new("let json = json(Json.json) in { c: json.a.c, first_plus_one: (json.a.c[0] as i64) + 1 }").run_to_csv(&nested_json_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,c,first_plus_one
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,"[1,2]",2
+    1996-12-20T00:40:57.000000000,9223372036854775808,2867199309159137213,B,[3],4
+    1996-12-20T00:41:57.000000000,9223372036854775808,2867199309159137213,B,[],
+    1996-12-20T00:42:57.000000000,9223372036854775808,12960666915911099378,A,,
+    1996-12-20T00:43:57.000000000,9223372036854775808,2867199309159137213,B,,
+    "###);
+}
+
+#[tokio::test]
+async fn test_json_string_as_struct() {
+    insta::assert_snapshot!(QueryFixture::new("let parsed = Json.json as {d: i64, a: {b: string, c: list<i64>}} in { d: parsed.d, b: parsed.a.b, c0: parsed.a.c[0], plus_one: parsed.d + 1 }").run_to_csv(&nested_json_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,d,b,c0,plus_one
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,10,dog,1,11
+    1996-12-20T00:40:57.000000000,9223372036854775808,2867199309159137213,B,4,cat,3,5
+    1996-12-20T00:41:57.000000000,9223372036854775808,2867199309159137213,B,,,,
+    1996-12-20T00:42:57.000000000,9223372036854775808,12960666915911099378,A,7,,,8
+    1996-12-20T00:43:57.000000000,9223372036854775808,2867199309159137213,B,,,,
+    "###);
+}
+
+#[tokio::test]
+async fn test_json_index_must_be_literal() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ out: json(Json.json).a.c[len(Json.json)] }").run_to_csv(&nested_json_data_fixture().await).await.unwrap_err(), @r###"
     ---
     code: Client specified an invalid argument
     message: 1 errors in Fenl statements; see diagnostics
     fenl_diagnostics:
       - severity: error
-        code: E0001
-        message: Illegal field reference
+        code: E0014
+        message: Invalid non-constant argument
         formatted:
-          - "error[E0001]: Illegal field reference"
-          - "  --> Query:1:8"
+          - "error[E0014]: Invalid non-constant argument"
+          - "  --> Query:1:28"
           - "  |"
-          - "1 | { out: json(Json.json).a.b }"
-          - "  |        ^^^^^^^^^^^^^^^^^ No fields for non-record base type string"
+          - "1 | { out: json(Json.json).a.c[len(Json.json)] }"
+          - "  |                            ^^^^^^^^^^^^^^ Index into 'json' must be a non-negative integer literal"
           - ""
           - ""
     "###);
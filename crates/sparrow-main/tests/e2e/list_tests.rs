@@ -95,6 +95,25 @@ async fn test_index_list_bool_dynamic() {
     "###);
 }
 
+#[tokio::test]
+async fn test_index_list_brackets_expression_base() {
+    // `a[i]` is equivalent to `index(i, a)`, including when `a` is an expression.
+    insta::assert_snapshot!(QueryFixture::new("{
+        field: Input.i64_list[1],
+        dynamic: Input.i64_list[Input.index],
+        call: list_sort(Input.string_list)[0],
+        parens: (Input.string_list | last())[0],
+    }
+     ").run_to_csv(&list_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,field,dynamic,call,parens
+    1996-12-19T16:39:57.000000000,0,18433805721903975440,1,2,1,bird,dog
+    1996-12-19T16:40:57.000000000,0,18433805721903975440,1,2,3,bird,cat
+    1996-12-19T16:40:59.000000000,0,18433805721903975440,1,2,2,,
+    1996-12-19T16:41:57.000000000,0,18433805721903975440,1,2,3,cat,dog
+    1996-12-19T16:42:57.000000000,0,18433805721903975440,1,2,1,dog,dog
+    "###);
+}
+
 #[tokio::test]
 #[ignore = "https://docs.rs/arrow-ord/44.0.0/src/arrow_ord/comparison.rs.html#1746"]
 async fn test_list_equality() {
@@ -127,6 +146,72 @@ async fn test_last_list() {
     "###);
 }
 
+#[tokio::test]
+async fn test_first_last_list_since() {
+    insta::assert_snapshot!(QueryFixture::new("{ first: Input.string_list | first(window=since(Input.index == 2)) | list_len(), last: Input.string_list | last(window=since(Input.index == 2)) | index(0) }").run_to_csv(&list_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,first,last
+    1996-12-19T16:39:57.000000000,0,18433805721903975440,1,2,dog
+    1996-12-19T16:40:57.000000000,0,18433805721903975440,1,2,cat
+    1996-12-19T16:40:59.000000000,0,18433805721903975440,1,0,
+    1996-12-19T16:41:57.000000000,0,18433805721903975440,1,0,dog
+    1996-12-19T16:42:57.000000000,0,18433805721903975440,1,1,dog
+    "###);
+}
+
+#[tokio::test]
+async fn test_first_last_list_sliding() {
+    insta::assert_snapshot!(QueryFixture::new("{ first: Input.string_list | first(window=sliding(2, Input.index == 2)) | list_len(), last: Input.string_list | last(window=sliding(2, Input.index == 2)) | list_len() }").run_to_csv(&list_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,first,last
+    1996-12-19T16:39:57.000000000,0,18433805721903975440,1,2,2
+    1996-12-19T16:40:57.000000000,0,18433805721903975440,1,2,3
+    1996-12-19T16:40:59.000000000,0,18433805721903975440,1,2,0
+    1996-12-19T16:41:57.000000000,0,18433805721903975440,1,2,2
+    1996-12-19T16:42:57.000000000,0,18433805721903975440,1,0,1
+    "###);
+}
+
+#[tokio::test]
+async fn test_last_list_trailing_unsupported() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ f1: Input.string_list | last(window=trailing(seconds(300))) }").run_to_csv(&list_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0010
+        message: Invalid argument type(s)
+        formatted:
+          - "error[E0010]: Invalid argument type(s)"
+          - "  --> Query:1:39"
+          - "  |"
+          - "1 | { f1: Input.string_list | last(window=trailing(seconds(300))) }"
+          - "  |                                       ^^^^^^^^^^^^^^^^^^^^^^ Trailing windows are not supported for 'last' of type list<string>"
+          - ""
+          - ""
+    "###);
+}
+
+#[tokio::test]
+async fn test_last_record_with_list_trailing_unsupported() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ f1: { s: Input.string_list } | last(window=trailing(seconds(300))) }").run_to_csv(&list_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0010
+        message: Invalid argument type(s)
+        formatted:
+          - "error[E0010]: Invalid argument type(s)"
+          - "  --> Query:1:46"
+          - "  |"
+          - "1 | { f1: { s: Input.string_list } | last(window=trailing(seconds(300))) }"
+          - "  |                                              ^^^^^^^^^^^^^^^^^^^^^^ Trailing windows are not supported for 'last' of type {s: list<string>}"
+          - ""
+          - ""
+    "###);
+}
+
 #[tokio::test]
 async fn test_list_len() {
     insta::assert_snapshot!(QueryFixture::new("{
@@ -145,6 +230,67 @@ async fn test_list_len() {
     "###);
 }
 
+#[tokio::test]
+async fn test_list_map_filter() {
+    insta::assert_snapshot!(QueryFixture::new("{
+        doubled: list_map(Input.i64_list, x -> x * 2) | index(1),
+        offset: list_map(Input.i64_list, x -> x + Input.index) | index(0),
+        num_true: list_filter(Input.bool_list, b -> b) | list_len(),
+    }
+     ").run_to_csv(&list_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,doubled,offset,num_true
+    1996-12-19T16:39:57.000000000,0,18433805721903975440,1,4,1,1
+    1996-12-19T16:40:57.000000000,0,18433805721903975440,1,4,3,1
+    1996-12-19T16:40:59.000000000,0,18433805721903975440,1,4,2,2
+    1996-12-19T16:41:57.000000000,0,18433805721903975440,1,4,3,2
+    1996-12-19T16:42:57.000000000,0,18433805721903975440,1,4,1,1
+    "###);
+}
+
+#[tokio::test]
+async fn test_list_functions() {
+    insta::assert_snapshot!(QueryFixture::new("{
+        sum: list_sum(Input.i64_list),
+        mean: list_mean(Input.i64_list),
+        has_dog: list_contains(Input.string_list, \"dog\"),
+        first_sorted: list_sort(Input.string_list) | index(0),
+        num_sliced: list_slice(Input.string_list, 1, 3) | list_len(),
+        num_distinct: list_distinct(Input.bool_list) | list_len(),
+    }
+     ").run_to_csv(&list_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,sum,mean,has_dog,first_sorted,num_sliced,num_distinct
+    1996-12-19T16:39:57.000000000,0,18433805721903975440,1,6,2.0,true,bird,1,2
+    1996-12-19T16:40:57.000000000,0,18433805721903975440,1,6,2.0,false,bird,2,2
+    1996-12-19T16:40:59.000000000,0,18433805721903975440,1,6,2.0,false,,0,2
+    1996-12-19T16:41:57.000000000,0,18433805721903975440,1,6,2.0,true,cat,1,2
+    1996-12-19T16:42:57.000000000,0,18433805721903975440,1,6,2.0,true,dog,0,1
+    "###);
+}
+
+#[tokio::test]
+async fn test_aggregation_in_lambda_fails() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ f1: list_map(Input.i64_list, x -> sum(x)) | index(0) }")
+        .run_to_csv(&list_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0017
+        message: Illegal lambda
+        formatted:
+          - "error[E0017]: Illegal lambda"
+          - "  --> Query:1:37"
+          - "  |"
+          - "1 | { f1: list_map(Input.i64_list, x -> sum(x)) | index(0) }"
+          - "  |                                     ^^^ Function 'sum' may not be used within a lambda"
+          - "  |"
+          - "  = Lambdas are applied to each element of a list, so they may not use aggregations or functions depending on the time of each row"
+          - ""
+          - ""
+    "###);
+}
+
 #[tokio::test]
 async fn test_list_schemas_are_compatible() {
     // This query puts a collect() into a record, which
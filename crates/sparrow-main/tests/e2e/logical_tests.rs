@@ -394,3 +394,65 @@ async fn test_null_if_condition_null_values() {
     2004-12-06T00:44:57.000000000,9223372036854775808,2867199309159137213,B,,,,,,,
     "###);
 }
+
+#[tokio::test]
+async fn test_case_i64() {
+    insta::assert_snapshot!(QueryFixture::new("{ m: Numbers.m, case_i64: case { Numbers.m > 20 => \"large\", Numbers.m > 10 => \"medium\", _ => \"small\" } }").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,m,case_i64
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,5,small
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,24,large
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,17,medium
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,,small
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,12,medium
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,,small
+    "###);
+}
+
+#[tokio::test]
+async fn test_case_without_default() {
+    insta::assert_snapshot!(QueryFixture::new("{ m: Numbers.m, n: Numbers.n, case_i64: case { Numbers.m > 20 => Numbers.m, Numbers.n > 5 => Numbers.n } }").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,m,n,case_i64
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,5,10,10
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,24,3,24
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,17,6,6
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,,9,9
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,12,,
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,,,
+    "###);
+}
+
+#[tokio::test]
+async fn test_case_promotes_values() {
+    insta::assert_snapshot!(QueryFixture::new("{ m: Numbers.m, case_f64: case { Numbers.m > 20 => Numbers.m, _ => 0.5 } }").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,m,case_f64
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,5,0.5
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,24,24.0
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,17,0.5
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,,0.5
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,12,0.5
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,,0.5
+    "###);
+}
+
+#[tokio::test]
+async fn test_case_invalid_condition() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ x: case { Numbers.m => 1 } }").run_to_csv(&i64_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0010
+        message: Invalid argument type(s)
+        formatted:
+          - "error[E0010]: Invalid argument type(s)"
+          - "  --> Query:1:13"
+          - "  |"
+          - "1 | { x: case { Numbers.m => 1 } }"
+          - "  |             ^^^^^^^^^ Invalid condition type 'i64' for 'case'"
+          - "  |"
+          - "  = Conditions must be of type 'bool'"
+          - ""
+          - ""
+    "###);
+}
@@ -39,6 +39,7 @@ mod shift_tests;
 mod string_tests;
 mod tick_tests;
 mod time_tests;
+mod user_function_tests;
 mod when_tests;
 mod windowed_aggregation_tests;
 mod with_key_tests;
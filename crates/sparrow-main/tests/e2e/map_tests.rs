@@ -123,6 +123,18 @@ async fn test_s_to_i64_get_with_first_last_agg() {
     "###);
 }
 
+#[tokio::test]
+async fn test_s_to_i64_get_with_windowed_first() {
+    insta::assert_snapshot!(QueryFixture::new("{ since_f2: Input.s_to_i64 | first(window=since(Input.s_to_i64_key == \"f2\")) | get(\"f2\"), sliding_f2: Input.s_to_i64 | first(window=sliding(2, Input.s_to_i64_key == \"f2\")) | get(\"f2\") }").run_to_csv(&map_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,since_f2,sliding_f2
+    1996-12-19T16:39:57.000000000,0,18433805721903975440,1,22,22
+    1996-12-19T16:40:57.000000000,0,18433805721903975440,1,22,22
+    1996-12-19T16:40:59.000000000,0,18433805721903975440,1,3,22
+    1996-12-19T16:41:57.000000000,0,18433805721903975440,1,3,22
+    1996-12-19T16:42:57.000000000,0,18433805721903975440,1,,3
+    "###);
+}
+
 #[tokio::test]
 async fn test_map_output_into_sum_aggregation() {
     insta::assert_snapshot!(QueryFixture::new("{ sum: Input.s_to_i64 | get(\"f1\") | sum(), value: Input.s_to_i64 | get(Input.s_to_i64_key) } | with_key(Input.s_to_i64_key)").run_to_csv(&map_data_fixture().await).await.unwrap(), @r###"
@@ -262,3 +274,91 @@ async fn test_using_map_in_index_fails() {
           - ""
     "###);
 }
+
+#[tokio::test]
+async fn test_map_len_keys_and_values() {
+    insta::assert_snapshot!(QueryFixture::new("{ len: map_len(Input.s_to_i64), first_key: Input.s_to_i64 | map_keys() | index(0), first_value: Input.s_to_i64 | map_values() | index(0) }").run_to_csv(&map_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,len,first_key,first_value
+    1996-12-19T16:39:57.000000000,0,18433805721903975440,1,2,f1,0
+    1996-12-19T16:40:57.000000000,0,18433805721903975440,1,2,f1,1
+    1996-12-19T16:40:59.000000000,0,18433805721903975440,1,2,f1,5
+    1996-12-19T16:41:57.000000000,0,18433805721903975440,1,1,f2,13
+    1996-12-19T16:42:57.000000000,0,18433805721903975440,1,2,f1,15
+    "###);
+}
+
+#[tokio::test]
+async fn test_map_contains() {
+    insta::assert_snapshot!(QueryFixture::new("{ has_f1: map_contains(\"f1\", Input.s_to_i64), has_key: Input.s_to_i64 | map_contains(Input.s_to_i64_key) }").run_to_csv(&map_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,has_f1,has_key
+    1996-12-19T16:39:57.000000000,0,18433805721903975440,1,true,true
+    1996-12-19T16:40:57.000000000,0,18433805721903975440,1,true,true
+    1996-12-19T16:40:59.000000000,0,18433805721903975440,1,true,false
+    1996-12-19T16:41:57.000000000,0,18433805721903975440,1,false,true
+    1996-12-19T16:42:57.000000000,0,18433805721903975440,1,true,true
+    "###);
+}
+
+#[tokio::test]
+async fn test_map_from_lists() {
+    // Swaps the keys and values, so looking up the value for the row's key
+    // produces the key.
+    insta::assert_snapshot!(QueryFixture::new("let inverted = map_from_lists(Input.s_to_i64 | map_values(), Input.s_to_i64 | map_keys())
+        in { len: map_len(inverted), key: get(Input.s_to_i64 | get(Input.s_to_i64_key), inverted) }").run_to_csv(&map_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,len,key
+    1996-12-19T16:39:57.000000000,0,18433805721903975440,1,2,f1
+    1996-12-19T16:40:57.000000000,0,18433805721903975440,1,2,f2
+    1996-12-19T16:40:59.000000000,0,18433805721903975440,1,2,
+    1996-12-19T16:41:57.000000000,0,18433805721903975440,1,1,f2
+    1996-12-19T16:42:57.000000000,0,18433805721903975440,1,2,f3
+    "###);
+}
+
+#[tokio::test]
+async fn test_collect_map() {
+    // The value for `f3` in the third row is null, so it isn't collected.
+    insta::assert_snapshot!(QueryFixture::new("let latest = collect_map(Input.s_to_i64_key, Input.s_to_i64 | get(Input.s_to_i64_key))
+        in { len: map_len(latest), f1: get(\"f1\", latest), f2: get(\"f2\", latest), f3: get(\"f3\", latest) }").run_to_csv(&map_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,len,f1,f2,f3
+    1996-12-19T16:39:57.000000000,0,18433805721903975440,1,1,0,,
+    1996-12-19T16:40:57.000000000,0,18433805721903975440,1,2,0,10,
+    1996-12-19T16:40:59.000000000,0,18433805721903975440,1,2,0,10,
+    1996-12-19T16:41:57.000000000,0,18433805721903975440,1,2,0,13,
+    1996-12-19T16:42:57.000000000,0,18433805721903975440,1,3,0,13,11
+    "###);
+}
+
+#[tokio::test]
+async fn test_collect_map_since() {
+    insta::assert_snapshot!(QueryFixture::new("let latest = collect_map(Input.s_to_i64_key, Input.s_to_i64 | get(Input.s_to_i64_key), window = since(Input.s_to_i64_key == \"f2\"))
+        in { len: map_len(latest), f2: get(\"f2\", latest) }").run_to_csv(&map_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,len,f2
+    1996-12-19T16:39:57.000000000,0,18433805721903975440,1,1,
+    1996-12-19T16:40:57.000000000,0,18433805721903975440,1,2,10
+    1996-12-19T16:40:59.000000000,0,18433805721903975440,1,0,
+    1996-12-19T16:41:57.000000000,0,18433805721903975440,1,1,13
+    1996-12-19T16:42:57.000000000,0,18433805721903975440,1,1,
+    "###);
+}
+
+#[tokio::test]
+async fn test_collect_map_sliding_window_produces_diagnostic() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ len: collect_map(Input.s_to_i64_key, Input.s_to_i64 | get(Input.s_to_i64_key), window=sliding(2, hourly())) | map_len() }")
+        .run_to_csv(&map_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0010
+        message: Invalid argument type(s)
+        formatted:
+          - "error[E0010]: Invalid argument type(s)"
+          - "  --> Query:1:89"
+          - "  |"
+          - "1 | { len: collect_map(Input.s_to_i64_key, Input.s_to_i64 | get(Input.s_to_i64_key), window=sliding(2, hourly())) | map_len() }"
+          - "  |                                                                                         ^^^^^^^^^^^^^^^^^^^^ Sliding windows are not supported for 'collect_map'"
+          - ""
+          - ""
+    "###);
+}
@@ -516,3 +516,170 @@ async fn test_i64_sqrt() {
     1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,,
     "###);
 }
+
+#[tokio::test]
+async fn test_i64_rem() {
+    insta::assert_snapshot!(QueryFixture::new("{ m: Numbers.m
+                , n: Numbers.n
+                , rem_m_n: Numbers.m % Numbers.n
+                , rem_m_7: rem(Numbers.m, 7)
+                , rem_neg_m_7: -Numbers.m % 7
+                }").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,m,n,rem_m_n,rem_m_7,rem_neg_m_7
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,5,10,5,5,-5
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,24,3,0,3,-3
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,17,6,5,3,-3
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,,9,,,
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,12,,,5,-5
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,,,,,
+    "###);
+}
+
+#[tokio::test]
+async fn test_i64_mod_floor_div() {
+    insta::assert_snapshot!(QueryFixture::new("{ m: Numbers.m
+                , n: Numbers.n
+                , mod_neg_m_n: mod(-Numbers.m, Numbers.n)
+                , floor_div_neg_m_n: floor_div(-Numbers.m, Numbers.n)
+                }").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,m,n,mod_neg_m_n,floor_div_neg_m_n
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,5,10,5,-1
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,24,3,0,-8
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,17,6,1,-3
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,,9,,
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,12,,,
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,,,,
+    "###);
+}
+
+#[tokio::test]
+async fn test_i64_bitwise() {
+    insta::assert_snapshot!(QueryFixture::new("{ m: Numbers.m
+                , n: Numbers.n
+                , and_m_12: Numbers.m & 12
+                , or_m_n: bitwise_or(Numbers.m, Numbers.n)
+                , xor_m_n: Numbers.m ^ Numbers.n
+                , not_m: ~Numbers.m
+                }").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,m,n,and_m_12,or_m_n,xor_m_n,not_m
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,5,10,4,15,15,-6
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,24,3,8,27,27,-25
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,17,6,0,23,23,-18
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,,9,,,,
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,12,,12,,,-13
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,,,,,,
+    "###);
+}
+
+#[tokio::test]
+async fn test_i64_shift() {
+    insta::assert_snapshot!(QueryFixture::new("{ m: Numbers.m
+                , shift_left_m: Numbers.m << 2
+                , shift_right_m: Numbers.m >> 2
+                }").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,m,shift_left_m,shift_right_m
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,5,20,1
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,24,96,6
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,17,68,4
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,,,
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,12,48,3
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,,,
+    "###);
+}
+
+#[tokio::test]
+async fn test_f64_bitwise_error() {
+    // Bitwise operators are only defined for the `int` type class.
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ and_m_n: Numbers.m & Numbers.n }").run_to_csv(&f64_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0010
+        message: Invalid argument type(s)
+        formatted:
+          - "error[E0010]: Invalid argument type(s)"
+          - "  --> Query:1:22"
+          - "  |"
+          - "1 | { and_m_n: Numbers.m & Numbers.n }"
+          - "  |            --------- ^ Invalid types for call to 'bitwise_and'"
+          - "  |            |          "
+          - "  |            Type: f64"
+          - "  |"
+          - "  = Expected 'int'"
+          - ""
+          - ""
+    "###);
+}
+
+#[tokio::test]
+async fn test_i64_abs_sign() {
+    insta::assert_snapshot!(QueryFixture::new("{ m: Numbers.m
+                , n: Numbers.n
+                , abs_m_n: abs(Numbers.m - Numbers.n)
+                , sign_m_n: sign(Numbers.m - Numbers.n)
+                }").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,m,n,abs_m_n,sign_m_n
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,5,10,5,-1
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,24,3,21,1
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,17,6,11,1
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,,9,,
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,12,,,
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,,,,
+    "###);
+}
+
+#[tokio::test]
+async fn test_i64_log() {
+    insta::assert_snapshot!(QueryFixture::new("{ m: Numbers.m
+                , n: Numbers.n
+                , ln_m: ln(Numbers.m)
+                , log10_n: log10(Numbers.n)
+                , log_2_m: log(Numbers.m, 2)
+                }").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,m,n,ln_m,log10_n,log_2_m
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,5,10,1.6094379124341003,1.0,2.321928094887362
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,24,3,3.1780538303479458,0.47712125471966244,4.584962500721157
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,17,6,2.833213344056216,0.7781512503836436,4.08746284125034
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,,9,,0.9542425094393249,
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,12,,2.4849066497880004,,3.5849625007211565
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,,,,,
+    "###);
+}
+
+#[tokio::test]
+async fn test_i64_trig() {
+    insta::assert_snapshot!(QueryFixture::new("{ m: Numbers.m
+                , n: Numbers.n
+                , sin_m: sin(Numbers.m)
+                , cos_m: cos(Numbers.m)
+                , tan_m: tan(Numbers.m)
+                , atan2_m_n: atan2(Numbers.m, Numbers.n)
+                }").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,m,n,sin_m,cos_m,tan_m,atan2_m_n
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,5,10,-0.9589242746631385,0.28366218546322625,-3.380515006246586,0.4636476090008061
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,24,3,-0.9055783620066239,0.424179007336997,-2.1348966977217008,1.446441332248135
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,17,6,-0.9613974918795568,-0.27516333805159693,3.49391564547484,1.2315037123408519
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,,9,,,,
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,12,,-0.5365729180004349,0.8438539587324921,-0.6358599286615808,
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,,,,,,
+    "###);
+}
+
+#[tokio::test]
+async fn test_i64_inverse_trig() {
+    insta::assert_snapshot!(QueryFixture::new("{ n: Numbers.n
+                , asin_n: asin(Numbers.n / 10.0)
+                , acos_n: acos(Numbers.n / 10.0)
+                , atan_n: atan(Numbers.n)
+                }").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,n,asin_n,acos_n,atan_n
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,10,1.5707963267948966,0.0,1.4711276743037347
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,3,0.3046926540153975,1.2661036727794992,1.2490457723982544
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,6,0.6435011087932844,0.9272952180016123,1.4056476493802699
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,9,1.1197695149986342,0.45102681179626236,1.460139105621001
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,,,,
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,,,,
+    "###);
+}
@@ -24,6 +24,18 @@ async fn test_record_creation() {
     "###);
 }
 
+#[tokio::test]
+async fn test_record_to_json() {
+    insta::assert_snapshot!(QueryFixture::new("{ rec: { s: Strings.s, n: Strings.n } }").run_to_json(&strings_data_fixture().await).await.unwrap(), @r###"
+    {"_key":"A","_key_hash":"12960666915911099378","_subsort":"9223372036854775808","_time":"1996-12-20T00:39:57","rec":{"n":0,"s":"hEllo"}}
+    {"_key":"B","_key_hash":"2867199309159137213","_subsort":"9223372036854775808","_time":"1996-12-20T00:40:57","rec":{"n":5,"s":"World"}}
+    {"_key":"B","_key_hash":"2867199309159137213","_subsort":"9223372036854775808","_time":"1996-12-20T00:41:57","rec":{"n":-2,"s":"hello world"}}
+    {"_key":"B","_key_hash":"2867199309159137213","_subsort":"9223372036854775808","_time":"1996-12-20T00:42:57","rec":{"n":-2}}
+    {"_key":"B","_key_hash":"2867199309159137213","_subsort":"9223372036854775808","_time":"1996-12-20T00:43:57","rec":{"n":2}}
+    {"_key":"B","_key_hash":"2867199309159137213","_subsort":"9223372036854775808","_time":"1996-12-20T00:44:57","rec":{"s":"goodbye"}}
+    "###);
+}
+
 #[tokio::test]
 async fn test_record_extension() {
     insta::assert_snapshot!(QueryFixture::new("
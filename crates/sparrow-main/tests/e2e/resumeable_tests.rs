@@ -591,6 +591,48 @@ async fn test_resumeable_lag_basic() {
     "###);
 }
 
+#[tokio::test]
+async fn test_resumeable_collect_values() {
+    // Test for resuming a query with the aggregations that collect a limited
+    // number of values, which persist both the values and their counts.
+    let query_fixture = QueryFixture::new(
+        "{ top: Numbers.m | top_k(k=2) | index(1), frequent: Numbers.n | most_frequent(k=1) | index(0), distinct: Numbers.m | collect_distinct(max=2) | index(0) }",
+    );
+    let result = assert_final_incremental_same_as_complete(
+        query_fixture,
+        TableConfig::new_with_table_source(
+            "Numbers",
+            &Uuid::new_v4(),
+            "time",
+            Some("subsort"),
+            "key",
+            "",
+        ),
+        indoc! {"
+        time,subsort,key,m,n
+        1996-12-19T16:39:57-08:00,0,A,5,10
+        1996-12-19T16:39:58-08:00,0,B,24,3
+        1996-12-19T16:39:59-08:00,0,A,17,6
+        1996-12-19T16:40:00-08:00,0,A,,9
+        "},
+        indoc! {"
+        time,subsort,key,m,n
+        1996-12-19T16:40:01-08:00,0,A,12,
+        1997-12-19T16:40:01-08:00,0,B,2,5
+        1997-12-19T16:40:02-08:00,0,B,2,
+        1996-12-19T16:40:02-08:00,0,B,2,
+        1996-12-19T16:40:03-08:00,0,A,,
+    "},
+    )
+    .await;
+
+    insta::assert_snapshot!(result, @r###"
+    _time,_subsort,_key_hash,_key,top,frequent,distinct
+    1997-12-20T00:40:02.000000001,18446744073709551615,2867199309159137213,B,2,3,24
+    1997-12-20T00:40:02.000000001,18446744073709551615,12960666915911099378,A,12,10,17
+    "###);
+}
+
 #[tokio::test]
 async fn test_resumeable_final_no_new_data() {
     // Test that producing final results from a snapshot with no new data works.
@@ -58,3 +58,227 @@ async fn test_substring() {
     1996-12-20T00:44:57.000000000,9223372036854775808,2867199309159137213,B,go,oodbye,goodbye,goodbye
     "###);
 }
+
+#[tokio::test]
+async fn test_regex_match() {
+    insta::assert_snapshot!(QueryFixture::new("{ regex_match: regex_match(Strings.s, \"^[a-z ]+$\")}").run_to_csv(&strings_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,regex_match
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,false
+    1996-12-20T00:40:57.000000000,9223372036854775808,2867199309159137213,B,false
+    1996-12-20T00:41:57.000000000,9223372036854775808,2867199309159137213,B,true
+    1996-12-20T00:42:57.000000000,9223372036854775808,2867199309159137213,B,false
+    1996-12-20T00:43:57.000000000,9223372036854775808,2867199309159137213,B,false
+    1996-12-20T00:44:57.000000000,9223372036854775808,2867199309159137213,B,true
+    "###);
+}
+
+#[tokio::test]
+async fn test_regex_extract() {
+    insta::assert_snapshot!(QueryFixture::new("{ extract_0: regex_extract(Strings.s, \"l+o\", 0)
+                , extract_2: regex_extract(Strings.s, \"([a-z]+) ([a-z]+)\", 2)
+                }").run_to_csv(&strings_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,extract_0,extract_2
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,llo,
+    1996-12-20T00:40:57.000000000,9223372036854775808,2867199309159137213,B,,
+    1996-12-20T00:41:57.000000000,9223372036854775808,2867199309159137213,B,llo,world
+    1996-12-20T00:42:57.000000000,9223372036854775808,2867199309159137213,B,,
+    1996-12-20T00:43:57.000000000,9223372036854775808,2867199309159137213,B,,
+    1996-12-20T00:44:57.000000000,9223372036854775808,2867199309159137213,B,,
+    "###);
+}
+
+#[tokio::test]
+async fn test_regex_replace() {
+    insta::assert_snapshot!(QueryFixture::new("{ replace_vowels: regex_replace(Strings.s, \"[aeiou]\", \"_\")
+                , replace_group: regex_replace(Strings.s, \"(l+)\", \"[$1]\")
+                }").run_to_csv(&strings_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,replace_vowels,replace_group
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,hEll_,hE[ll]o
+    1996-12-20T00:40:57.000000000,9223372036854775808,2867199309159137213,B,W_rld,Wor[l]d
+    1996-12-20T00:41:57.000000000,9223372036854775808,2867199309159137213,B,h_ll_ w_rld,he[ll]o wor[l]d
+    1996-12-20T00:42:57.000000000,9223372036854775808,2867199309159137213,B,,
+    1996-12-20T00:43:57.000000000,9223372036854775808,2867199309159137213,B,,
+    1996-12-20T00:44:57.000000000,9223372036854775808,2867199309159137213,B,g__dby_,goodbye
+    "###);
+}
+
+#[tokio::test]
+async fn test_regex_invalid_pattern() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ matched: regex_match(Strings.s, \"(hello\") }").run_to_csv(&strings_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0008
+        message: Invalid arguments
+        formatted:
+          - "error[E0008]: Invalid arguments"
+          - "  --> Query:1:35"
+          - "  |"
+          - "1 | { matched: regex_match(Strings.s, \"(hello\") }"
+          - "  |                                   ^^^^^^^^ Invalid regular expression: '(hello'"
+          - "  |"
+          - "  = unclosed group"
+          - ""
+          - ""
+    "###);
+}
+
+#[tokio::test]
+async fn test_regex_extract_invalid_group() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ extracted: regex_extract(Strings.s, \"(l+)\", 2) }").run_to_csv(&strings_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0008
+        message: Invalid arguments
+        formatted:
+          - "error[E0008]: Invalid arguments"
+          - "  --> Query:1:47"
+          - "  |"
+          - "1 | { extracted: regex_extract(Strings.s, \"(l+)\", 2) }"
+          - "  |                                               ^ Invalid capture group: '2'"
+          - "  |"
+          - "  = The pattern has 1 capture group(s), so the group must be between 0 and 1"
+          - ""
+          - ""
+    "###);
+}
+
+#[tokio::test]
+async fn test_concat() {
+    insta::assert_snapshot!(QueryFixture::new("{ concat: concat(Strings.s, \"-\", Strings.t) }").run_to_csv(&strings_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,concat
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,hEllo-hEllo
+    1996-12-20T00:40:57.000000000,9223372036854775808,2867199309159137213,B,World-world
+    1996-12-20T00:41:57.000000000,9223372036854775808,2867199309159137213,B,hello world-hello world
+    1996-12-20T00:42:57.000000000,9223372036854775808,2867199309159137213,B,-greetings
+    1996-12-20T00:43:57.000000000,9223372036854775808,2867199309159137213,B,-salutations
+    1996-12-20T00:44:57.000000000,9223372036854775808,2867199309159137213,B,goodbye-
+    "###);
+}
+
+#[tokio::test]
+async fn test_add_strings() {
+    insta::assert_snapshot!(QueryFixture::new("{ add: Strings.s + \"-\" + Strings.t }").run_to_csv(&strings_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,add
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,hEllo-hEllo
+    1996-12-20T00:40:57.000000000,9223372036854775808,2867199309159137213,B,World-world
+    1996-12-20T00:41:57.000000000,9223372036854775808,2867199309159137213,B,hello world-hello world
+    1996-12-20T00:42:57.000000000,9223372036854775808,2867199309159137213,B,-greetings
+    1996-12-20T00:43:57.000000000,9223372036854775808,2867199309159137213,B,-salutations
+    1996-12-20T00:44:57.000000000,9223372036854775808,2867199309159137213,B,goodbye-
+    "###);
+}
+
+#[tokio::test]
+async fn test_split() {
+    insta::assert_snapshot!(QueryFixture::new("{ first: split(Strings.s, \" \") | index(0)
+                , second: split(Strings.s, \" \") | index(1)
+                , chars: split(Strings.t, \"\") | list_len()
+                }").run_to_csv(&strings_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,first,second,chars
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,hEllo,,5
+    1996-12-20T00:40:57.000000000,9223372036854775808,2867199309159137213,B,World,,5
+    1996-12-20T00:41:57.000000000,9223372036854775808,2867199309159137213,B,hello,world,11
+    1996-12-20T00:42:57.000000000,9223372036854775808,2867199309159137213,B,,,9
+    1996-12-20T00:43:57.000000000,9223372036854775808,2867199309159137213,B,,,11
+    1996-12-20T00:44:57.000000000,9223372036854775808,2867199309159137213,B,goodbye,,0
+    "###);
+}
+
+#[tokio::test]
+async fn test_trim() {
+    insta::assert_snapshot!(QueryFixture::new("{ trim: trim(concat(\"  \", Strings.s, \" \")) }").run_to_csv(&strings_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,trim
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,hEllo
+    1996-12-20T00:40:57.000000000,9223372036854775808,2867199309159137213,B,World
+    1996-12-20T00:41:57.000000000,9223372036854775808,2867199309159137213,B,hello world
+    1996-12-20T00:42:57.000000000,9223372036854775808,2867199309159137213,B,
+    1996-12-20T00:43:57.000000000,9223372036854775808,2867199309159137213,B,
+    1996-12-20T00:44:57.000000000,9223372036854775808,2867199309159137213,B,goodbye
+    "###);
+}
+
+#[tokio::test]
+async fn test_string_predicates() {
+    insta::assert_snapshot!(QueryFixture::new("{ starts_with: starts_with(Strings.s, \"h\")
+                , ends_with: ends_with(Strings.s, Strings.t)
+                , contains: contains(Strings.t, \"o\")
+                }").run_to_csv(&strings_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,starts_with,ends_with,contains
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,true,true,true
+    1996-12-20T00:40:57.000000000,9223372036854775808,2867199309159137213,B,false,false,true
+    1996-12-20T00:41:57.000000000,9223372036854775808,2867199309159137213,B,true,true,true
+    1996-12-20T00:42:57.000000000,9223372036854775808,2867199309159137213,B,false,false,false
+    1996-12-20T00:43:57.000000000,9223372036854775808,2867199309159137213,B,false,false,true
+    1996-12-20T00:44:57.000000000,9223372036854775808,2867199309159137213,B,false,true,false
+    "###);
+}
+
+#[tokio::test]
+async fn test_replace() {
+    insta::assert_snapshot!(QueryFixture::new("{ replace: replace(Strings.s, \"l\", \"L\") }").run_to_csv(&strings_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,replace
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,hELLo
+    1996-12-20T00:40:57.000000000,9223372036854775808,2867199309159137213,B,WorLd
+    1996-12-20T00:41:57.000000000,9223372036854775808,2867199309159137213,B,heLLo worLd
+    1996-12-20T00:42:57.000000000,9223372036854775808,2867199309159137213,B,
+    1996-12-20T00:43:57.000000000,9223372036854775808,2867199309159137213,B,
+    1996-12-20T00:44:57.000000000,9223372036854775808,2867199309159137213,B,goodbye
+    "###);
+}
+
+#[tokio::test]
+async fn test_pad() {
+    insta::assert_snapshot!(QueryFixture::new("{ lpad: lpad(Strings.s, 7, \"*\")
+                , rpad: rpad(Strings.s, Strings.n, \"-.\")
+                }").run_to_csv(&strings_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,lpad,rpad
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,**hEllo,
+    1996-12-20T00:40:57.000000000,9223372036854775808,2867199309159137213,B,**World,World
+    1996-12-20T00:41:57.000000000,9223372036854775808,2867199309159137213,B,hello w,
+    1996-12-20T00:42:57.000000000,9223372036854775808,2867199309159137213,B,*******,
+    1996-12-20T00:43:57.000000000,9223372036854775808,2867199309159137213,B,*******,-.
+    1996-12-20T00:44:57.000000000,9223372036854775808,2867199309159137213,B,goodbye,
+    "###);
+}
+
+#[tokio::test]
+async fn test_format() {
+    insta::assert_snapshot!(QueryFixture::new("{ format: format(\"{1}: {0}!\", Strings.s, Strings.t) }").run_to_csv(&strings_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,format
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,hEllo: hEllo!
+    1996-12-20T00:40:57.000000000,9223372036854775808,2867199309159137213,B,world: World!
+    1996-12-20T00:41:57.000000000,9223372036854775808,2867199309159137213,B,hello world: hello world!
+    1996-12-20T00:42:57.000000000,9223372036854775808,2867199309159137213,B,greetings: !
+    1996-12-20T00:43:57.000000000,9223372036854775808,2867199309159137213,B,salutations: !
+    1996-12-20T00:44:57.000000000,9223372036854775808,2867199309159137213,B,: goodbye!
+    "###);
+}
+
+#[tokio::test]
+async fn test_format_missing_values() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ formatted: format(\"{} {}\", Strings.s) }").run_to_csv(&strings_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0008
+        message: Invalid arguments
+        formatted:
+          - "error[E0008]: Invalid arguments"
+          - "  --> Query:1:21"
+          - "  |"
+          - "1 | { formatted: format(\"{} {}\", Strings.s) }"
+          - "  |                     ^^^^^^^ Invalid format template: '{} {}'"
+          - "  |"
+          - "  = The template references 2 value(s), but only 1 provided"
+          - ""
+          - ""
+    "###);
+}
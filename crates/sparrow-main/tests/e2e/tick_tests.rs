@@ -409,6 +409,52 @@ async fn test_since_hourly() {
     "###);
 }
 
+#[tokio::test]
+async fn test_since_every() {
+    // Ticks every 90 minutes, offset by 30 minutes from the epoch.
+    insta::assert_snapshot!(QueryFixture::new("{ n: Foo.n, sum_since: sum(Foo.n, window=since(every(seconds(5400), seconds(1800)))) }").run_to_csv(&data_fixture_over_hours().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,n,sum_since
+    1996-12-20T07:39:57.000000000,9223372036854775808,12960666915911099378,A,10.0,10.0
+    1996-12-20T07:39:58.000000000,9223372036854775808,2867199309159137213,B,3.9,3.9
+    1996-12-20T08:00:00.000000000,9223372036854775808,12960666915911099378,A,6.2,16.2
+    1996-12-20T08:00:00.000000000,18446744073709551615,2867199309159137213,B,,3.9
+    1996-12-20T08:00:00.000000000,18446744073709551615,12960666915911099378,A,,16.2
+    1996-12-20T08:44:00.000000000,9223372036854775808,12960666915911099378,A,9.25,9.25
+    1996-12-20T08:45:01.000000000,9223372036854775808,12960666915911099378,A,3.0,12.25
+    1996-12-20T09:20:02.000000000,9223372036854775808,12960666915911099378,A,8.0,20.25
+    1996-12-20T09:25:02.000000000,9223372036854775808,2867199309159137213,B,23.9,23.9
+    1996-12-20T09:30:00.000000000,18446744073709551615,2867199309159137213,B,,23.9
+    1996-12-20T09:30:00.000000000,18446744073709551615,12960666915911099378,A,,20.25
+    1996-12-20T10:30:03.000000000,9223372036854775808,12960666915911099378,A,,
+    1996-12-20T11:00:00.000000000,18446744073709551615,2867199309159137213,B,,
+    1996-12-20T11:00:00.000000000,18446744073709551615,12960666915911099378,A,,
+    1996-12-20T11:40:04.000000000,9223372036854775808,12960666915911099378,A,10.0,10.0
+    "###);
+}
+
+#[tokio::test]
+async fn test_every_invalid_interval() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ tick: every(months(1)) }").run_to_csv(&data_fixture_over_hours().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0008
+        message: Invalid arguments
+        formatted:
+          - "error[E0008]: Invalid arguments"
+          - "  --> Query:1:15"
+          - "  |"
+          - "1 | { tick: every(months(1)) }"
+          - "  |               ^^^^^^^^^ Invalid tick interval: 'interval_months:1'"
+          - "  |"
+          - "  = Tick intervals must be positive fixed durations, such as 'seconds(900)'"
+          - ""
+          - ""
+    "###);
+}
+
 #[tokio::test]
 async fn test_since_hourly_end_on_hour() {
     insta::assert_snapshot!(QueryFixture::new("{ n: Foo.n, sum_since: sum(Foo.n, window=since(hourly())) }").run_to_csv(&data_fixture_over_hours_end_on_hour().await).await.unwrap(), @r###"
@@ -482,6 +528,48 @@ async fn test_since_daily_over_span_of_days() {
     "###);
 }
 
+#[tokio::test]
+async fn test_since_weekly() {
+    // 1996-12-21 is a Saturday.
+    insta::assert_snapshot!(QueryFixture::new("{ n: Foo.n, sum_since: sum(Foo.n, window=since(weekly(start = \"saturday\"))) }").run_to_csv(&data_fixture_over_days().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,n,sum_since
+    1996-12-19T20:39:57.000000000,9223372036854775808,12960666915911099378,A,10.0,10.0
+    1996-12-19T20:39:58.000000000,9223372036854775808,2867199309159137213,B,3.9,3.9
+    1996-12-21T00:00:00.000000000,18446744073709551615,2867199309159137213,B,,3.9
+    1996-12-21T00:00:00.000000000,18446744073709551615,12960666915911099378,A,,10.0
+    1996-12-21T00:32:59.000000000,9223372036854775808,12960666915911099378,A,6.2,6.2
+    1996-12-21T00:44:00.000000000,9223372036854775808,12960666915911099378,A,9.25,15.45
+    1996-12-21T00:45:01.000000000,9223372036854775808,12960666915911099378,A,3.0,18.45
+    1996-12-21T08:00:00.000000000,9223372036854775808,12960666915911099378,A,8.0,26.45
+    1996-12-22T00:25:02.000000000,9223372036854775808,2867199309159137213,B,23.9,23.9
+    1996-12-22T00:30:03.000000000,9223372036854775808,12960666915911099378,A,,26.45
+    1996-12-23T00:40:04.000000000,9223372036854775808,12960666915911099378,A,10.0,36.45
+    "###);
+}
+
+#[tokio::test]
+async fn test_since_daily_time_zone() {
+    // Midnight in New York is 05:00 UTC during standard time.
+    insta::assert_snapshot!(QueryFixture::new("{ n: Foo.n, sum_since: sum(Foo.n, window=since(daily(tz = \"America/New_York\"))) }").run_to_csv(&data_fixture_over_days().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,n,sum_since
+    1996-12-19T20:39:57.000000000,9223372036854775808,12960666915911099378,A,10.0,10.0
+    1996-12-19T20:39:58.000000000,9223372036854775808,2867199309159137213,B,3.9,3.9
+    1996-12-20T05:00:00.000000000,18446744073709551615,2867199309159137213,B,,3.9
+    1996-12-20T05:00:00.000000000,18446744073709551615,12960666915911099378,A,,10.0
+    1996-12-21T00:32:59.000000000,9223372036854775808,12960666915911099378,A,6.2,6.2
+    1996-12-21T00:44:00.000000000,9223372036854775808,12960666915911099378,A,9.25,15.45
+    1996-12-21T00:45:01.000000000,9223372036854775808,12960666915911099378,A,3.0,18.45
+    1996-12-21T05:00:00.000000000,18446744073709551615,2867199309159137213,B,,
+    1996-12-21T05:00:00.000000000,18446744073709551615,12960666915911099378,A,,18.45
+    1996-12-21T08:00:00.000000000,9223372036854775808,12960666915911099378,A,8.0,8.0
+    1996-12-22T00:25:02.000000000,9223372036854775808,2867199309159137213,B,23.9,23.9
+    1996-12-22T00:30:03.000000000,9223372036854775808,12960666915911099378,A,,8.0
+    1996-12-22T05:00:00.000000000,18446744073709551615,2867199309159137213,B,,23.9
+    1996-12-22T05:00:00.000000000,18446744073709551615,12960666915911099378,A,,8.0
+    1996-12-23T00:40:04.000000000,9223372036854775808,12960666915911099378,A,10.0,10.0
+    "###);
+}
+
 #[tokio::test]
 async fn test_since_monthly() {
     insta::assert_snapshot!(QueryFixture::new("{ n: Foo.n, sum_since: sum(Foo.n, window=since(monthly())) }").run_to_csv(&data_fixture_over_months().await).await.unwrap(), @r###"
@@ -187,6 +187,94 @@ async fn test_year() {
     "###);
 }
 
+#[tokio::test]
+async fn test_day_of_month_time_zone() {
+    insta::assert_snapshot!(QueryFixture::new("{ utc: day_of_month(Times.time), new_york: day_of_month(Times.time, tz = \"America/New_York\") }").run_to_csv(&timestamp_ns_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,utc,new_york
+    1994-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,20,19
+    1995-10-20T00:40:57.000000000,9223372036854775808,2867199309159137213,B,20,19
+    1996-08-20T00:41:57.000000000,9223372036854775808,2867199309159137213,B,20,19
+    1997-12-12T00:42:57.000000000,9223372036854775808,2867199309159137213,B,12,11
+    1998-12-13T00:43:57.000000000,9223372036854775808,2867199309159137213,B,13,12
+    2004-12-06T00:44:57.000000000,9223372036854775808,2867199309159137213,B,6,5
+    "###);
+}
+
+#[tokio::test]
+async fn test_day_of_week() {
+    insta::assert_snapshot!(QueryFixture::new("{ day_of_week: day_of_week(Times.time) }").run_to_csv(&timestamp_ns_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,day_of_week
+    1994-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,2
+    1995-10-20T00:40:57.000000000,9223372036854775808,2867199309159137213,B,5
+    1996-08-20T00:41:57.000000000,9223372036854775808,2867199309159137213,B,2
+    1997-12-12T00:42:57.000000000,9223372036854775808,2867199309159137213,B,5
+    1998-12-13T00:43:57.000000000,9223372036854775808,2867199309159137213,B,7
+    2004-12-06T00:44:57.000000000,9223372036854775808,2867199309159137213,B,1
+    "###);
+}
+
+#[tokio::test]
+async fn test_day_of_week0() {
+    insta::assert_snapshot!(QueryFixture::new("{ day_of_week0: day_of_week0(Times.time) }").run_to_csv(&timestamp_ns_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,day_of_week0
+    1994-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,1
+    1995-10-20T00:40:57.000000000,9223372036854775808,2867199309159137213,B,4
+    1996-08-20T00:41:57.000000000,9223372036854775808,2867199309159137213,B,1
+    1997-12-12T00:42:57.000000000,9223372036854775808,2867199309159137213,B,4
+    1998-12-13T00:43:57.000000000,9223372036854775808,2867199309159137213,B,6
+    2004-12-06T00:44:57.000000000,9223372036854775808,2867199309159137213,B,0
+    "###);
+}
+
+#[tokio::test]
+async fn test_day_of_week_time_zone() {
+    insta::assert_snapshot!(QueryFixture::new("{ day_of_week: day_of_week(Times.time, tz = \"America/New_York\") }").run_to_csv(&timestamp_ns_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,day_of_week
+    1994-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,1
+    1995-10-20T00:40:57.000000000,9223372036854775808,2867199309159137213,B,4
+    1996-08-20T00:41:57.000000000,9223372036854775808,2867199309159137213,B,1
+    1997-12-12T00:42:57.000000000,9223372036854775808,2867199309159137213,B,4
+    1998-12-13T00:43:57.000000000,9223372036854775808,2867199309159137213,B,6
+    2004-12-06T00:44:57.000000000,9223372036854775808,2867199309159137213,B,7
+    "###);
+}
+
+#[tokio::test]
+async fn test_year_time_zone() {
+    insta::assert_snapshot!(QueryFixture::new("{ year: year(Times.time, tz = \"America/New_York\"), month_of_year: month_of_year(Times.time, tz = \"America/New_York\") }").run_to_csv(&timestamp_ns_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,year,month_of_year
+    1994-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,1994,12
+    1995-10-20T00:40:57.000000000,9223372036854775808,2867199309159137213,B,1995,10
+    1996-08-20T00:41:57.000000000,9223372036854775808,2867199309159137213,B,1996,8
+    1997-12-12T00:42:57.000000000,9223372036854775808,2867199309159137213,B,1997,12
+    1998-12-13T00:43:57.000000000,9223372036854775808,2867199309159137213,B,1998,12
+    2004-12-06T00:44:57.000000000,9223372036854775808,2867199309159137213,B,2004,12
+    "###);
+}
+
+#[tokio::test]
+async fn test_day_of_month_invalid_time_zone() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ day: day_of_month(Times.time, \"America/New_Yrok\") }").run_to_csv(&timestamp_ns_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0008
+        message: Invalid arguments
+        formatted:
+          - "error[E0008]: Invalid arguments"
+          - "  --> Query:1:33"
+          - "  |"
+          - "1 | { day: day_of_month(Times.time, \"America/New_Yrok\") }"
+          - "  |                                 ^^^^^^^^^^^^^^^^^^ Invalid time zone: 'America/New_Yrok'"
+          - "  |"
+          - "  = Nearest matches: 'America/New_York', 'America/Dawson', 'America/Denver', 'America/Detroit', 'America/Nipigon'"
+          - ""
+          - ""
+    "###);
+}
+
 #[tokio::test]
 async fn test_add_time_duration_s() {
     insta::assert_snapshot!(QueryFixture::new("{ add_time: Times.time | add_time(seconds(Times.n)) }").run_to_csv(&timestamp_ns_data_fixture().await).await.unwrap(), @r###"
@@ -0,0 +1,94 @@
+//! e2e tests for functions defined within a query using `def`.
+
+use crate::fixtures::i64_data_fixture;
+use crate::QueryFixture;
+
+#[tokio::test]
+async fn test_user_function() {
+    insta::assert_snapshot!(QueryFixture::new("def ratio(a, b) = a / b in { ratio: ratio(Numbers.m, Numbers.n), half: ratio(Numbers.m, 2) }").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,ratio,half
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,0,2
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,8,12
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,2,8
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,,
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,,6
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,,
+    "###);
+}
+
+#[tokio::test]
+async fn test_user_function_aggregation() {
+    insta::assert_snapshot!(QueryFixture::new("def total(x) = sum(x) in { m: Numbers.m, total: total(Numbers.m) }").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,m,total
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,5,5
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,24,24
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,17,22
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,,22
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,12,34
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,,34
+    "###);
+}
+
+#[tokio::test]
+async fn test_user_function_captures_definition_scope() {
+    // The body refers to the `scale` in scope where the function was defined,
+    // not the one in scope where it is called.
+    insta::assert_snapshot!(QueryFixture::new("let scale = 2 in def scaled(x) = x * scale in let scale = 100 in { m: Numbers.m, scaled: scaled(Numbers.m), scale }").run_to_csv(&i64_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,m,scaled,scale
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,5,10,100
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,24,48,100
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,17,34,100
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,,,100
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,12,24,100
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,,,100
+    "###);
+}
+
+#[tokio::test]
+async fn test_user_function_too_many_arguments() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("def double(x) = x + x
+in { a: double(Numbers.m, Numbers.n) }").run_to_csv(&i64_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0008
+        message: Invalid arguments
+        formatted:
+          - "error[E0008]: Invalid arguments"
+          - "  --> Query:2:27"
+          - "  |"
+          - 1 | def double(x) = x + x
+          - "  |     ------ 'double' defined here"
+          - "2 | in { a: double(Numbers.m, Numbers.n) }"
+          - "  |                           ^^^^^^^^^ First unexpected argument"
+          - "  |"
+          - "  = Expected 1 but got 2"
+          - ""
+          - ""
+    "###);
+}
+
+#[tokio::test]
+async fn test_user_function_redefines_builtin() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("def sum(x) = x in { a: sum(Numbers.m) }").run_to_csv(&i64_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0016
+        message: Function already defined
+        formatted:
+          - "error[E0016]: Function already defined"
+          - "  --> Query:1:5"
+          - "  |"
+          - "1 | def sum(x) = x in { a: sum(Numbers.m) }"
+          - "  |     ^^^ Function 'sum' is already defined"
+          - "  |"
+          - "  = 'sum' is a built-in function"
+          - ""
+          - ""
+    "###);
+}
@@ -3,6 +3,7 @@
 // ordered: i64, f64, timestamp
 // number: i64, f64
 
+use chrono::NaiveDate;
 use indoc::indoc;
 use sparrow_api::kaskada::v1alpha::TableConfig;
 use uuid::Uuid;
@@ -609,3 +610,170 @@ async fn test_final_sliding_window_constant() {
     1996-12-20T00:40:04.000000001,18446744073709551615,12960666915911099378,A,30.25
     "###);
 }
+
+#[tokio::test]
+async fn test_sum_trailing_window() {
+    insta::assert_snapshot!(QueryFixture::new("{ trailing_sum: sum(Foo.n, window=trailing(seconds(3))) }").run_to_csv(&window_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,trailing_sum
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,10.0
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,3.9
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,16.2
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,15.45
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,18.45
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,20.25
+    1996-12-20T00:40:03.000000000,9223372036854775808,12960666915911099378,A,11.0
+    1996-12-20T00:40:04.000000000,9223372036854775808,12960666915911099378,A,18.0
+    "###);
+}
+
+#[tokio::test]
+async fn test_count_trailing_window() {
+    insta::assert_snapshot!(QueryFixture::new("{ trailing_count: count(Foo.n, window=trailing(seconds(2))) }").run_to_csv(&window_data_fixture_with_nulls().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,trailing_count
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,1
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,1
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,0
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,1
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,1
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,0
+    1996-12-20T00:40:03.000000000,9223372036854775808,12960666915911099378,A,1
+    1996-12-20T00:40:04.000000000,9223372036854775808,12960666915911099378,A,2
+    "###);
+}
+
+#[tokio::test]
+async fn test_first_string_trailing_window() {
+    insta::assert_snapshot!(QueryFixture::new("{ trailing_first: first(Foo.vegetable, window=trailing(seconds(2))) }").run_to_csv(&window_data_fixture().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,trailing_first
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,arugula
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,beet
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,carrot
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,carrot
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,dill
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,edamame
+    1996-12-20T00:40:03.000000000,9223372036854775808,12960666915911099378,A,fennel
+    1996-12-20T00:40:04.000000000,9223372036854775808,12960666915911099378,A,green beans
+    "###);
+}
+
+#[tokio::test]
+async fn test_trailing_window_months_produces_diagnostic() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ trailing_sum: sum(Foo.n, window=trailing(months(1))) }").run_to_csv(&window_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0010
+        message: Invalid argument type(s)
+        formatted:
+          - "error[E0010]: Invalid argument type(s)"
+          - "  --> Query:1:44"
+          - "  |"
+          - "1 | { trailing_sum: sum(Foo.n, window=trailing(months(1))) }"
+          - "  |                                            ^^^^^^^^^ Invalid duration for trailing window: 'interval_months:1'"
+          - "  |"
+          - "  = Trailing windows require a positive fixed duration, such as 'days(7)' or 'seconds(30)'"
+          - ""
+          - ""
+    "###);
+}
+
+#[tokio::test]
+async fn test_sliding_window_non_positive_produces_diagnostic() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ slide: count(Foo.n, window=sliding(0, daily())) }").run_to_csv(&window_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0010
+        message: Invalid argument type(s)
+        formatted:
+          - "error[E0010]: Invalid argument type(s)"
+          - "  --> Query:1:38"
+          - "  |"
+          - "1 | { slide: count(Foo.n, window=sliding(0, daily())) }"
+          - "  |                                      ^ Invalid duration for sliding window: '0'"
+          - "  |"
+          - "  = Sliding windows require a positive number of intervals"
+          - ""
+          - ""
+    "###);
+}
+
+#[tokio::test]
+async fn test_sum_session_window() {
+    insta::assert_snapshot!(QueryFixture::new("{ session_sum: sum(Foo.n, window=session(seconds(1))) }").run_to_csv(&window_data_fixture_with_nulls().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,session_sum
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,10.0
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,3.9
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,9.25
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,9.25
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,9.25
+    1996-12-20T00:40:03.000000000,9223372036854775808,12960666915911099378,A,10.25
+    1996-12-20T00:40:04.000000000,9223372036854775808,12960666915911099378,A,20.25
+    "###);
+}
+
+#[tokio::test]
+async fn test_count_session_window_final() {
+    // The session for `B` is closed, since more than the gap passed between
+    // the last input for `B` and the final tick.
+    insta::assert_snapshot!(QueryFixture::new("{ session_count: count(Foo.n, window=session(seconds(1))) }").with_final_results().run_to_csv(&window_data_fixture_with_nulls().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,session_count
+    1996-12-20T00:40:04.000000001,18446744073709551615,2867199309159137213,B,0
+    1996-12-20T00:40:04.000000001,18446744073709551615,12960666915911099378,A,3
+    "###);
+}
+
+#[tokio::test]
+async fn test_count_session_window_final_at_time() {
+    // Only inputs up to the final time are included, so the session for `A`
+    // contains the inputs at 00:39:59, 00:40:00 and 00:40:01.
+    let datetime = NaiveDate::from_ymd_opt(1996, 12, 20)
+        .and_then(|date| date.and_hms_opt(0, 40, 1))
+        .expect("valid date");
+    insta::assert_snapshot!(QueryFixture::new("{ session_count: count(Foo.n, window=session(seconds(1))) }").with_final_results_at_time(datetime).run_to_csv(&window_data_fixture_with_nulls().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,session_count
+    1996-12-20T00:40:01.000000001,18446744073709551615,2867199309159137213,B,0
+    1996-12-20T00:40:01.000000001,18446744073709551615,12960666915911099378,A,1
+    "###);
+}
+
+#[tokio::test]
+async fn test_first_last_session_window() {
+    insta::assert_snapshot!(QueryFixture::new("{ session_first: first(Foo.vegetable, window=session(seconds(1))), session_last: last(Foo.bool, window=session(seconds(1))) }").run_to_csv(&window_data_fixture_with_nulls().await).await.unwrap(), @r###"
+    _time,_subsort,_key_hash,_key,session_first,session_last
+    1996-12-20T00:39:57.000000000,9223372036854775808,12960666915911099378,A,arugula,true
+    1996-12-20T00:39:58.000000000,9223372036854775808,2867199309159137213,B,beet,true
+    1996-12-20T00:39:59.000000000,9223372036854775808,12960666915911099378,A,carrot,false
+    1996-12-20T00:40:00.000000000,9223372036854775808,12960666915911099378,A,carrot,false
+    1996-12-20T00:40:01.000000000,9223372036854775808,12960666915911099378,A,carrot,false
+    1996-12-20T00:40:02.000000000,9223372036854775808,12960666915911099378,A,carrot,false
+    1996-12-20T00:40:03.000000000,9223372036854775808,12960666915911099378,A,carrot,true
+    1996-12-20T00:40:04.000000000,9223372036854775808,12960666915911099378,A,carrot,true
+    "###);
+}
+
+#[tokio::test]
+async fn test_collect_session_window_produces_diagnostic() {
+    insta::assert_yaml_snapshot!(QueryFixture::new("{ session_collect: Foo.n | collect(max=10, window=session(seconds(1))) }").run_to_csv(&window_data_fixture().await).await.unwrap_err(), @r###"
+    ---
+    code: Client specified an invalid argument
+    message: 1 errors in Fenl statements; see diagnostics
+    fenl_diagnostics:
+      - severity: error
+        code: E0010
+        message: Invalid argument type(s)
+        formatted:
+          - "error[E0010]: Invalid argument type(s)"
+          - "  --> Query:1:51"
+          - "  |"
+          - "1 | { session_collect: Foo.n | collect(max=10, window=session(seconds(1))) }"
+          - "  |                                                   ^^^^^^^^^^^^^^^^^^^ Session windows are not supported for 'collect'"
+          - ""
+          - ""
+    "###);
+}
@@ -48,12 +48,40 @@ pub enum Mode {
 )]
 #[display(style = "snake_case")]
 pub enum InstOp {
+    #[strum(props(signature = "abs<N: number>(n: N) -> N"))]
+    Abs,
+    #[strum(props(signature = "acos(n: f64) -> f64"))]
+    Acos,
     #[strum(props(signature = "add<N: number>(a: N, b: N) -> N"))]
     Add,
     #[strum(props(
         signature = "add_time<D: timedelta>(delta: D, time: timestamp_ns) -> timestamp_ns"
     ))]
     AddTime,
+    #[strum(props(
+        signature = "approx_count_distinct<K: key>(input: K, ticks: bool = null, slide_duration: \
+                     i64 = null, window_kind: string = null) -> u32"
+    ))]
+    ApproxCountDistinct,
+    #[strum(props(
+        signature = "approx_percentile<N: number>(input: N, const p: f64, ticks: bool = null, \
+                     slide_duration: i64 = null, window_kind: string = null) -> f64"
+    ))]
+    ApproxPercentile,
+    #[strum(props(signature = "asin(n: f64) -> f64"))]
+    Asin,
+    #[strum(props(signature = "atan(n: f64) -> f64"))]
+    Atan,
+    #[strum(props(signature = "atan2(y: f64, x: f64) -> f64"))]
+    Atan2,
+    #[strum(props(signature = "bitwise_and<I: int>(a: I, b: I) -> I"))]
+    BitwiseAnd,
+    #[strum(props(signature = "bitwise_not<I: int>(n: I) -> I"))]
+    BitwiseNot,
+    #[strum(props(signature = "bitwise_or<I: int>(a: I, b: I) -> I"))]
+    BitwiseOr,
+    #[strum(props(signature = "bitwise_xor<I: int>(a: I, b: I) -> I"))]
+    BitwiseXor,
     #[strum(props(signature = "ceil<N: number>(n: N) -> N"))]
     Ceil,
     #[strum(props(signature = "clamp<N: number>(value: N, min: N = null, max: N = null) -> N"))]
@@ -61,21 +89,51 @@ pub enum InstOp {
     #[strum(props(signature = "coalesce<T: any>(values+: T) -> T"))]
     Coalesce,
     #[strum(props(
-        signature = "collect<T: any>(input: T, const max: i64, const min: i64 = 0, ticks: bool = null, slide_duration: i64 = null) -> list<T>"
+        signature = "collect<T: any>(input: T, const max: i64, const min: i64 = 0, ticks: bool = null, slide_duration: i64 = null, window_kind: string = null) -> list<T>"
     ))]
     Collect,
     #[strum(props(
-        signature = "count_if<T: any>(input: T, ticks: bool = null, slide_duration: i64 = null) -> \
-                          u32"
+        signature = "collect_distinct<T: key>(input: T, const max: i64, ticks: bool = null, slide_duration: i64 = null, window_kind: string = null) -> list<T>"
+    ))]
+    CollectDistinct,
+    #[strum(props(
+        signature = "collect_map<K: key, V: any>(key: K, value: V, ticks: bool = null, slide_duration: i64 = null, window_kind: string = null) -> map<K, V>"
+    ))]
+    CollectMap,
+    #[strum(props(signature = "concat(strings+: string) -> string"))]
+    Concat,
+    #[strum(props(signature = "contains(s: string, substring: string) -> bool"))]
+    Contains,
+    #[strum(props(signature = "cos(n: f64) -> f64"))]
+    Cos,
+    #[strum(props(
+        signature = "count_if<T: any>(input: T, ticks: bool = null, slide_duration: i64 = null, \
+                          window_kind: string = null) -> u32"
     ))]
     CountIf,
-    #[strum(props(signature = "day_of_month(time: timestamp_ns) -> u32"))]
+    #[strum(props(
+        signature = "day_of_month(time: timestamp_ns, const tz: string = \"UTC\") -> u32"
+    ))]
     DayOfMonth,
-    #[strum(props(signature = "day_of_month0(time: timestamp_ns) -> u32"))]
+    #[strum(props(
+        signature = "day_of_month0(time: timestamp_ns, const tz: string = \"UTC\") -> u32"
+    ))]
     DayOfMonth0,
-    #[strum(props(signature = "day_of_year(time: timestamp_ns) -> u32"))]
+    #[strum(props(
+        signature = "day_of_week(time: timestamp_ns, const tz: string = \"UTC\") -> u32"
+    ))]
+    DayOfWeek,
+    #[strum(props(
+        signature = "day_of_week0(time: timestamp_ns, const tz: string = \"UTC\") -> u32"
+    ))]
+    DayOfWeek0,
+    #[strum(props(
+        signature = "day_of_year(time: timestamp_ns, const tz: string = \"UTC\") -> u32"
+    ))]
     DayOfYear,
-    #[strum(props(signature = "day_of_year0(time: timestamp_ns) -> u32"))]
+    #[strum(props(
+        signature = "day_of_year0(time: timestamp_ns, const tz: string = \"UTC\") -> u32"
+    ))]
     DayOfYear0,
     #[strum(props(signature = "days(days: i64) -> interval_days"))]
     Days,
@@ -83,20 +141,41 @@ pub enum InstOp {
         signature = "days_between(t1: timestamp_ns, t2: timestamp_ns) -> interval_days"
     ))]
     DaysBetween,
+    #[strum(props(
+        signature = "decayed_count<T: any>(input: T, const half_life: i64, ticks: bool = null, \
+                     slide_duration: i64 = null, window_kind: string = null) -> f64"
+    ))]
+    DecayedCount,
+    #[strum(props(
+        signature = "decayed_sum<N: number>(input: N, const half_life: i64, ticks: bool = null, \
+                     slide_duration: i64 = null, window_kind: string = null) -> f64"
+    ))]
+    DecayedSum,
     #[strum(props(signature = "div<N: number>(a: N, b: N) -> N"))]
     Div,
+    #[strum(props(signature = "ends_with(s: string, suffix: string) -> bool"))]
+    EndsWith,
     #[strum(props(signature = "eq<T: any>(a: T, b: T) -> bool"))]
     Eq,
+    #[strum(props(
+        signature = "ewma<N: number>(input: N, const half_life: i64, ticks: bool = null, \
+                     slide_duration: i64 = null, window_kind: string = null) -> f64"
+    ))]
+    Ewma,
     #[strum(props(signature = "exp(power: f64) -> f64"))]
     Exp,
     #[strum(props(
-        signature = "first<T: any>(input: T, ticks: bool = null, slide_duration: i64 = null) -> T"
+        signature = "first<T: any>(input: T, ticks: bool = null, slide_duration: i64 = null, window_kind: string = null) -> T"
     ))]
     First,
     #[strum(props(signature = "flatten<T: any>(input: list<list<T>>) -> list<T>"))]
     Flatten,
     #[strum(props(signature = "floor<N: number>(n: N) -> N"))]
     Floor,
+    #[strum(props(signature = "floor_div<I: int>(a: I, b: I) -> I"))]
+    FloorDiv,
+    #[strum(props(signature = "format(const template: string, values+: string) -> string"))]
+    Format,
     #[strum(props(signature = "get<K: key, V: any>(key: K, map: map<K, V>) -> V"))]
     Get,
     #[strum(props(signature = "gt<O: ordered>(a: O, b: O) -> bool"))]
@@ -118,39 +197,93 @@ pub enum InstOp {
     #[strum(props(signature = "json_field(s: string, field: string) -> string"))]
     JsonField,
     #[strum(props(
-        signature = "last<T: any>(input: T, ticks: bool = null, slide_duration: i64 = null) -> T"
+        signature = "last<T: any>(input: T, ticks: bool = null, slide_duration: i64 = null, window_kind: string = null) -> T"
     ))]
     Last,
     #[strum(props(signature = "len(s: string) -> i32"))]
     Len,
+    #[strum(props(signature = "list_broadcast<T: any, U: any>(list: list<T>, value: U) -> U"))]
+    ListBroadcast,
+    #[strum(props(signature = "list_contains<T: key>(list: list<T>, value: T) -> bool"))]
+    ListContains,
+    #[strum(props(signature = "list_distinct<T: key>(list: list<T>) -> list<T>"))]
+    ListDistinct,
+    #[strum(props(signature = "list_elements<T: any>(list: list<T>) -> T"))]
+    ListElements,
+    #[strum(props(signature = "list_filter<T: any>(list: list<T>, predicate: bool) -> list<T>"))]
+    ListFilter,
     #[strum(props(signature = "list_len<T: any>(input: list<T>) -> i32"))]
     ListLen,
+    #[strum(props(signature = "list_map<T: any, U: any>(list: list<T>, f: U) -> list<U>"))]
+    ListMap,
+    #[strum(props(signature = "list_mean<N: number>(list: list<N>) -> f64"))]
+    ListMean,
+    #[strum(props(
+        signature = "list_slice<T: any>(list: list<T>, start: i64, end: i64) -> list<T>"
+    ))]
+    ListSlice,
+    #[strum(props(signature = "list_sort<T: ordered>(list: list<T>) -> list<T>"))]
+    ListSort,
+    #[strum(props(signature = "list_sum<N: number>(list: list<N>) -> N"))]
+    ListSum,
+    #[strum(props(signature = "ln(n: f64) -> f64"))]
+    Ln,
+    #[strum(props(signature = "log10(n: f64) -> f64"))]
+    Log10,
     #[strum(props(signature = "logical_and(a: bool, b: bool) -> bool"))]
     LogicalAnd,
     #[strum(props(signature = "logical_or(a: bool, b: bool) -> bool"))]
     LogicalOr,
     #[strum(props(signature = "lower(s: string) -> string"))]
     Lower,
+    #[strum(props(signature = "lpad(s: string, length: i64, pad: string = \" \") -> string"))]
+    Lpad,
     #[strum(props(signature = "lt<O: ordered>(a: O, b: O) -> bool"))]
     Lt,
     #[strum(props(signature = "lte<O: ordered>(a: O, b: O) -> bool"))]
     Lte,
+    #[strum(props(signature = "map_contains<K: key, V: any>(key: K, map: map<K, V>) -> bool"))]
+    MapContains,
     #[strum(props(
-        signature = "max<O: ordered>(input: O, ticks: bool = null, slide_duration: i64 = null) -> O"
+        signature = "map_from_lists<K: key, V: any>(keys: list<K>, values: list<V>) -> map<K, V>"
+    ))]
+    MapFromLists,
+    #[strum(props(signature = "map_keys<K: key, V: any>(map: map<K, V>) -> list<K>"))]
+    MapKeys,
+    #[strum(props(signature = "map_len<K: key, V: any>(map: map<K, V>) -> i32"))]
+    MapLen,
+    #[strum(props(signature = "map_values<K: key, V: any>(map: map<K, V>) -> list<V>"))]
+    MapValues,
+    #[strum(props(
+        signature = "max<O: ordered>(input: O, ticks: bool = null, slide_duration: i64 = null, window_kind: string = null) -> O"
     ))]
     Max,
     #[strum(props(
-        signature = "mean<N: number>(input: N, ticks: bool = null, slide_duration: i64 = null) -> \
-                          f64"
+        signature = "max_by<T: any, O: ordered>(value: T, ordering: O, ticks: bool = null, slide_duration: i64 = null, window_kind: string = null) -> T"
+    ))]
+    MaxBy,
+    #[strum(props(
+        signature = "mean<N: number>(input: N, ticks: bool = null, slide_duration: i64 = null, \
+                          window_kind: string = null) -> f64"
     ))]
     Mean,
     #[strum(props(
-        signature = "min<O: ordered>(input: O, ticks: bool = null, slide_duration: i64 = null) -> O"
+        signature = "min<O: ordered>(input: O, ticks: bool = null, slide_duration: i64 = null, window_kind: string = null) -> O"
     ))]
     Min,
-    #[strum(props(signature = "month_of_year(time: timestamp_ns) -> u32"))]
+    #[strum(props(
+        signature = "min_by<T: any, O: ordered>(value: T, ordering: O, ticks: bool = null, slide_duration: i64 = null, window_kind: string = null) -> T"
+    ))]
+    MinBy,
+    #[strum(props(signature = "mod<I: int>(a: I, b: I) -> I"))]
+    Mod,
+    #[strum(props(
+        signature = "month_of_year(time: timestamp_ns, const tz: string = \"UTC\") -> u32"
+    ))]
     MonthOfYear,
-    #[strum(props(signature = "month_of_year0(time: timestamp_ns) -> u32"))]
+    #[strum(props(
+        signature = "month_of_year0(time: timestamp_ns, const tz: string = \"UTC\") -> u32"
+    ))]
     MonthOfYear0,
     #[strum(props(signature = "months(months: i64) -> interval_months"))]
     Months,
@@ -158,6 +291,10 @@ pub enum InstOp {
         signature = "months_between(t1: timestamp_ns, t2: timestamp_ns) -> interval_months"
     ))]
     MonthsBetween,
+    #[strum(props(
+        signature = "most_frequent<T: key>(input: T, const k: i64, ticks: bool = null, slide_duration: i64 = null, window_kind: string = null) -> list<T>"
+    ))]
+    MostFrequent,
     #[strum(props(signature = "mul<N: number>(a: N, b: N) -> N"))]
     Mul,
     #[strum(props(signature = "neg<S: signed>(n: S) -> S"))]
@@ -170,14 +307,45 @@ pub enum InstOp {
     NullIf,
     #[strum(props(signature = "powf(base: f64, power: f64) -> f64"))]
     Powf,
+    #[strum(props(
+        signature = "regex_extract(s: string, const pattern: string, const group: i64) -> string"
+    ))]
+    RegexExtract,
+    #[strum(props(signature = "regex_match(s: string, const pattern: string) -> bool"))]
+    RegexMatch,
+    #[strum(props(
+        signature = "regex_replace(s: string, const pattern: string, replacement: string) -> \
+                     string"
+    ))]
+    RegexReplace,
+    #[strum(props(signature = "rem<N: number>(a: N, b: N) -> N"))]
+    Rem,
+    #[strum(props(
+        signature = "replace(s: string, pattern: string, replacement: string) -> string"
+    ))]
+    Replace,
     #[strum(props(signature = "round<N: number>(n: N) -> N"))]
     Round,
+    #[strum(props(signature = "rpad(s: string, length: i64, pad: string = \" \") -> string"))]
+    Rpad,
     #[strum(props(signature = "seconds(seconds: i64) -> duration_s"))]
     Seconds,
     #[strum(props(
         signature = "seconds_between(t1: timestamp_ns, t2: timestamp_ns) -> duration_s"
     ))]
     SecondsBetween,
+    #[strum(props(signature = "shift_left<I: int>(n: I, bits: I) -> I"))]
+    ShiftLeft,
+    #[strum(props(signature = "shift_right<I: int>(n: I, bits: I) -> I"))]
+    ShiftRight,
+    #[strum(props(signature = "sign<N: number>(n: N) -> N"))]
+    Sign,
+    #[strum(props(signature = "sin(n: f64) -> f64"))]
+    Sin,
+    #[strum(props(signature = "split(s: string, separator: string) -> list<string>"))]
+    Split,
+    #[strum(props(signature = "starts_with(s: string, prefix: string) -> bool"))]
+    StartsWith,
     #[strum(props(signature = "sub<N: number>(a: N, b: N) -> N"))]
     Sub,
     #[strum(props(
@@ -185,22 +353,30 @@ pub enum InstOp {
     ))]
     Substring,
     #[strum(props(
-        signature = "sum<N: number>(input: N, ticks: bool = null, slide_duration: i64 = null) -> \
-                          N"
+        signature = "sum<N: number>(input: N, ticks: bool = null, slide_duration: i64 = null, \
+                          window_kind: string = null) -> N"
     ))]
     Sum,
+    #[strum(props(signature = "tan(n: f64) -> f64"))]
+    Tan,
     #[strum(props(signature = "time_of<T: any>(input: T) -> timestamp_ns"))]
     TimeOf,
+    #[strum(props(
+        signature = "top_k<O: ordered>(input: O, const k: i64, ticks: bool = null, slide_duration: i64 = null, window_kind: string = null) -> list<O>"
+    ))]
+    TopK,
+    #[strum(props(signature = "trim(s: string) -> string"))]
+    Trim,
     #[strum(props(signature = "upper(s: string) -> string"))]
     Upper,
     #[strum(props(signature = "union<T: any>(a: list<T>, b: list<T>) -> list<T>"))]
     Union,
     #[strum(props(
-        signature = "variance<N: number>(input: N, ticks: bool = null, slide_duration: i64 = null) \
-                          -> f64"
+        signature = "variance<N: number>(input: N, ticks: bool = null, slide_duration: i64 = null, \
+                          window_kind: string = null) -> f64"
     ))]
     Variance,
-    #[strum(props(signature = "year(time: timestamp_ns) -> i32"))]
+    #[strum(props(signature = "year(time: timestamp_ns, const tz: string = \"UTC\") -> i32"))]
     Year,
     #[strum(props(signature = "zip_max<O: ordered>(a: O, b: O) -> O"))]
     ZipMax,
@@ -213,7 +389,24 @@ impl InstOp {
         use InstOp::*;
         matches!(
             self,
-            Sum | Last | First | CountIf | Min | Max | Mean | Variance
+            Sum | Last
+                | First
+                | CollectDistinct
+                | CollectMap
+                | CountIf
+                | Min
+                | MinBy
+                | Max
+                | MaxBy
+                | Mean
+                | Variance
+                | ApproxCountDistinct
+                | ApproxPercentile
+                | DecayedCount
+                | DecayedSum
+                | Ewma
+                | MostFrequent
+                | TopK
         )
     }
 
@@ -241,6 +434,11 @@ pub enum InstKind {
     ///
     /// The number of arguments should match the number of fields.
     Record,
+    /// Select the value of the first arm whose condition is true.
+    ///
+    /// The arguments are pairs of conditions and values, followed by the
+    /// default value.
+    Case,
 }
 
 impl fmt::Display for InstKind {
@@ -250,6 +448,7 @@ impl fmt::Display for InstKind {
             InstKind::FieldRef => write!(f, "field"),
             InstKind::Cast(data_type) => write!(f, "cast:{data_type}"),
             InstKind::Record => write!(f, "record"),
+            InstKind::Case => write!(f, "case"),
         }
     }
 }
@@ -320,6 +519,7 @@ static INST_OP_SIGNATURES: EnumMap<InstOp, OpSignatures> = {
             // planning / execution).
             assert_ne!(signatures.dfg().name(), "record", "'record' is a reserved instruction name");
             assert_ne!(signatures.dfg().name(), "field_ref", "'field_ref' is a reserved instruction name");
+            assert_ne!(signatures.dfg().name(), "case", "'case' is a reserved instruction name");
 
             signatures
         }
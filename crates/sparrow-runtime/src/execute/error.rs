@@ -18,6 +18,9 @@ pub enum Error {
     PreprocessNextInput,
     #[display(fmt = "output '{output}' is not supported")]
     UnsupportedOutput { output: &'static str },
+    #[allow(dead_code)]
+    #[display(fmt = "feature '{feature}' not enabled")]
+    FeatureNotEnabled { feature: &'static str },
 }
 
 macro_rules! invalid_operation {
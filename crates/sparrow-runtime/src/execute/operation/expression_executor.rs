@@ -28,6 +28,12 @@ pub(super) struct ExpressionExecutor {
     operation_label: &'static str,
     input_columns: Vec<InputColumn>,
     expression_evaluators: Vec<Box<dyn Evaluator>>,
+    /// The first non-literal argument of each evaluator, if any.
+    ///
+    /// Instructions within the body of a lambda are applied to the elements
+    /// of a list rather than the rows of the batch. The length of this
+    /// argument determines how many rows literal arguments should have.
+    length_arguments: Vec<Option<ValueRef>>,
     /// Expressions which are part of the output schema.
     output_columns: Vec<ValueRef>,
     schema: SchemaRef,
@@ -62,6 +68,7 @@ impl ExpressionExecutor {
         // Note that each expression will produce AT MOST one entry.
         // Literals and late bound values *do not* add to expression impls.
         let mut expression_evaluators = Vec::with_capacity(expressions.len());
+        let mut length_arguments = Vec::with_capacity(expressions.len());
         let mut output_columns = Vec::new();
 
         // Static information about how to reference the value of this expression.
@@ -99,6 +106,8 @@ impl ExpressionExecutor {
                         sparrow_plan::InstKind::FieldRef
                     } else if inst == "record" {
                         sparrow_plan::InstKind::Record
+                    } else if inst == "case" {
+                        sparrow_plan::InstKind::Case
                     } else if inst == "cast" {
                         sparrow_plan::InstKind::Cast(data_type.clone())
                     } else {
@@ -106,11 +115,17 @@ impl ExpressionExecutor {
                         sparrow_plan::InstKind::Simple(inst_op)
                     };
 
+                    let length_argument = args
+                        .iter()
+                        .find(|arg| arg.value_ref.literal_value().is_none())
+                        .map(|arg| arg.value_ref.clone());
+
                     let static_info = StaticInfo::new(&inst_kind, args, &data_type);
                     let evaluator = create_evaluator(static_info)?;
 
                     let index = expression_evaluators.len();
                     expression_evaluators.push(evaluator);
+                    length_arguments.push(length_argument);
                     ValueRef::Inst(index as u32)
                 }
 
@@ -190,6 +205,7 @@ impl ExpressionExecutor {
             operation_label,
             input_columns,
             expression_evaluators,
+            length_arguments,
             output_columns,
             schema,
         })
@@ -237,8 +253,23 @@ impl ExpressionExecutor {
         );
 
         // Run the expression to compute additional columns.
-        for evaluator in self.expression_evaluators.iter_mut() {
-            let column = evaluator.evaluate(&work_area)?;
+        for (evaluator, length_argument) in self
+            .expression_evaluators
+            .iter_mut()
+            .zip(self.length_arguments.iter())
+        {
+            let num_rows = match length_argument {
+                Some(argument) => work_area.value(argument)?.array_ref()?.len(),
+                None => work_area.num_rows(),
+            };
+            let column = if num_rows == work_area.num_rows() {
+                evaluator.evaluate(&work_area)?
+            } else {
+                evaluator.evaluate(&ElementInfo {
+                    work_area: &work_area,
+                    num_rows,
+                })?
+            };
             work_area.push_computed_column(column);
         }
 
@@ -345,6 +376,46 @@ impl RuntimeInfo for WorkArea {
     }
 }
 
+/// Runtime info for instructions applied to the elements of a list.
+///
+/// Literals are produced with one row per element. Everything else is
+/// delegated to the work area.
+struct ElementInfo<'a> {
+    work_area: &'a WorkArea,
+    num_rows: usize,
+}
+
+impl<'a> RuntimeInfo for ElementInfo<'a> {
+    fn value(
+        &self,
+        arg: &sparrow_plan::ValueRef,
+    ) -> anyhow::Result<sparrow_instructions::ColumnarValue> {
+        match arg {
+            ValueRef::Literal(literal) => Ok(ColumnarValue::Literal {
+                rows: self.num_rows,
+                literal: literal.clone(),
+            }),
+            non_literal => self.work_area.value(non_literal),
+        }
+    }
+
+    fn grouping(&self) -> &GroupingIndices {
+        self.work_area.grouping()
+    }
+
+    fn time_column(&self) -> sparrow_instructions::ColumnarValue {
+        self.work_area.time_column()
+    }
+
+    fn storage(&self) -> Option<&sparrow_instructions::ComputeStore> {
+        self.work_area.storage()
+    }
+
+    fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+}
+
 #[static_init::dynamic]
 static KEY_FIELDS: [Field; 3] = [
     Field::new(
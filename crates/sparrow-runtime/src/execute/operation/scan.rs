@@ -262,7 +262,7 @@ impl ScanOperation {
                 .boxed();
                 input_stream
             }
-            v1alpha::source::Source::Pulsar(p) => {
+            v1alpha::source::Source::Pulsar(_) | v1alpha::source::Source::Kafka(_) => {
                 let input_stream = stream_reader(
                     context,
                     table_info,
@@ -270,7 +270,7 @@ impl ScanOperation {
                     projected_columns,
                     // TODO: Fix flight recorder
                     FlightRecorder::disabled(),
-                    p,
+                    backing_source,
                 )
                 .await
                 .change_context(Error::internal_msg("failed to create stream reader"))?
@@ -279,7 +279,6 @@ impl ScanOperation {
 
                 input_stream
             }
-            v1alpha::source::Source::Kafka(_) => todo!(),
         };
 
         // Currently configures the stream for the following cases:
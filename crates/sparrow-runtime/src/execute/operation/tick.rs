@@ -17,7 +17,6 @@ use error_stack::{IntoReport, IntoReportCompat, ResultExt};
 use itertools::{izip, Itertools};
 use serde::{Deserialize, Serialize};
 use sparrow_api::kaskada::v1alpha::operation_plan;
-use sparrow_api::kaskada::v1alpha::operation_plan::tick_operation::TickBehavior;
 use sparrow_arrow::downcast::downcast_primitive_array;
 use sparrow_core::KeyTriple;
 use sparrow_instructions::{ComputeStore, GroupingIndices, StoreKey};
@@ -45,7 +44,7 @@ const MAX_TICK_ROWS: usize = 100_000;
 /// Holds state necessary to produce batches with ticks.
 ///
 /// This operation is responsible for producing ticks at certain
-/// times, configured by the `behavior`. It reads input batches from
+/// times, configured by the `schedule`. It reads input batches from
 /// the `input_stream`, then decides whether to produce a data batch or
 /// a tick batch depending on when the next tick is relative to the input
 /// batch's times.
@@ -64,7 +63,7 @@ pub(super) struct TickOperation {
     /// The sorted key hashes seen by this operation up to a point in time.
     key_hashes: SortedKeyHashMap,
     /// Configures when to tick at.
-    behavior: TickBehavior,
+    schedule: TickSchedule,
 }
 
 impl std::fmt::Debug for TickOperation {
@@ -73,7 +72,7 @@ impl std::fmt::Debug for TickOperation {
             .field("next_tick", &self.next_tick)
             .field("current_time", &self.current_time)
             .field("key_hashes", &format!("{} entries", self.key_hashes.len()))
-            .field("behavior", &self.behavior)
+            .field("schedule", &self.schedule)
             .finish_non_exhaustive()
     }
 }
@@ -106,15 +105,18 @@ impl Operation for TickOperation {
         self.current_time = state.current_time;
         self.next_tick = state.next_tick;
 
-        let producer: Box<dyn TickProducer> = match self.behavior {
-            TickBehavior::Minutely => Box::new(MinutelyTickProducer),
-            TickBehavior::Hourly => Box::new(HourlyTickProducer),
-            TickBehavior::Daily => Box::new(DailyTickProducer),
-            TickBehavior::Monthly => Box::new(MonthlyTickProducer),
-            TickBehavior::Yearly => Box::new(YearlyTickProducer),
-            TickBehavior::Finished => anyhow::bail!("Final ticks should use separate operation"),
-            unknown => anyhow::bail!("Unknown tick behavior {:?}", unknown),
-        };
+        // Ticks from the stored state are only valid for the same schedule.
+        let stored: Option<TickSchedule> =
+            compute_store.get(&StoreKey::new_tick_schedule(operation_index))?;
+        if let Some(stored) = stored {
+            anyhow::ensure!(
+                stored == self.schedule,
+                "Stored tick schedule {stored:?} does not match {:?}",
+                self.schedule
+            );
+        }
+
+        let producer = self.schedule.producer()?;
 
         // If `next_tick` is 0, we can assume that it has not been initialized yet.
         if self.next_tick.timestamp_nanos() == 0 {
@@ -141,6 +143,10 @@ impl Operation for TickOperation {
             current_time: self.current_time,
         };
         compute_store.put(&StoreKey::new_tick_state(operation_index), &state)?;
+        compute_store.put(
+            &StoreKey::new_tick_schedule(operation_index),
+            &self.schedule,
+        )?;
 
         Ok(())
     }
@@ -156,7 +162,7 @@ impl Operation for TickOperation {
         // tick iter with incorrect bounds.
         if self.tick_iter.is_none() {
             if let Some(incoming) = self.input_stream.next().await {
-                let mut tick_iter = initialize_tick_iter(&incoming, self.behavior)
+                let mut tick_iter = initialize_tick_iter(&incoming, &self.schedule)
                     .into_report()
                     .change_context(Error::internal())?;
                 if let Some(next_tick) = tick_iter.next() {
@@ -216,13 +222,17 @@ impl TickOperation {
             "Tick column should have 0th input index"
         );
 
+        let schedule = TickSchedule::try_new(&operation)
+            .into_report()
+            .change_context(Error::internal_msg("invalid tick schedule"))?;
+
         Ok(Box::new(Self {
             input_stream,
             tick_iter: None,
             next_tick: NaiveDateTime::from_timestamp_opt(0, 0).expect("zero time"),
             current_time: 0,
             key_hashes: SortedKeyHashMap::new(),
-            behavior: operation.behavior(),
+            schedule,
         }))
     }
 
@@ -399,16 +409,8 @@ impl TickOperation {
 }
 
 /// Initializes the tick iter using the bounds of the first incoming batch.
-fn initialize_tick_iter(batch: &Batch, tick_behavior: TickBehavior) -> anyhow::Result<TickIter> {
-    let producer: Box<dyn TickProducer> = match tick_behavior {
-        TickBehavior::Minutely => Box::new(MinutelyTickProducer),
-        TickBehavior::Hourly => Box::new(HourlyTickProducer),
-        TickBehavior::Daily => Box::new(DailyTickProducer),
-        TickBehavior::Monthly => Box::new(MonthlyTickProducer),
-        TickBehavior::Yearly => Box::new(YearlyTickProducer),
-        TickBehavior::Finished => anyhow::bail!("Final ticks should use separate operation"),
-        unknown => anyhow::bail!("Unknown tick behavior {:?}", unknown),
-    };
+fn initialize_tick_iter(batch: &Batch, schedule: &TickSchedule) -> anyhow::Result<TickIter> {
+    let producer = schedule.producer()?;
 
     // The tick iter is initialized from the first record batch's minimum time.
     // The tick iter will produces ticks from this time until the time of the last
@@ -549,6 +551,7 @@ mod tests {
                 operation_plan::TickOperation {
                     input: 0,
                     behavior: (TickBehavior::Hourly as i32),
+                    ..Default::default()
                 },
             )),
         };
@@ -594,6 +597,14 @@ mod tests {
         )
     }
 
+    fn schedule(behavior: TickBehavior) -> TickSchedule {
+        TickSchedule::try_new(&operation_plan::TickOperation {
+            behavior: behavior as i32,
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
     fn default_tick_operation(
         behavior: TickBehavior,
         input_stream: Pin<Box<dyn futures::Stream<Item = Batch> + Send>>,
@@ -604,7 +615,7 @@ mod tests {
             next_tick: NaiveDateTime::from_timestamp_opt(0, 0).expect("zero time"),
             current_time: 0,
             key_hashes: SortedKeyHashMap::new(),
-            behavior,
+            schedule: schedule(behavior),
         }
     }
 
@@ -992,7 +1003,7 @@ mod tests {
                 next_tick: tick1,
                 current_time: current1,
                 key_hashes: keys1.clone(),
-                behavior: TickBehavior::Hourly,
+                schedule: schedule(TickBehavior::Hourly),
             };
             original_operation.store_to(0, &store).unwrap();
 
@@ -1002,7 +1013,7 @@ mod tests {
                 next_tick: NaiveDateTime::from_timestamp_opt(0, 0).unwrap(),
                 current_time: 0,
                 key_hashes: SortedKeyHashMap::new(),
-                behavior: TickBehavior::Hourly,
+                schedule: schedule(TickBehavior::Hourly),
             };
             restored_operation.restore_from(0, &store).unwrap();
 
@@ -1022,5 +1033,55 @@ mod tests {
                 NaiveDateTime::from_timestamp_opt(7200, 0).unwrap()
             )
         }
+
+        #[test]
+        fn test_restore_interval_schedule() {
+            // Every 15 minutes, offset by 5 minutes.
+            let every = TickSchedule::try_new(&operation_plan::TickOperation {
+                behavior: TickBehavior::Every as i32,
+                interval: 900_000_000_000,
+                offset: 300_000_000_000,
+                ..Default::default()
+            })
+            .unwrap();
+
+            let store = compute_store();
+            let tick1 = NaiveDateTime::from_timestamp_opt(1200, 0).unwrap();
+            let original_operation = TickOperation {
+                input_stream: Box::pin(futures::stream::iter(vec![])),
+                tick_iter: None,
+                next_tick: tick1,
+                current_time: 1100,
+                key_hashes: SortedKeyHashMap::new(),
+                schedule: every.clone(),
+            };
+            original_operation.store_to(0, &store).unwrap();
+
+            let mut restored_operation = TickOperation {
+                input_stream: Box::pin(futures::stream::iter(vec![])),
+                tick_iter: None,
+                next_tick: NaiveDateTime::from_timestamp_opt(0, 0).unwrap(),
+                current_time: 0,
+                key_hashes: SortedKeyHashMap::new(),
+                schedule: every,
+            };
+            restored_operation.restore_from(0, &store).unwrap();
+            assert_eq!(restored_operation.next_tick, tick1);
+            assert_eq!(
+                restored_operation.tick_iter.unwrap().next().unwrap(),
+                NaiveDateTime::from_timestamp_opt(2100, 0).unwrap()
+            );
+
+            // Resuming with a different schedule is an error.
+            let mut restored_operation = TickOperation {
+                input_stream: Box::pin(futures::stream::iter(vec![])),
+                tick_iter: None,
+                next_tick: NaiveDateTime::from_timestamp_opt(0, 0).unwrap(),
+                current_time: 0,
+                key_hashes: SortedKeyHashMap::new(),
+                schedule: schedule(TickBehavior::Hourly),
+            };
+            assert!(restored_operation.restore_from(0, &store).is_err());
+        }
     }
 }
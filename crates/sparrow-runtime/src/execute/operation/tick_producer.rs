@@ -1,5 +1,9 @@
 use anyhow::Context;
-use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Weekday};
+use serde::{Deserialize, Serialize};
+use sparrow_api::kaskada::v1alpha::operation_plan;
+use sparrow_api::kaskada::v1alpha::operation_plan::tick_operation::TickBehavior;
+use sparrow_kernels::time::{from_local, parse_time_zone, to_local, Tz};
 
 use crate::min_heap::{HasPriority, MinHeap};
 
@@ -156,6 +160,56 @@ impl TickProducer for DailyTickProducer {
     }
 }
 
+#[derive(Debug, Clone)]
+pub(super) struct WeeklyTickProducer {
+    /// The day each week starts on.
+    start: Weekday,
+}
+
+impl WeeklyTickProducer {
+    pub(super) fn new(start: Weekday) -> Self {
+        Self { start }
+    }
+
+    /// The number of days since the start of the week containing `date`.
+    fn days_into_week(&self, date: NaiveDate) -> u32 {
+        (date.weekday().num_days_from_monday() + 7 - self.start.num_days_from_monday()) % 7
+    }
+}
+
+impl TickProducer for WeeklyTickProducer {
+    fn is_tick(&self, time: NaiveDateTime) -> bool {
+        time.weekday() == self.start
+            && time.hour() == 0
+            && time.minute() == 0
+            && time.second() == 0
+            && time.nanosecond() == 0
+    }
+
+    fn truncate(&self, time: NaiveDateTime) -> anyhow::Result<NaiveDateTime> {
+        if self.is_tick(time) {
+            Ok(time)
+        } else {
+            let days = Duration::days(self.days_into_week(time.date()) as i64);
+            let date = time
+                .date()
+                .checked_sub_signed(days)
+                .context("start of week not representable")?;
+            Ok(NaiveDateTime::new(date, zero_time()))
+        }
+    }
+
+    fn next_tick(&self, tick: NaiveDateTime) -> anyhow::Result<NaiveDateTime> {
+        debug_assert!(
+            self.is_tick(tick),
+            "Expected time to be aligned to {self:?}, but was: {tick:?}"
+        );
+
+        tick.checked_add_signed(Duration::weeks(1))
+            .context("next week not representable")
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(super) struct MonthlyTickProducer;
 
@@ -232,6 +286,173 @@ impl TickProducer for YearlyTickProducer {
     }
 }
 
+/// Produces ticks at a fixed interval.
+///
+/// Ticks are produced at times `t` (in nanoseconds since the epoch) where `t -
+/// offset` is a multiple of `interval`.
+#[derive(Debug, Clone)]
+pub(super) struct IntervalTickProducer {
+    /// The nanoseconds between ticks. Must be positive.
+    interval: i64,
+    /// The offset of ticks from the epoch, in nanoseconds.
+    offset: i64,
+}
+
+impl IntervalTickProducer {
+    pub(super) fn try_new(interval: i64, offset: i64) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            interval > 0,
+            "Tick interval must be positive, was {interval}"
+        );
+        Ok(Self {
+            interval,
+            offset: offset.rem_euclid(interval),
+        })
+    }
+}
+
+impl TickProducer for IntervalTickProducer {
+    fn is_tick(&self, time: NaiveDateTime) -> bool {
+        (time.timestamp_nanos() - self.offset).rem_euclid(self.interval) == 0
+    }
+
+    fn truncate(&self, time: NaiveDateTime) -> anyhow::Result<NaiveDateTime> {
+        let nanos = time.timestamp_nanos();
+        let tick = nanos - (nanos - self.offset).rem_euclid(self.interval);
+        NaiveDateTime::from_timestamp_opt(
+            tick.div_euclid(1_000_000_000),
+            tick.rem_euclid(1_000_000_000) as u32,
+        )
+        .context("tick not representable")
+    }
+
+    fn next_tick(&self, tick: NaiveDateTime) -> anyhow::Result<NaiveDateTime> {
+        debug_assert!(
+            self.is_tick(tick),
+            "Expected time to be aligned to {self:?}, but was: {tick:?}"
+        );
+
+        tick.checked_add_signed(Duration::nanoseconds(self.interval))
+            .context("next tick not representable")
+    }
+}
+
+/// Produces the ticks of another producer at local times in a time zone.
+///
+/// The `local` producer operates on local (wall clock) times in the time
+/// zone. Like other producers, this operates on UTC times. Ticks at local
+/// times which are skipped by daylight saving time are produced when the
+/// clocks change, and ticks at local times which occur twice are produced at
+/// the first occurrence.
+pub(super) struct LocalTickProducer {
+    local: Box<dyn TickProducer>,
+    time_zone: Tz,
+}
+
+impl LocalTickProducer {
+    pub(super) fn new(local: Box<dyn TickProducer>, time_zone: Tz) -> Self {
+        Self { local, time_zone }
+    }
+}
+
+impl TickProducer for LocalTickProducer {
+    fn is_tick(&self, time: NaiveDateTime) -> bool {
+        // A tick at a skipped local time isn't a tick in local time, so compare
+        // against the truncated time rather than checking the local time.
+        self.truncate(time).map_or(false, |tick| tick == time)
+    }
+
+    fn truncate(&self, time: NaiveDateTime) -> anyhow::Result<NaiveDateTime> {
+        let local = self.local.truncate(to_local(time, &self.time_zone))?;
+        Ok(from_local(local, &self.time_zone))
+    }
+
+    fn next_tick(&self, tick: NaiveDateTime) -> anyhow::Result<NaiveDateTime> {
+        // Truncate the local time, since ticks at skipped local times are
+        // produced after the gap.
+        let local = self.local.truncate(to_local(tick, &self.time_zone))?;
+        let local = self.local.next_tick(local)?;
+        Ok(from_local(local, &self.time_zone))
+    }
+}
+
+/// Describes the times a tick operation produces ticks at.
+///
+/// This is stored with the state of the tick operation, so that resuming
+/// can verify the ticks are produced on the same schedule.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) struct TickSchedule {
+    /// The `TickBehavior`, as the value of the enum.
+    behavior: i32,
+    /// The IANA name of the time zone calendar ticks are produced in.
+    time_zone: String,
+    /// The day weekly ticks are produced on, as days from Monday.
+    week_start: u32,
+    /// The nanoseconds between interval ticks.
+    interval: i64,
+    /// The offset of interval ticks from the epoch, in nanoseconds.
+    offset: i64,
+}
+
+impl TickSchedule {
+    /// Create the schedule for the given tick operation.
+    pub(super) fn try_new(operation: &operation_plan::TickOperation) -> anyhow::Result<Self> {
+        let time_zone = if operation.time_zone.is_empty() {
+            "UTC".to_owned()
+        } else {
+            operation.time_zone.clone()
+        };
+        let schedule = Self {
+            behavior: operation.behavior,
+            time_zone,
+            week_start: operation.week_start,
+            interval: operation.interval,
+            offset: operation.offset,
+        };
+
+        // Make sure the producer can be created, to report invalid schedules
+        // when the operation is created.
+        schedule.producer()?;
+        Ok(schedule)
+    }
+
+    /// Create the producer for this schedule.
+    pub(super) fn producer(&self) -> anyhow::Result<Box<dyn TickProducer>> {
+        let behavior = TickBehavior::from_i32(self.behavior).unwrap_or(TickBehavior::Unspecified);
+        let producer: Box<dyn TickProducer> = match behavior {
+            TickBehavior::Minutely => Box::new(MinutelyTickProducer),
+            TickBehavior::Hourly => Box::new(HourlyTickProducer),
+            TickBehavior::Daily => Box::new(DailyTickProducer),
+            TickBehavior::Weekly => {
+                let start = u8::try_from(self.week_start)
+                    .ok()
+                    .and_then(|day| Weekday::try_from(day).ok())
+                    .with_context(|| format!("invalid week start {}", self.week_start))?;
+                Box::new(WeeklyTickProducer::new(start))
+            }
+            TickBehavior::Monthly => Box::new(MonthlyTickProducer),
+            TickBehavior::Yearly => Box::new(YearlyTickProducer),
+            TickBehavior::Every => {
+                // Interval ticks are fixed durations from the epoch, so they
+                // don't depend on the time zone.
+                return Ok(Box::new(IntervalTickProducer::try_new(
+                    self.interval,
+                    self.offset,
+                )?));
+            }
+            TickBehavior::Finished => anyhow::bail!("Final ticks should use separate operation"),
+            unknown => anyhow::bail!("Unknown tick behavior {:?}", unknown),
+        };
+
+        let time_zone = parse_time_zone(&self.time_zone)?;
+        if time_zone == Tz::UTC {
+            Ok(producer)
+        } else {
+            Ok(Box::new(LocalTickProducer::new(producer, time_zone)))
+        }
+    }
+}
+
 /// An iterator over the merged times produced by one or more tick producers.
 pub(super) struct TickIter {
     producers: Vec<Box<dyn TickProducer>>,
@@ -554,6 +775,81 @@ mod tests {
         }
     }
 
+    mod weekly {
+        use chrono::{NaiveDate, Weekday};
+
+        use super::super::{TickProducer, WeeklyTickProducer};
+
+        #[test]
+        fn test_is_tick() {
+            let producer = WeeklyTickProducer::new(Weekday::Sun);
+
+            // 2020-03-15 is a Sunday.
+            let sunday = NaiveDate::from_ymd_opt(2020, 3, 15).unwrap();
+            assert!(producer.is_tick(sunday.and_hms_opt(0, 0, 0).unwrap()));
+            assert!(!producer.is_tick(sunday.and_hms_opt(0, 0, 1).unwrap()));
+            assert!(!producer.is_tick(
+                NaiveDate::from_ymd_opt(2020, 3, 16)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap()
+            ));
+        }
+
+        #[test]
+        fn test_truncate() {
+            let producer = WeeklyTickProducer::new(Weekday::Sun);
+
+            let sunday = NaiveDate::from_ymd_opt(2020, 3, 15).unwrap();
+            assert_eq!(
+                sunday.and_hms_opt(0, 0, 0).unwrap(),
+                producer
+                    .truncate(sunday.and_hms_opt(13, 6, 33).unwrap())
+                    .unwrap()
+            );
+            assert_eq!(
+                sunday.and_hms_opt(0, 0, 0).unwrap(),
+                producer
+                    .truncate(
+                        NaiveDate::from_ymd_opt(2020, 3, 21)
+                            .unwrap()
+                            .and_hms_opt(23, 59, 59)
+                            .unwrap()
+                    )
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        fn test_next() {
+            let producer = WeeklyTickProducer::new(Weekday::Mon);
+
+            // 2020-03-14 is a Saturday.
+            let date = NaiveDate::from_ymd_opt(2020, 3, 14).unwrap();
+            assert_eq!(
+                NaiveDate::from_ymd_opt(2020, 3, 16)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap(),
+                producer.next(date.and_hms_opt(2, 0, 0).unwrap()).unwrap()
+            );
+            assert_eq!(
+                NaiveDate::from_ymd_opt(2020, 3, 23)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap(),
+                producer
+                    .next_tick(
+                        NaiveDate::from_ymd_opt(2020, 3, 16)
+                            .unwrap()
+                            .and_hms_opt(0, 0, 0)
+                            .unwrap()
+                    )
+                    .unwrap()
+            );
+        }
+    }
+
     mod monthly {
         use chrono::NaiveDate;
 
@@ -1091,6 +1387,168 @@ mod tests {
         )
     }
 
+    mod interval {
+        use chrono::NaiveDate;
+
+        use super::super::{IntervalTickProducer, TickProducer};
+
+        const MINUTE: i64 = 60_000_000_000;
+
+        #[test]
+        fn test_is_tick() {
+            let producer = IntervalTickProducer::try_new(15 * MINUTE, 5 * MINUTE).unwrap();
+
+            let date = NaiveDate::from_ymd_opt(2020, 3, 14).unwrap();
+            assert!(producer.is_tick(date.and_hms_opt(2, 5, 0).unwrap()));
+            assert!(producer.is_tick(date.and_hms_opt(2, 50, 0).unwrap()));
+            assert!(!producer.is_tick(date.and_hms_opt(2, 0, 0).unwrap()));
+            assert!(!producer.is_tick(date.and_hms_opt(2, 5, 1).unwrap()));
+        }
+
+        #[test]
+        fn test_truncate() {
+            let producer = IntervalTickProducer::try_new(15 * MINUTE, 5 * MINUTE).unwrap();
+
+            let date = NaiveDate::from_ymd_opt(2020, 3, 14).unwrap();
+            assert_eq!(
+                date.and_hms_opt(2, 5, 0).unwrap(),
+                producer
+                    .truncate(date.and_hms_opt(2, 19, 59).unwrap())
+                    .unwrap()
+            );
+            assert_eq!(
+                NaiveDate::from_ymd_opt(2020, 3, 13)
+                    .unwrap()
+                    .and_hms_opt(23, 50, 0)
+                    .unwrap(),
+                producer
+                    .truncate(date.and_hms_opt(0, 4, 0).unwrap())
+                    .unwrap()
+            );
+
+            // Times before the epoch are truncated down.
+            let before_epoch = NaiveDate::from_ymd_opt(1969, 12, 31).unwrap();
+            assert_eq!(
+                before_epoch.and_hms_opt(23, 50, 0).unwrap(),
+                producer
+                    .truncate(before_epoch.and_hms_opt(23, 59, 0).unwrap())
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        fn test_next() {
+            // Every 6 hours, offset by 30 minutes.
+            let producer = IntervalTickProducer::try_new(360 * MINUTE, 30 * MINUTE).unwrap();
+
+            let date = NaiveDate::from_ymd_opt(2020, 3, 14).unwrap();
+            assert_eq!(
+                date.and_hms_opt(6, 30, 0).unwrap(),
+                producer.next(date.and_hms_opt(0, 30, 0).unwrap()).unwrap()
+            );
+            assert_eq!(
+                date.and_hms_opt(0, 30, 0).unwrap(),
+                producer
+                    .next_inclusive(date.and_hms_opt(0, 0, 0).unwrap())
+                    .unwrap()
+            );
+            assert_eq!(
+                date.succ_opt().unwrap().and_hms_opt(0, 30, 0).unwrap(),
+                producer
+                    .next_tick(date.and_hms_opt(18, 30, 0).unwrap())
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        fn test_invalid_interval() {
+            assert!(IntervalTickProducer::try_new(0, 0).is_err());
+        }
+    }
+
+    mod local {
+        use chrono::{NaiveDate, NaiveDateTime};
+        use sparrow_kernels::time::Tz;
+
+        use super::super::{DailyTickProducer, LocalTickProducer, MonthlyTickProducer, TickIter};
+
+        fn date_time(y: i32, m: u32, d: u32, h: u32, min: u32) -> NaiveDateTime {
+            NaiveDate::from_ymd_opt(y, m, d)
+                .unwrap()
+                .and_hms_opt(h, min, 0)
+                .unwrap()
+        }
+
+        #[test]
+        fn test_daily_daylight_saving() {
+            // Clocks in New York are set forward at 2AM on 2021-03-14, so
+            // midnight moves from 05:00 UTC to 04:00 UTC.
+            let producer =
+                LocalTickProducer::new(Box::new(DailyTickProducer), Tz::America__New_York);
+            let times: Vec<_> = TickIter::try_new(
+                vec![Box::new(producer)],
+                date_time(2021, 3, 13, 0, 0),
+                date_time(2021, 3, 16, 0, 0),
+            )
+            .unwrap()
+            .collect();
+
+            assert_eq!(
+                times,
+                vec![
+                    date_time(2021, 3, 13, 5, 0),
+                    date_time(2021, 3, 14, 5, 0),
+                    date_time(2021, 3, 15, 4, 0),
+                ]
+            )
+        }
+
+        #[test]
+        fn test_daily_skipped_midnight() {
+            // Clocks in Santiago are set forward at midnight on 2021-09-05, so
+            // the tick is produced when the clocks change (1AM local time).
+            let producer =
+                LocalTickProducer::new(Box::new(DailyTickProducer), Tz::America__Santiago);
+            let times: Vec<_> = TickIter::try_new(
+                vec![Box::new(producer)],
+                date_time(2021, 9, 4, 0, 0),
+                date_time(2021, 9, 7, 0, 0),
+            )
+            .unwrap()
+            .collect();
+
+            assert_eq!(
+                times,
+                vec![
+                    date_time(2021, 9, 4, 4, 0),
+                    date_time(2021, 9, 5, 4, 0),
+                    date_time(2021, 9, 6, 3, 0),
+                ]
+            )
+        }
+
+        #[test]
+        fn test_monthly() {
+            let producer = LocalTickProducer::new(Box::new(MonthlyTickProducer), Tz::Asia__Kolkata);
+            let times: Vec<_> = TickIter::try_new(
+                vec![Box::new(producer)],
+                date_time(2021, 1, 1, 0, 0),
+                date_time(2021, 4, 1, 0, 0),
+            )
+            .unwrap()
+            .collect();
+
+            assert_eq!(
+                times,
+                vec![
+                    date_time(2021, 1, 31, 18, 30),
+                    date_time(2021, 2, 28, 18, 30),
+                    date_time(2021, 3, 31, 18, 30),
+                ]
+            )
+        }
+    }
+
     // TODO: Proptest for tick iteration. We could easily verify a variety of
     // properties:
     // - Choose two intervals, ensure that all results are from one or the
@@ -9,6 +9,8 @@ use futures::stream::BoxStream;
 use futures::{FutureExt, StreamExt};
 use itertools::Itertools;
 use sparrow_api::kaskada::v1alpha::execute_request::Limits;
+#[cfg(feature = "kafka")]
+use sparrow_api::kaskada::v1alpha::KafkaDestination;
 use sparrow_api::kaskada::v1alpha::{data_type, ObjectStoreDestination, PulsarDestination};
 use sparrow_arrow::downcast::{downcast_primitive_array, downcast_struct_array};
 
@@ -17,6 +19,8 @@ use crate::execute::progress_reporter::ProgressUpdate;
 use crate::key_hash_inverse::ThreadSafeKeyHashInverse;
 use crate::Batch;
 
+#[cfg(feature = "kafka")]
+mod kafka;
 mod object_store;
 
 pub mod pulsar;
@@ -45,6 +49,8 @@ pub enum Destination {
     ObjectStore(ObjectStoreDestination),
     #[cfg(feature = "pulsar")]
     Pulsar(PulsarDestination),
+    #[cfg(feature = "kafka")]
+    Kafka(KafkaDestination),
     Channel(tokio::sync::mpsc::Sender<RecordBatch>),
 }
 
@@ -69,6 +75,16 @@ impl TryFrom<sparrow_api::kaskada::v1alpha::Destination> for Destination {
             sparrow_api::kaskada::v1alpha::destination::Destination::Pulsar(pulsar) => {
                 Ok(Destination::Pulsar(pulsar))
             }
+            #[cfg(not(feature = "kafka"))]
+            sparrow_api::kaskada::v1alpha::destination::Destination::Kafka(_) => {
+                error_stack::bail!(Error::FeatureNotEnabled {
+                    feature: "kafka".to_owned()
+                })
+            }
+            #[cfg(feature = "kafka")]
+            sparrow_api::kaskada::v1alpha::destination::Destination::Kafka(kafka) => {
+                Ok(Destination::Kafka(kafka))
+            }
         }
     }
 }
@@ -150,6 +166,14 @@ pub(super) fn write(
                     .boxed(),
             )
         }
+        #[cfg(feature = "kafka")]
+        Destination::Kafka(kafka) => {
+            Ok(
+                kafka::write(kafka, sink_schema, progress_updates_tx, batches)
+                    .change_context(Error::WritingToDestination("kafka"))
+                    .boxed(),
+            )
+        }
     }
 }
 
@@ -0,0 +1,445 @@
+use std::time::Duration;
+
+use arrow::array::{Array, ArrayRef, AsArray};
+use arrow::datatypes::{
+    DataType, Date32Type, Float32Type, Float64Type, Int32Type, Int64Type, SchemaRef, TimeUnit,
+    TimestampMicrosecondType, TimestampMillisecondType,
+};
+use arrow::record_batch::RecordBatch;
+use avro_rs::types::Value;
+use error_stack::{IntoReport, ResultExt};
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use rdkafka::producer::{FutureProducer, FutureRecord};
+use rdkafka::ClientConfig;
+use sparrow_api::kaskada::v1alpha::kafka_config::{self, MessageFormat};
+use sparrow_api::kaskada::v1alpha::{destination, KafkaConfig, KafkaDestination};
+
+use crate::execute::progress_reporter::ProgressUpdate;
+use crate::streams;
+
+#[derive(Debug, derive_more::Display)]
+pub enum Error {
+    ProducerCreation,
+    ProgressUpdate,
+    JsonSerialization,
+    AvroSerialization,
+    SchemaSerialization,
+    #[display(fmt = "registering schema for topic '{_0}'")]
+    SchemaRegistration(String),
+    #[display(fmt = "unsupported output type '{_0}'")]
+    UnsupportedType(DataType),
+    SendingMessage,
+    Internal,
+}
+
+impl error_stack::Context for Error {}
+
+/// How long the producer may queue a message before failing to send it.
+const SEND_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub(super) async fn write(
+    kafka: KafkaDestination,
+    schema: SchemaRef,
+    progress_updates_tx: tokio::sync::mpsc::Sender<ProgressUpdate>,
+    mut batches: BoxStream<'static, RecordBatch>,
+) -> error_stack::Result<(), Error> {
+    let config = kafka.config.ok_or(Error::Internal)?;
+    let output_schema = streams::pulsar::schema::get_output_schema(schema)
+        .change_context(Error::SchemaSerialization)?;
+    let encoder = Encoder::try_new(&config, output_schema.clone()).await?;
+
+    // Inform tracker of output type
+    progress_updates_tx
+        .send(ProgressUpdate::Destination {
+            destination: Some(destination::Destination::Kafka(KafkaDestination {
+                config: Some(config.clone()),
+            })),
+        })
+        .await
+        .into_report()
+        .change_context(Error::ProgressUpdate)?;
+
+    let producer: FutureProducer = ClientConfig::new()
+        .set("bootstrap.servers", config.hosts.join(","))
+        .create()
+        .into_report()
+        .change_context(Error::ProducerCreation)?;
+
+    while let Some(batch) = batches.next().await {
+        let batch = super::pulsar::get_output_batch(output_schema.clone(), batch)
+            .change_context(Error::Internal)?;
+        let num_rows = batch.num_rows();
+        let payloads = encoder.encode(&batch)?;
+
+        // The entity key is used as the message key, so all updates for an
+        // entity are sent to the same partition.
+        let keys = arrow::compute::cast(batch.column(1), &DataType::Utf8)
+            .into_report()
+            .change_context(Error::UnsupportedType(batch.column(1).data_type().clone()))?;
+        let keys = keys.as_string::<i32>();
+
+        tracing::debug!("Sending {num_rows} messages to kafka");
+        // Enqueue every row before waiting, so the producer can batch them.
+        let deliveries: Vec<_> = payloads
+            .iter()
+            .enumerate()
+            .map(|(row, payload)| {
+                let record = FutureRecord::to(&config.topic).payload(payload);
+                let record = if keys.is_valid(row) {
+                    record.key(keys.value(row))
+                } else {
+                    record
+                };
+                producer.send(record, SEND_TIMEOUT)
+            })
+            .collect();
+        for delivery in futures::future::join_all(deliveries).await {
+            delivery
+                .map_err(|(error, _)| error)
+                .into_report()
+                .change_context(Error::SendingMessage)?;
+        }
+        tracing::debug!("Success. Sent {num_rows} messages to kafka");
+
+        progress_updates_tx
+            .send(ProgressUpdate::Output { num_rows })
+            .await
+            .into_report()
+            .change_context(Error::ProgressUpdate)?;
+    }
+
+    Ok(())
+}
+
+/// Serializes the rows of output batches into message payloads.
+enum Encoder {
+    Json,
+    Avro {
+        schema: avro_rs::Schema,
+        /// The schema registry header to prefix each message with, if any.
+        header: Option<Vec<u8>>,
+    },
+}
+
+impl Encoder {
+    async fn try_new(
+        config: &KafkaConfig,
+        output_schema: SchemaRef,
+    ) -> error_stack::Result<Self, Error> {
+        match config.message_format() {
+            MessageFormat::Json => Ok(Self::Json),
+            MessageFormat::Unspecified | MessageFormat::Avro => {
+                let formatted = streams::pulsar::schema::format_schema(output_schema)
+                    .change_context(Error::SchemaSerialization)?;
+                tracing::info!(
+                    "Writing to kafka topic {} with schema: {formatted}",
+                    config.topic
+                );
+                let schema = avro_rs::Schema::parse_str(&formatted)
+                    .into_report()
+                    .change_context(Error::SchemaSerialization)?;
+
+                let header = match &config.schema {
+                    Some(kafka_config::Schema::SchemaRegistryUrl(url)) => {
+                        let id =
+                            streams::kafka::schema::register_schema(url, &config.topic, &formatted)
+                                .await
+                                .change_context_lazy(|| {
+                                    Error::SchemaRegistration(config.topic.clone())
+                                })?;
+                        let mut header = vec![streams::kafka::stream::REGISTRY_MAGIC_BYTE];
+                        header.extend_from_slice(&id.to_be_bytes());
+                        Some(header)
+                    }
+                    _ => None,
+                };
+                Ok(Self::Avro { schema, header })
+            }
+        }
+    }
+
+    /// Encode each row of the batch as a message payload.
+    fn encode(&self, batch: &RecordBatch) -> error_stack::Result<Vec<Vec<u8>>, Error> {
+        match self {
+            Self::Json => {
+                let json_rows = arrow::json::writer::record_batches_to_json_rows(&[batch])
+                    .into_report()
+                    .change_context(Error::JsonSerialization)?;
+                json_rows
+                    .iter()
+                    .map(|row| {
+                        serde_json::to_vec(row)
+                            .into_report()
+                            .change_context(Error::JsonSerialization)
+                    })
+                    .collect()
+            }
+            Self::Avro { schema, header } => (0..batch.num_rows())
+                .map(|row| {
+                    let fields = batch
+                        .schema()
+                        .fields()
+                        .iter()
+                        .zip(batch.columns())
+                        .map(|(field, column)| {
+                            let value = avro_value(column, row, field.is_nullable())?;
+                            Ok((field.name().clone(), value))
+                        })
+                        .collect::<error_stack::Result<_, Error>>()?;
+                    // Resolving converts the values to the logical types of the schema.
+                    let record = Value::Record(fields)
+                        .resolve(schema)
+                        .into_report()
+                        .change_context(Error::AvroSerialization)?;
+                    let datum = avro_rs::to_avro_datum(schema, record)
+                        .into_report()
+                        .change_context(Error::AvroSerialization)?;
+
+                    Ok(match header {
+                        Some(header) => [header.as_slice(), &datum].concat(),
+                        None => datum,
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Convert the value at `row` of `array` to an Avro value.
+///
+/// This supports the types produced by `sparrow_arrow::avro::to_avro_schema`.
+fn avro_value(array: &ArrayRef, row: usize, nullable: bool) -> error_stack::Result<Value, Error> {
+    if nullable {
+        // Nullable types are represented in Avro as the union of `null` and the type.
+        let value = if array.is_null(row) {
+            Value::Null
+        } else {
+            avro_value(array, row, false)?
+        };
+        return Ok(Value::Union(Box::new(value)));
+    }
+
+    let value = match array.data_type() {
+        DataType::Null => Value::Null,
+        DataType::Boolean => Value::Boolean(array.as_boolean().value(row)),
+        DataType::Int32 => Value::Int(array.as_primitive::<Int32Type>().value(row)),
+        DataType::Int64 => Value::Long(array.as_primitive::<Int64Type>().value(row)),
+        DataType::Float32 => Value::Float(array.as_primitive::<Float32Type>().value(row)),
+        DataType::Float64 => Value::Double(array.as_primitive::<Float64Type>().value(row)),
+        DataType::Utf8 => Value::String(array.as_string::<i32>().value(row).to_owned()),
+        DataType::LargeUtf8 => Value::String(array.as_string::<i64>().value(row).to_owned()),
+        DataType::Binary => Value::Bytes(array.as_binary::<i32>().value(row).to_vec()),
+        DataType::LargeBinary => Value::Bytes(array.as_binary::<i64>().value(row).to_vec()),
+        DataType::Date32 => Value::Int(array.as_primitive::<Date32Type>().value(row)),
+        DataType::Timestamp(TimeUnit::Millisecond, None) => {
+            Value::Long(array.as_primitive::<TimestampMillisecondType>().value(row))
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, None) => {
+            Value::Long(array.as_primitive::<TimestampMicrosecondType>().value(row))
+        }
+        DataType::Struct(fields) => {
+            let array = array.as_struct();
+            let fields = fields
+                .iter()
+                .zip(array.columns())
+                .map(|(field, column)| {
+                    let value = avro_value(column, row, field.is_nullable())?;
+                    Ok((field.name().clone(), value))
+                })
+                .collect::<error_stack::Result<_, Error>>()?;
+            Value::Record(fields)
+        }
+        other => error_stack::bail!(Error::UnsupportedType(other.clone())),
+    };
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use arrow::array::{Float64Array, StringArray, TimestampNanosecondArray, UInt64Array};
+    use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+    use arrow::record_batch::RecordBatch;
+    use futures::StreamExt;
+    use rdkafka::consumer::{Consumer, StreamConsumer};
+    use rdkafka::mocking::MockCluster;
+    use rdkafka::{ClientConfig, Message, Offset, TopicPartitionList};
+    use sparrow_api::kaskada::v1alpha::kafka_config::MessageFormat;
+    use sparrow_api::kaskada::v1alpha::{KafkaConfig, KafkaDestination};
+
+    use super::write;
+    use crate::execute::progress_reporter::ProgressUpdate;
+
+    const TOPIC: &str = "results";
+
+    fn sink_schema() -> Arc<Schema> {
+        Arc::new(Schema::new(vec![
+            Field::new(
+                "_time",
+                DataType::Timestamp(TimeUnit::Nanosecond, None),
+                false,
+            ),
+            Field::new("_subsort", DataType::UInt64, false),
+            Field::new("_key_hash", DataType::UInt64, false),
+            Field::new("_key", DataType::Utf8, true),
+            Field::new("sum_amount", DataType::Float64, true),
+        ]))
+    }
+
+    fn sink_batch() -> RecordBatch {
+        RecordBatch::try_new(
+            sink_schema(),
+            vec![
+                Arc::new(TimestampNanosecondArray::from(vec![1_000_000, 2_000_000])),
+                Arc::new(UInt64Array::from(vec![0, 1])),
+                Arc::new(UInt64Array::from(vec![57, 58])),
+                Arc::new(StringArray::from(vec!["alice", "bob"])),
+                Arc::new(Float64Array::from(vec![Some(5.5), None])),
+            ],
+        )
+        .unwrap()
+    }
+
+    /// Write a single batch to the topic and read back the keys and payloads.
+    async fn write_and_read(format: MessageFormat) -> Vec<(String, Vec<u8>)> {
+        let cluster = MockCluster::new(1).unwrap();
+        cluster.create_topic(TOPIC, 1, 1).unwrap();
+        let config = KafkaConfig {
+            hosts: vec![cluster.bootstrap_servers()],
+            topic: TOPIC.to_owned(),
+            message_format: format as i32,
+            schema: None,
+        };
+
+        let (progress_tx, mut progress_rx) = tokio::sync::mpsc::channel(10);
+        let batches = futures::stream::iter(vec![sink_batch()]).boxed();
+        write(
+            KafkaDestination {
+                config: Some(config.clone()),
+            },
+            sink_schema(),
+            progress_tx,
+            batches,
+        )
+        .await
+        .unwrap();
+
+        assert!(matches!(
+            progress_rx.recv().await,
+            Some(ProgressUpdate::Destination { .. })
+        ));
+        assert!(matches!(
+            progress_rx.recv().await,
+            Some(ProgressUpdate::Output { num_rows: 2 })
+        ));
+
+        let consumer: StreamConsumer = ClientConfig::new()
+            .set("bootstrap.servers", cluster.bootstrap_servers())
+            .set("group.id", "test-group")
+            .create()
+            .unwrap();
+        let mut partitions = TopicPartitionList::new();
+        partitions
+            .add_partition_offset(TOPIC, 0, Offset::Beginning)
+            .unwrap();
+        consumer.assign(&partitions).unwrap();
+
+        let mut messages = Vec::new();
+        while messages.len() < 2 {
+            let message = tokio::time::timeout(Duration::from_secs(10), consumer.recv())
+                .await
+                .unwrap()
+                .unwrap();
+            messages.push((
+                String::from_utf8(message.key().unwrap().to_vec()).unwrap(),
+                message.payload().unwrap().to_vec(),
+            ));
+        }
+        messages
+    }
+
+    #[tokio::test]
+    async fn test_write_json() {
+        let messages = write_and_read(MessageFormat::Json).await;
+        let messages: Vec<_> = messages
+            .into_iter()
+            .map(|(key, payload)| {
+                let payload: serde_json::Value = serde_json::from_slice(&payload).unwrap();
+                (key, payload)
+            })
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                (
+                    "alice".to_owned(),
+                    serde_json::json!({
+                        "_time": "1970-01-01T00:00:00.001",
+                        "_key": "alice",
+                        "sum_amount": 5.5,
+                    })
+                ),
+                (
+                    "bob".to_owned(),
+                    serde_json::json!({
+                        "_time": "1970-01-01T00:00:00.002",
+                        "_key": "bob",
+                    })
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_write_avro() {
+        use avro_rs::types::Value;
+
+        let messages = write_and_read(MessageFormat::Avro).await;
+        let output_schema =
+            crate::streams::pulsar::schema::get_output_schema(sink_schema()).unwrap();
+        let avro_schema = crate::streams::pulsar::schema::format_schema(output_schema).unwrap();
+        let avro_schema = avro_rs::Schema::parse_str(&avro_schema).unwrap();
+
+        let messages: Vec<_> = messages
+            .into_iter()
+            .map(|(key, payload)| {
+                let value =
+                    avro_rs::from_avro_datum(&avro_schema, &mut payload.as_slice(), None).unwrap();
+                (key, value)
+            })
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                (
+                    "alice".to_owned(),
+                    Value::Record(vec![
+                        ("_time".to_owned(), Value::Long(1000)),
+                        (
+                            "_key".to_owned(),
+                            Value::Union(Box::new(Value::String("alice".to_owned())))
+                        ),
+                        (
+                            "sum_amount".to_owned(),
+                            Value::Union(Box::new(Value::Double(5.5)))
+                        ),
+                    ])
+                ),
+                (
+                    "bob".to_owned(),
+                    Value::Record(vec![
+                        ("_time".to_owned(), Value::Long(2000)),
+                        (
+                            "_key".to_owned(),
+                            Value::Union(Box::new(Value::String("bob".to_owned())))
+                        ),
+                        ("sum_amount".to_owned(), Value::Union(Box::new(Value::Null))),
+                    ])
+                ),
+            ]
+        );
+    }
+}
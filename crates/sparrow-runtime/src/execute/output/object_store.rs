@@ -1,14 +1,29 @@
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::fmt::Write;
 use std::str::FromStr;
 use std::sync::Arc;
 
-use arrow::datatypes::SchemaRef;
+use arrow::array::{
+    ArrayRef, AsArray, GenericListArray, MapArray, OffsetSizeTrait, StructArray, UInt32Array,
+};
+use arrow::datatypes::{
+    DataType, FieldRef, Fields, Schema, SchemaRef, TimestampNanosecondType, UInt64Type,
+};
+use arrow::error::ArrowError;
+use arrow::ipc::writer::{FileWriter, StreamWriter};
 use arrow::record_batch::RecordBatch;
+use arrow::temporal_conversions::timestamp_ns_to_datetime;
+use chrono::{Datelike, NaiveDateTime, Timelike};
 use error_stack::{IntoReport, ResultExt};
 use futures::stream::BoxStream;
 use futures::StreamExt;
+use itertools::Itertools;
 use object_store::ObjectStore;
 use parquet::arrow::AsyncArrowWriter;
 use sparrow_api::kaskada::v1alpha::destination::Destination;
+use sparrow_api::kaskada::v1alpha::object_store_destination::partitioning::TimeBucket;
+use sparrow_api::kaskada::v1alpha::object_store_destination::Partitioning;
 use sparrow_api::kaskada::v1alpha::{FileType, ObjectStoreDestination};
 use tokio::io::{AsyncWrite, AsyncWriteExt};
 use uuid::Uuid;
@@ -17,7 +32,18 @@ use crate::execute::progress_reporter::ProgressUpdate;
 use crate::stores::{ObjectStoreRegistry, ObjectStoreUrl};
 use crate::UPLOAD_BUFFER_SIZE_IN_BYTES;
 
+/// The number of rows written to a file before starting a new one.
+#[cfg(not(test))]
 const ROWS_PER_FILE: usize = 1_000_000;
+/// Use a small number of rows per file in tests to exercise rolling over.
+#[cfg(test)]
+const ROWS_PER_FILE: usize = 4;
+
+/// The maximum number of key buckets when partitioning output.
+///
+/// Each key bucket within the current time bucket may have an open file, so
+/// this limits the number of files being written at once.
+const MAX_KEY_BUCKETS: u32 = 1024;
 
 #[derive(derive_more::Display, Debug)]
 pub enum Error {
@@ -33,6 +59,10 @@ pub enum Error {
     Upload,
     #[display(fmt = "error writing output")]
     Write,
+    #[display(fmt = "unable to determine output partition")]
+    Partition,
+    #[display(fmt = "invalid key buckets '{_0}': must be at most {MAX_KEY_BUCKETS}")]
+    InvalidKeyBuckets(u32),
 }
 
 impl error_stack::Context for Error {}
@@ -48,6 +78,26 @@ enum WriterState {
         url: ObjectStoreUrl,
         writer: Box<dyn AsyncWrite + Send + Unpin>,
     },
+    /// Open newline-delimited JSON writer.
+    Json {
+        url: ObjectStoreUrl,
+        writer: Box<dyn AsyncWrite + Send + Unpin>,
+    },
+    /// Open Arrow IPC file format writer.
+    ///
+    /// The IPC writers are synchronous, so they encode into an in-memory
+    /// buffer which is drained to the object store after each batch.
+    ArrowFile {
+        url: ObjectStoreUrl,
+        ipc_writer: FileWriter<Vec<u8>>,
+        writer: Box<dyn AsyncWrite + Send + Unpin>,
+    },
+    /// Open Arrow IPC stream format writer.
+    ArrowStream {
+        url: ObjectStoreUrl,
+        ipc_writer: StreamWriter<Vec<u8>>,
+        writer: Box<dyn AsyncWrite + Send + Unpin>,
+    },
 }
 
 impl WriterState {
@@ -63,6 +113,9 @@ impl WriterState {
             FileType::Unspecified => error_stack::bail!(Error::UnspecifiedFileType),
             FileType::Parquet => "parquet",
             FileType::Csv => "csv",
+            FileType::Arrow => "arrow",
+            FileType::ArrowStream => "arrows",
+            FileType::Json => "jsonl",
         };
         let url = output_prefix
             .join(&format!(
@@ -110,6 +163,29 @@ impl WriterState {
                 // Then return the state
                 Ok(Self::Csv { url, writer })
             }
+            FileType::Json => Ok(Self::Json { url, writer }),
+            FileType::Arrow => {
+                let mut ipc_writer = FileWriter::try_new(Vec::new(), schema.as_ref())
+                    .into_report()
+                    .change_context(Error::Write)?;
+                upload(&mut writer, ipc_writer.get_mut()).await?;
+                Ok(Self::ArrowFile {
+                    url,
+                    ipc_writer,
+                    writer,
+                })
+            }
+            FileType::ArrowStream => {
+                let mut ipc_writer = StreamWriter::try_new(Vec::new(), schema.as_ref())
+                    .into_report()
+                    .change_context(Error::Write)?;
+                upload(&mut writer, ipc_writer.get_mut()).await?;
+                Ok(Self::ArrowStream {
+                    url,
+                    ipc_writer,
+                    writer,
+                })
+            }
         }
     }
 
@@ -150,6 +226,43 @@ impl WriterState {
                     .change_context(Error::Write)?;
                 Ok(())
             }
+            WriterState::Json { writer, .. } => {
+                let batch = u64_columns_to_strings(batch)?;
+
+                // As with CSV, each batch is encoded to a buffer before being
+                // written to the object store.
+                let mut buffer = Vec::new();
+                {
+                    let mut json_writer = arrow_json::LineDelimitedWriter::new(&mut buffer);
+                    json_writer
+                        .write(&batch)
+                        .into_report()
+                        .change_context(Error::Write)?;
+                    json_writer
+                        .finish()
+                        .into_report()
+                        .change_context(Error::Write)?;
+                }
+                upload(writer, &mut buffer).await
+            }
+            WriterState::ArrowFile {
+                ipc_writer, writer, ..
+            } => {
+                ipc_writer
+                    .write(&batch)
+                    .into_report()
+                    .change_context(Error::Write)?;
+                upload(writer, ipc_writer.get_mut()).await
+            }
+            WriterState::ArrowStream {
+                ipc_writer, writer, ..
+            } => {
+                ipc_writer
+                    .write(&batch)
+                    .into_report()
+                    .change_context(Error::Write)?;
+                upload(writer, ipc_writer.get_mut()).await
+            }
         }
     }
 
@@ -165,7 +278,48 @@ impl WriterState {
             }
             WriterState::Csv {
                 url, mut writer, ..
+            }
+            | WriterState::Json {
+                url, mut writer, ..
+            } => {
+                writer
+                    .shutdown()
+                    .await
+                    .into_report()
+                    .change_context(Error::Write)?;
+                Ok(url)
+            }
+            WriterState::ArrowFile {
+                url,
+                ipc_writer,
+                mut writer,
+            } => {
+                // Finishing the IPC writer writes the footer and flushes any
+                // buffered bytes.
+                let mut buffer = ipc_writer
+                    .into_inner()
+                    .into_report()
+                    .change_context(Error::Write)?;
+                upload(&mut writer, &mut buffer).await?;
+                writer
+                    .shutdown()
+                    .await
+                    .into_report()
+                    .change_context(Error::Write)?;
+                Ok(url)
+            }
+            WriterState::ArrowStream {
+                url,
+                ipc_writer,
+                mut writer,
             } => {
+                // Finishing the IPC writer writes the end-of-stream marker and
+                // flushes any buffered bytes.
+                let mut buffer = ipc_writer
+                    .into_inner()
+                    .into_report()
+                    .change_context(Error::Write)?;
+                upload(&mut writer, &mut buffer).await?;
                 writer
                     .shutdown()
                     .await
@@ -177,14 +331,381 @@ impl WriterState {
     }
 }
 
+/// Write the bytes encoded so far to the object store, clearing the buffer.
+async fn upload(
+    writer: &mut Box<dyn AsyncWrite + Send + Unpin>,
+    buffer: &mut Vec<u8>,
+) -> error_stack::Result<(), Error> {
+    writer
+        .write_all(buffer)
+        .await
+        .into_report()
+        .change_context(Error::Write)?;
+    buffer.clear();
+    Ok(())
+}
+
+/// Convert the `u64` values of `batch`, including those nested within records,
+/// lists and maps, to strings.
+///
+/// Many JSON readers parse numbers as doubles, which can't represent every
+/// `u64`. Writing them as strings preserves values such as `_key_hash`.
+fn u64_columns_to_strings(batch: RecordBatch) -> error_stack::Result<RecordBatch, Error> {
+    let schema = batch.schema();
+    if !schema
+        .fields()
+        .iter()
+        .any(|field| contains_u64(field.data_type()))
+    {
+        return Ok(batch);
+    }
+
+    let columns: Vec<_> = batch
+        .columns()
+        .iter()
+        .map(u64_to_strings)
+        .try_collect()
+        .into_report()
+        .change_context(Error::Write)?;
+    let fields: Vec<_> = schema
+        .fields()
+        .iter()
+        .zip(&columns)
+        .map(|(field, column)| with_array_type(field, column))
+        .collect();
+
+    RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)
+        .into_report()
+        .change_context(Error::Write)
+}
+
+/// Returns true if the type is a `u64`, or contains one.
+fn contains_u64(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::UInt64 => true,
+        DataType::Struct(fields) => fields.iter().any(|field| contains_u64(field.data_type())),
+        DataType::List(field) | DataType::LargeList(field) | DataType::Map(field, _) => {
+            contains_u64(field.data_type())
+        }
+        _ => false,
+    }
+}
+
+/// Convert the `u64` values of `array`, including nested ones, to strings.
+fn u64_to_strings(array: &ArrayRef) -> Result<ArrayRef, ArrowError> {
+    if !contains_u64(array.data_type()) {
+        return Ok(array.clone());
+    }
+
+    match array.data_type() {
+        DataType::UInt64 => arrow::compute::cast(array, &DataType::Utf8),
+        DataType::Struct(_) => {
+            let (fields, columns, nulls) = array.as_struct().clone().into_parts();
+            let columns: Vec<_> = columns.iter().map(u64_to_strings).try_collect()?;
+            let fields: Fields = fields
+                .iter()
+                .zip(&columns)
+                .map(|(field, column)| with_array_type(field, column))
+                .collect();
+            Ok(Arc::new(StructArray::try_new(fields, columns, nulls)?))
+        }
+        DataType::List(_) => list_u64_to_strings(array.as_list::<i32>()),
+        DataType::LargeList(_) => list_u64_to_strings(array.as_list::<i64>()),
+        DataType::Map(_, ordered) => {
+            let ordered = *ordered;
+            let (field, offsets, entries, nulls, _) = array.as_map().clone().into_parts();
+            let entries = u64_to_strings(&(Arc::new(entries) as ArrayRef))?;
+            Ok(Arc::new(MapArray::try_new(
+                with_array_type(&field, &entries),
+                offsets,
+                entries.as_struct().clone(),
+                nulls,
+                ordered,
+            )?))
+        }
+        unsupported => Err(ArrowError::CastError(format!(
+            "Unable to convert u64 values within {unsupported:?} to strings"
+        ))),
+    }
+}
+
+fn list_u64_to_strings<O: OffsetSizeTrait>(
+    list: &GenericListArray<O>,
+) -> Result<ArrayRef, ArrowError> {
+    let (field, offsets, values, nulls) = list.clone().into_parts();
+    let values = u64_to_strings(&values)?;
+    Ok(Arc::new(GenericListArray::try_new(
+        with_array_type(&field, &values),
+        offsets,
+        values,
+        nulls,
+    )?))
+}
+
+/// Returns the field with the type of the given array.
+fn with_array_type(field: &FieldRef, array: &ArrayRef) -> FieldRef {
+    Arc::new(
+        field
+            .as_ref()
+            .clone()
+            .with_data_type(array.data_type().clone()),
+    )
+}
+
+/// Options and state shared by the writers for each partition.
+struct Output {
+    object_store: Arc<dyn ObjectStore>,
+    file_uuid: Uuid,
+    file_type: FileType,
+    schema: SchemaRef,
+    progress_updates_tx: tokio::sync::mpsc::Sender<ProgressUpdate>,
+    /// The files written so far, for the manifest.
+    files: Vec<ManifestFile>,
+}
+
+impl Output {
+    /// Report that the rows of an input batch were written.
+    async fn report_rows(&self, num_rows: usize) -> error_stack::Result<(), Error> {
+        self.progress_updates_tx
+            .send(ProgressUpdate::Output { num_rows })
+            .await
+            .into_report()
+            .change_context(Error::ProgressUpdate)
+    }
+}
+
+/// An entry in the manifest of a partitioned output.
+#[derive(serde::Serialize)]
+struct ManifestFile {
+    path: String,
+    partition: String,
+    num_rows: usize,
+}
+
+#[derive(serde::Serialize)]
+struct Manifest<'a> {
+    files: &'a [ManifestFile],
+}
+
+/// Writes the files within a single partition of the output.
+///
+/// Without partitioning, the entire output is written by a single
+/// `PartitionWriter` at the output prefix.
+struct PartitionWriter {
+    /// The Hive-style path of the partition relative to the output prefix,
+    /// such as `date=2023-06-01/`. Empty if the output isn't partitioned.
+    partition: String,
+    /// The prefix to write files in this partition to.
+    prefix: ObjectStoreUrl,
+    state: Option<WriterState>,
+    num_files: usize,
+    num_rows_in_file: usize,
+}
+
+impl PartitionWriter {
+    fn try_new(
+        output_prefix: &ObjectStoreUrl,
+        partition: String,
+    ) -> error_stack::Result<Self, Error> {
+        let prefix = if partition.is_empty() {
+            output_prefix.clone()
+        } else {
+            output_prefix
+                .join(&partition)
+                .change_context(Error::Internal)?
+        };
+        Ok(Self {
+            partition,
+            prefix,
+            state: None,
+            num_files: 0,
+            num_rows_in_file: 0,
+        })
+    }
+
+    async fn open(&mut self, output: &Output) -> error_stack::Result<(), Error> {
+        let state = WriterState::open(
+            output.object_store.as_ref(),
+            &self.prefix,
+            &output.file_uuid,
+            self.num_files,
+            output.file_type,
+            output.schema.clone(),
+        )
+        .await?;
+        self.state = Some(state);
+        self.num_files += 1;
+        Ok(())
+    }
+
+    async fn write(
+        &mut self,
+        output: &mut Output,
+        batch: RecordBatch,
+    ) -> error_stack::Result<(), Error> {
+        // Initialize the writer if we need a new one.
+        if self.state.is_none() {
+            self.open(output).await?;
+        }
+
+        // Write the batch.
+        let num_rows = batch.num_rows();
+        self.state
+            .as_mut()
+            .expect("opened above")
+            .write(batch)
+            .await?;
+        self.num_rows_in_file += num_rows;
+
+        // If we've written enough rows, rotate the file.
+        if self.num_rows_in_file > ROWS_PER_FILE {
+            self.close(output).await?;
+        }
+        Ok(())
+    }
+
+    /// Close the current file, if any.
+    async fn close(&mut self, output: &mut Output) -> error_stack::Result<(), Error> {
+        let Some(state) = self.state.take() else {
+            return Ok(());
+        };
+        let url = state.close().await?;
+
+        tracing::info!("Wrote {} rows to file {url}", self.num_rows_in_file);
+        output.files.push(ManifestFile {
+            path: url.to_string(),
+            partition: self.partition.trim_end_matches('/').to_owned(),
+            num_rows: self.num_rows_in_file,
+        });
+        output
+            .progress_updates_tx
+            .send(ProgressUpdate::FilesProduced { paths: vec![url] })
+            .await
+            .into_report()
+            .change_context(Error::ProgressUpdate)?;
+
+        self.num_rows_in_file = 0;
+        Ok(())
+    }
+}
+
+/// The partition containing a row.
+///
+/// Partitions are ordered by time bucket first, so the partitions for earlier
+/// time buckets come before those for later ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct PartitionKey {
+    /// The start of the time bucket containing the row.
+    time: Option<NaiveDateTime>,
+    key_bucket: Option<u64>,
+}
+
+impl PartitionKey {
+    /// Return the Hive-style path of the partition.
+    fn path(&self, time_bucket: TimeBucket) -> String {
+        let mut path = String::new();
+        if let Some(time) = self.time {
+            let format = match time_bucket {
+                TimeBucket::Unspecified => "",
+                TimeBucket::Hour => "date=%Y-%m-%d/hour=%H/",
+                TimeBucket::Day => "date=%Y-%m-%d/",
+                TimeBucket::Month => "month=%Y-%m/",
+                TimeBucket::Year => "year=%Y/",
+            };
+            write!(path, "{}", time.format(format)).expect("write to string");
+        }
+        if let Some(key_bucket) = self.key_bucket {
+            write!(path, "key_bucket={key_bucket}/").expect("write to string");
+        }
+        path
+    }
+}
+
+/// Return the start of the time bucket containing `time`.
+fn time_bucket_start(time_bucket: TimeBucket, time: NaiveDateTime) -> Option<NaiveDateTime> {
+    let date = time.date();
+    let start = match time_bucket {
+        TimeBucket::Unspecified => return None,
+        TimeBucket::Hour => date.and_hms_opt(time.hour(), 0, 0)?,
+        TimeBucket::Day => date.and_hms_opt(0, 0, 0)?,
+        TimeBucket::Month => date.with_day(1)?.and_hms_opt(0, 0, 0)?,
+        TimeBucket::Year => date.with_ordinal(1)?.and_hms_opt(0, 0, 0)?,
+    };
+    Some(start)
+}
+
+/// Split `batch` into the rows for each partition.
+///
+/// Returns the key and rows of each partition, ordered by key, with the rows
+/// in their original order.
+fn partition_batch(
+    partitioning: &Partitioning,
+    batch: RecordBatch,
+) -> error_stack::Result<Vec<(PartitionKey, RecordBatch)>, Error> {
+    let time_bucket = partitioning.time_bucket();
+    let key_buckets = partitioning.key_buckets as u64;
+
+    // The output batches start with the `_time, _subsort, _key_hash` columns.
+    let times = batch.column(0).as_primitive::<TimestampNanosecondType>();
+    let key_hashes = batch.column(2).as_primitive::<UInt64Type>();
+
+    let mut partitions: BTreeMap<PartitionKey, Vec<u32>> = BTreeMap::new();
+    for (index, (time, key_hash)) in times.values().iter().zip(key_hashes.values()).enumerate() {
+        let time = if time_bucket == TimeBucket::Unspecified {
+            None
+        } else {
+            let time = timestamp_ns_to_datetime(*time)
+                .and_then(|time| time_bucket_start(time_bucket, time))
+                .ok_or(Error::Partition)
+                .into_report()?;
+            Some(time)
+        };
+        let key_bucket = (key_buckets > 0).then(|| key_hash % key_buckets);
+        partitions
+            .entry(PartitionKey { time, key_bucket })
+            .or_default()
+            .push(index as u32);
+    }
+
+    if partitions.len() == 1 {
+        let (key, _) = partitions.pop_first().expect("one partition");
+        return Ok(vec![(key, batch)]);
+    }
+
+    partitions
+        .into_iter()
+        .map(|(key, indices)| {
+            let indices = UInt32Array::from(indices);
+            let columns = batch
+                .columns()
+                .iter()
+                .map(|column| arrow::compute::take(column.as_ref(), &indices, None))
+                .collect::<Result<Vec<_>, _>>()
+                .into_report()
+                .change_context(Error::Partition)?;
+            let batch = RecordBatch::try_new(batch.schema(), columns)
+                .into_report()
+                .change_context(Error::Partition)?;
+            Ok((key, batch))
+        })
+        .collect()
+}
+
 /// Write `batches` to one or more files in the `destination`.
 pub(super) async fn write(
     object_stores: Arc<ObjectStoreRegistry>,
     destination: ObjectStoreDestination,
     schema: SchemaRef,
     progress_updates_tx: tokio::sync::mpsc::Sender<ProgressUpdate>,
-    mut batches: BoxStream<'static, RecordBatch>,
+    batches: BoxStream<'static, RecordBatch>,
 ) -> error_stack::Result<(), Error> {
+    if let Some(partitioning) = &destination.partitioning {
+        error_stack::ensure!(
+            partitioning.key_buckets <= MAX_KEY_BUCKETS,
+            Error::InvalidKeyBuckets(partitioning.key_buckets)
+        );
+    }
+
     // Inform tracker of destination type
     progress_updates_tx
         .send(ProgressUpdate::Destination {
@@ -200,24 +721,33 @@ pub(super) async fn write(
         .object_store(&output_prefix)
         .change_context(Error::InvalidDestination)?;
 
-    let file_uuid = Uuid::new_v4();
-    let mut num_files = 0;
-    let mut num_rows_in_file = 0;
+    let mut output = Output {
+        object_store,
+        file_uuid: Uuid::new_v4(),
+        file_type: destination.file_type(),
+        schema,
+        progress_updates_tx,
+        files: Vec::new(),
+    };
+
+    match &destination.partitioning {
+        None => write_unpartitioned(&mut output, &output_prefix, batches).await,
+        Some(partitioning) => {
+            write_partitioned(&mut output, &output_prefix, partitioning, batches).await
+        }
+    }
+}
+
+async fn write_unpartitioned(
+    output: &mut Output,
+    output_prefix: &ObjectStoreUrl,
+    mut batches: BoxStream<'static, RecordBatch>,
+) -> error_stack::Result<(), Error> {
+    let mut writer = PartitionWriter::try_new(output_prefix, String::new())?;
 
     // Always create at least one writer. This ensures that if there are
     // no rows, we still emit an empty file.
-    let mut state = Some(
-        WriterState::open(
-            object_store.as_ref(),
-            &output_prefix,
-            &file_uuid,
-            num_files,
-            destination.file_type(),
-            schema.clone(),
-        )
-        .await?,
-    );
-    num_files += 1;
+    writer.open(output).await?;
 
     // Currently, we pull batches and upload them asynchronously.
     // We could increase concurrency by buffering some batches and performing
@@ -229,58 +759,498 @@ pub(super) async fn write(
             continue;
         }
 
-        // Initialize the writer if we need a new one.
-        if state.is_none() {
-            state = Some(
-                WriterState::open(
-                    object_store.as_ref(),
-                    &output_prefix,
-                    &file_uuid,
-                    num_files,
-                    destination.file_type(),
-                    schema.clone(),
-                )
-                .await?,
-            );
-            num_files += 1;
-        }
+        let num_rows = batch.num_rows();
+        writer.write(output, batch).await?;
+        output.report_rows(num_rows).await?;
+    }
 
-        // Write the batch.
-        {
-            let num_rows = batch.num_rows();
-            state.as_mut().expect("opened above").write(batch).await?;
-            num_rows_in_file += num_rows;
-            progress_updates_tx
-                .try_send(ProgressUpdate::Output { num_rows })
-                .into_report()
-                .change_context(Error::ProgressUpdate)?;
+    // Close any remaining state.
+    writer.close(output).await
+}
+
+async fn write_partitioned(
+    output: &mut Output,
+    output_prefix: &ObjectStoreUrl,
+    partitioning: &Partitioning,
+    mut batches: BoxStream<'static, RecordBatch>,
+) -> error_stack::Result<(), Error> {
+    let time_bucket = partitioning.time_bucket();
+
+    // Writers are created when the first row in a partition is seen, so no
+    // files are written for empty partitions.
+    let mut writers: BTreeMap<PartitionKey, PartitionWriter> = BTreeMap::new();
+    while let Some(batch) = batches.next().await {
+        if batch.num_rows() == 0 {
+            continue;
         }
 
-        // If we've written enough rows, rotate the file.
-        if num_rows_in_file > ROWS_PER_FILE {
-            let url = state.expect("opened above").close().await?;
+        let num_rows = batch.num_rows();
+        let partitions = partition_batch(partitioning, batch)?;
 
-            tracing::info!("Wrote {num_rows_in_file} rows to file {url}");
-            progress_updates_tx
-                .try_send(ProgressUpdate::FilesProduced { paths: vec![url] })
-                .into_report()
-                .change_context(Error::ProgressUpdate)?;
+        // Output rows are ordered by time, so no later batch contains rows in
+        // a time bucket before the first one in this batch. Close the writers
+        // for those time buckets so their files are reported as they finish.
+        if let Some((first, _)) = partitions.first() {
+            let current = writers.split_off(&PartitionKey {
+                time: first.time,
+                key_bucket: None,
+            });
+            for (_, mut writer) in std::mem::replace(&mut writers, current) {
+                writer.close(output).await?;
+            }
+        }
 
-            num_rows_in_file = 0;
-            state = None;
+        for (key, batch) in partitions {
+            let writer = match writers.entry(key) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    let writer = PartitionWriter::try_new(output_prefix, key.path(time_bucket))?;
+                    entry.insert(writer)
+                }
+            };
+            writer.write(output, batch).await?;
         }
+        output.report_rows(num_rows).await?;
     }
 
     // Close any remaining state.
-    if let Some(state) = state {
-        let url = state.close().await?;
-
-        tracing::info!("Wrote {num_rows_in_file} to {url}");
-        progress_updates_tx
-            .try_send(ProgressUpdate::FilesProduced { paths: vec![url] })
-            .into_report()
-            .change_context(Error::ProgressUpdate)?;
+    for writer in writers.values_mut() {
+        writer.close(output).await?;
     }
 
+    // Write the manifest of all the files.
+    let url = output_prefix
+        .join(&format!(
+            "_{}-manifest.json",
+            output.file_uuid.as_hyphenated()
+        ))
+        .change_context(Error::Internal)?;
+    let path = url.path().change_context(Error::Internal)?;
+    let manifest = serde_json::to_vec(&Manifest {
+        files: &output.files,
+    })
+    .into_report()
+    .change_context(Error::Internal)?;
+    output
+        .object_store
+        .put(&path, manifest.into())
+        .await
+        .into_report()
+        .change_context(Error::Write)?;
+    tracing::info!(
+        "Wrote manifest listing {} files to {url}",
+        output.files.len()
+    );
+
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::sync::Arc;
+
+    use arrow::array::{
+        Int64Array, ListBuilder, StringArray, StructArray, TimestampNanosecondArray, UInt64Array,
+        UInt64Builder,
+    };
+    use arrow::datatypes::{DataType, Field, Fields, Schema, TimeUnit};
+    use arrow::ipc::reader::{FileReader, StreamReader};
+    use arrow::record_batch::RecordBatch;
+    use futures::StreamExt;
+    use sparrow_api::kaskada::v1alpha::object_store_destination::partitioning::TimeBucket;
+    use sparrow_api::kaskada::v1alpha::object_store_destination::Partitioning;
+    use sparrow_api::kaskada::v1alpha::{FileType, ObjectStoreDestination};
+
+    use super::{write, Error, MAX_KEY_BUCKETS};
+    use crate::execute::progress_reporter::ProgressUpdate;
+    use crate::stores::ObjectStoreRegistry;
+
+    const DAY_IN_NS: i64 = 86_400_000_000_000;
+
+    fn sink_schema() -> Arc<Schema> {
+        Arc::new(Schema::new(vec![
+            Field::new(
+                "_time",
+                DataType::Timestamp(TimeUnit::Nanosecond, None),
+                false,
+            ),
+            Field::new("_subsort", DataType::UInt64, false),
+            Field::new("_key_hash", DataType::UInt64, false),
+            Field::new("_key", DataType::Utf8, true),
+            Field::new("n", DataType::Int64, true),
+        ]))
+    }
+
+    fn sink_batch() -> RecordBatch {
+        RecordBatch::try_new(
+            sink_schema(),
+            vec![
+                Arc::new(TimestampNanosecondArray::from(vec![0, 1, 2, DAY_IN_NS + 3])),
+                Arc::new(UInt64Array::from(vec![0, 1, 2, 3])),
+                Arc::new(UInt64Array::from(vec![57, 58, 57, 58])),
+                Arc::new(StringArray::from(vec!["alice", "bob", "alice", "bob"])),
+                Arc::new(Int64Array::from(vec![1, 2, 3, 4])),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_write_partitioned() {
+        let output_dir = tempfile::tempdir().unwrap();
+        let destination = ObjectStoreDestination {
+            file_type: FileType::Csv as i32,
+            output_prefix_uri: format!("file://{}/", output_dir.path().display()),
+            output_paths: None,
+            partitioning: Some(Partitioning {
+                time_bucket: TimeBucket::Day as i32,
+                key_buckets: 2,
+            }),
+        };
+
+        let (progress_tx, mut progress_rx) = tokio::sync::mpsc::channel(10);
+        let batches = futures::stream::iter(vec![sink_batch()]).boxed();
+        write(
+            Arc::new(ObjectStoreRegistry::default()),
+            destination,
+            sink_schema(),
+            progress_tx,
+            batches,
+        )
+        .await
+        .unwrap();
+
+        let mut files = Vec::new();
+        while let Some(update) = progress_rx.recv().await {
+            if let ProgressUpdate::FilesProduced { paths } = update {
+                files.extend(paths.into_iter().map(|url| {
+                    let path = url.local_path().unwrap();
+                    let partition = path
+                        .parent()
+                        .unwrap()
+                        .strip_prefix(output_dir.path())
+                        .unwrap();
+                    let contents = std::fs::read_to_string(path).unwrap();
+                    (partition.display().to_string(), contents)
+                }));
+            }
+        }
+        assert_eq!(
+            files,
+            vec![
+                (
+                    "date=1970-01-01/key_bucket=0".to_owned(),
+                    "_time,_subsort,_key_hash,_key,n\n\
+                     1970-01-01T00:00:00.000000001,1,58,bob,2\n"
+                        .to_owned()
+                ),
+                (
+                    "date=1970-01-01/key_bucket=1".to_owned(),
+                    "_time,_subsort,_key_hash,_key,n\n\
+                     1970-01-01T00:00:00.000000000,0,57,alice,1\n\
+                     1970-01-01T00:00:00.000000002,2,57,alice,3\n"
+                        .to_owned()
+                ),
+                (
+                    "date=1970-01-02/key_bucket=0".to_owned(),
+                    "_time,_subsort,_key_hash,_key,n\n\
+                     1970-01-02T00:00:00.000000003,3,58,bob,4\n"
+                        .to_owned()
+                ),
+            ]
+        );
+
+        // The manifest lists every file written.
+        let manifest = std::fs::read_dir(output_dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap())
+            .find(|entry| entry.file_name().to_string_lossy().starts_with('_'))
+            .unwrap();
+        let manifest: serde_json::Value =
+            serde_json::from_slice(&std::fs::read(manifest.path()).unwrap()).unwrap();
+        let manifest: Vec<_> = manifest["files"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|file| (file["partition"].clone(), file["num_rows"].clone()))
+            .collect();
+        assert_eq!(
+            manifest,
+            vec![
+                ("date=1970-01-01/key_bucket=0".into(), 1.into()),
+                ("date=1970-01-01/key_bucket=1".into(), 2.into()),
+                ("date=1970-01-02/key_bucket=0".into(), 1.into()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_write_partitioned_too_many_key_buckets() {
+        let output_dir = tempfile::tempdir().unwrap();
+        let destination = ObjectStoreDestination {
+            file_type: FileType::Csv as i32,
+            output_prefix_uri: format!("file://{}/", output_dir.path().display()),
+            output_paths: None,
+            partitioning: Some(Partitioning {
+                time_bucket: TimeBucket::Unspecified as i32,
+                key_buckets: MAX_KEY_BUCKETS + 1,
+            }),
+        };
+
+        let (progress_tx, _progress_rx) = tokio::sync::mpsc::channel(10);
+        let batches = futures::stream::iter(vec![sink_batch()]).boxed();
+        let error = write(
+            Arc::new(ObjectStoreRegistry::default()),
+            destination,
+            sink_schema(),
+            progress_tx,
+            batches,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            Error::InvalidKeyBuckets(1025)
+        ));
+
+        // Nothing is written.
+        assert_eq!(std::fs::read_dir(output_dir.path()).unwrap().count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_write_partitioned_closes_earlier_time_buckets() {
+        let output_dir = tempfile::tempdir().unwrap();
+        let destination = ObjectStoreDestination {
+            file_type: FileType::Csv as i32,
+            output_prefix_uri: format!("file://{}/", output_dir.path().display()),
+            output_paths: None,
+            partitioning: Some(Partitioning {
+                time_bucket: TimeBucket::Day as i32,
+                key_buckets: 2,
+            }),
+        };
+
+        // Write the rows for each day in separate batches.
+        let batch = sink_batch();
+        let batches = futures::stream::iter(vec![batch.slice(0, 3), batch.slice(3, 1)]).boxed();
+        let (progress_tx, mut progress_rx) = tokio::sync::mpsc::channel(10);
+        write(
+            Arc::new(ObjectStoreRegistry::default()),
+            destination,
+            sink_schema(),
+            progress_tx,
+            batches,
+        )
+        .await
+        .unwrap();
+
+        let mut updates = Vec::new();
+        while let Some(update) = progress_rx.recv().await {
+            match update {
+                ProgressUpdate::Output { num_rows } => updates.push(format!("{num_rows} rows")),
+                ProgressUpdate::FilesProduced { paths } => {
+                    updates.extend(paths.into_iter().map(|url| {
+                        let path = url.local_path().unwrap();
+                        let partition = path
+                            .parent()
+                            .unwrap()
+                            .strip_prefix(output_dir.path())
+                            .unwrap();
+                        partition.display().to_string()
+                    }))
+                }
+                _ => {}
+            }
+        }
+
+        // The files for the first day are reported once the second day starts,
+        // and each batch is reported once.
+        assert_eq!(
+            updates,
+            vec![
+                "3 rows",
+                "date=1970-01-01/key_bucket=0",
+                "date=1970-01-01/key_bucket=1",
+                "1 rows",
+                "date=1970-01-02/key_bucket=0",
+            ]
+        );
+    }
+
+    /// Write three copies of the sink batch to an unpartitioned destination.
+    ///
+    /// Returns the contents of each file produced, in order.
+    async fn write_unpartitioned_files(file_type: FileType) -> Vec<Vec<u8>> {
+        let output_dir = tempfile::tempdir().unwrap();
+        let destination = ObjectStoreDestination {
+            file_type: file_type as i32,
+            output_prefix_uri: format!("file://{}/", output_dir.path().display()),
+            output_paths: None,
+            partitioning: None,
+        };
+
+        let batches = futures::stream::iter(vec![sink_batch(), sink_batch(), sink_batch()]).boxed();
+        let (progress_tx, mut progress_rx) = tokio::sync::mpsc::channel(10);
+        write(
+            Arc::new(ObjectStoreRegistry::default()),
+            destination,
+            sink_schema(),
+            progress_tx,
+            batches,
+        )
+        .await
+        .unwrap();
+
+        let mut files = Vec::new();
+        while let Some(update) = progress_rx.recv().await {
+            if let ProgressUpdate::FilesProduced { paths } = update {
+                files.extend(
+                    paths
+                        .into_iter()
+                        .map(|url| std::fs::read(url.local_path().unwrap()).unwrap()),
+                );
+            }
+        }
+        files
+    }
+
+    #[tokio::test]
+    async fn test_write_arrow_file() {
+        // The first file rolls over after the second batch exceeds the
+        // rows per file, and the second file is finished when closed.
+        let files = write_unpartitioned_files(FileType::Arrow).await;
+        assert_eq!(files.len(), 2);
+
+        let mut num_rows = Vec::new();
+        for file in files {
+            assert!(file.starts_with(b"ARROW1"));
+            assert!(file.ends_with(b"ARROW1"), "missing file footer");
+
+            let reader = FileReader::try_new(Cursor::new(file), None).unwrap();
+            let batches: Vec<_> = reader.map(|batch| batch.unwrap()).collect();
+            assert!(batches.iter().all(|batch| batch == &sink_batch()));
+            num_rows.push(batches.iter().map(|batch| batch.num_rows()).sum::<usize>());
+        }
+        assert_eq!(num_rows, vec![8, 4]);
+    }
+
+    #[tokio::test]
+    async fn test_write_json() {
+        let files = write_unpartitioned_files(FileType::Json).await;
+        assert_eq!(files.len(), 2);
+
+        let lines: Vec<serde_json::Value> = files
+            .iter()
+            .flat_map(|file| std::str::from_utf8(file).unwrap().lines())
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 12);
+
+        // The `u64` columns are written as strings, so readers parsing
+        // numbers as doubles don't lose precision.
+        assert_eq!(lines[1]["_subsort"], serde_json::json!("1"));
+        assert_eq!(lines[1]["_key_hash"], serde_json::json!("58"));
+        assert_eq!(lines[1]["_key"], serde_json::json!("bob"));
+        assert_eq!(lines[1]["n"], serde_json::json!(2));
+    }
+
+    #[tokio::test]
+    async fn test_write_json_nested_u64() {
+        let record_fields = Fields::from(vec![
+            Field::new("id", DataType::UInt64, true),
+            Field::new("name", DataType::Utf8, true),
+        ]);
+        let ids = Field::new(
+            "ids",
+            DataType::List(Arc::new(Field::new("item", DataType::UInt64, true))),
+            true,
+        );
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("record", DataType::Struct(record_fields.clone()), true),
+            ids,
+        ]));
+
+        let mut ids = ListBuilder::new(UInt64Builder::new());
+        ids.append_value([Some(u64::MAX), Some(1)]);
+        ids.append(false);
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(StructArray::new(
+                    record_fields,
+                    vec![
+                        Arc::new(UInt64Array::from(vec![Some(u64::MAX), None])),
+                        Arc::new(StringArray::from(vec!["alice", "bob"])),
+                    ],
+                    None,
+                )),
+                Arc::new(ids.finish()),
+            ],
+        )
+        .unwrap();
+
+        let output_dir = tempfile::tempdir().unwrap();
+        let destination = ObjectStoreDestination {
+            file_type: FileType::Json as i32,
+            output_prefix_uri: format!("file://{}/", output_dir.path().display()),
+            output_paths: None,
+            partitioning: None,
+        };
+        let (progress_tx, mut progress_rx) = tokio::sync::mpsc::channel(10);
+        write(
+            Arc::new(ObjectStoreRegistry::default()),
+            destination,
+            schema,
+            progress_tx,
+            futures::stream::iter(vec![batch]).boxed(),
+        )
+        .await
+        .unwrap();
+
+        let mut lines = Vec::new();
+        while let Some(update) = progress_rx.recv().await {
+            if let ProgressUpdate::FilesProduced { paths } = update {
+                for url in paths {
+                    let file = std::fs::read_to_string(url.local_path().unwrap()).unwrap();
+                    lines.extend(
+                        file.lines()
+                            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap()),
+                    );
+                }
+            }
+        }
+
+        // Nested `u64` values are also written as strings.
+        assert_eq!(
+            lines,
+            vec![
+                serde_json::json!({
+                    "record": { "id": "18446744073709551615", "name": "alice" },
+                    "ids": ["18446744073709551615", "1"],
+                }),
+                serde_json::json!({ "record": { "name": "bob" } }),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_write_arrow_stream() {
+        let files = write_unpartitioned_files(FileType::ArrowStream).await;
+        assert_eq!(files.len(), 2);
+
+        let mut num_rows = Vec::new();
+        for file in files {
+            assert!(
+                file.ends_with(&[0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0]),
+                "missing end-of-stream marker"
+            );
+
+            let reader = StreamReader::try_new(Cursor::new(file), None).unwrap();
+            let batches: Vec<_> = reader.map(|batch| batch.unwrap()).collect();
+            assert!(batches.iter().all(|batch| batch == &sink_batch()));
+            num_rows.push(batches.iter().map(|batch| batch.num_rows()).sum::<usize>());
+        }
+        assert_eq!(num_rows, vec![8, 4]);
+    }
+}
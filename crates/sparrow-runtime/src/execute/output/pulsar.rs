@@ -214,7 +214,7 @@ async fn build_client(
 }
 
 // Drops columns to match the given output schema
-fn get_output_batch(
+pub(super) fn get_output_batch(
     output_schema: SchemaRef,
     batch: RecordBatch,
 ) -> error_stack::Result<RecordBatch, Error> {
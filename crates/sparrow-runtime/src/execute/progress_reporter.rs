@@ -148,6 +148,7 @@ impl ProgressTracker {
                     ObjectStoreDestination {
                         file_type: store.file_type,
                         output_prefix_uri: store.output_prefix_uri.clone(),
+                        partitioning: store.partitioning.clone(),
                         output_paths: Some(ResultPaths {
                             paths: self.output_paths.clone(),
                         }),
@@ -178,6 +179,14 @@ impl ProgressTracker {
                     })),
                 }))
             }
+            #[cfg(not(feature = "kafka"))]
+            Some(destination::Destination::Kafka(_)) => {
+                error_stack::bail!(Error::FeatureNotEnabled { feature: "kafka" })
+            }
+            #[cfg(feature = "kafka")]
+            Some(destination::Destination::Kafka(kafka)) => Ok(Some(Destination {
+                destination: Some(destination::Destination::Kafka(kafka.clone())),
+            })),
         }
     }
 }
@@ -30,6 +30,11 @@ pub enum Error {
     ReadSchema,
     #[display(fmt = "failed to get pulsar schema: {_0}")]
     PulsarSchema(String),
+    #[display(fmt = "failed to get kafka schema: {_0}")]
+    KafkaSchema(String),
+    #[allow(dead_code)]
+    #[display(fmt = "feature '{feature}' not enabled")]
+    FeatureNotEnabled { feature: String },
     #[display(fmt = "unsupport column detected: '{_0}")]
     UnsupportedColumn(String),
 }
@@ -59,6 +64,16 @@ pub struct PulsarMetadata {
     pub sparrow_metadata: RawMetadata,
 }
 
+/// For Kafka, we keep the information needed to decode the messages around
+/// for use by the consumer.
+#[cfg(feature = "kafka")]
+pub(crate) struct KafkaMetadata {
+    /// the schema and encoding of the messages in the topic
+    pub kafka_schema: streams::kafka::schema::KafkaSchema,
+    /// schema used by Sparrow
+    pub sparrow_metadata: RawMetadata,
+}
+
 impl RawMetadata {
     pub async fn try_from(
         source: &Source,
@@ -75,6 +90,19 @@ impl RawMetadata {
                 let string_reader = BufReader::new(Cursor::new(content));
                 Self::try_from_csv_reader(string_reader)
             }
+            source_data::Source::JsonPath(path) => {
+                Self::try_from_json(path, object_store_registry).await
+            }
+            source_data::Source::JsonData(content) => {
+                let string_reader = BufReader::new(Cursor::new(content));
+                Self::try_from_json_reader(string_reader)
+            }
+            source_data::Source::AvroPath(path) => {
+                Self::try_from_avro(path, object_store_registry).await
+            }
+            source_data::Source::ArrowPath(path) => {
+                Self::try_from_arrow(path, object_store_registry).await
+            }
         }
     }
 
@@ -94,8 +122,18 @@ impl RawMetadata {
             .sparrow_metadata)
     }
 
+    #[cfg(feature = "kafka")]
+    pub async fn try_from_kafka_subscription(
+        config: &KafkaConfig,
+    ) -> error_stack::Result<Self, Error> {
+        Ok(Self::try_from_kafka(config).await?.sparrow_metadata)
+    }
+
+    #[cfg(not(feature = "kafka"))]
     pub async fn try_from_kafka_subscription(_: &KafkaConfig) -> error_stack::Result<Self, Error> {
-        todo!()
+        error_stack::bail!(Error::FeatureNotEnabled {
+            feature: "kafka".to_owned()
+        })
     }
 
     /// Create `RawMetadata` from a raw schema.
@@ -158,6 +196,107 @@ impl RawMetadata {
         }
     }
 
+    /// Create a `RawMetadata` from a newline-delimited JSON string path and
+    /// object store registry.
+    ///
+    /// As with CSV, this needs to download a local copy of the file to infer
+    /// the schema.
+    async fn try_from_json(
+        path: &str,
+        object_stores: &ObjectStoreRegistry,
+    ) -> error_stack::Result<Self, Error> {
+        let object_store_url = ObjectStoreUrl::from_str(path)
+            .change_context_lazy(|| Error::ObjectStore(path.to_owned()))?;
+
+        if let Some(local_path) = object_store_url.local_path() {
+            let file = file_from_path(local_path)
+                .into_report()
+                .change_context_lazy(|| Error::LocalFile)?;
+            Self::try_from_json_reader(BufReader::new(file))
+        } else {
+            let download_file = NamedTempFile::new()
+                .into_report()
+                .change_context_lazy(|| Error::Download)?;
+            object_stores
+                .download(object_store_url, download_file.path())
+                .await
+                .change_context_lazy(|| Error::Download)?;
+            Self::try_from_json_reader(BufReader::new(download_file))
+        }
+    }
+
+    /// Create a `RawMetadata` from an Avro object container file path and
+    /// object store registry.
+    ///
+    /// The schema is read from the header of the file, which currently
+    /// requires downloading a local copy of the file.
+    #[cfg(feature = "avro")]
+    async fn try_from_avro(
+        path: &str,
+        object_stores: &ObjectStoreRegistry,
+    ) -> error_stack::Result<Self, Error> {
+        let object_store_url = ObjectStoreUrl::from_str(path)
+            .change_context_lazy(|| Error::ObjectStore(path.to_owned()))?;
+
+        let reader = if let Some(local_path) = object_store_url.local_path() {
+            let file = file_from_path(local_path)
+                .into_report()
+                .change_context_lazy(|| Error::LocalFile)?;
+            crate::prepare::avro_reader::AvroReader::try_new(BufReader::new(file), 1)
+        } else {
+            let download_file = NamedTempFile::new()
+                .into_report()
+                .change_context_lazy(|| Error::Download)?;
+            object_stores
+                .download(object_store_url, download_file.path())
+                .await
+                .change_context_lazy(|| Error::Download)?;
+            crate::prepare::avro_reader::AvroReader::try_new(BufReader::new(download_file), 1)
+        };
+        let raw_schema = reader.change_context(Error::ReadSchema)?.schema();
+        Self::from_raw_schema(raw_schema)
+    }
+
+    #[cfg(not(feature = "avro"))]
+    async fn try_from_avro(
+        _path: &str,
+        _object_stores: &ObjectStoreRegistry,
+    ) -> error_stack::Result<Self, Error> {
+        error_stack::bail!(Error::FeatureNotEnabled {
+            feature: "avro".to_owned()
+        })
+    }
+
+    /// Create a `RawMetadata` from an Arrow IPC (file or stream format) path
+    /// and object store registry.
+    ///
+    /// As with Avro, the schema is read from the local copy of the file.
+    async fn try_from_arrow(
+        path: &str,
+        object_stores: &ObjectStoreRegistry,
+    ) -> error_stack::Result<Self, Error> {
+        let object_store_url = ObjectStoreUrl::from_str(path)
+            .change_context_lazy(|| Error::ObjectStore(path.to_owned()))?;
+
+        let reader = if let Some(local_path) = object_store_url.local_path() {
+            let file = file_from_path(local_path)
+                .into_report()
+                .change_context_lazy(|| Error::LocalFile)?;
+            crate::prepare::arrow_ipc::ArrowIpcReader::try_new(file)
+        } else {
+            let download_file = NamedTempFile::new()
+                .into_report()
+                .change_context_lazy(|| Error::Download)?;
+            object_stores
+                .download(object_store_url, download_file.path())
+                .await
+                .change_context_lazy(|| Error::Download)?;
+            crate::prepare::arrow_ipc::ArrowIpcReader::try_new(download_file)
+        };
+        let raw_schema = reader.change_context(Error::ReadSchema)?.schema();
+        Self::from_raw_schema(raw_schema)
+    }
+
     /// Create a `RawMetadata` from a Pulsar topic.
     pub(crate) async fn try_from_pulsar(
         config: &PulsarConfig,
@@ -199,6 +338,23 @@ impl RawMetadata {
         })
     }
 
+    /// Create a `RawMetadata` from a Kafka topic.
+    #[cfg(feature = "kafka")]
+    pub(crate) async fn try_from_kafka(
+        config: &KafkaConfig,
+    ) -> error_stack::Result<KafkaMetadata, Error> {
+        let kafka_schema = streams::kafka::schema::get_kafka_schema(config)
+            .await
+            .change_context_lazy(|| Error::KafkaSchema("unable to get schema".to_owned()))?;
+
+        tracing::debug!("kafka schema fields: {:?}", kafka_schema.schema.fields);
+        let sparrow_metadata = Self::from_raw_schema(Arc::new(kafka_schema.schema.clone()))?;
+        Ok(KafkaMetadata {
+            kafka_schema,
+            sparrow_metadata,
+        })
+    }
+
     /// Create a `RawMetadata` from a reader of a CSV file or string.
     fn try_from_csv_reader<R>(reader: R) -> error_stack::Result<Self, Error>
     where
@@ -212,6 +368,18 @@ impl RawMetadata {
 
         Self::from_raw_schema(Arc::new(raw_schema))
     }
+
+    /// Create a `RawMetadata` from a reader of newline-delimited JSON.
+    fn try_from_json_reader<R>(reader: R) -> error_stack::Result<Self, Error>
+    where
+        R: std::io::BufRead,
+    {
+        let raw_schema = arrow_json::reader::infer_json_schema(reader, None)
+            .into_report()
+            .change_context(Error::ReadSchema)?;
+
+        Self::from_raw_schema(Arc::new(raw_schema))
+    }
 }
 
 /// Converts the schema to a table schema
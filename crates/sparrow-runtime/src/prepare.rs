@@ -2,6 +2,7 @@ use std::io::{BufReader, Cursor};
 use std::str::FromStr;
 use std::sync::Arc;
 
+use arrow::datatypes::SchemaRef;
 use arrow::record_batch::RecordBatch;
 use error_stack::{IntoReport, IntoReportCompat, ResultExt};
 use futures::stream::{BoxStream, FuturesUnordered};
@@ -10,10 +11,15 @@ use object_store::ObjectStore;
 use sparrow_api::kaskada::v1alpha::{
     slice_plan, source_data, PreparedFile, SourceData, TableConfig,
 };
+use tempfile::NamedTempFile;
 
+pub(crate) mod arrow_ipc;
+#[cfg(feature = "avro")]
+pub(crate) mod avro_reader;
 mod column_behavior;
 mod error;
 pub(crate) mod execute_input_stream;
+mod json_lines;
 mod prepare_input_stream;
 mod prepare_metadata;
 pub mod preparer;
@@ -79,34 +85,11 @@ pub async fn prepared_batches<'a>(
                 .change_context(Error::CreateReader)?
             }
             source_data::Source::CsvPath(source) => {
-                let url = ObjectStoreUrl::from_str(source)
-                    .change_context(Error::InvalidUrl(source.to_owned()))?;
-                let local_file = tempfile::Builder::new()
-                    .suffix(".csv")
-                    .tempfile()
-                    .into_report()
-                    .change_context(Error::CreateReader)?;
-
-                // Get the prepare hash. This could be cleaned up if we had a better wrapper
-                // around the object stores.
-                let object_store = object_stores
-                    .object_store(&url)
-                    .change_context(Error::CreateReader)?;
-                let location = url.path().change_context(Error::CreateReader)?;
-                let object_meta = object_store
-                    .head(&location)
-                    .await
-                    .into_report()
-                    .change_context(Error::CreateReader)?;
-                let prepare_hash = object_meta.etag_hash();
-
                 // For CSV we need to download the file (for now) to perform inference.
                 // We could improve this by looking at the size and creating an in-memory
                 // buffer and/or looking at a prefix of the file...
-                object_stores
-                    .download(url, local_file.path())
-                    .await
-                    .change_context(Error::DownloadingObject)?;
+                let (local_file, prepare_hash) =
+                    download_source(object_stores, source, ".csv").await?;
 
                 // Transfer the local file to the reader. When the CSV reader
                 // completes the reader will be dropped, and the file deleted.
@@ -120,12 +103,87 @@ pub async fn prepared_batches<'a>(
                 let reader = BufReader::new(content);
                 reader_from_csv(config, reader, prepare_hash, slice).await?
             }
+            source_data::Source::JsonPath(source) => {
+                // As with CSV, we download the file (for now) in case the
+                // schema needs to be inferred.
+                let (local_file, prepare_hash) =
+                    download_source(object_stores, source, ".jsonl").await?;
+
+                let reader = BufReader::new(local_file);
+                let schema = declared_schema(source_data)?;
+                reader_from_json(config, reader, schema, prepare_hash, slice).await?
+            }
+            source_data::Source::JsonData(content) => {
+                let prepare_hash = DETERMINISTIC_RUNTIME_HASHER.hash_one(content);
+
+                let content = Cursor::new(content.to_string());
+                let reader = BufReader::new(content);
+                let schema = declared_schema(source_data)?;
+                reader_from_json(config, reader, schema, prepare_hash, slice).await?
+            }
+            source_data::Source::AvroPath(source) => {
+                // The Avro reader is synchronous, so we download the file and
+                // read the blocks from the local copy.
+                // TODO: Read the blocks from the object store stream instead,
+                // so large files don't need to be copied to local disk first.
+                let (local_file, prepare_hash) =
+                    download_source(object_stores, source, ".avro").await?;
+
+                let reader = BufReader::new(local_file);
+                reader_from_avro(config, reader, prepare_hash, slice).await?
+            }
+            source_data::Source::ArrowPath(source) => {
+                // The IPC file format requires random access to read the
+                // footer, so we download the file and read the local copy.
+                let (local_file, prepare_hash) =
+                    download_source(object_stores, source, ".arrow").await?;
+
+                reader_from_arrow(config, local_file, prepare_hash, slice).await?
+            }
         },
     };
 
     Ok(prepare_iter)
 }
 
+/// Download the object at `source` to a local temporary file.
+///
+/// Returns the file, which is deleted when dropped, and the prepare hash of
+/// the object.
+async fn download_source(
+    object_stores: &ObjectStoreRegistry,
+    source: &str,
+    suffix: &str,
+) -> error_stack::Result<(NamedTempFile, u64), Error> {
+    let url =
+        ObjectStoreUrl::from_str(source).change_context(Error::InvalidUrl(source.to_owned()))?;
+    let local_file = tempfile::Builder::new()
+        .suffix(suffix)
+        .tempfile()
+        .into_report()
+        .change_context(Error::CreateReader)?;
+
+    // Get the prepare hash. This could be cleaned up if we had a better wrapper
+    // around the object stores.
+    let object_store = object_stores
+        .object_store(&url)
+        .change_context(Error::CreateReader)?;
+    let location = url.path().change_context(Error::CreateReader)?;
+    let object_meta = object_store
+        .head(&location)
+        .await
+        .into_report()
+        .change_context(Error::CreateReader)?;
+    let prepare_hash = object_meta.etag_hash();
+
+    object_stores
+        .download(url, local_file.path())
+        .await
+        .change_context(Error::DownloadingObject)?;
+
+    Ok((local_file, prepare_hash))
+}
+
 pub async fn prepare_file(
     object_stores: &ObjectStoreRegistry,
     source_data: &SourceData,
@@ -277,6 +335,107 @@ async fn reader_from_csv<'a, R: std::io::Read + std::io::Seek + Send + 'static>(
         .change_context(Error::CreateReader)
 }
 
+/// Return the declared schema of the source data, if any.
+fn declared_schema(source_data: &SourceData) -> error_stack::Result<Option<SchemaRef>, Error> {
+    let Some(schema) = source_data.schema.as_ref() else {
+        return Ok(None);
+    };
+    let schema = schema
+        .as_arrow_schema()
+        .into_report()
+        .change_context(Error::ReadSchema)?;
+    Ok(Some(Arc::new(schema)))
+}
+
+/// Prepare newline-delimited JSON.
+///
+/// If no schema is declared it is inferred from the contents, after which
+/// the reader is rewound to read the batches.
+async fn reader_from_json<'a, R: std::io::BufRead + std::io::Seek + Send + 'static>(
+    config: &'a TableConfig,
+    mut reader: R,
+    schema: Option<SchemaRef>,
+    prepare_hash: u64,
+    slice: &'a Option<slice_plan::Slice>,
+) -> error_stack::Result<BoxStream<'a, error_stack::Result<(RecordBatch, RecordBatch), Error>>, Error>
+{
+    let raw_schema = match schema {
+        Some(schema) => schema,
+        None => {
+            let position = reader
+                .stream_position()
+                .into_report()
+                .change_context(Error::Internal)?;
+            let schema = json_lines::infer_json_schema(&mut reader)?;
+
+            reader
+                .seek(std::io::SeekFrom::Start(position))
+                .into_report()
+                .change_context(Error::Internal)?;
+            schema
+        }
+    };
+    let reader = json_lines::JsonLinesReader::try_new(reader, raw_schema.clone(), BATCH_SIZE)?;
+    let raw_metadata =
+        RawMetadata::from_raw_schema(raw_schema).change_context(Error::ReadSchema)?;
+    let reader = futures::stream::iter(reader).boxed();
+
+    prepare_input_stream::prepare_input(reader, config, raw_metadata, prepare_hash, slice)
+        .await
+        .into_report()
+        .change_context(Error::CreateReader)
+}
+
+/// Prepare an Avro object container file.
+#[cfg(feature = "avro")]
+async fn reader_from_avro<'a, R: std::io::Read + Send + 'static>(
+    config: &'a TableConfig,
+    reader: R,
+    prepare_hash: u64,
+    slice: &'a Option<slice_plan::Slice>,
+) -> error_stack::Result<BoxStream<'a, error_stack::Result<(RecordBatch, RecordBatch), Error>>, Error>
+{
+    let reader = avro_reader::AvroReader::try_new(reader, BATCH_SIZE)?;
+    let raw_metadata =
+        RawMetadata::from_raw_schema(reader.schema()).change_context(Error::ReadSchema)?;
+    let reader = futures::stream::iter(reader).boxed();
+
+    prepare_input_stream::prepare_input(reader, config, raw_metadata, prepare_hash, slice)
+        .await
+        .into_report()
+        .change_context(Error::CreateReader)
+}
+
+/// Prepare Arrow IPC data, in either the file or stream format.
+async fn reader_from_arrow<'a, R: std::io::Read + std::io::Seek + Send + 'static>(
+    config: &'a TableConfig,
+    reader: R,
+    prepare_hash: u64,
+    slice: &'a Option<slice_plan::Slice>,
+) -> error_stack::Result<BoxStream<'a, error_stack::Result<(RecordBatch, RecordBatch), Error>>, Error>
+{
+    let reader = arrow_ipc::ArrowIpcReader::try_new(reader)?;
+    let raw_metadata =
+        RawMetadata::from_raw_schema(reader.schema()).change_context(Error::ReadSchema)?;
+    let reader = futures::stream::iter(reader).boxed();
+
+    prepare_input_stream::prepare_input(reader, config, raw_metadata, prepare_hash, slice)
+        .await
+        .into_report()
+        .change_context(Error::CreateReader)
+}
+
+#[cfg(not(feature = "avro"))]
+async fn reader_from_avro<'a, R: std::io::Read + Send + 'static>(
+    _config: &'a TableConfig,
+    _reader: R,
+    _prepare_hash: u64,
+    _slice: &'a Option<slice_plan::Slice>,
+) -> error_stack::Result<BoxStream<'a, error_stack::Result<(RecordBatch, RecordBatch), Error>>, Error>
+{
+    error_stack::bail!(Error::FeatureNotEnabled { feature: "avro" })
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
@@ -353,6 +512,7 @@ mod tests {
             source_data::Source::ParquetPath(format!("file:///{}", input_path.display()));
         let source_data = SourceData {
             source: Some(input_path),
+            schema: None,
         };
 
         let table_config = TableConfig::new_with_table_source(
@@ -388,6 +548,7 @@ mod tests {
         let input_path = source_data::Source::CsvPath(format!("file:///{}", input_path.display()));
         let source_data = SourceData {
             source: Some(input_path),
+            schema: None,
         };
 
         let table_config = TableConfig::new_with_table_source(
@@ -415,6 +576,301 @@ mod tests {
         let _metadata_schema = metadata.schema();
     }
 
+    #[tokio::test]
+    async fn test_prepare_csv_data_invalid_row() {
+        // The time column is inferred as a timestamp, but the second row has
+        // a date which doesn't exist.
+        let source_data = SourceData {
+            source: Some(source_data::Source::CsvData(
+                [
+                    "time,key,n",
+                    "2023-01-01T00:00:00,a,1",
+                    "2023-02-30T00:00:00,b,2",
+                ]
+                .join("\n"),
+            )),
+            schema: None,
+        };
+
+        let table_config =
+            TableConfig::new_with_table_source("Csv", &Uuid::new_v4(), "time", None, "key", "");
+
+        let prepared_batches = super::prepared_batches(
+            &ObjectStoreRegistry::default(),
+            &source_data,
+            &table_config,
+            &None,
+        )
+        .await
+        .unwrap()
+        .collect::<Vec<_>>()
+        .await;
+        assert_eq!(prepared_batches.len(), 1);
+        let error = prepared_batches[0].as_ref().unwrap_err();
+        assert_eq!(error.current_context().to_string(), "reading batch");
+    }
+
+    #[tokio::test]
+    async fn test_prepare_json_data() {
+        let source_data = SourceData {
+            source: Some(source_data::Source::JsonData(
+                [
+                    r#"{"time": "2023-01-02T00:00:00Z", "key": "b", "n": 2}"#,
+                    r#"{"time": "2023-01-01T00:00:00Z", "key": "a", "n": 1}"#,
+                    "",
+                    r#"{"time": "2023-01-03T00:00:00Z", "key": "a"}"#,
+                ]
+                .join("\n"),
+            )),
+            schema: None,
+        };
+
+        let table_config =
+            TableConfig::new_with_table_source("Json", &Uuid::new_v4(), "time", None, "key", "");
+
+        let prepared_batches = super::prepared_batches(
+            &ObjectStoreRegistry::default(),
+            &source_data,
+            &table_config,
+            &None,
+        )
+        .await
+        .unwrap()
+        .collect::<Vec<_>>()
+        .await;
+        assert_eq!(prepared_batches.len(), 1);
+        let (prepared_batch, metadata) = prepared_batches[0].as_ref().unwrap();
+        assert_eq!(prepared_batch.num_rows(), 3);
+        assert_metadata_schema_eq(metadata.schema());
+        assert_eq!(metadata.num_rows(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_prepare_json_data_malformed_line() {
+        let source_data = SourceData {
+            source: Some(source_data::Source::JsonData(
+                [
+                    r#"{"time": "2023-01-01T00:00:00Z", "key": "a", "n": 1}"#,
+                    r#"{"time": "2023-01-02T00:00:00Z", "key": "b", "n": 2"#,
+                ]
+                .join("\n"),
+            )),
+            schema: None,
+        };
+
+        let table_config =
+            TableConfig::new_with_table_source("Json", &Uuid::new_v4(), "time", None, "key", "");
+
+        let error = match super::prepared_batches(
+            &ObjectStoreRegistry::default(),
+            &source_data,
+            &table_config,
+            &None,
+        )
+        .await
+        {
+            Ok(_) => panic!("expected malformed JSON to fail"),
+            Err(error) => error,
+        };
+        assert_eq!(
+            error.current_context().to_string(),
+            "malformed JSON on line 2"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_prepare_json_data_declared_schema() {
+        // Without the declared schema, `n` would be inferred as an integer.
+        let schema = Schema::new(vec![
+            Field::new("time", DataType::Utf8, false),
+            Field::new("key", DataType::Utf8, false),
+            Field::new("n", DataType::Float64, true),
+        ]);
+        let source_data = SourceData {
+            source: Some(source_data::Source::JsonData(
+                [
+                    r#"{"time": "2023-01-02T00:00:00Z", "key": "b", "n": 2}"#,
+                    r#"{"time": "2023-01-01T00:00:00Z", "key": "a", "n": 1}"#,
+                ]
+                .join("\n"),
+            )),
+            schema: Some((&schema).try_into().unwrap()),
+        };
+
+        let table_config =
+            TableConfig::new_with_table_source("Json", &Uuid::new_v4(), "time", None, "key", "");
+
+        let prepared_batches = super::prepared_batches(
+            &ObjectStoreRegistry::default(),
+            &source_data,
+            &table_config,
+            &None,
+        )
+        .await
+        .unwrap()
+        .collect::<Vec<_>>()
+        .await;
+        assert_eq!(prepared_batches.len(), 1);
+        let (prepared_batch, _) = prepared_batches[0].as_ref().unwrap();
+        assert_eq!(prepared_batch.num_rows(), 2);
+        assert_eq!(
+            prepared_batch
+                .schema()
+                .field_with_name("n")
+                .unwrap()
+                .data_type(),
+            &DataType::Float64
+        );
+    }
+
+    #[tokio::test]
+    async fn test_prepare_json_data_declared_schema_malformed_line() {
+        // With a declared schema, malformed lines are found when reading the
+        // batches rather than when inferring the schema.
+        let schema = Schema::new(vec![
+            Field::new("time", DataType::Utf8, false),
+            Field::new("key", DataType::Utf8, false),
+            Field::new("n", DataType::Int64, true),
+        ]);
+        let source_data = SourceData {
+            source: Some(source_data::Source::JsonData(
+                [
+                    r#"{"time": "2023-01-01T00:00:00Z", "key": "a", "n": 1}"#,
+                    r#"{"time": "2023-01-02T00:00:00Z", "key": "b", "n": 2"#,
+                ]
+                .join("\n"),
+            )),
+            schema: Some((&schema).try_into().unwrap()),
+        };
+
+        let table_config =
+            TableConfig::new_with_table_source("Json", &Uuid::new_v4(), "time", None, "key", "");
+
+        let prepared_batches = super::prepared_batches(
+            &ObjectStoreRegistry::default(),
+            &source_data,
+            &table_config,
+            &None,
+        )
+        .await
+        .unwrap()
+        .collect::<Vec<_>>()
+        .await;
+        assert_eq!(prepared_batches.len(), 1);
+        let error = prepared_batches[0].as_ref().unwrap_err();
+        assert_eq!(
+            error.current_context().to_string(),
+            "malformed JSON on line 2"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_prepare_arrow_stream() {
+        use arrow::array::{ArrayRef, Int64Array, StringArray, TimestampNanosecondArray};
+        use arrow::record_batch::RecordBatch;
+
+        let time: ArrayRef = Arc::new(TimestampNanosecondArray::from(vec![3, 1, 2]));
+        let key: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "a"]));
+        let n: ArrayRef = Arc::new(Int64Array::from(vec![Some(5), None, Some(7)]));
+        let batch = RecordBatch::try_from_iter([("time", time), ("key", key), ("n", n)]).unwrap();
+
+        let mut writer =
+            arrow::ipc::writer::StreamWriter::try_new(Vec::new(), &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        let input_file = tempfile::Builder::new()
+            .suffix(".arrows")
+            .tempfile()
+            .unwrap();
+        std::fs::write(input_file.path(), writer.into_inner().unwrap()).unwrap();
+
+        let source_data = SourceData {
+            source: Some(source_data::Source::ArrowPath(format!(
+                "file:///{}",
+                input_file.path().display()
+            ))),
+            schema: None,
+        };
+        let table_config =
+            TableConfig::new_with_table_source("Arrow", &Uuid::new_v4(), "time", None, "key", "");
+
+        let prepared_batches = super::prepared_batches(
+            &ObjectStoreRegistry::default(),
+            &source_data,
+            &table_config,
+            &None,
+        )
+        .await
+        .unwrap()
+        .collect::<Vec<_>>()
+        .await;
+        assert_eq!(prepared_batches.len(), 1);
+        let (prepared_batch, metadata) = prepared_batches[0].as_ref().unwrap();
+        assert_eq!(prepared_batch.num_rows(), 3);
+        assert_metadata_schema_eq(metadata.schema());
+        assert_eq!(metadata.num_rows(), 2);
+    }
+
+    #[cfg(feature = "avro")]
+    #[tokio::test]
+    async fn test_prepare_avro() {
+        use avro_rs::types::Value;
+
+        let schema = avro_rs::Schema::parse_str(
+            r#"{
+                "type": "record",
+                "name": "Event",
+                "fields": [
+                    {"name": "time", "type": {"type": "long", "logicalType": "timestamp-micros"}},
+                    {"name": "key", "type": "string"},
+                    {"name": "n", "type": ["null", "long"]}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let mut writer = avro_rs::Writer::new(&schema, Vec::new());
+        for (time, key, n) in [
+            (3, "a", Value::Long(5)),
+            (1, "b", Value::Null),
+            (2, "a", Value::Long(7)),
+        ] {
+            writer
+                .append(Value::Record(vec![
+                    ("time".to_owned(), Value::TimestampMicros(time)),
+                    ("key".to_owned(), Value::String(key.to_owned())),
+                    ("n".to_owned(), Value::Union(Box::new(n))),
+                ]))
+                .unwrap();
+        }
+        let input_file = tempfile::Builder::new().suffix(".avro").tempfile().unwrap();
+        std::fs::write(input_file.path(), writer.into_inner().unwrap()).unwrap();
+
+        let source_data = SourceData {
+            source: Some(source_data::Source::AvroPath(format!(
+                "file:///{}",
+                input_file.path().display()
+            ))),
+            schema: None,
+        };
+        let table_config =
+            TableConfig::new_with_table_source("Avro", &Uuid::new_v4(), "time", None, "key", "");
+
+        let prepared_batches = super::prepared_batches(
+            &ObjectStoreRegistry::default(),
+            &source_data,
+            &table_config,
+            &None,
+        )
+        .await
+        .unwrap()
+        .collect::<Vec<_>>()
+        .await;
+        assert_eq!(prepared_batches.len(), 1);
+        let (prepared_batch, metadata) = prepared_batches[0].as_ref().unwrap();
+        assert_eq!(prepared_batch.num_rows(), 3);
+        assert_metadata_schema_eq(metadata.schema());
+        assert_eq!(metadata.num_rows(), 2);
+    }
+
     #[tokio::test]
     async fn test_preparation_single_entity_key_slicing() {
         let entity_keys = vec!["0b00083c-5c1e-47f5-abba-f89b12ae3cf4".to_owned()];
@@ -447,6 +903,7 @@ mod tests {
             source_data::Source::ParquetPath(format!("file:///{}", input_path.display()));
         let source_data = SourceData {
             source: Some(input_path),
+            schema: None,
         };
 
         let table_config = TableConfig::new_with_table_source(
@@ -490,6 +947,7 @@ mod tests {
             source_data::Source::ParquetPath(format!("file:///{}", input_path.display()));
         let source_data = SourceData {
             source: Some(input_path),
+            schema: None,
         };
 
         let table_config = TableConfig::new_with_table_source(
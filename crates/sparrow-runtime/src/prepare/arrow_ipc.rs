@@ -0,0 +1,131 @@
+use std::io::{BufReader, Read, Seek, SeekFrom};
+
+use arrow::datatypes::SchemaRef;
+use arrow::ipc::reader::{FileReader, StreamReader};
+use arrow::record_batch::RecordBatch;
+use error_stack::{IntoReport, ResultExt};
+
+use crate::prepare::Error;
+
+/// The magic bytes at the start (and end) of the Arrow IPC file format.
+const ARROW_MAGIC: &[u8; 6] = b"ARROW1";
+
+/// Reads batches from Arrow IPC data.
+///
+/// Both the IPC file format (random access, with a footer) and the IPC
+/// stream format are supported. The format is detected from the leading
+/// magic bytes, so the same path may refer to either.
+///
+/// The IPC readers buffer their input, so `R` need not be buffered.
+pub(crate) enum ArrowIpcReader<R: Read + Seek> {
+    File(FileReader<R>),
+    Stream(StreamReader<BufReader<R>>),
+}
+
+impl<R: Read + Seek> ArrowIpcReader<R> {
+    pub(crate) fn try_new(mut reader: R) -> error_stack::Result<Self, Error> {
+        let position = reader
+            .stream_position()
+            .into_report()
+            .change_context(Error::Internal)?;
+        let mut magic = [0; ARROW_MAGIC.len()];
+        let is_file = match reader.read_exact(&mut magic) {
+            Ok(()) => &magic == ARROW_MAGIC,
+            // Too short to be the file format. Let the stream reader report
+            // any problems.
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => false,
+            Err(e) => return Err(e).into_report().change_context(Error::CreateReader),
+        };
+        reader
+            .seek(SeekFrom::Start(position))
+            .into_report()
+            .change_context(Error::Internal)?;
+
+        let reader = if is_file {
+            Self::File(
+                FileReader::try_new(reader, None)
+                    .into_report()
+                    .change_context(Error::CreateReader)?,
+            )
+        } else {
+            Self::Stream(
+                StreamReader::try_new(reader, None)
+                    .into_report()
+                    .change_context(Error::CreateReader)?,
+            )
+        };
+        Ok(reader)
+    }
+
+    pub(crate) fn schema(&self) -> SchemaRef {
+        match self {
+            Self::File(reader) => reader.schema(),
+            Self::Stream(reader) => reader.schema(),
+        }
+    }
+}
+
+impl<R: Read + Seek> Iterator for ArrowIpcReader<R> {
+    type Item = error_stack::Result<RecordBatch, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let batch = match self {
+            Self::File(reader) => reader.next(),
+            Self::Stream(reader) => reader.next(),
+        }?;
+        Some(batch.into_report().change_context(Error::ReadingBatch))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::sync::Arc;
+
+    use arrow::array::{ArrayRef, Int64Array, StringArray};
+    use arrow::ipc::writer::{FileWriter, StreamWriter};
+
+    use super::*;
+
+    fn batch() -> RecordBatch {
+        let key: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "a"]));
+        let n: ArrayRef = Arc::new(Int64Array::from(vec![Some(1), None, Some(3)]));
+        RecordBatch::try_from_iter([("key", key), ("n", n)]).unwrap()
+    }
+
+    #[test]
+    fn test_read_ipc_file() {
+        let batch = batch();
+        let mut writer = FileWriter::try_new(Vec::new(), &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        let bytes = writer.into_inner().unwrap();
+
+        let reader = ArrowIpcReader::try_new(Cursor::new(bytes)).unwrap();
+        assert!(matches!(reader, ArrowIpcReader::File(_)));
+        assert_eq!(reader.schema(), batch.schema());
+        let batches: Vec<_> = reader.collect::<Result<_, _>>().unwrap();
+        assert_eq!(batches, vec![batch]);
+    }
+
+    #[test]
+    fn test_read_ipc_stream() {
+        let batch = batch();
+        let mut writer = StreamWriter::try_new(Vec::new(), &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        let bytes = writer.into_inner().unwrap();
+
+        let reader = ArrowIpcReader::try_new(Cursor::new(bytes)).unwrap();
+        assert!(matches!(reader, ArrowIpcReader::Stream(_)));
+        assert_eq!(reader.schema(), batch.schema());
+        let batches: Vec<_> = reader.collect::<Result<_, _>>().unwrap();
+        assert_eq!(batches, vec![batch]);
+    }
+
+    #[test]
+    fn test_read_invalid_ipc() {
+        let error = ArrowIpcReader::try_new(Cursor::new(b"not arrow".to_vec()))
+            .err()
+            .unwrap();
+        assert!(matches!(error.current_context(), Error::CreateReader));
+    }
+}
@@ -0,0 +1,148 @@
+use std::io::Read;
+use std::sync::Arc;
+
+use arrow::datatypes::{Schema, SchemaRef};
+use arrow::record_batch::RecordBatch;
+use avro_rs::types::Value;
+use error_stack::{IntoReport, ResultExt};
+use itertools::Itertools;
+
+use crate::prepare::Error;
+
+/// Reads batches from an Avro object container file.
+///
+/// Records are decoded from `R` as batches are requested, so only the current
+/// batch is held in memory. Reading from `R` is synchronous; when preparing
+/// from an object store the file is first downloaded to a local copy.
+pub(crate) struct AvroReader<R> {
+    reader: avro_rs::Reader<'static, R>,
+    schema: SchemaRef,
+    batch_size: usize,
+}
+
+impl<R: Read> AvroReader<R> {
+    /// Create a reader, reading the header (and schema) of the file.
+    pub(crate) fn try_new(reader: R, batch_size: usize) -> error_stack::Result<Self, Error> {
+        let reader = avro_rs::Reader::new(reader)
+            .into_report()
+            .change_context(Error::CreateReader)?;
+        let schema = arrow_schema(reader.writer_schema())?;
+        Ok(Self {
+            reader,
+            schema: Arc::new(schema),
+            batch_size,
+        })
+    }
+
+    pub(crate) fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
+/// Convert the schema of the file to Arrow.
+///
+/// The `avro_rs` schema is converted to an `avro_schema` schema by way of
+/// JSON, so that the conversion used for Pulsar may be re-used.
+fn arrow_schema(schema: &avro_rs::Schema) -> error_stack::Result<Schema, Error> {
+    let json = serde_json::to_value(schema)
+        .into_report()
+        .change_context(Error::ReadSchema)?;
+    let avro_schema: avro_schema::schema::Schema = serde_json::from_value(json)
+        .into_report()
+        .change_context(Error::ReadSchema)
+        .attach_printable_lazy(|| format!("unsupported Avro schema: {schema:?}"))?;
+    sparrow_arrow::avro::from_avro_schema(&avro_schema).change_context(Error::ReadSchema)
+}
+
+impl<R: Read> Iterator for AvroReader<R> {
+    type Item = error_stack::Result<RecordBatch, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let records: Vec<Value> = match self.reader.by_ref().take(self.batch_size).try_collect() {
+            Ok(records) => records,
+            Err(e) => return Some(Err(e).into_report().change_context(Error::ReadingBatch)),
+        };
+        if records.is_empty() {
+            return None;
+        }
+
+        Some(
+            sparrow_arrow::avro::avro_records_to_batch(self.schema.clone(), &records)
+                .into_report()
+                .change_context(Error::ReadingBatch),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use arrow::array::{AsArray, Int64Array};
+    use arrow::datatypes::{DataType, Field, Int64Type, TimeUnit};
+
+    use super::*;
+
+    fn avro_file() -> Vec<u8> {
+        let schema = avro_rs::Schema::parse_str(
+            r#"{
+                "type": "record",
+                "name": "Event",
+                "fields": [
+                    {"name": "time", "type": {"type": "long", "logicalType": "timestamp-millis"}},
+                    {"name": "key", "type": "string"},
+                    {"name": "n", "type": ["null", "long"]}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let mut writer = avro_rs::Writer::new(&schema, Vec::new());
+        for (time, key, n) in [
+            (1000, "a", Some(5)),
+            (2000, "b", None),
+            (3000, "a", Some(7)),
+        ] {
+            let n = match n {
+                Some(n) => Value::Union(Box::new(Value::Long(n))),
+                None => Value::Union(Box::new(Value::Null)),
+            };
+            writer
+                .append(Value::Record(vec![
+                    ("time".to_owned(), Value::TimestampMillis(time)),
+                    ("key".to_owned(), Value::String(key.to_owned())),
+                    ("n".to_owned(), n),
+                ]))
+                .unwrap();
+        }
+        writer.into_inner().unwrap()
+    }
+
+    #[test]
+    fn test_read_batches() {
+        let reader = AvroReader::try_new(Cursor::new(avro_file()), 2).unwrap();
+        assert_eq!(
+            reader.schema().as_ref(),
+            &Schema::new(vec![
+                Field::new(
+                    "time",
+                    DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+                    false
+                ),
+                Field::new("key", DataType::Utf8, false),
+                Field::new("n", DataType::Int64, true),
+            ])
+        );
+
+        let batches: Vec<_> = reader.try_collect().unwrap();
+        assert_eq!(batches.len(), 2);
+        assert_eq!(
+            batches[0].column(2).as_primitive::<Int64Type>(),
+            &Int64Array::from(vec![Some(5), None])
+        );
+        assert_eq!(
+            batches[1].column(2).as_primitive::<Int64Type>(),
+            &Int64Array::from(vec![Some(7)])
+        );
+    }
+}
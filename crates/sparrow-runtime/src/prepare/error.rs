@@ -33,6 +33,13 @@ pub enum Error {
     DownloadingObject,
     #[display(fmt = "invalid url: {_0}")]
     InvalidUrl(String),
+    #[display(fmt = "malformed JSON on line {line}")]
+    MalformedJson { line: usize },
+    #[display(fmt = "unable to decode JSON on lines {first_line} to {last_line}")]
+    DecodingJson { first_line: usize, last_line: usize },
+    #[allow(dead_code)]
+    #[display(fmt = "feature '{feature}' not enabled")]
+    FeatureNotEnabled { feature: &'static str },
 }
 
 impl error_stack::Context for Error {}
@@ -40,7 +47,10 @@ impl error_stack::Context for Error {}
 impl sparrow_core::ErrorCode for Error {
     fn error_code(&self) -> tonic::Code {
         match self {
-            Self::MissingField(_) | Self::IncorrectSlicePlan { .. } => tonic::Code::InvalidArgument,
+            Self::MissingField(_)
+            | Self::IncorrectSlicePlan { .. }
+            | Self::MalformedJson { .. }
+            | Self::DecodingJson { .. } => tonic::Code::InvalidArgument,
             _ => tonic::Code::Internal,
         }
     }
@@ -0,0 +1,248 @@
+use std::io::BufRead;
+use std::sync::Arc;
+
+use arrow::datatypes::SchemaRef;
+use arrow::record_batch::RecordBatch;
+use arrow_json::reader::Decoder;
+use error_stack::{IntoReport, ResultExt};
+use serde::de::{DeserializeOwned, IgnoredAny};
+
+use crate::prepare::Error;
+
+/// Iterator over the non-empty lines of newline-delimited JSON.
+///
+/// Each line is returned with its (1-based) line number, so that malformed
+/// lines may be reported.
+struct Lines<R> {
+    lines: std::io::Lines<R>,
+    line_number: usize,
+}
+
+impl<R: BufRead> Lines<R> {
+    fn new(reader: R) -> Self {
+        Self {
+            lines: reader.lines(),
+            line_number: 0,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Lines<R> {
+    type Item = error_stack::Result<(usize, String), Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = self.lines.next()?;
+            self.line_number += 1;
+            match line {
+                Ok(line) if line.trim().is_empty() => continue,
+                Ok(line) => return Some(Ok((self.line_number, line))),
+                Err(e) => {
+                    return Some(Err(e).into_report().change_context(Error::MalformedJson {
+                        line: self.line_number,
+                    }))
+                }
+            }
+        }
+    }
+}
+
+fn parse_line<T: DeserializeOwned>(
+    line_number: usize,
+    line: &str,
+) -> error_stack::Result<T, Error> {
+    serde_json::from_str(line)
+        .into_report()
+        .change_context(Error::MalformedJson { line: line_number })
+}
+
+/// Infer the schema of newline-delimited JSON by reading all of the lines.
+///
+/// Unlike `arrow_json::reader::infer_json_schema`, this reports the line
+/// number of the first malformed line.
+pub(super) fn infer_json_schema<R: BufRead>(reader: R) -> error_stack::Result<SchemaRef, Error> {
+    let mut malformed = None;
+    let values = Lines::new(reader).map_while(|line| {
+        match line.and_then(|(line_number, line)| parse_line(line_number, &line)) {
+            Ok(value) => Some(Ok::<serde_json::Value, _>(value)),
+            Err(e) => {
+                malformed = Some(e);
+                None
+            }
+        }
+    });
+    let schema = arrow_json::reader::infer_json_schema_from_iterator(values);
+    if let Some(malformed) = malformed {
+        return Err(malformed);
+    }
+
+    let schema = schema.into_report().change_context(Error::ReadSchema)?;
+    Ok(Arc::new(schema))
+}
+
+/// Reads batches from newline-delimited JSON.
+///
+/// Each non-empty line must contain a single JSON object. Errors report the
+/// line (or range of lines) that couldn't be read.
+pub(super) struct JsonLinesReader<R> {
+    lines: Lines<R>,
+    decoder: Decoder,
+    batch_size: usize,
+    /// The number of rows decoded into the current batch.
+    num_rows: usize,
+    /// The first and last lines decoded into the current batch.
+    line_range: Option<(usize, usize)>,
+}
+
+impl<R: BufRead> JsonLinesReader<R> {
+    pub(super) fn try_new(
+        reader: R,
+        schema: SchemaRef,
+        batch_size: usize,
+    ) -> error_stack::Result<Self, Error> {
+        let decoder = arrow_json::ReaderBuilder::new(schema)
+            .with_batch_size(batch_size)
+            .build_decoder()
+            .into_report()
+            .change_context(Error::CreateReader)?;
+        Ok(Self {
+            lines: Lines::new(reader),
+            decoder,
+            batch_size,
+            num_rows: 0,
+            line_range: None,
+        })
+    }
+
+    fn decode_line(&mut self, line_number: usize, line: &str) -> error_stack::Result<(), Error> {
+        // The decoder accepts partial records, so an incomplete line would be
+        // merged with the following lines. Validate each line separately so
+        // that the malformed line is reported.
+        let _: IgnoredAny = parse_line(line_number, line)?;
+        self.decoder
+            .decode(line.as_bytes())
+            .into_report()
+            .change_context(Error::MalformedJson { line: line_number })?;
+
+        self.num_rows += 1;
+        let first_line = self.line_range.map_or(line_number, |(first, _)| first);
+        self.line_range = Some((first_line, line_number));
+        Ok(())
+    }
+
+    fn flush(&mut self) -> error_stack::Result<Option<RecordBatch>, Error> {
+        self.num_rows = 0;
+        let Some((first_line, last_line)) = self.line_range.take() else {
+            return Ok(None);
+        };
+
+        // Values are converted to the schema when flushing, so errors (such as
+        // a string in a numeric column) are reported for the entire batch.
+        self.decoder
+            .flush()
+            .into_report()
+            .change_context(Error::DecodingJson {
+                first_line,
+                last_line,
+            })
+    }
+}
+
+impl<R: BufRead> Iterator for JsonLinesReader<R> {
+    type Item = error_stack::Result<RecordBatch, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.num_rows < self.batch_size {
+            let Some(line) = self.lines.next() else {
+                break;
+            };
+            let decoded = line.and_then(|(line_number, line)| self.decode_line(line_number, &line));
+            if let Err(e) = decoded {
+                return Some(Err(e));
+            }
+        }
+        self.flush().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use arrow::array::{AsArray, Int64Array};
+    use arrow::datatypes::{DataType, Field, Int64Type, Schema};
+
+    use super::*;
+
+    const JSON: &str = r#"{"time": "2023-01-01T00:00:00Z", "key": "a", "n": 1}
+
+{"time": "2023-01-02T00:00:00Z", "key": "b", "n": 2}
+{"time": "2023-01-03T00:00:00Z", "key": "a"}
+"#;
+
+    #[test]
+    fn test_infer_json_schema() {
+        let schema = infer_json_schema(Cursor::new(JSON)).unwrap();
+        assert_eq!(
+            schema.as_ref(),
+            &Schema::new(vec![
+                Field::new("key", DataType::Utf8, true),
+                Field::new("n", DataType::Int64, true),
+                Field::new("time", DataType::Utf8, true),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_read_batches() {
+        let schema = infer_json_schema(Cursor::new(JSON)).unwrap();
+        let batches: Vec<_> = JsonLinesReader::try_new(Cursor::new(JSON), schema, 2)
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(batches.len(), 2);
+        assert_eq!(
+            batches[0].column(1).as_primitive::<Int64Type>(),
+            &Int64Array::from(vec![1, 2])
+        );
+        assert_eq!(
+            batches[1].column(1).as_primitive::<Int64Type>(),
+            &Int64Array::from(vec![None])
+        );
+    }
+
+    #[test]
+    fn test_malformed_line() {
+        let json = "{\"n\": 1}\n\n{\"n\": 2\n{\"n\": 3}\n";
+        let error = infer_json_schema(Cursor::new(json)).unwrap_err();
+        assert_eq!(
+            error.current_context().to_string(),
+            "malformed JSON on line 3"
+        );
+
+        let schema = Arc::new(Schema::new(vec![Field::new("n", DataType::Int64, true)]));
+        let error = JsonLinesReader::try_new(Cursor::new(json), schema, 10)
+            .unwrap()
+            .find_map(Result::err)
+            .unwrap();
+        assert_eq!(
+            error.current_context().to_string(),
+            "malformed JSON on line 3"
+        );
+    }
+
+    #[test]
+    fn test_undecodable_lines() {
+        let json = "{\"n\": 1}\n{\"n\": \"two\"}\n{\"n\": 3}\n";
+        let schema = Arc::new(Schema::new(vec![Field::new("n", DataType::Int64, true)]));
+        let error = JsonLinesReader::try_new(Cursor::new(json), schema, 10)
+            .unwrap()
+            .find_map(Result::err)
+            .unwrap();
+        assert_eq!(
+            error.current_context().to_string(),
+            "unable to decode JSON on lines 1 to 3"
+        );
+    }
+}
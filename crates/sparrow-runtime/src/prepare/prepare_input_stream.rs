@@ -87,7 +87,11 @@ pub async fn prepare_input<'a>(
     let mut metadata = PrepareMetadata::new(entity_key_column.data_type().clone());
 
     Ok(async_stream::try_stream! {
-        while let Some(Ok(batch)) = reader.next().await {
+        while let Some(batch) = reader.next().await {
+            // Errors reading the input (such as malformed rows) are reported
+            // rather than silently ending the stream.
+            let batch = batch?;
+
             // 1. Slicing may reduce the number of entities to operate and sort on.
             let read_batch = slice_preparer.slice_batch(batch)?;
             // 2. Prepare each of the columns by getting the column behavior result
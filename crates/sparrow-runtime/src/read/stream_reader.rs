@@ -1,12 +1,17 @@
 use std::sync::Arc;
 
 use arrow::datatypes::{Schema, SchemaRef};
+use arrow::error::ArrowError;
+use arrow::record_batch::RecordBatch;
 use error_stack::{IntoReportCompat, ResultExt};
 
+use futures::stream::BoxStream;
 use futures::{Stream, StreamExt};
 use hashbrown::HashSet;
 use sparrow_api::kaskada::v1alpha::slice_plan::Slice;
-use sparrow_api::kaskada::v1alpha::{PulsarSource, PulsarSubscription};
+use sparrow_api::kaskada::v1alpha::{self, PulsarSource, PulsarSubscription};
+#[cfg(feature = "kafka")]
+use sparrow_api::kaskada::v1alpha::{KafkaSource, KafkaSubscription};
 use sparrow_compiler::TableInfo;
 use sparrow_qfr::{
     activity, gauge, Activity, FlightRecorder, Gauge, PushRegistration, Registration, Registrations,
@@ -53,8 +58,75 @@ pub(crate) async fn stream_reader(
     requested_slice: Option<&Slice>,
     projected_columns: Option<Vec<String>>,
     _flight_recorder: FlightRecorder,
-    pulsar_source: &PulsarSource,
+    source: &v1alpha::source::Source,
 ) -> error_stack::Result<impl Stream<Item = error_stack::Result<Batch, Error>> + 'static, Error> {
+    let (user_schema, stream, projected_schema) = match source {
+        v1alpha::source::Source::Pulsar(pulsar_source) => {
+            pulsar_stream(table_info, projected_columns, pulsar_source).await?
+        }
+        #[cfg(feature = "kafka")]
+        v1alpha::source::Source::Kafka(kafka_source) => {
+            kafka_stream(table_info, projected_columns, kafka_source).await?
+        }
+        #[cfg(not(feature = "kafka"))]
+        v1alpha::source::Source::Kafka(_) => {
+            error_stack::bail!(Error::Unsupported(
+                "kafka sources require the 'kafka' feature"
+            ))
+        }
+        v1alpha::source::Source::Kaskada(_) => {
+            error_stack::bail!(Error::Unsupported("kaskada tables are not streams"))
+        }
+    };
+
+    let table_config = table_info.config().clone();
+    let bounded_lateness = if let Some(bounded_lateness) = context.bounded_lateness_ns {
+        bounded_lateness
+    } else {
+        BOUNDED_LATENESS_NS
+    };
+
+    let mut input_stream = prepare::execute_input_stream::prepare_input(
+        stream,
+        table_config,
+        user_schema,
+        projected_schema,
+        0,
+        requested_slice,
+        context.key_hash_inverse.clone(),
+        bounded_lateness,
+    )
+    .await
+    .into_report()
+    .change_context(Error::CreateStream)?;
+
+    Ok(async_stream::try_stream! {
+        loop {
+            if let Some(next_input) = input_stream.next().await {
+                let next_input = next_input.change_context(Error::ReadNextBatch)?;
+                match next_input {
+                    None => continue,
+                    Some(input) => {
+                        yield Batch::try_new_from_batch(input).into_report().change_context(Error::Internal)?
+                    }
+                }
+            } else {
+                // Loop indefinitely - it's possible a batch was not produced because the watermark did not advance.
+            }
+        }
+    })
+}
+
+type RawStream = BoxStream<'static, Result<RecordBatch, ArrowError>>;
+
+/// Create the stream of raw batches read from a Pulsar topic.
+///
+/// Returns the user schema of the topic, the stream and the projected schema.
+async fn pulsar_stream(
+    table_info: &TableInfo,
+    projected_columns: Option<Vec<String>>,
+    pulsar_source: &PulsarSource,
+) -> error_stack::Result<(SchemaRef, RawStream, SchemaRef), Error> {
     // TODO: This should be the materialization ID, or configurable by the user.
     // This will be important when restarting a consumer at a specific point.
     let pulsar_subscription =
@@ -97,42 +169,54 @@ pub(crate) async fn stream_reader(
         pulsar_subscription.last_publish_time,
     );
 
-    let table_config = table_info.config().clone();
-    let bounded_lateness = if let Some(bounded_lateness) = context.bounded_lateness_ns {
-        bounded_lateness
+    Ok((
+        pulsar_metadata.user_schema,
+        stream.boxed(),
+        projected_schema,
+    ))
+}
+
+/// Create the stream of raw batches read from a Kafka topic.
+///
+/// Returns the user schema of the topic, the stream and the projected schema.
+#[cfg(feature = "kafka")]
+async fn kafka_stream(
+    table_info: &TableInfo,
+    projected_columns: Option<Vec<String>>,
+    kafka_source: &KafkaSource,
+) -> error_stack::Result<(SchemaRef, RawStream, SchemaRef), Error> {
+    // TODO: This should be the materialization ID, or configurable by the user.
+    // The committed offsets of the group determine where a restarted consumer resumes.
+    let kafka_group = std::env::var("KAFKA_GROUP").unwrap_or("group-default".to_owned());
+    let kafka_config = kafka_source.config.as_ref().ok_or(Error::Internal)?;
+    let kafka_subscription = KafkaSubscription {
+        config: Some(kafka_config.clone()),
+        group: kafka_group,
+    };
+    let kafka_metadata = RawMetadata::try_from_kafka(kafka_config)
+        .await
+        .change_context(Error::CreateStream)?;
+    let user_schema = kafka_metadata.sparrow_metadata.raw_schema.clone();
+    // Verify the provided table schema matches the topic schema
+    verify_schema_match(user_schema.clone(), table_info.schema().clone())?;
+
+    let projected_schema = if let Some(columns) = &projected_columns {
+        projected_schema(kafka_metadata.sparrow_metadata.table_schema, columns)
+            .change_context(Error::CreateStream)?
     } else {
-        BOUNDED_LATENESS_NS
+        kafka_metadata.sparrow_metadata.table_schema
     };
 
-    let mut input_stream = prepare::execute_input_stream::prepare_input(
-        stream.boxed(),
-        table_config,
-        pulsar_metadata.user_schema.clone(),
-        projected_schema,
-        0,
-        requested_slice,
-        context.key_hash_inverse.clone(),
-        bounded_lateness,
-    )
-    .await
-    .into_report()
-    .change_context(Error::CreateStream)?;
+    let consumer = streams::kafka::stream::consumer(&kafka_subscription)
+        .change_context(Error::CreateStream)?;
+    let stream = streams::kafka::stream::execution_stream(
+        user_schema.clone(),
+        projected_schema.clone(),
+        consumer,
+        kafka_metadata.kafka_schema,
+    );
 
-    Ok(async_stream::try_stream! {
-        loop {
-            if let Some(next_input) = input_stream.next().await {
-                let next_input = next_input.change_context(Error::ReadNextBatch)?;
-                match next_input {
-                    None => continue,
-                    Some(input) => {
-                        yield Batch::try_new_from_batch(input).into_report().change_context(Error::Internal)?
-                    }
-                }
-            } else {
-                // Loop indefinitely - it's possible a batch was not produced because the watermark did not advance.
-            }
-        }
-    })
+    Ok((user_schema, stream.boxed(), projected_schema))
 }
 
 /// Compute the projected schema from a base schema and projected columns.
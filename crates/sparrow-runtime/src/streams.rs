@@ -1 +1,3 @@
+#[cfg(feature = "kafka")]
+pub(crate) mod kafka;
 pub(crate) mod pulsar;
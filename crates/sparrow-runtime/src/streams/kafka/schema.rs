@@ -0,0 +1,341 @@
+use arrow::datatypes::Schema;
+use error_stack::{IntoReport, Result, ResultExt};
+use hashbrown::HashMap;
+use sparrow_api::kaskada::v1alpha::kafka_config::{self, MessageFormat};
+use sparrow_api::kaskada::v1alpha::KafkaConfig;
+
+/// The number of messages to sample from the topic when inferring a JSON schema.
+const SCHEMA_INFERENCE_SAMPLE_SIZE: usize = 1000;
+
+#[derive(Debug, derive_more::Display)]
+pub enum Error {
+    #[display(fmt = "missing schema for Avro encoded topic")]
+    MissingSchema,
+    AvroSchemaConversion,
+    SchemaRequest,
+    #[display(fmt = "unsupported schema type '{_0}'")]
+    UnsupportedSchema(String),
+    #[display(fmt = "failed to infer JSON schema")]
+    JsonSchemaInference,
+}
+
+impl error_stack::Context for Error {}
+
+/// The schema of a topic, and how messages are encoded.
+#[derive(Debug, Clone)]
+pub struct KafkaSchema {
+    /// The user-defined schema of messages in the topic.
+    pub schema: Schema,
+    /// The Avro reader schema, if the messages are Avro encoded.
+    ///
+    /// Without a schema registry, this is also the writer schema.
+    pub avro_schema: Option<avro_rs::Schema>,
+    /// The writer schemas of Avro messages, if the messages are prefixed with
+    /// the schema registry header.
+    pub registry: Option<RegistrySchemas>,
+}
+
+/// The writer schemas of messages produced with a schema registry.
+///
+/// Each message names the ID of the schema it was written with. Schemas are
+/// retrieved from the registry the first time each ID is seen.
+#[derive(Debug, Clone)]
+pub struct RegistrySchemas {
+    /// The URL of the schema registry.
+    url: String,
+    /// The schemas retrieved from the registry, by ID.
+    schemas: HashMap<u32, avro_rs::Schema>,
+}
+
+impl RegistrySchemas {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            schemas: HashMap::new(),
+        }
+    }
+
+    /// Add a schema which has already been retrieved from the registry.
+    pub fn with_schema(mut self, id: u32, schema: avro_rs::Schema) -> Self {
+        self.schemas.insert(id, schema);
+        self
+    }
+
+    /// Return the writer schema with the given ID.
+    pub async fn writer_schema(&mut self, id: u32) -> Result<&avro_rs::Schema, Error> {
+        if !self.schemas.contains_key(&id) {
+            let schema = get_schema_by_id(&self.url, id).await?;
+            self.schemas.insert(id, schema);
+        }
+        Ok(&self.schemas[&id])
+    }
+}
+
+/// Determine the schema of the messages in the configured topic.
+///
+/// The schema is determined as follows:
+/// * If an Avro schema is configured, it is used directly.
+/// * If a schema registry is configured, the latest value schema for the
+///   topic is retrieved from the registry. Messages are read with this schema,
+///   after being decoded with the schema they were written with.
+/// * Otherwise, if the topic contains JSON messages, the schema is inferred
+///   from a sample of the messages currently in the topic.
+pub async fn get_kafka_schema(config: &KafkaConfig) -> Result<KafkaSchema, Error> {
+    let format = config.message_format();
+    let (avro_schema, registry_id) = match &config.schema {
+        Some(kafka_config::Schema::AvroSchema(avro_schema)) => (avro_schema.clone(), None),
+        Some(kafka_config::Schema::SchemaRegistryUrl(url)) => {
+            let (id, avro_schema) = get_registry_schema(url, &config.topic).await?;
+            (avro_schema, Some((url, id)))
+        }
+        None if format == MessageFormat::Json => {
+            let schema = infer_json_schema(config).await?;
+            return Ok(KafkaSchema {
+                schema,
+                avro_schema: None,
+                registry: None,
+            });
+        }
+        None => error_stack::bail!(Error::MissingSchema),
+    };
+
+    let schema = schema_from_formatted(&avro_schema)?;
+    let avro_schema = match format {
+        MessageFormat::Json => None,
+        MessageFormat::Unspecified | MessageFormat::Avro => Some(parse_avro_schema(&avro_schema)?),
+    };
+    let registry = match (&avro_schema, registry_id) {
+        (Some(avro_schema), Some((url, id))) => {
+            Some(RegistrySchemas::new(url).with_schema(id, avro_schema.clone()))
+        }
+        _ => None,
+    };
+    Ok(KafkaSchema {
+        schema,
+        avro_schema,
+        registry,
+    })
+}
+
+fn parse_avro_schema(avro_schema: &str) -> Result<avro_rs::Schema, Error> {
+    avro_rs::Schema::parse_str(avro_schema)
+        .into_report()
+        .change_context(Error::AvroSchemaConversion)
+        .attach_printable_lazy(|| format!("failed to parse avro schema {avro_schema}"))
+}
+
+/// Convert a JSON formatted Avro schema to an Arrow schema.
+fn schema_from_formatted(formatted_schema: &str) -> Result<Schema, Error> {
+    let avro_schema: avro_schema::schema::Schema = serde_json::from_str(formatted_schema)
+        .into_report()
+        .change_context(Error::AvroSchemaConversion)
+        .attach_printable_lazy(|| {
+            format!("failed to deserialize avro schema from json string: {formatted_schema}")
+        })?;
+    sparrow_arrow::avro::from_avro_schema(&avro_schema)
+        .change_context(Error::AvroSchemaConversion)
+        .attach_printable_lazy(|| format!("from_avro_schema({:?}) failed", &avro_schema))
+}
+
+#[derive(serde::Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct SchemaResponse {
+    /// The registry omits the ID when retrieving a schema by ID.
+    id: Option<u32>,
+    schema: String,
+    /// The registry omits the schema type for Avro schemas.
+    schema_type: Option<String>,
+}
+
+/// Retrieve the latest value schema for the `topic` from the schema registry.
+///
+/// Returns the ID and the JSON formatted Avro schema.
+///
+/// This uses the default `TopicNameStrategy`, in which the subject for the
+/// values of a topic is `<topic>-value`.
+async fn get_registry_schema(registry_url: &str, topic: &str) -> Result<(u32, String), Error> {
+    let url = format!(
+        "{}/subjects/{topic}-value/versions/latest",
+        registry_url.trim_end_matches('/')
+    );
+    let schema_response = request_schema(&url).await?;
+    let id = schema_response.id.ok_or_else(|| {
+        error_stack::report!(Error::SchemaRequest)
+            .attach_printable(format!("missing schema id in response from {url}"))
+    })?;
+    Ok((id, schema_response.schema))
+}
+
+/// Retrieve the schema with the given `id` from the schema registry.
+async fn get_schema_by_id(registry_url: &str, id: u32) -> Result<avro_rs::Schema, Error> {
+    let url = format!("{}/schemas/ids/{id}", registry_url.trim_end_matches('/'));
+    let schema_response = request_schema(&url).await?;
+    parse_avro_schema(&schema_response.schema)
+}
+
+/// Request an Avro schema from the schema registry.
+async fn request_schema(url: &str) -> Result<SchemaResponse, Error> {
+    tracing::debug!("requesting schema from {}", url);
+
+    let text = reqwest::get(url)
+        .await
+        .into_report()
+        .change_context(Error::SchemaRequest)?
+        .error_for_status()
+        .into_report()
+        .change_context(Error::SchemaRequest)?
+        .text()
+        .await
+        .into_report()
+        .change_context(Error::SchemaRequest)?;
+
+    let schema_response: SchemaResponse = serde_json::from_str(&text)
+        .into_report()
+        .change_context(Error::SchemaRequest)
+        .attach_printable_lazy(|| format!("from_str({:?}) failed", &text))?;
+    match schema_response.schema_type.as_deref() {
+        None | Some("AVRO") => Ok(schema_response),
+        Some(other) => error_stack::bail!(Error::UnsupportedSchema(other.to_owned())),
+    }
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct RegisterResponse {
+    id: u32,
+}
+
+/// Register `avro_schema` as the value schema for the `topic` with the schema registry.
+///
+/// Returns the ID assigned to the schema by the registry. Registering a schema
+/// identical to an existing version returns the ID of that version.
+pub async fn register_schema(
+    registry_url: &str,
+    topic: &str,
+    avro_schema: &str,
+) -> Result<u32, Error> {
+    let url = format!(
+        "{}/subjects/{topic}-value/versions",
+        registry_url.trim_end_matches('/')
+    );
+    tracing::debug!("registering schema with {}", url);
+
+    let body = serde_json::json!({ "schema": avro_schema }).to_string();
+    let text = reqwest::Client::new()
+        .post(&url)
+        .header(
+            reqwest::header::CONTENT_TYPE,
+            "application/vnd.schemaregistry.v1+json",
+        )
+        .body(body)
+        .send()
+        .await
+        .into_report()
+        .change_context(Error::SchemaRequest)?
+        .error_for_status()
+        .into_report()
+        .change_context(Error::SchemaRequest)?
+        .text()
+        .await
+        .into_report()
+        .change_context(Error::SchemaRequest)?;
+
+    let response: RegisterResponse = serde_json::from_str(&text)
+        .into_report()
+        .change_context(Error::SchemaRequest)
+        .attach_printable_lazy(|| format!("from_str({:?}) failed", &text))?;
+    Ok(response.id)
+}
+
+/// Infer the schema of a JSON topic from the messages currently in the topic.
+async fn infer_json_schema(config: &KafkaConfig) -> Result<Schema, Error> {
+    let messages = super::stream::sample_messages(config, SCHEMA_INFERENCE_SAMPLE_SIZE)
+        .await
+        .change_context(Error::JsonSchemaInference)?;
+    error_stack::ensure!(
+        !messages.is_empty(),
+        error_stack::report!(Error::JsonSchemaInference)
+            .attach_printable(format!("no messages in topic '{}'", config.topic))
+    );
+
+    let values = messages.iter().map(|message| {
+        serde_json::from_slice::<serde_json::Value>(message)
+            .map_err(|e| arrow::error::ArrowError::JsonError(e.to_string()))
+    });
+    arrow::json::reader::infer_json_schema_from_iterator(values)
+        .into_report()
+        .change_context(Error::JsonSchemaInference)
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+    use sparrow_api::kaskada::v1alpha::kafka_config::{MessageFormat, Schema as KafkaSchema};
+    use sparrow_api::kaskada::v1alpha::KafkaConfig;
+
+    use super::get_kafka_schema;
+
+    const AVRO_SCHEMA: &str = r#"{
+        "type": "record",
+        "name": "Purchase",
+        "fields": [
+            {"name": "time", "type": {"type": "long", "logicalType": "local-timestamp-millis"}},
+            {"name": "user", "type": "string"},
+            {"name": "amount", "type": ["null", "double"]}
+        ]
+    }"#;
+
+    #[tokio::test]
+    async fn test_configured_avro_schema() {
+        let config = KafkaConfig {
+            hosts: vec![],
+            topic: "purchases".to_owned(),
+            message_format: MessageFormat::Avro as i32,
+            schema: Some(KafkaSchema::AvroSchema(AVRO_SCHEMA.to_owned())),
+        };
+
+        let schema = get_kafka_schema(&config).await.unwrap();
+        assert_eq!(
+            schema.schema,
+            Schema::new(vec![
+                Field::new(
+                    "time",
+                    DataType::Timestamp(TimeUnit::Millisecond, None),
+                    false
+                ),
+                Field::new("user", DataType::Utf8, false),
+                Field::new("amount", DataType::Float64, true),
+            ])
+        );
+        assert!(schema.avro_schema.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_json_with_configured_schema() {
+        let config = KafkaConfig {
+            hosts: vec![],
+            topic: "purchases".to_owned(),
+            message_format: MessageFormat::Json as i32,
+            schema: Some(KafkaSchema::AvroSchema(AVRO_SCHEMA.to_owned())),
+        };
+
+        let schema = get_kafka_schema(&config).await.unwrap();
+        assert_eq!(schema.schema.fields().len(), 3);
+        assert!(schema.avro_schema.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_avro_requires_schema() {
+        let config = KafkaConfig {
+            hosts: vec![],
+            topic: "purchases".to_owned(),
+            message_format: MessageFormat::Avro as i32,
+            schema: None,
+        };
+
+        let error = get_kafka_schema(&config).await.unwrap_err();
+        assert_eq!(
+            error.current_context().to_string(),
+            "missing schema for Avro encoded topic"
+        );
+    }
+}
@@ -0,0 +1,770 @@
+use std::time::Duration;
+
+use arrow::datatypes::SchemaRef;
+use arrow::error::ArrowError;
+use arrow::json::reader::Decoder;
+use arrow::record_batch::RecordBatch;
+use error_stack::{IntoReport, ResultExt};
+use futures::Stream;
+use hashbrown::HashMap;
+use rdkafka::consumer::{CommitMode, Consumer, StreamConsumer};
+use rdkafka::{ClientConfig, Message, Offset, TopicPartitionList};
+use sparrow_api::kaskada::v1alpha::{KafkaConfig, KafkaSubscription};
+use tokio::time::timeout;
+
+use super::schema::KafkaSchema;
+use crate::prepare::Error;
+
+/// The magic byte prefixed to messages produced with a schema registry.
+pub(crate) const REGISTRY_MAGIC_BYTE: u8 = 0;
+
+/// The length of the schema registry header (magic byte and 4 byte schema id).
+const REGISTRY_HEADER_LEN: usize = 5;
+
+/// How long to wait for the next message before producing the current batch.
+const READ_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// How long to wait for the topic metadata when sampling messages.
+const METADATA_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Creates a kafka stream to be used during execution in a long-lived process.
+///
+/// This stream should not close naturally. It continually reads messages from the
+/// topic, batches them, and passes them to the runtime layer.
+///
+/// Note that this stream does not do any filtering or ordering of events.
+///
+/// The offsets of each batch are committed to the consumer group once the
+/// batch has been consumed, which is when the next batch is requested.
+/// Batches which were produced but not consumed before the process stopped
+/// are read again by the next consumer in the group, so messages are
+/// delivered at least once.
+pub fn execution_stream(
+    raw_schema: SchemaRef,
+    projected_schema: SchemaRef,
+    consumer: StreamConsumer,
+    kafka_schema: KafkaSchema,
+) -> impl Stream<Item = Result<RecordBatch, ArrowError>> {
+    async_stream::try_stream! {
+        let mut reader = KafkaReader::try_new(raw_schema, projected_schema, consumer, kafka_schema)?;
+        loop {
+            // Indefinitely reads messages from the stream
+            if let Some(next) = reader.next_result_async().await? {
+                let offsets = reader.next_offsets()?;
+                yield next;
+
+                // Resuming after the yield means the batch was consumed, so
+                // its messages don't need to be read again.
+                reader.commit_offsets(&offsets)?;
+            } else {
+                // Keep looping - this may happen if we timed out trying to read from the stream
+            }
+        }
+    }
+}
+
+#[derive(derive_more::Display, Debug)]
+pub enum DeserializeError {
+    #[display(fmt = "error reading Avro record")]
+    Avro,
+    #[display(fmt = "error reading JSON record")]
+    Json,
+    #[display(fmt = "message missing payload")]
+    MissingPayload,
+    #[display(fmt = "message missing schema registry header")]
+    MissingRegistryHeader,
+    #[display(fmt = "error retrieving writer schema {_0}")]
+    WriterSchema(u32),
+}
+
+impl error_stack::Context for DeserializeError {}
+
+#[derive(Debug)]
+struct DeserializeErrorWrapper(error_stack::Report<DeserializeError>);
+
+impl std::fmt::Display for DeserializeErrorWrapper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl std::error::Error for DeserializeErrorWrapper {}
+
+impl From<error_stack::Report<DeserializeError>> for DeserializeErrorWrapper {
+    fn from(error: error_stack::Report<DeserializeError>) -> Self {
+        DeserializeErrorWrapper(error)
+    }
+}
+
+struct KafkaReader {
+    /// The raw schema; includes all columns in the stream.
+    raw_schema: SchemaRef,
+    /// The projected schema; includes only columns that are needed by the query.
+    projected_schema: SchemaRef,
+    consumer: StreamConsumer,
+    kafka_schema: KafkaSchema,
+    /// Decodes the JSON representation of each message into the raw schema.
+    decoder: Decoder,
+    /// The next offset to read for each partition of the topic.
+    ///
+    /// Messages at earlier offsets (which may be redelivered after a rebalance)
+    /// are skipped, and these offsets are committed to the consumer group after
+    /// each batch is consumed.
+    next_offsets: HashMap<(String, i32), i64>,
+}
+
+/// The maximum number of messages to read into a single batch.
+const MAX_BATCH_SIZE: usize = 100_000;
+
+impl KafkaReader {
+    fn try_new(
+        raw_schema: SchemaRef,
+        projected_schema: SchemaRef,
+        consumer: StreamConsumer,
+        kafka_schema: KafkaSchema,
+    ) -> Result<Self, ArrowError> {
+        let decoder = arrow::json::ReaderBuilder::new(raw_schema.clone())
+            .with_batch_size(MAX_BATCH_SIZE)
+            .build_decoder()?;
+        Ok(KafkaReader {
+            raw_schema,
+            projected_schema,
+            consumer,
+            kafka_schema,
+            decoder,
+            next_offsets: HashMap::new(),
+        })
+    }
+
+    // Using ArrowError is not a great fit but that is what PrepareIter requires
+    async fn next_result_async(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        tracing::debug!("reading kafka messages");
+        let mut num_messages = 0;
+        while num_messages < MAX_BATCH_SIZE {
+            let Ok(msg) = timeout(READ_TIMEOUT, self.consumer.recv()).await else {
+                tracing::trace!("timed out reading next message");
+                break;
+            };
+            let msg = msg.map_err(|e| ArrowError::from_external_error(Box::new(e)))?;
+
+            let partition = (msg.topic().to_owned(), msg.partition());
+            let next_offset = self.next_offsets.entry(partition).or_insert(0);
+            if msg.offset() < *next_offset {
+                tracing::trace!(
+                    "skipping redelivered message at offset {} of partition {}",
+                    msg.offset(),
+                    msg.partition()
+                );
+                continue;
+            }
+            *next_offset = msg.offset() + 1;
+
+            if let Err(e) = decode(&mut self.decoder, &mut self.kafka_schema, msg.payload()).await {
+                let e = e.attach_printable(format!(
+                    "partition {} offset {}",
+                    msg.partition(),
+                    msg.offset()
+                ));
+                tracing::debug!("error deserializing message: {:#?}", e);
+                return Err(ArrowError::from_external_error(Box::new(
+                    DeserializeErrorWrapper::from(e),
+                )));
+            }
+            num_messages += 1;
+        }
+
+        tracing::debug!("read {} messages", num_messages);
+        let Some(batch) = self.decoder.flush()? else {
+            return Ok(None);
+        };
+
+        // Only keep the columns needed by the query.
+        let columns: Vec<_> = self
+            .projected_schema
+            .fields()
+            .iter()
+            .map(|field| {
+                self.raw_schema
+                    .index_of(field.name())
+                    .map(|index| batch.column(index).clone())
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Some(RecordBatch::try_new(
+            self.projected_schema.clone(),
+            columns,
+        )?))
+    }
+
+    /// Returns the next offset to read for each partition, following the
+    /// messages read so far.
+    fn next_offsets(&self) -> Result<TopicPartitionList, ArrowError> {
+        let mut offsets = TopicPartitionList::with_capacity(self.next_offsets.len());
+        for ((topic, partition), offset) in self.next_offsets.iter() {
+            offsets
+                .add_partition_offset(topic, *partition, Offset::Offset(*offset))
+                .map_err(|e| ArrowError::from_external_error(Box::new(e)))?;
+        }
+        Ok(offsets)
+    }
+
+    /// Commit the given offsets to the consumer group.
+    ///
+    /// This should only be called with the offsets following messages which
+    /// have been consumed, since a restarted consumer resumes from them.
+    fn commit_offsets(&self, offsets: &TopicPartitionList) -> Result<(), ArrowError> {
+        self.consumer
+            .commit(offsets, CommitMode::Async)
+            .map_err(|e| ArrowError::from_external_error(Box::new(e)))
+    }
+}
+
+/// Add the payload of a message to the pending batch.
+///
+/// Messages with the schema registry header are decoded with the schema they
+/// were written with, which is retrieved from the registry if needed.
+async fn decode(
+    decoder: &mut Decoder,
+    kafka_schema: &mut KafkaSchema,
+    payload: Option<&[u8]>,
+) -> error_stack::Result<(), DeserializeError> {
+    let payload = payload.ok_or(DeserializeError::MissingPayload)?;
+    match (&kafka_schema.avro_schema, &mut kafka_schema.registry) {
+        (None, _) => {
+            decoder
+                .decode(payload)
+                .into_report()
+                .change_context(DeserializeError::Json)?;
+            Ok(())
+        }
+        (Some(avro_schema), None) => decode_avro(decoder, avro_schema, None, payload),
+        (Some(avro_schema), Some(registry)) => {
+            error_stack::ensure!(
+                payload.len() >= REGISTRY_HEADER_LEN && payload[0] == REGISTRY_MAGIC_BYTE,
+                DeserializeError::MissingRegistryHeader
+            );
+            let (header, datum) = payload.split_at(REGISTRY_HEADER_LEN);
+            let id = u32::from_be_bytes([header[1], header[2], header[3], header[4]]);
+            let writer_schema = registry
+                .writer_schema(id)
+                .await
+                .change_context(DeserializeError::WriterSchema(id))?;
+            decode_avro(decoder, writer_schema, Some(avro_schema), datum)
+        }
+    }
+}
+
+/// Add an Avro encoded record to the pending batch.
+///
+/// The record is decoded with the `writer_schema`, and then resolved against
+/// the `reader_schema`, if any.
+fn decode_avro(
+    decoder: &mut Decoder,
+    writer_schema: &avro_rs::Schema,
+    reader_schema: Option<&avro_rs::Schema>,
+    mut datum: &[u8],
+) -> error_stack::Result<(), DeserializeError> {
+    let value = avro_rs::from_avro_datum(writer_schema, &mut datum, reader_schema)
+        .into_report()
+        .change_context(DeserializeError::Avro)?;
+    let value = serde_json::Value::try_from(value)
+        .into_report()
+        .change_context(DeserializeError::Avro)?;
+    decoder
+        .serialize(&[value])
+        .into_report()
+        .change_context(DeserializeError::Avro)
+}
+
+fn client_config(config: &KafkaConfig, group: &str) -> ClientConfig {
+    let mut client_config = ClientConfig::new();
+    client_config
+        .set("bootstrap.servers", config.hosts.join(","))
+        .set("group.id", group)
+        // Offsets are committed after each batch is consumed.
+        .set("enable.auto.commit", "false")
+        // New consumer groups start from the beginning of the topic.
+        .set("auto.offset.reset", "earliest");
+    client_config
+}
+
+pub fn consumer(subscription: &KafkaSubscription) -> error_stack::Result<StreamConsumer, Error> {
+    let config = subscription.config.as_ref().ok_or(Error::Internal)?;
+    let consumer: StreamConsumer = client_config(config, &subscription.group)
+        .create()
+        .into_report()
+        .change_context(Error::CreateReader)?;
+    consumer
+        .subscribe(&[&config.topic])
+        .into_report()
+        .change_context(Error::CreateReader)
+        .attach_printable_lazy(|| format!("failed to subscribe to topic '{}'", config.topic))?;
+    Ok(consumer)
+}
+
+/// Read the payloads of up to `max_messages` messages from the start of the topic.
+///
+/// The partitions of the topic are assigned directly rather than joining a
+/// consumer group, so no offsets are committed.
+pub(super) async fn sample_messages(
+    config: &KafkaConfig,
+    max_messages: usize,
+) -> error_stack::Result<Vec<Vec<u8>>, Error> {
+    let group = format!("sparrow-sample-{}", uuid::Uuid::new_v4().as_hyphenated());
+    let consumer: StreamConsumer = client_config(config, &group)
+        .create()
+        .into_report()
+        .change_context(Error::CreateReader)?;
+
+    let metadata = consumer
+        .fetch_metadata(Some(&config.topic), METADATA_TIMEOUT)
+        .into_report()
+        .change_context(Error::CreateReader)
+        .attach_printable_lazy(|| format!("failed to fetch metadata for '{}'", config.topic))?;
+    let mut partitions = TopicPartitionList::new();
+    for topic in metadata.topics() {
+        for partition in topic.partitions() {
+            partitions
+                .add_partition_offset(topic.name(), partition.id(), Offset::Beginning)
+                .into_report()
+                .change_context(Error::CreateReader)?;
+        }
+    }
+    consumer
+        .assign(&partitions)
+        .into_report()
+        .change_context(Error::CreateReader)?;
+
+    let mut messages = Vec::with_capacity(max_messages);
+    while messages.len() < max_messages {
+        let Ok(msg) = timeout(READ_TIMEOUT, consumer.recv()).await else {
+            break;
+        };
+        let msg = msg.into_report().change_context(Error::ReadingBatch)?;
+        if let Some(payload) = msg.payload() {
+            messages.push(payload.to_vec());
+        }
+    }
+    Ok(messages)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use arrow::array::{Float64Array, StringArray, TimestampMillisecondArray};
+    use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+    use arrow::error::ArrowError;
+    use arrow::record_batch::RecordBatch;
+    use futures::stream::BoxStream;
+    use futures::StreamExt;
+    use rdkafka::consumer::{Consumer, StreamConsumer};
+    use rdkafka::mocking::MockCluster;
+    use rdkafka::producer::{FutureProducer, FutureRecord};
+    use rdkafka::{ClientConfig, Offset, TopicPartitionList};
+    use sparrow_api::kaskada::v1alpha::kafka_config::{MessageFormat, Schema as KafkaConfigSchema};
+    use sparrow_api::kaskada::v1alpha::{KafkaConfig, KafkaSubscription};
+
+    use super::{consumer, execution_stream, REGISTRY_MAGIC_BYTE};
+    use crate::streams::kafka::schema::{get_kafka_schema, KafkaSchema, RegistrySchemas};
+
+    const TOPIC: &str = "purchases";
+
+    /// A schema registry which can't be reached, for tests in which all the
+    /// writer schemas are already known.
+    const UNREACHABLE_REGISTRY: &str = "http://127.0.0.1:1";
+    const GROUP: &str = "test-group";
+
+    const AVRO_SCHEMA: &str = r#"{
+        "type": "record",
+        "name": "Purchase",
+        "fields": [
+            {"name": "time", "type": {"type": "long", "logicalType": "local-timestamp-millis"}},
+            {"name": "user", "type": "string"},
+            {"name": "amount", "type": ["null", "double"]}
+        ]
+    }"#;
+
+    fn raw_schema() -> Arc<Schema> {
+        Arc::new(Schema::new(vec![
+            Field::new(
+                "time",
+                DataType::Timestamp(TimeUnit::Millisecond, None),
+                false,
+            ),
+            Field::new("user", DataType::Utf8, false),
+            Field::new("amount", DataType::Float64, true),
+        ]))
+    }
+
+    async fn produce(
+        cluster: &MockCluster<'_, rdkafka::producer::DefaultProducerContext>,
+        payloads: Vec<Vec<u8>>,
+    ) {
+        let producer: FutureProducer = ClientConfig::new()
+            .set("bootstrap.servers", cluster.bootstrap_servers())
+            .create()
+            .unwrap();
+        for payload in payloads {
+            producer
+                .send(
+                    FutureRecord::<(), _>::to(TOPIC).payload(&payload),
+                    std::time::Duration::from_secs(5),
+                )
+                .await
+                .unwrap();
+        }
+    }
+
+    fn config(
+        cluster: &MockCluster<'_, rdkafka::producer::DefaultProducerContext>,
+        format: MessageFormat,
+        schema: Option<KafkaConfigSchema>,
+    ) -> KafkaConfig {
+        KafkaConfig {
+            hosts: vec![cluster.bootstrap_servers()],
+            topic: TOPIC.to_owned(),
+            message_format: format as i32,
+            schema,
+        }
+    }
+
+    fn stream(
+        config: KafkaConfig,
+        kafka_schema: KafkaSchema,
+    ) -> BoxStream<'static, Result<RecordBatch, ArrowError>> {
+        let subscription = KafkaSubscription {
+            config: Some(config),
+            group: GROUP.to_owned(),
+        };
+        let consumer = consumer(&subscription).unwrap();
+        let projected_schema = Arc::new(Schema::new(vec![
+            Field::new("user", DataType::Utf8, false),
+            Field::new("amount", DataType::Float64, true),
+            Field::new(
+                "time",
+                DataType::Timestamp(TimeUnit::Millisecond, None),
+                false,
+            ),
+        ]));
+        execution_stream(raw_schema(), projected_schema, consumer, kafka_schema).boxed()
+    }
+
+    async fn read_batch(config: KafkaConfig, kafka_schema: KafkaSchema) -> RecordBatch {
+        stream(config, kafka_schema).next().await.unwrap().unwrap()
+    }
+
+    /// Returns the offset committed by the test group for the topic.
+    fn committed_offset(
+        cluster: &MockCluster<'_, rdkafka::producer::DefaultProducerContext>,
+    ) -> Offset {
+        let consumer: StreamConsumer = ClientConfig::new()
+            .set("bootstrap.servers", cluster.bootstrap_servers())
+            .set("group.id", GROUP)
+            .create()
+            .unwrap();
+        let mut partitions = TopicPartitionList::new();
+        partitions.add_partition(TOPIC, 0);
+        let committed = consumer
+            .committed_offsets(partitions, Duration::from_secs(5))
+            .unwrap();
+        committed.find_partition(TOPIC, 0).unwrap().offset()
+    }
+
+    fn assert_purchases(batch: &RecordBatch) {
+        assert_eq!(batch.num_rows(), 2);
+        let users: &StringArray = batch.column(0).as_any().downcast_ref().unwrap();
+        assert_eq!(users, &StringArray::from(vec!["alice", "bob"]));
+        let amounts: &Float64Array = batch.column(1).as_any().downcast_ref().unwrap();
+        assert_eq!(amounts, &Float64Array::from(vec![Some(5.5), None]));
+        let times: &TimestampMillisecondArray = batch.column(2).as_any().downcast_ref().unwrap();
+        assert_eq!(times, &TimestampMillisecondArray::from(vec![1000, 2000]));
+    }
+
+    #[tokio::test]
+    async fn test_read_json_messages() {
+        let cluster = MockCluster::new(1).unwrap();
+        cluster.create_topic(TOPIC, 1, 1).unwrap();
+        produce(
+            &cluster,
+            vec![
+                br#"{"time": 1000, "user": "alice", "amount": 5.5}"#.to_vec(),
+                br#"{"time": 2000, "user": "bob"}"#.to_vec(),
+            ],
+        )
+        .await;
+
+        let config = config(
+            &cluster,
+            MessageFormat::Json,
+            Some(KafkaConfigSchema::AvroSchema(AVRO_SCHEMA.to_owned())),
+        );
+        let kafka_schema = get_kafka_schema(&config).await.unwrap();
+        let batch = read_batch(config, kafka_schema).await;
+        assert_purchases(&batch);
+    }
+
+    #[tokio::test]
+    async fn test_unconsumed_batch_is_read_again() {
+        let cluster = MockCluster::new(1).unwrap();
+        cluster.create_topic(TOPIC, 1, 1).unwrap();
+        produce(
+            &cluster,
+            vec![
+                br#"{"time": 1000, "user": "alice", "amount": 5.5}"#.to_vec(),
+                br#"{"time": 2000, "user": "bob"}"#.to_vec(),
+            ],
+        )
+        .await;
+
+        let config = config(
+            &cluster,
+            MessageFormat::Json,
+            Some(KafkaConfigSchema::AvroSchema(AVRO_SCHEMA.to_owned())),
+        );
+        let kafka_schema = get_kafka_schema(&config).await.unwrap();
+
+        // The offsets of a batch aren't committed until the next batch is
+        // requested, so a consumer stopping after producing a batch doesn't
+        // lose it.
+        let batch = read_batch(config.clone(), kafka_schema.clone()).await;
+        assert_purchases(&batch);
+        assert_eq!(committed_offset(&cluster), Offset::Invalid);
+
+        let batch = read_batch(config, kafka_schema).await;
+        assert_purchases(&batch);
+    }
+
+    #[tokio::test]
+    async fn test_consumed_batch_is_committed() {
+        let cluster = MockCluster::new(1).unwrap();
+        cluster.create_topic(TOPIC, 1, 1).unwrap();
+        produce(
+            &cluster,
+            vec![
+                br#"{"time": 1000, "user": "alice", "amount": 5.5}"#.to_vec(),
+                br#"{"time": 2000, "user": "bob"}"#.to_vec(),
+            ],
+        )
+        .await;
+
+        let config = config(
+            &cluster,
+            MessageFormat::Json,
+            Some(KafkaConfigSchema::AvroSchema(AVRO_SCHEMA.to_owned())),
+        );
+        let kafka_schema = get_kafka_schema(&config).await.unwrap();
+        let mut stream = stream(config, kafka_schema);
+        let batch = stream.next().await.unwrap().unwrap();
+        assert_purchases(&batch);
+
+        // Requesting the next batch commits the offsets of the first, but not
+        // those of the batch being returned.
+        produce(
+            &cluster,
+            vec![br#"{"time": 3000, "user": "carol", "amount": 1.5}"#.to_vec()],
+        )
+        .await;
+        let batch = stream.next().await.unwrap().unwrap();
+        assert_eq!(batch.num_rows(), 1);
+
+        // Commits are asynchronous, so wait for the offset to be committed.
+        let mut offset = committed_offset(&cluster);
+        for _ in 0..50 {
+            if offset != Offset::Invalid {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            offset = committed_offset(&cluster);
+        }
+        assert_eq!(offset, Offset::Offset(2));
+    }
+
+    #[tokio::test]
+    async fn test_infer_json_schema() {
+        let cluster = MockCluster::new(1).unwrap();
+        cluster.create_topic(TOPIC, 1, 1).unwrap();
+        produce(
+            &cluster,
+            vec![
+                br#"{"user": "alice", "amount": 5.5}"#.to_vec(),
+                br#"{"user": "bob", "count": 2}"#.to_vec(),
+            ],
+        )
+        .await;
+
+        let config = config(&cluster, MessageFormat::Json, None);
+        let kafka_schema = get_kafka_schema(&config).await.unwrap();
+        assert_eq!(
+            kafka_schema.schema,
+            Schema::new(vec![
+                Field::new("amount", DataType::Float64, true),
+                Field::new("user", DataType::Utf8, true),
+                Field::new("count", DataType::Int64, true),
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_read_avro_messages() {
+        let avro_schema = avro_rs::Schema::parse_str(AVRO_SCHEMA).unwrap();
+        let payloads = [("alice", Some(5.5), 1000), ("bob", None, 2000)]
+            .into_iter()
+            .map(|(user, amount, time)| {
+                let mut record = avro_rs::types::Record::new(&avro_schema).unwrap();
+                record.put("time", avro_rs::types::Value::Long(time));
+                record.put("user", user);
+                record.put(
+                    "amount",
+                    avro_rs::types::Value::Union(Box::new(match amount {
+                        Some(amount) => avro_rs::types::Value::Double(amount),
+                        None => avro_rs::types::Value::Null,
+                    })),
+                );
+                avro_rs::to_avro_datum(&avro_schema, record).unwrap()
+            })
+            .collect();
+
+        let cluster = MockCluster::new(1).unwrap();
+        cluster.create_topic(TOPIC, 1, 1).unwrap();
+        produce(&cluster, payloads).await;
+
+        let config = config(
+            &cluster,
+            MessageFormat::Avro,
+            Some(KafkaConfigSchema::AvroSchema(AVRO_SCHEMA.to_owned())),
+        );
+        let kafka_schema = get_kafka_schema(&config).await.unwrap();
+        let batch = read_batch(config, kafka_schema).await;
+        assert_purchases(&batch);
+    }
+
+    #[tokio::test]
+    async fn test_read_avro_messages_with_registry_header() {
+        let avro_schema = avro_rs::Schema::parse_str(AVRO_SCHEMA).unwrap();
+        let payloads = [("alice", Some(5.5), 1000), ("bob", None, 2000)]
+            .into_iter()
+            .map(|(user, amount, time)| {
+                let mut record = avro_rs::types::Record::new(&avro_schema).unwrap();
+                record.put("time", avro_rs::types::Value::Long(time));
+                record.put("user", user);
+                record.put(
+                    "amount",
+                    avro_rs::types::Value::Union(Box::new(match amount {
+                        Some(amount) => avro_rs::types::Value::Double(amount),
+                        None => avro_rs::types::Value::Null,
+                    })),
+                );
+                let mut payload = vec![REGISTRY_MAGIC_BYTE, 0, 0, 0, 1];
+                payload.extend(avro_rs::to_avro_datum(&avro_schema, record).unwrap());
+                payload
+            })
+            .collect();
+
+        let cluster = MockCluster::new(1).unwrap();
+        cluster.create_topic(TOPIC, 1, 1).unwrap();
+        produce(&cluster, payloads).await;
+
+        let config = config(&cluster, MessageFormat::Avro, None);
+        let kafka_schema = KafkaSchema {
+            schema: raw_schema().as_ref().clone(),
+            avro_schema: Some(avro_schema.clone()),
+            registry: Some(RegistrySchemas::new(UNREACHABLE_REGISTRY).with_schema(1, avro_schema)),
+        };
+        let batch = read_batch(config, kafka_schema).await;
+        assert_purchases(&batch);
+    }
+
+    #[tokio::test]
+    async fn test_read_avro_messages_with_older_writer_schema() {
+        // An older version of the schema, with a field that has since been
+        // removed. Messages written with it are resolved against the latest
+        // schema.
+        let writer_schema = avro_rs::Schema::parse_str(
+            r#"{
+                "type": "record",
+                "name": "Purchase",
+                "fields": [
+                    {"name": "time", "type": {"type": "long", "logicalType": "local-timestamp-millis"}},
+                    {"name": "store", "type": "string"},
+                    {"name": "user", "type": "string"},
+                    {"name": "amount", "type": ["null", "double"]}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let payloads = [("alice", Some(5.5), 1000), ("bob", None, 2000)]
+            .into_iter()
+            .map(|(user, amount, time)| {
+                let mut record = avro_rs::types::Record::new(&writer_schema).unwrap();
+                record.put("time", avro_rs::types::Value::Long(time));
+                record.put("store", "downtown");
+                record.put("user", user);
+                record.put(
+                    "amount",
+                    avro_rs::types::Value::Union(Box::new(match amount {
+                        Some(amount) => avro_rs::types::Value::Double(amount),
+                        None => avro_rs::types::Value::Null,
+                    })),
+                );
+                let mut payload = vec![REGISTRY_MAGIC_BYTE, 0, 0, 0, 1];
+                payload.extend(avro_rs::to_avro_datum(&writer_schema, record).unwrap());
+                payload
+            })
+            .collect();
+
+        let cluster = MockCluster::new(1).unwrap();
+        cluster.create_topic(TOPIC, 1, 1).unwrap();
+        produce(&cluster, payloads).await;
+
+        let config = config(&cluster, MessageFormat::Avro, None);
+        let avro_schema = avro_rs::Schema::parse_str(AVRO_SCHEMA).unwrap();
+        let registry = RegistrySchemas::new(UNREACHABLE_REGISTRY)
+            .with_schema(1, writer_schema)
+            .with_schema(2, avro_schema.clone());
+        let kafka_schema = KafkaSchema {
+            schema: raw_schema().as_ref().clone(),
+            avro_schema: Some(avro_schema),
+            registry: Some(registry),
+        };
+        let batch = read_batch(config, kafka_schema).await;
+        assert_purchases(&batch);
+    }
+
+    #[tokio::test]
+    async fn test_read_avro_messages_with_unknown_writer_schema() {
+        let avro_schema = avro_rs::Schema::parse_str(AVRO_SCHEMA).unwrap();
+        let mut record = avro_rs::types::Record::new(&avro_schema).unwrap();
+        record.put("time", avro_rs::types::Value::Long(1000));
+        record.put("user", "alice");
+        record.put(
+            "amount",
+            avro_rs::types::Value::Union(Box::new(avro_rs::types::Value::Null)),
+        );
+        let mut payload = vec![REGISTRY_MAGIC_BYTE, 0, 0, 0, 3];
+        payload.extend(avro_rs::to_avro_datum(&avro_schema, record).unwrap());
+
+        let cluster = MockCluster::new(1).unwrap();
+        cluster.create_topic(TOPIC, 1, 1).unwrap();
+        produce(&cluster, vec![payload]).await;
+
+        // Schema 3 isn't known, and can't be retrieved from the registry.
+        let config = config(&cluster, MessageFormat::Avro, None);
+        let kafka_schema = KafkaSchema {
+            schema: raw_schema().as_ref().clone(),
+            avro_schema: Some(avro_schema.clone()),
+            registry: Some(RegistrySchemas::new(UNREACHABLE_REGISTRY).with_schema(1, avro_schema)),
+        };
+        let error = stream(config, kafka_schema)
+            .next()
+            .await
+            .unwrap()
+            .unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("error retrieving writer schema 3"),
+            "unexpected error: {error}"
+        );
+    }
+}
@@ -5,7 +5,7 @@
 
 use lalrpop_util::lalrpop_mod;
 
-use crate::{ExprRef, FeatureSetPart, FenlType, Signature};
+use crate::{ExprRef, FeatureSetPart, FenlType, FormulaBody, Signature};
 
 lalrpop_mod!(
     #[allow(clippy::all)]
@@ -45,6 +45,15 @@ pub(crate) fn try_parse_expr(
     })
 }
 
+pub(crate) fn try_parse_formula(
+    part_id: FeatureSetPart,
+    input: &str,
+) -> Result<FormulaBody, ParseErrors<'_>> {
+    try_parse(input, |errors, lexer| {
+        grammar::FormulaParser::new().parse(part_id, errors, lexer)
+    })
+}
+
 pub(crate) fn try_parse_signature(
     part_id: FeatureSetPart,
     input: &str,
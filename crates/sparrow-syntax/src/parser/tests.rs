@@ -490,6 +490,30 @@ fn test_prefix_precedence() {
     assert_eq!(test_expr("5 + -3"), test_expr("5 + (-3)"));
 }
 
+#[test]
+fn test_math_operators() {
+    assert_eq!(test_expr("a % b"), test_expr("rem(a, b)"));
+    assert_eq!(test_expr("a & b"), test_expr("bitwise_and(a, b)"));
+    assert_eq!(test_expr("a ^ b"), test_expr("bitwise_xor(a, b)"));
+    assert_eq!(test_expr("~a"), test_expr("bitwise_not(a)"));
+    assert_eq!(test_expr("a << b"), test_expr("shift_left(a, b)"));
+    assert_eq!(test_expr("a >> b"), test_expr("shift_right(a, b)"));
+}
+
+#[test]
+fn test_math_operator_precedence() {
+    assert_eq!(test_expr("a % b * c"), test_expr("(a % b) * c"));
+    assert_eq!(test_expr("a + b % c"), test_expr("a + (b % c)"));
+    assert_eq!(test_expr("a + b & c"), test_expr("(a + b) & c"));
+    assert_eq!(test_expr("a ^ b & c"), test_expr("a ^ (b & c)"));
+    assert_eq!(test_expr("a & b << c"), test_expr("a & (b << c)"));
+    assert_eq!(test_expr("a << b + c"), test_expr("a << (b + c)"));
+    assert_eq!(test_expr("a << b >> c"), test_expr("(a << b) >> c"));
+    assert_eq!(test_expr("a >> b > c"), test_expr("(a >> b) > c"));
+    assert_eq!(test_expr("a ^ b < c"), test_expr("(a ^ b) < c"));
+    assert_eq!(test_expr("~a & b"), test_expr("(~a) & b"));
+}
+
 #[test]
 fn test_pipe_precedence() {
     // "1 + 2 | 3 * 4" == "(1 + 2) | (3 * 4)"
@@ -598,38 +622,38 @@ fn test_indexing_one_literal() {
       args: Arguments([
         Positional(Located(
           value: Expr(
-            op: Reference(Located(
-              value: "a",
+            op: Literal(Located(
+              value: Number("0"),
               location: Location(
                 part: Internal("a[0]"),
-                start: 0,
-                end: 1,
+                start: 2,
+                end: 3,
               ),
             )),
             args: Arguments([]),
           ),
           location: Location(
             part: Internal("a[0]"),
-            start: 0,
-            end: 1,
+            start: 2,
+            end: 3,
           ),
         )),
         Positional(Located(
           value: Expr(
-            op: Literal(Located(
-              value: Number("0"),
+            op: Reference(Located(
+              value: "a",
               location: Location(
                 part: Internal("a[0]"),
-                start: 2,
-                end: 3,
+                start: 0,
+                end: 1,
               ),
             )),
             args: Arguments([]),
           ),
           location: Location(
             part: Internal("a[0]"),
-            start: 2,
-            end: 3,
+            start: 0,
+            end: 1,
           ),
         )),
       ]),
@@ -653,111 +677,111 @@ fn test_indexing_with_expressions() {
         Positional(Located(
           value: Expr(
             op: Call(Located(
-              value: "index",
+              value: "add",
               location: Location(
                 part: Internal("a[0][1 + 1]"),
-                start: 1,
-                end: 4,
+                start: 7,
+                end: 8,
               ),
             )),
             args: Arguments([
               Positional(Located(
                 value: Expr(
-                  op: Reference(Located(
-                    value: "a",
+                  op: Literal(Located(
+                    value: Number("1"),
                     location: Location(
                       part: Internal("a[0][1 + 1]"),
-                      start: 0,
-                      end: 1,
+                      start: 5,
+                      end: 6,
                     ),
                   )),
                   args: Arguments([]),
                 ),
                 location: Location(
                   part: Internal("a[0][1 + 1]"),
-                  start: 0,
-                  end: 1,
+                  start: 5,
+                  end: 6,
                 ),
               )),
               Positional(Located(
                 value: Expr(
                   op: Literal(Located(
-                    value: Number("0"),
+                    value: Number("1"),
                     location: Location(
                       part: Internal("a[0][1 + 1]"),
-                      start: 2,
-                      end: 3,
+                      start: 9,
+                      end: 10,
                     ),
                   )),
                   args: Arguments([]),
                 ),
                 location: Location(
                   part: Internal("a[0][1 + 1]"),
-                  start: 2,
-                  end: 3,
+                  start: 9,
+                  end: 10,
                 ),
               )),
             ]),
           ),
           location: Location(
             part: Internal("a[0][1 + 1]"),
-            start: 0,
-            end: 4,
+            start: 5,
+            end: 10,
           ),
         )),
         Positional(Located(
           value: Expr(
             op: Call(Located(
-              value: "add",
+              value: "index",
               location: Location(
                 part: Internal("a[0][1 + 1]"),
-                start: 7,
-                end: 8,
+                start: 1,
+                end: 4,
               ),
             )),
             args: Arguments([
               Positional(Located(
                 value: Expr(
                   op: Literal(Located(
-                    value: Number("1"),
+                    value: Number("0"),
                     location: Location(
                       part: Internal("a[0][1 + 1]"),
-                      start: 5,
-                      end: 6,
+                      start: 2,
+                      end: 3,
                     ),
                   )),
                   args: Arguments([]),
                 ),
                 location: Location(
                   part: Internal("a[0][1 + 1]"),
-                  start: 5,
-                  end: 6,
+                  start: 2,
+                  end: 3,
                 ),
               )),
               Positional(Located(
                 value: Expr(
-                  op: Literal(Located(
-                    value: Number("1"),
+                  op: Reference(Located(
+                    value: "a",
                     location: Location(
                       part: Internal("a[0][1 + 1]"),
-                      start: 9,
-                      end: 10,
+                      start: 0,
+                      end: 1,
                     ),
                   )),
                   args: Arguments([]),
                 ),
                 location: Location(
                   part: Internal("a[0][1 + 1]"),
-                  start: 9,
-                  end: 10,
+                  start: 0,
+                  end: 1,
                 ),
               )),
             ]),
           ),
           location: Location(
             part: Internal("a[0][1 + 1]"),
-            start: 5,
-            end: 10,
+            start: 0,
+            end: 4,
           ),
         )),
       ]),
@@ -778,24 +802,6 @@ fn test_index_arithmetic() {
         ),
       )),
       args: Arguments([
-        Positional(Located(
-          value: Expr(
-            op: Reference(Located(
-              value: "a",
-              location: Location(
-                part: Internal("a[0 + 1]"),
-                start: 0,
-                end: 1,
-              ),
-            )),
-            args: Arguments([]),
-          ),
-          location: Location(
-            part: Internal("a[0 + 1]"),
-            start: 0,
-            end: 1,
-          ),
-        )),
         Positional(Located(
           value: Expr(
             op: Call(Located(
@@ -851,6 +857,24 @@ fn test_index_arithmetic() {
             end: 7,
           ),
         )),
+        Positional(Located(
+          value: Expr(
+            op: Reference(Located(
+              value: "a",
+              location: Location(
+                part: Internal("a[0 + 1]"),
+                start: 0,
+                end: 1,
+              ),
+            )),
+            args: Arguments([]),
+          ),
+          location: Location(
+            part: Internal("a[0 + 1]"),
+            start: 0,
+            end: 1,
+          ),
+        )),
       ]),
     )
     "###);
@@ -909,6 +933,24 @@ fn test_field_ref_with_indexing() {
         ),
       )),
       args: Arguments([
+        Positional(Located(
+          value: Expr(
+            op: Literal(Located(
+              value: Number("1"),
+              location: Location(
+                part: Internal("a[0].foo[1]"),
+                start: 9,
+                end: 10,
+              ),
+            )),
+            args: Arguments([]),
+          ),
+          location: Location(
+            part: Internal("a[0].foo[1]"),
+            start: 9,
+            end: 10,
+          ),
+        )),
         Positional(Located(
           value: Expr(
             op: FieldRef(Located(
@@ -937,38 +979,38 @@ fn test_field_ref_with_indexing() {
                   args: Arguments([
                     Positional(Located(
                       value: Expr(
-                        op: Reference(Located(
-                          value: "a",
+                        op: Literal(Located(
+                          value: Number("0"),
                           location: Location(
                             part: Internal("a[0].foo[1]"),
-                            start: 0,
-                            end: 1,
+                            start: 2,
+                            end: 3,
                           ),
                         )),
                         args: Arguments([]),
                       ),
                       location: Location(
                         part: Internal("a[0].foo[1]"),
-                        start: 0,
-                        end: 1,
+                        start: 2,
+                        end: 3,
                       ),
                     )),
                     Positional(Located(
                       value: Expr(
-                        op: Literal(Located(
-                          value: Number("0"),
+                        op: Reference(Located(
+                          value: "a",
                           location: Location(
                             part: Internal("a[0].foo[1]"),
-                            start: 2,
-                            end: 3,
+                            start: 0,
+                            end: 1,
                           ),
                         )),
                         args: Arguments([]),
                       ),
                       location: Location(
                         part: Internal("a[0].foo[1]"),
-                        start: 2,
-                        end: 3,
+                        start: 0,
+                        end: 1,
                       ),
                     )),
                   ]),
@@ -987,24 +1029,6 @@ fn test_field_ref_with_indexing() {
             end: 8,
           ),
         )),
-        Positional(Located(
-          value: Expr(
-            op: Literal(Located(
-              value: Number("1"),
-              location: Location(
-                part: Internal("a[0].foo[1]"),
-                start: 9,
-                end: 10,
-              ),
-            )),
-            args: Arguments([]),
-          ),
-          location: Location(
-            part: Internal("a[0].foo[1]"),
-            start: 9,
-            end: 10,
-          ),
-        )),
       ]),
     )
     "###);
@@ -2005,3 +2029,237 @@ fn test_parse_cast_with_pipe() {
     )
     "###);
 }
+
+#[test]
+fn test_parse_def() {
+    let expr = test_expr("def ratio(a, b: number, c: i64) = a / b + c in ratio(x, y, 5)");
+    let ExprOp::Def(name, signature, _) = expr.op() else {
+        panic!("Expected def, saw {expr:?}")
+    };
+    assert_eq!(name.inner(), "ratio");
+    assert_eq!(signature.name(), "ratio");
+
+    let parameters = signature.parameters();
+    let names: Vec<_> = parameters.names().iter().map(|n| n.inner()).collect();
+    assert_eq!(names, vec!["a", "b", "c"]);
+    let types: Vec<_> = parameters.types().iter().map(|t| t.to_string()).collect();
+    assert_eq!(types, vec!["a", "b", "i64"]);
+    assert_eq!(
+        signature.type_parameters,
+        vec![
+            TypeParameter::new("a".to_owned(), vec![TypeClass::Any]),
+            TypeParameter::new("b".to_owned(), vec![TypeClass::Number]),
+        ]
+    );
+
+    // The arguments are the body and the value.
+    assert_eq!(expr.args().len(), 2);
+    assert_eq!(expr.arg(0).unwrap().inner(), &test_expr("a / b + c"));
+    assert_eq!(expr.arg(1).unwrap().inner(), &test_expr("ratio(x, y, 5)"));
+}
+
+#[test]
+fn test_parse_def_nested() {
+    let expr = test_expr("def f(x) = x + 1 in def g(y) = f(y) * 2 in g(a)");
+    let ExprOp::Def(name, _, _) = expr.op() else {
+        panic!("Expected def, saw {expr:?}")
+    };
+    assert_eq!(name.inner(), "f");
+    assert!(matches!(
+        expr.arg(1).unwrap().op(),
+        ExprOp::Def(name, _, _) if name.inner() == "g"
+    ));
+}
+
+#[test]
+fn test_parse_def_duplicate_parameter() {
+    let input = "def f(x, x) = x in f(1, 2)";
+    let errors = Expr::try_from_str(FeatureSetPart::Internal(input), input).unwrap_err();
+    assert_eq!(
+        errors,
+        vec![lalrpop_util::ParseError::User {
+            error: (9, "Parameter 'x' is already defined for 'f'".to_owned(), 10)
+        }]
+    );
+}
+
+#[test]
+fn test_parse_def_invalid_parameter_type() {
+    let input = "def f(x: foo) = x in f(1)";
+    let errors = Expr::try_from_str(FeatureSetPart::Internal(input), input).unwrap_err();
+    assert_eq!(
+        errors,
+        vec![lalrpop_util::ParseError::User {
+            error: (9, "Invalid type 'foo' for parameter 'x'".to_owned(), 12)
+        }]
+    );
+}
+
+#[test]
+fn test_parse_case() {
+    let expr = test_expr("case { x > 10 => \"big\", x > 5 => \"medium\", _ => \"small\" }");
+    assert!(matches!(expr.op(), ExprOp::Case(_)));
+
+    // The arguments are the conditions and values, followed by the default.
+    assert_eq!(expr.args().len(), 5);
+    assert_eq!(expr.arg(0).unwrap().inner(), &test_expr("x > 10"));
+    assert_eq!(expr.arg(1).unwrap().inner(), &test_expr("\"big\""));
+    assert_eq!(expr.arg(2).unwrap().inner(), &test_expr("x > 5"));
+    assert_eq!(expr.arg(3).unwrap().inner(), &test_expr("\"medium\""));
+    assert_eq!(expr.arg(4).unwrap().inner(), &test_expr("\"small\""));
+}
+
+#[test]
+fn test_parse_case_without_default() {
+    let expr = test_expr("case { a | b => c, d => e | f, }");
+    assert!(matches!(expr.op(), ExprOp::Case(_)));
+
+    // The default is `null` when omitted.
+    assert_eq!(expr.args().len(), 5);
+    assert!(matches!(expr.arg(0).unwrap().op(), ExprOp::Pipe(_)));
+    assert_eq!(expr.arg(1).unwrap().inner(), &test_expr("c"));
+    assert_eq!(expr.arg(2).unwrap().inner(), &test_expr("d"));
+    assert!(matches!(expr.arg(3).unwrap().op(), ExprOp::Pipe(_)));
+    assert_eq!(expr.arg(4).unwrap().inner(), &test_expr("null"));
+}
+
+#[test]
+fn test_parse_case_default_not_last() {
+    let input = "case { _ => 1, x => 2 }";
+    let errors = Expr::try_from_str(FeatureSetPart::Internal(input), input).unwrap_err();
+    assert_eq!(
+        errors,
+        vec![lalrpop_util::ParseError::User {
+            error: (
+                15,
+                "Default arm must be the last arm of 'case'".to_owned(),
+                16
+            )
+        }]
+    );
+}
+
+#[test]
+fn test_parse_case_only_default() {
+    let input = "case { _ => 1 }";
+    let errors = Expr::try_from_str(FeatureSetPart::Internal(input), input).unwrap_err();
+    assert_eq!(
+        errors,
+        vec![lalrpop_util::ParseError::User {
+            error: (
+                0,
+                "'case' must have at least one conditional arm".to_owned(),
+                15
+            )
+        }]
+    );
+}
+
+#[test]
+fn test_parse_case_as_identifier() {
+    // `case` and `_` are only keywords within a `case` expression.
+    let expr = test_expr("Foo.case");
+    assert!(matches!(expr.op(), ExprOp::FieldRef(field, _) if field.inner() == "case"));
+    assert!(matches!(expr.arg(0).unwrap().op(), ExprOp::Reference(name) if name.inner() == "Foo"));
+
+    let expr = test_expr("{ case: case, _: _ }");
+    assert!(matches!(expr.op(), ExprOp::Record(..)));
+    assert!(matches!(expr.arg(0).unwrap().op(), ExprOp::Reference(name) if name.inner() == "case"));
+    assert!(matches!(expr.arg(1).unwrap().op(), ExprOp::Reference(name) if name.inner() == "_"));
+
+    assert!(is_valid_ident("case"));
+    assert!(is_valid_ident("_"));
+}
+
+#[test]
+fn test_parse_case_comments() {
+    let expr = test_expr("case # the size\n { x > 10 => 1, _ # otherwise\n => 2 }");
+    assert!(matches!(expr.op(), ExprOp::Case(_)));
+    assert_eq!(expr.args().len(), 3);
+    assert_eq!(expr.arg(0).unwrap().inner(), &test_expr("x > 10"));
+    assert_eq!(expr.arg(2).unwrap().inner(), &test_expr("2"));
+}
+
+#[test]
+fn test_index_expression_base() {
+    // `base[i]` passes the index first, matching `index(i, base)`.
+    let expr = test_expr("list_sort(l)[i + 1]");
+    assert!(matches!(expr.op(), ExprOp::Call(name) if name.inner() == "index"));
+    assert!(matches!(expr.arg(0).unwrap().op(), ExprOp::Call(name) if name.inner() == "add"));
+    assert!(matches!(expr.arg(1).unwrap().op(), ExprOp::Call(name) if name.inner() == "list_sort"));
+
+    let expr = test_expr("(l | last())[0]");
+    assert!(matches!(expr.op(), ExprOp::Call(name) if name.inner() == "index"));
+    assert!(matches!(expr.arg(0).unwrap().op(), ExprOp::Literal(_)));
+    assert!(matches!(expr.arg(1).unwrap().op(), ExprOp::Pipe(_)));
+}
+
+#[test]
+fn test_parse_lambda() {
+    let expr = test_expr("list_map(l, x -> x + 1)");
+    assert!(matches!(expr.op(), ExprOp::Call(name) if name.inner() == "list_map"));
+
+    let lambda = expr.arg(1).unwrap();
+    let ExprOp::Lambda(parameter, _) = lambda.op() else {
+        panic!("Expected lambda, saw {lambda:?}")
+    };
+    assert_eq!(parameter.inner(), "x");
+
+    // The only argument is the body.
+    assert_eq!(lambda.args().len(), 1);
+    assert_eq!(lambda.arg(0).unwrap().inner(), &test_expr("x + 1"));
+}
+
+#[test]
+fn test_parse_lambda_body_extends_right() {
+    let lambda = test_expr("x -> x.a | add(1) as f64");
+    assert!(matches!(lambda.op(), ExprOp::Lambda(_, _)));
+    assert!(matches!(lambda.arg(0).unwrap().op(), ExprOp::Cast(_, _)));
+
+    let nested = test_expr("x -> y -> x + y");
+    let ExprOp::Lambda(parameter, _) = nested.op() else {
+        panic!("Expected lambda, saw {nested:?}")
+    };
+    assert_eq!(parameter.inner(), "x");
+    let body = nested.arg(0).unwrap();
+    let ExprOp::Lambda(parameter, _) = body.op() else {
+        panic!("Expected lambda, saw {body:?}")
+    };
+    assert_eq!(parameter.inner(), "y");
+}
+
+fn test_formula(input: &'static str) -> FormulaBody {
+    FormulaBody::try_from_str(FeatureSetPart::Internal(input), input).unwrap()
+}
+
+#[test]
+fn test_parse_formula_function() {
+    let FormulaBody::Function(function) = test_formula("def ratio(a, b: number) = a / b") else {
+        panic!("Expected function definition")
+    };
+    assert_eq!(function.name().inner(), "ratio");
+    assert_eq!(function.signature().name(), "ratio");
+
+    let names: Vec<_> = function
+        .signature()
+        .arg_names()
+        .iter()
+        .map(|n| n.inner())
+        .collect();
+    assert_eq!(names, vec!["a", "b"]);
+    assert_eq!(function.body(), &test_expr("a / b"));
+}
+
+#[test]
+fn test_parse_formula_expr() {
+    // A definition followed by `in` is an expression, rather than a function.
+    let FormulaBody::Expr(expr) = test_formula("def f(x) = x + 1 in f(a)") else {
+        panic!("Expected expression")
+    };
+    assert!(matches!(expr.op(), ExprOp::Def(name, _, _) if name.inner() == "f"));
+
+    let FormulaBody::Expr(expr) = test_formula("a + 1") else {
+        panic!("Expected expression")
+    };
+    assert_eq!(expr, test_expr("a + 1"));
+}
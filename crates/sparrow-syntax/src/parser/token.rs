@@ -51,6 +51,8 @@ pub enum Token<'input> {
     KwAs,
     #[token("const")]
     KwConst,
+    #[token("def")]
+    KwDef,
 
     // Lex literals.
     #[regex("[0-9]+([.][0-9]+)?(([ui]8)|([ui]16)|([ufi]32)|([ufi]64))?", |lex| { LiteralValue::Number(lex.slice().to_owned()) })]
@@ -76,6 +78,18 @@ pub enum Token<'input> {
     #[token("/")]
     SymSlash,
 
+    #[token("%")]
+    SymPercent,
+
+    #[token("&")]
+    SymAmpersand,
+
+    #[token("^")]
+    SymCaret,
+
+    #[token("~")]
+    SymTilde,
+
     #[token("|")]
     SymPipe,
 
@@ -98,6 +112,10 @@ pub enum Token<'input> {
     SymLte,
     #[token(">=")]
     SymGte,
+    #[token("<<")]
+    SymLtLt,
+    #[token(">>")]
+    SymGtGt,
 
     #[token(".")]
     SymDot,
@@ -129,6 +147,8 @@ pub enum Token<'input> {
     SymColon,
     #[token("->")]
     SymSingleArrow,
+    #[token("=>")]
+    SymFatArrow,
 
     #[error]
     // Skip whitespace
@@ -150,12 +170,17 @@ impl<'a> Display for Token<'a> {
             Token::KwInput => write!(f, "$input"),
             Token::KwAs => write!(f, "as"),
             Token::KwConst => write!(f, "const"),
+            Token::KwDef => write!(f, "def"),
             Token::Literal(literal) => write!(f, "{literal}"),
             Token::Ident(ident) => write!(f, "{ident}"),
             Token::SymPlus => write!(f, "+"),
             Token::SymMinus => write!(f, "-"),
             Token::SymStar => write!(f, "*"),
             Token::SymSlash => write!(f, "/"),
+            Token::SymPercent => write!(f, "%"),
+            Token::SymAmpersand => write!(f, "&"),
+            Token::SymCaret => write!(f, "^"),
+            Token::SymTilde => write!(f, "~"),
             Token::SymPipe => write!(f, "|"),
             Token::SymEquals => write!(f, "="),
             Token::SymDoubleEquals => write!(f, "=="),
@@ -165,6 +190,8 @@ impl<'a> Display for Token<'a> {
             Token::SymGt => write!(f, ">"),
             Token::SymLte => write!(f, "<="),
             Token::SymGte => write!(f, ">="),
+            Token::SymLtLt => write!(f, "<<"),
+            Token::SymGtGt => write!(f, ">>"),
             Token::SymDot => write!(f, "."),
             Token::SymComma => write!(f, ","),
             Token::SymLParen => write!(f, "("),
@@ -176,6 +203,7 @@ impl<'a> Display for Token<'a> {
             Token::SymExclamation => write!(f, "!"),
             Token::SymColon => write!(f, ":"),
             Token::SymSingleArrow => write!(f, "->"),
+            Token::SymFatArrow => write!(f, "=>"),
             Token::Unrecognized(s) => write!(f, "{s}"),
             Token::Error => write!(f, "ERROR"),
         }
@@ -17,7 +17,7 @@ use crate::{FenlType, Located, Location};
 /// The parameter names for a single method must be unique.
 ///
 /// Required parameters must appear before optional parameters.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(test, derive(serde::Serialize))]
 pub struct Parameters<T> {
     /// The names of each parameter.
@@ -4,8 +4,8 @@ use std::sync::Arc;
 use codespan_reporting::diagnostic::Label;
 use static_init::dynamic;
 
-use crate::parser::try_parse_expr;
-use crate::{ArgVec, Arguments, FenlType, LiteralValue, ParseErrors, Resolved};
+use crate::parser::{try_parse_expr, try_parse_formula};
+use crate::{ArgVec, Arguments, FenlType, LiteralValue, ParseErrors, Resolved, Signature};
 
 /// Identifies a specific part of a feature set query.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -235,7 +235,7 @@ pub struct Expr {
 ///
 /// A resolved expression has verified its signature against
 /// the arguments given.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ResolvedExpr {
     pub op: ExprOp,
     // The resolved arguments to the expression.
@@ -277,6 +277,20 @@ pub enum ExprOp {
     /// and then the value is evaluated and returned. Earlier bindings are
     /// available to the expressions of later bindings.
     Let(ArgVec<Located<String>>, Location),
+    /// A function definition. Specifies the name and signature of the
+    /// function. Should have two arguments -- the body of the function and
+    /// the value in which the function may be called.
+    ///
+    /// The body is evaluated at each call, with the parameters bound to the
+    /// arguments of that call.
+    Def(Located<String>, Box<Signature>, Location),
+    /// A lambda. Specifies the name of the parameter. Should have one
+    /// argument -- the body of the lambda.
+    ///
+    /// Lambdas may only be passed to functions which apply them to the
+    /// elements of a list, such as `list_map`. The body is evaluated with the
+    /// parameter bound to each element.
+    Lambda(Located<String>, Location),
     /// A record expression. Creates a record with the given field names.
     /// Each argument corresponds to a record.
     Record(ArgVec<Located<String>>, Location),
@@ -298,6 +312,13 @@ pub enum ExprOp {
     SelectFields(Location),
     /// Cast the input to the given type.
     Cast(Located<FenlType>, Location),
+    /// A case expression.
+    ///
+    /// The arguments are pairs of conditions and values, followed by the
+    /// default value. The result is the value of the first arm whose condition
+    /// is `true`, or the default if no condition is `true`. If the default arm
+    /// is omitted, the default is `null`.
+    Case(Location),
     /// Indicates an error parsing an expression.
     Error,
 }
@@ -393,6 +414,40 @@ impl Expr {
         }
     }
 
+    /// Create a function definition.
+    ///
+    /// Returns the location and message of an error if the parameters are
+    /// invalid.
+    pub fn try_new_def(
+        name: Located<&str>,
+        parameters: ArgVec<(Located<&str>, Option<Located<FenlType>>)>,
+        body: Located<ExprRef>,
+        value: Located<ExprRef>,
+        location: Location,
+    ) -> Result<Expr, (Location, String)> {
+        let signature = Signature::try_new_user_defined(name.inner(), parameters, &location)?;
+        Ok(Expr {
+            op: ExprOp::Def(
+                name.transform(ToOwned::to_owned),
+                Box::new(signature),
+                location,
+            ),
+            args: [body, value].into_iter().collect(),
+        })
+    }
+
+    /// Create a lambda with the given parameter and body.
+    pub fn new_lambda(
+        parameter: Located<&str>,
+        body: Located<ExprRef>,
+        location: Location,
+    ) -> Expr {
+        Expr {
+            op: ExprOp::Lambda(parameter.transform(ToOwned::to_owned), location),
+            args: [body].into_iter().collect(),
+        }
+    }
+
     pub fn new_record(
         fields: ArgVec<(Located<&str>, Located<ExprRef>)>,
         location: Location,
@@ -409,6 +464,50 @@ impl Expr {
         }
     }
 
+    /// Create a case expression.
+    ///
+    /// Each arm is a condition (or `None` for the default arm) and a value.
+    /// Returns the location and message of an error if the arms are invalid.
+    pub fn try_new_case(
+        arms: ArgVec<(Option<Located<ExprRef>>, Located<ExprRef>)>,
+        location: Location,
+    ) -> Result<Expr, (Location, String)> {
+        let mut args = ArgVec::with_capacity(arms.len() * 2 + 1);
+        let mut default = None;
+        for (condition, value) in arms {
+            if default.is_some() {
+                let location = condition.as_ref().unwrap_or(&value).location().clone();
+                return Err((
+                    location,
+                    "Default arm must be the last arm of 'case'".to_owned(),
+                ));
+            }
+
+            match condition {
+                Some(condition) => {
+                    args.push(condition);
+                    args.push(value);
+                }
+                None => default = Some(value),
+            }
+        }
+
+        if args.is_empty() {
+            return Err((
+                location,
+                "'case' must have at least one conditional arm".to_owned(),
+            ));
+        }
+
+        let default = default.unwrap_or_else(|| {
+            let null = Expr::literal(Located::new(LiteralValue::Null, location.clone()));
+            Located::new(Arc::new(null), location.clone())
+        });
+        args.push(default);
+
+        Ok(Expr::new(ExprOp::Case(location), args))
+    }
+
     /// Parse the expression from a string.
     pub fn try_from_str(part_id: FeatureSetPart, input: &str) -> Result<ExprRef, ParseErrors<'_>> {
         try_parse_expr(part_id, input)
@@ -426,3 +525,66 @@ impl Expr {
         self.args.get(index).map(|arg| arg.value())
     }
 }
+
+/// The contents of a formula in a feature set.
+///
+/// Most formulas are expressions, the value of which is bound to the name of
+/// the formula. A formula may instead define a function, which may be called
+/// from the query and other formulas.
+#[derive(Debug)]
+pub enum FormulaBody {
+    Expr(ExprRef),
+    Function(Box<FunctionDef>),
+}
+
+impl FormulaBody {
+    /// Parse the formula from a string.
+    pub fn try_from_str(
+        part_id: FeatureSetPart,
+        input: &str,
+    ) -> Result<FormulaBody, ParseErrors<'_>> {
+        try_parse_formula(part_id, input)
+    }
+}
+
+/// A function defined by a formula, such as `def ratio(a, b) = a / b`.
+///
+/// Unlike `ExprOp::Def`, this has no value in which the function is called.
+#[derive(Debug)]
+pub struct FunctionDef {
+    name: Located<String>,
+    signature: Signature,
+    body: ExprRef,
+}
+
+impl FunctionDef {
+    /// Create a function definition.
+    ///
+    /// Returns the location and message of an error if the parameters are
+    /// invalid.
+    pub fn try_new(
+        name: Located<&str>,
+        parameters: ArgVec<(Located<&str>, Option<Located<FenlType>>)>,
+        body: ExprRef,
+        location: Location,
+    ) -> Result<FunctionDef, (Location, String)> {
+        let signature = Signature::try_new_user_defined(name.inner(), parameters, &location)?;
+        Ok(FunctionDef {
+            name: name.transform(ToOwned::to_owned),
+            signature,
+            body,
+        })
+    }
+
+    pub fn name(&self) -> &Located<String> {
+        &self.name
+    }
+
+    pub fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    pub fn body(&self) -> &ExprRef {
+        &self.body
+    }
+}
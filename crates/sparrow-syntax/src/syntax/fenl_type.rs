@@ -143,6 +143,8 @@ pub enum TypeClass {
     Signed,
     /// Any floating point numeric type.
     Float,
+    /// Any integer numeric type.
+    Int,
     /// Any time delta.
     TimeDelta,
     /// Any ordered type. This includes numbers and timestamps.
@@ -162,6 +164,7 @@ impl Display for TypeClass {
             TypeClass::Any => fmt.write_str("any"),
             TypeClass::Signed => fmt.write_str("signed"),
             TypeClass::Float => fmt.write_str("float"),
+            TypeClass::Int => fmt.write_str("int"),
             TypeClass::TimeDelta => fmt.write_str("timedelta"),
             TypeClass::Ordered => fmt.write_str("ordered"),
             TypeClass::Error => fmt.write_str("error"),
@@ -179,6 +182,7 @@ impl FromStr for TypeClass {
             "any" => Ok(TypeClass::Any),
             "signed" => Ok(TypeClass::Signed),
             "float" => Ok(TypeClass::Float),
+            "int" => Ok(TypeClass::Int),
             "timedelta" => Ok(TypeClass::TimeDelta),
             "ordered" => Ok(TypeClass::Ordered),
             _ => Err(TypeClass::Error),
@@ -192,6 +196,8 @@ impl FromStr for TypeClass {
 pub enum WindowBehavior {
     Since,
     Sliding,
+    Trailing,
+    Session,
 }
 
 impl WindowBehavior {
@@ -199,6 +205,21 @@ impl WindowBehavior {
         match self {
             Self::Since => "since",
             Self::Sliding => "sliding",
+            Self::Trailing => "trailing",
+            Self::Session => "session",
+        }
+    }
+
+    /// Returns the window behavior with the given label, if any.
+    ///
+    /// This is the inverse of [WindowBehavior::label].
+    pub fn from_label(label: &str) -> Option<Self> {
+        match label {
+            "since" => Some(Self::Since),
+            "sliding" => Some(Self::Sliding),
+            "trailing" => Some(Self::Trailing),
+            "session" => Some(Self::Session),
+            _ => None,
         }
     }
 }
@@ -376,7 +397,9 @@ impl FenlType {
 #[cfg(test)]
 mod tests {
     use crate::{Collection, FeatureSetPart, FenlType};
-    use arrow::datatypes::DataType;
+    use std::sync::Arc;
+
+    use arrow::datatypes::{DataType, Field};
 
     #[test]
     fn test_parse() {
@@ -389,7 +412,7 @@ mod tests {
         let i64 = FenlType::Concrete(DataType::Int64);
         let t = FenlType::TypeRef(crate::TypeVariable("T".to_owned()));
         assert_eq!(parse("i32"), i32);
-        assert_eq!(parse("i64"), i64);
+        assert_eq!(parse("i64"), i64.clone());
         assert_eq!(
             parse("map<i32, i64>"),
             FenlType::Collection(Collection::Map, vec![i32.clone(), i64.clone()]).normalize()
@@ -402,7 +425,7 @@ mod tests {
             parse("list<list<i32>>"),
             FenlType::Collection(
                 Collection::List,
-                vec![FenlType::Collection(Collection::List, vec![i32]).normalize()]
+                vec![FenlType::Collection(Collection::List, vec![i32.clone()]).normalize()]
             )
             .normalize()
         );
@@ -413,5 +436,52 @@ mod tests {
                 vec![FenlType::Collection(Collection::List, vec![t])]
             )
         );
+        assert_eq!(
+            parse("map<i64, list<i32>>"),
+            FenlType::Collection(
+                Collection::Map,
+                vec![
+                    i64,
+                    FenlType::Collection(Collection::List, vec![i32.clone()]).normalize()
+                ]
+            )
+            .normalize()
+        );
+        assert_eq!(
+            parse("list<list<list<i32>>>"),
+            parse("list< list< list<i32> > >")
+        );
+        assert_eq!(
+            parse("list<list<list<list<i32>>>>"),
+            parse("list< list< list< list<i32> > > >")
+        );
+    }
+
+    #[test]
+    fn test_parse_struct() {
+        let parse = |input| {
+            let part_id = FeatureSetPart::Internal(input);
+            crate::parser::try_parse_type(part_id, input)
+        };
+
+        let list_i64 = DataType::List(Arc::new(Field::new("item", DataType::Int64, true)));
+        let inner = DataType::Struct(vec![Field::new("c", list_i64, true)].into());
+        assert_eq!(
+            parse("{a: i64, b: {c: list<i64>}}").unwrap(),
+            FenlType::Concrete(DataType::Struct(
+                vec![
+                    Field::new("a", DataType::Int64, true),
+                    Field::new("b", inner, true)
+                ]
+                .into()
+            ))
+        );
+        assert_eq!(
+            parse("{}").unwrap(),
+            FenlType::Concrete(DataType::Struct(Vec::<Field>::new().into()))
+        );
+
+        // Fields must have concrete types.
+        assert!(parse("{a: T}").is_err());
     }
 }
@@ -1,8 +1,15 @@
+use std::str::FromStr;
+
+use bitvec::prelude::BitVec;
 use hashbrown::HashSet;
 use itertools::Itertools;
+use smallvec::smallvec;
 
 use crate::parser::try_parse_signature;
-use crate::{ExprRef, FeatureSetPart, FenlType, Located, Parameters, ParseErrors, TypeClass};
+use crate::{
+    ArgVec, ExprRef, FeatureSetPart, FenlType, Located, Location, Parameters, ParseErrors,
+    TypeClass,
+};
 
 /// Type variable defined by a signature
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -49,7 +56,7 @@ impl TypeParameter {
 }
 
 /// The signature of an operator or function.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(test, derive(serde::Serialize))]
 pub struct Signature {
     /// The name of the operator or function.
@@ -153,6 +160,88 @@ impl Signature {
         })
     }
 
+    /// Create the signature of a user-defined function.
+    ///
+    /// Each parameter may be annotated with a concrete type or a type class,
+    /// such as `number`. Parameters without a type accept `any` value. Each
+    /// type class is given a type variable named after the parameter, so the
+    /// arguments need not have the same type.
+    ///
+    /// The result type depends on the body of the function, which is checked
+    /// at each call. Since it can't be determined from the signature alone, the
+    /// result is [FenlType::Error].
+    ///
+    /// Returns the location and message of an error if the parameters are
+    /// invalid.
+    pub(crate) fn try_new_user_defined(
+        name: &str,
+        parameters: ArgVec<(Located<&str>, Option<Located<FenlType>>)>,
+        location: &Location,
+    ) -> Result<Self, (Location, String)> {
+        let mut names: ArgVec<Located<String>> = ArgVec::with_capacity(parameters.len());
+        let mut types = ArgVec::with_capacity(parameters.len());
+        let mut type_parameters = Vec::new();
+        for (parameter, fenl_type) in parameters {
+            if names.iter().any(|name| name.inner() == parameter.inner()) {
+                return Err((
+                    parameter.location().clone(),
+                    format!("Parameter '{parameter}' is already defined for '{name}'"),
+                ));
+            }
+
+            let fenl_type = match fenl_type {
+                None => {
+                    type_parameters.push(TypeParameter::new(
+                        parameter.to_string(),
+                        vec![TypeClass::Any],
+                    ));
+                    parameter.with_value(FenlType::TypeRef(TypeVariable(parameter.to_string())))
+                }
+                Some(fenl_type) => match fenl_type.inner() {
+                    FenlType::TypeRef(type_class) => {
+                        let type_class = TypeClass::from_str(&type_class.0).map_err(|_| {
+                            (
+                                fenl_type.location().clone(),
+                                format!("Invalid type '{type_class}' for parameter '{parameter}'"),
+                            )
+                        })?;
+                        type_parameters
+                            .push(TypeParameter::new(parameter.to_string(), vec![type_class]));
+                        fenl_type.with_value(FenlType::TypeRef(TypeVariable(parameter.to_string())))
+                    }
+                    FenlType::Collection(_, _) => {
+                        return Err((
+                            fenl_type.location().clone(),
+                            format!("Invalid type '{fenl_type}' for parameter '{parameter}'"),
+                        ));
+                    }
+                    _ => fenl_type,
+                },
+            };
+
+            names.push(parameter.transform(ToOwned::to_owned));
+            types.push(fenl_type);
+        }
+
+        let num_parameters = names.len();
+        Parameters::try_new(
+            names,
+            BitVec::repeat(false, num_parameters),
+            types,
+            smallvec![None; num_parameters],
+            BitVec::repeat(false, num_parameters),
+        )
+        .and_then(|parameters| {
+            Self::try_new(
+                name.to_owned(),
+                parameters,
+                type_parameters,
+                FenlType::Error,
+            )
+        })
+        .map_err(|e| (location.clone(), e.to_string()))
+    }
+
     pub fn try_from_str(part_id: FeatureSetPart, input: &str) -> Result<Self, ParseErrors<'_>> {
         try_parse_signature(part_id, input)
     }
@@ -1109,19 +1109,25 @@ def _aggregation(
         If the window is not a known type.
     """
     if window is None:
-        return Timestream._call(op, input, *args, None, None)
+        return Timestream._call(op, input, *args, None, None, None)
     elif isinstance(window, kt.windows.Since):
-        return Timestream._call(op, input, *args, window.predicate, None)
+        return Timestream._call(op, input, *args, window.predicate, None, "since")
     elif isinstance(window, kt.windows.Sliding):
-        return Timestream._call(op, input, *args, window.predicate, window.duration)
+        return Timestream._call(
+            op, input, *args, window.predicate, window.duration, "sliding"
+        )
     elif isinstance(window, kt.windows.Trailing):
         if op != "collect":
             raise NotImplementedError(
                 f"Aggregation '{op} does not support trailing windows"
             )
         trailing_ns = int(window.duration.total_seconds() * 1e9)
-        # HACK: Use null predicate and number of nanoseconds to encode trailing windows.
-        return Timestream._call(op, input, *args, None, trailing_ns)
+        return Timestream._call(op, input, *args, None, trailing_ns, "trailing")
+    elif isinstance(window, kt.windows.Session):
+        gap_ns = int(window.gap.total_seconds() * 1e9)
+        # Each input event extends the session, even if the input is null.
+        events = input.time_of().is_not_null()
+        return Timestream._call(op, input, *args, events, gap_ns, "session")
     else:
         raise NotImplementedError(f"Unknown window type {window!r}")
 
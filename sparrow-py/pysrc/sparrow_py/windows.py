@@ -72,3 +72,26 @@ class Trailing(Window):
         """Validate the window parameters."""
         if self.duration <= timedelta(0):
             raise ValueError("duration must be positive")
+
+
+@dataclass(frozen=True)
+class Session(Window):
+    """
+    Window containing the inputs since the last gap in activity.
+
+    A new session starts when an input occurs more than `gap` after the
+    previous input for the same entity. Inputs with null values still extend
+    the session.
+
+    Parameters
+    ----------
+    gap : timedelta
+        The inactivity gap that ends a session.
+    """
+
+    gap: timedelta
+
+    def __post_init__(self):
+        """Validate the window parameters."""
+        if self.gap <= timedelta(0):
+            raise ValueError("gap must be positive")
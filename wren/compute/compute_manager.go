@@ -279,6 +279,7 @@ func (m *computeManager) processMaterializations(requestCtx context.Context, own
 				ObjectStore: &v1alpha.ObjectStoreDestination{
 					FileType:        kind.ObjectStore.GetFileType(),
 					OutputPrefixUri: vfs_utils.EnsureTrailingSlash(outputPrefixUri),
+					Partitioning:    kind.ObjectStore.GetPartitioning(),
 				},
 			}
 		case *v1alpha.Destination_Pulsar:
@@ -1,6 +1,8 @@
 package service
 
 import (
+	"fmt"
+
 	"github.com/rs/zerolog"
 	"google.golang.org/genproto/googleapis/rpc/errdetails"
 	"google.golang.org/grpc/codes"
@@ -81,3 +83,17 @@ func getAnalysisFromCompileResponse(compileResp *v1alpha.CompileResponse) *v1alp
 		MissingNames:    compileResp.MissingNames,
 	}
 }
+
+// maxKeyBuckets is the maximum number of key buckets when partitioning
+// object store output, matching the limit in the compute engine.
+const maxKeyBuckets = 1024
+
+// validatePartitioning returns an error if the partitioning of an object
+// store destination is invalid.
+func validatePartitioning(objectStore *v1alpha.ObjectStoreDestination) error {
+	keyBuckets := objectStore.GetPartitioning().GetKeyBuckets()
+	if keyBuckets > maxKeyBuckets {
+		return customerrors.NewInvalidArgumentErrorWithCustomText(fmt.Sprintf("partitioning: key_buckets %d is larger than the maximum of %d", keyBuckets, maxKeyBuckets))
+	}
+	return nil
+}
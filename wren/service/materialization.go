@@ -174,6 +174,9 @@ func (s *materializationService) createMaterialization(ctx context.Context, owne
 	case *v1alpha.Destination_ObjectStore:
 		// ensure output prefix has an ending slash
 		if kind.ObjectStore != nil {
+			if err := validatePartitioning(kind.ObjectStore); err != nil {
+				return nil, err
+			}
 			kind.ObjectStore.OutputPrefixUri = vfs_utils.EnsureTrailingSlash(kind.ObjectStore.OutputPrefixUri)
 			request.Materialization.Destination.Destination = kind
 		}
@@ -237,6 +237,7 @@ func (q *queryV1Service) CreateQuery(request *v1alpha.CreateQueryRequest, respon
 				ObjectStore: &v1alpha.ObjectStoreDestination{
 					FileType:        kind.ObjectStore.FileType,
 					OutputPrefixUri: outputURI,
+					Partitioning:    kind.ObjectStore.Partitioning,
 				},
 			}
 		default:
@@ -342,6 +343,7 @@ func (q *queryV1Service) CreateQuery(request *v1alpha.CreateQueryRequest, respon
 						ObjectStore: &v1alpha.ObjectStoreDestination{
 							FileType:        kind.ObjectStore.FileType,
 							OutputPrefixUri: outputURI,
+							Partitioning:    kind.ObjectStore.Partitioning,
 							OutputPaths: &v1alpha.ObjectStoreDestination_ResultPaths{
 								Paths: outputPaths,
 							},
@@ -420,8 +422,11 @@ func (q *queryV1Service) validateOutputTo(ctx context.Context, query *v1alpha.Qu
 	} else {
 		switch kind := query.Destination.Destination.(type) {
 		case *v1alpha.Destination_ObjectStore:
+			if err := validatePartitioning(kind.ObjectStore); err != nil {
+				return err
+			}
 			switch kind.ObjectStore.FileType {
-			case v1alpha.FileType_FILE_TYPE_PARQUET, v1alpha.FileType_FILE_TYPE_CSV:
+			case v1alpha.FileType_FILE_TYPE_PARQUET, v1alpha.FileType_FILE_TYPE_CSV, v1alpha.FileType_FILE_TYPE_ARROW, v1alpha.FileType_FILE_TYPE_ARROW_STREAM, v1alpha.FileType_FILE_TYPE_JSON:
 				return nil
 			default:
 				subLogger.Warn().Interface("kind", kind).Interface("type", kind.ObjectStore.FileType).Msg("unknown output_to file_type, defaulting to 'ObjectStore->Parquet'")
@@ -247,3 +247,49 @@ func Test_queryV1Service_listQueries(t *testing.T) {
 		})
 	}
 }
+
+func Test_queryV1Service_validateOutputTo(t *testing.T) {
+	objectStoreQuery := func(keyBuckets uint32) *v1alpha.Query {
+		return &v1alpha.Query{
+			Destination: &v1alpha.Destination{
+				Destination: &v1alpha.Destination_ObjectStore{
+					ObjectStore: &v1alpha.ObjectStoreDestination{
+						FileType: v1alpha.FileType_FILE_TYPE_CSV,
+						Partitioning: &v1alpha.ObjectStoreDestination_Partitioning{
+							KeyBuckets: keyBuckets,
+						},
+					},
+				},
+			},
+		}
+	}
+
+	tests := []struct {
+		name    string
+		query   *v1alpha.Query
+		wantErr error
+	}{
+		{
+			name:  "accepts the maximum number of key buckets",
+			query: objectStoreQuery(1024),
+		},
+		{
+			name:    "rejects more than the maximum number of key buckets",
+			query:   objectStoreQuery(1025),
+			wantErr: fmt.Errorf("partitioning: key_buckets 1025 is larger than the maximum of 1024"),
+		},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			q := &queryV1Service{}
+			err := q.validateOutputTo(context.Background(), tt.query)
+			if tt.wantErr == nil {
+				if err != nil {
+					t.Errorf("queryService.validateOutputTo() error = %v, want nil", err)
+				}
+			} else if err == nil || err.Error() != tt.wantErr.Error() {
+				t.Errorf("queryService.validateOutputTo() error = %v, want %v", err, tt.wantErr)
+			}
+		})
+	}
+}